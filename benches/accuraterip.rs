@@ -8,6 +8,8 @@ use brunch::{
 };
 use cdtoc::{
 	AccurateRip,
+	OffsetMatrix,
+	SignedDuration,
 	Toc,
 };
 
@@ -18,10 +20,48 @@ fn main() {
 		.expect("Failed to parse CDTOC.");
 	let ar = toc.accuraterip_id();
 
+	// A reusable buffer for the write_* methods.
+	let mut buf = String::with_capacity(84);
+
+	// A fake checksum bin with a handful of chunks.
+	let mut bin = Vec::new();
+	for chunk in 0..8_u32 {
+		bin.extend_from_slice(ar.as_ref());
+		for track in 0..16_u32 {
+			bin.push(10 + chunk as u8);
+			bin.extend_from_slice(&(track * 1000 + chunk % 3 + 1).to_le_bytes());
+			bin.extend_from_slice(&[0, 0, 0, 0]);
+		}
+	}
+
+	// A (short) three-track disc worth of noise for the offset matrix.
+	let short = Toc::from_cdtoc("3+96+2EE+546+79E").expect("Failed to parse CDTOC.");
+	let mut seed: u64 = 0x1234_5678;
+	let samples: Vec<u32> = (0..(0x79E - 0x96) * 588)
+		.map(|_| xorshift(&mut seed) as u32)
+		.collect();
+	let one = [SignedDuration::ZERO];
+	let few: Vec<SignedDuration> = (-8..8).map(|n| SignedDuration::from_samples(n * 6)).collect();
+	let all: Vec<SignedDuration> = (-2940..=2940).map(SignedDuration::from_samples).collect();
+
 	benches!(
 		inline:
 		Bench::new("Toc::accuraterip_id").run(|| toc.accuraterip_id()),
 		Bench::new("AccurateRip::to_string").run(|| ar.to_string()),
+		Bench::new("AccurateRip::write_id").run(|| {
+			buf.truncate(0);
+			let _res = ar.write_id(&mut buf);
+			buf.len()
+		}),
+
+		Bench::spacer(),
+
+		Bench::new("AccurateRip::checksum_url").run(|| ar.checksum_url()),
+		Bench::new("AccurateRip::write_checksum_url").run(|| {
+			buf.truncate(0);
+			let _res = ar.write_checksum_url(&mut buf);
+			buf.len()
+		}),
 
 		Bench::spacer(),
 
@@ -31,5 +71,55 @@ fn main() {
 		Bench::spacer(),
 
 		Bench::new("AccurateRip::cddb_id").run(|| ar.cddb_id()),
+
+		Bench::spacer(),
+
+		Bench::new("AccurateRip::parse_checksums").run(|| ar.parse_checksums(&bin)),
+		Bench::new("AccurateRip::checksum_chunks").run(|| {
+			let mut best = [0_u8; 16];
+			for chunk in ar.checksum_chunks(&bin) {
+				let Ok(chunk) = chunk else { break; };
+				for (b, (_, conf)) in best.iter_mut().zip(chunk.iter()) {
+					if *b < conf { *b = conf; }
+				}
+			}
+			best
+		}),
+
+		Bench::spacer(),
+
+		Bench::new("OffsetMatrix::push(v1, 1 offset)").run(|| {
+			let mut m = OffsetMatrix::new(&short, one);
+			m.push(&samples);
+			m
+		}),
+		Bench::new("OffsetMatrix::push(v1, 5881 offsets)").run(|| {
+			let mut m = OffsetMatrix::new(&short, all.iter().copied());
+			m.push(&samples);
+			m
+		}),
+		Bench::new("OffsetMatrix::push(v1+v2, 1 offset)").run(|| {
+			let mut m = OffsetMatrix::new(&short, one).with_v2();
+			m.push(&samples);
+			m
+		}),
+		Bench::new("OffsetMatrix::push(v1+v2, 16 offsets)").run(|| {
+			let mut m = OffsetMatrix::new(&short, few.iter().copied()).with_v2();
+			m.push(&samples);
+			m
+		}),
 	);
 }
+
+
+
+/// # Xorshift.
+///
+/// Advance a simple xorshift64 generator, returning the new state. (This is
+/// a copy of the library's test helper, which benchmarks can't see.)
+fn xorshift(seed: &mut u64) -> u64 {
+	*seed ^= *seed << 13;
+	*seed ^= *seed >> 7;
+	*seed ^= *seed << 17;
+	*seed
+}
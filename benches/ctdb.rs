@@ -10,15 +10,46 @@ use cdtoc::Toc;
 
 
 
+/// # A Full Disc.
+const TOC_99: &str = "63+96+12D9+5546+A8A2+CAAA+128BF+17194+171DF+1722A+17275+172C0+1730B+17356+173A1+173EC+17437+17482+174CD+17518+17563+175AE+175F9+17644+1768F+176DA+17725+17770+177BB+17806+17851+1789C+178E7+17932+1797D+179C8+17A13+17A5E+17AA9+17AF4+17B3F+17B8A+17BD5+17C20+17C6B+17CB6+17D01+17D4C+17D97+17DE2+17E2D+17E78+17EC3+17F0E+17F59+17FA4+17FEF+1803A+18085+180D0+1811B+18166+181B1+181FC+18247+18292+182DD+18328+18373+183BE+18409+18454+1849F+184EA+18535+18580+185CB+18616+18661+186AC+186F7+18742+1878D+187D8+18823+1886E+188B9+18904+1894F+1899A+189E5+18A30+18A7B+18AC6+18B11+18B5C+18BA7+18BF2+18C38+1ECDC+246E9";
+
+
+
 fn main() {
 	let toc = Toc::from_cdtoc("10+B6+5352+62AC+99D6+E218+12AC0+135E7+142E9+178B0+19D22+1B0D0+1E7FA+22882+247DB+27074+2A1BD+2C0FB")
 		.expect("Failed to parse CDTOC.");
 
 	let ctdb_id = toc.ctdb_id();
 
+	// The most tracks a disc can have.
+	let toc99 = Toc::from_cdtoc(TOC_99).expect("Failed to parse CDTOC.");
+
+	// A popular disc's worth of entries, highest confidence first.
+	let mut xml = String::from(r#"<?xml version="1.0" encoding="utf-8"?><ctdb>"#);
+	for n in 0..400_u32 {
+		xml.push_str(&format!(r#"<entry confidence="{}" crc32="{n:08x}" id="{n}" npar="8" stride="5880" trackcrcs=""#, 400 - n));
+		for track in 0..16_u32 {
+			if track != 0 { xml.push(' '); }
+			xml.push_str(&format!("{:08x}", track * 1000 + n % 7));
+		}
+		xml.push_str("\" />\n");
+	}
+	xml.push_str("</ctdb>");
+
 	benches!(
 		inline:
 		Bench::new("Toc::ctdb_id").run(|| toc.ctdb_id()),
+		Bench::new("Toc::ctdb_id(99)").run(|| toc99.ctdb_id()),
+		Bench::new("Toc::ctdb_hash_input(99)").run(|| toc99.ctdb_hash_input()),
 		Bench::new("ShaB64::pretty_print").run(|| ctdb_id.pretty_print()),
+		Bench::new("ShaB64::to_array").run(|| ctdb_id.to_array()),
+
+		Bench::spacer(),
+
+		Bench::new("Toc::ctdb_parse_checksums(400)").run(|| toc.ctdb_parse_checksums(&xml)),
+		Bench::new("Toc::ctdb_entries(400)").run(|| toc.ctdb_entries(&xml).filter(Result::is_ok).count()),
+		Bench::new("Toc::ctdb_entries(400).take(3)").run(||
+			toc.ctdb_entries(&xml).take(3).filter_map(|e| e.ok()?.track_crcs().next()).sum::<u32>()
+		),
 	);
 }
@@ -13,13 +13,23 @@ use cdtoc::{
 
 
 
+/// # A Full Disc.
+const TOC_99: &str = "63+96+12D9+5546+A8A2+CAAA+128BF+17194+171DF+1722A+17275+172C0+1730B+17356+173A1+173EC+17437+17482+174CD+17518+17563+175AE+175F9+17644+1768F+176DA+17725+17770+177BB+17806+17851+1789C+178E7+17932+1797D+179C8+17A13+17A5E+17AA9+17AF4+17B3F+17B8A+17BD5+17C20+17C6B+17CB6+17D01+17D4C+17D97+17DE2+17E2D+17E78+17EC3+17F0E+17F59+17FA4+17FEF+1803A+18085+180D0+1811B+18166+181B1+181FC+18247+18292+182DD+18328+18373+183BE+18409+18454+1849F+184EA+18535+18580+185CB+18616+18661+186AC+186F7+18742+1878D+187D8+18823+1886E+188B9+18904+1894F+1899A+189E5+18A30+18A7B+18AC6+18B11+18B5C+18BA7+18BF2+18C38+1ECDC+246E9";
+
+
+
 fn main() {
 	let toc = Toc::from_cdtoc("10+B6+5352+62AC+99D6+E218+12AC0+135E7+142E9+178B0+19D22+1B0D0+1E7FA+22882+247DB+27074+2A1BD+2C0FB")
 		.expect("Failed to parse CDTOC.");
 
+	// The most tracks a disc can have.
+	let toc99 = Toc::from_cdtoc(TOC_99).expect("Failed to parse CDTOC.");
+
 	benches!(
 		inline:
 		Bench::new("Toc::musicbrainz_id").run(|| toc.musicbrainz_id()),
+		Bench::new("Toc::musicbrainz_id(99)").run(|| toc99.musicbrainz_id()),
+		Bench::new("Toc::musicbrainz_hash_input(99)").run(|| toc99.musicbrainz_hash_input()),
 		Bench::spacer(),
 		Bench::new("ShaB64::decode(nljDXdC8B_pDwbdY1vZJvdrAZI4-)")
 			.run(|| ShaB64::decode("nljDXdC8B_pDwbdY1vZJvdrAZI4-")),
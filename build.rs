@@ -0,0 +1,154 @@
+/*!
+# CDTOC: Build
+
+When the `offsets-embedded` feature is enabled, this parses the bundled
+AccurateRip drive offset snapshot and writes it out as Rust source so the
+table can be compiled directly into the library.
+
+The snapshot defaults to `skel/DriveOffsets.bin`, but a different copy can
+be used by pointing the `CDTOC_DRIVE_OFFSETS` environment variable at it.
+
+Bad data is a hard error; the build will fail rather than ship a broken or
+empty table.
+*/
+
+use std::{
+	collections::BTreeMap,
+	fmt::Write,
+	path::PathBuf,
+};
+
+
+
+/// # Block Size.
+const BLOCK_SIZE: usize = 69;
+
+/// # Name Size.
+const NAME_SIZE: usize = 33;
+
+/// # Reserved Size.
+const RESERVED_SIZE: usize = 30;
+
+/// # Max Vendor Length.
+const VENDOR_MAX: usize = 8;
+
+/// # Max Model Length.
+const MODEL_MAX: usize = 16;
+
+/// # Offset Range.
+const OFFSET_RNG: std::ops::Range<i16> = -2940..2941;
+
+/// # Placeholder Threshold.
+///
+/// The real list contains thousands of drives; anything this small is
+/// probably a test fixture.
+const PLACEHOLDER_MAX: usize = 100;
+
+
+
+fn main() {
+	println!("cargo:rerun-if-env-changed=CDTOC_DRIVE_OFFSETS");
+	if std::env::var_os("CARGO_FEATURE_OFFSETS_EMBEDDED").is_none() { return; }
+
+	let src = std::env::var_os("CDTOC_DRIVE_OFFSETS").map_or_else(
+		|| PathBuf::from("skel/DriveOffsets.bin"),
+		PathBuf::from,
+	);
+	println!("cargo:rerun-if-changed={}", src.display());
+
+	let raw = std::fs::read(&src)
+		.unwrap_or_else(|e| panic!("Unable to read {}: {e}", src.display()));
+	let entries = parse(&raw)
+		.unwrap_or_else(|e| panic!("Invalid drive offsets in {}: {e}", src.display()));
+
+	if entries.len() < PLACEHOLDER_MAX {
+		println!(
+			"cargo:warning={} only has {} drive(s); this looks like a placeholder rather than a real AccurateRip snapshot.",
+			src.display(),
+			entries.len(),
+		);
+	}
+
+	let mut out = String::from("&[\n");
+	for (vendor, model, offset, submissions, reserved) in entries.values() {
+		writeln!(
+			out,
+			"\t({vendor:?}, {model:?}, {offset}, {submissions}, {reserved:?}),",
+		).expect("String write failed.");
+	}
+	out.push(']');
+
+	let dst = PathBuf::from(std::env::var_os("OUT_DIR").expect("Missing OUT_DIR."))
+		.join("drive-offsets.rs");
+	std::fs::write(&dst, out)
+		.unwrap_or_else(|e| panic!("Unable to write {}: {e}", dst.display()));
+}
+
+
+
+/// # Entry.
+///
+/// Vendor, model, offset, submissions, reserved.
+type Entry = (String, String, i16, u32, [u8; RESERVED_SIZE]);
+
+/// # Parse.
+///
+/// This mirrors `DriveOffsets::parse`, returning the deduplicated entries
+/// keyed by their normalized vendor and model.
+fn parse(raw: &[u8]) -> Result<BTreeMap<(String, String), Entry>, String> {
+	if raw.len() % BLOCK_SIZE != 0 {
+		return Err(format!("length {} is not a multiple of {BLOCK_SIZE}", raw.len()));
+	}
+
+	let mut out = BTreeMap::new();
+	for (idx, chunk) in raw.chunks_exact(BLOCK_SIZE).enumerate() {
+		let offset = i16::from_le_bytes([chunk[0], chunk[1]]);
+		let vm = std::str::from_utf8(&chunk[2..34])
+			.ok()
+			.filter(|vm| vm.is_ascii())
+			.ok_or_else(|| format!("block {idx} has a non-ASCII name"))?;
+
+		let (vendor, model) =
+			if let Some(model) = vm.strip_prefix("- ") { ("", model.trim_matches(trim_vm)) }
+			else {
+				let mut split = vm.splitn(2, " - ");
+				let vendor = split.next().unwrap_or("");
+				let model = split.next().unwrap_or("");
+				(vendor.trim_matches(trim_vm), model.trim_matches(trim_vm))
+			};
+
+		if model.is_empty() { continue; }
+		if
+			! OFFSET_RNG.contains(&offset) ||
+			VENDOR_MAX < vendor.len() ||
+			MODEL_MAX < model.len()
+		{
+			return Err(format!("block {idx} ({vendor} - {model}) is out of range"));
+		}
+
+		let submissions = u32::from_le_bytes([chunk[35], chunk[36], chunk[37], chunk[38]]);
+		let mut reserved = [0_u8; RESERVED_SIZE];
+		reserved.copy_from_slice(&chunk[2 + NAME_SIZE + 4..]);
+
+		out.insert(
+			(normalize(vendor), normalize(model)),
+			(vendor.to_owned(), model.to_owned(), offset, submissions, reserved),
+		);
+	}
+
+	if out.is_empty() { Err("no drives were found".to_owned()) }
+	else { Ok(out) }
+}
+
+/// # Normalize.
+fn normalize(src: &str) -> String {
+	let mut out = String::with_capacity(src.len());
+	for word in src.split_whitespace() {
+		if ! out.is_empty() { out.push(' '); }
+		for c in word.chars() { out.push(c.to_ascii_lowercase()); }
+	}
+	out
+}
+
+/// # Trim Callback.
+const fn trim_vm(c: char) -> bool { c.is_ascii_whitespace() || c.is_ascii_control() }
@@ -2,6 +2,31 @@
 # CDTOC: AccurateRip
 */
 
+mod checksums;
+mod drive;
+#[cfg(feature = "fetch")] mod fetch;
+#[cfg(any(feature = "claxon", feature = "hound"))] mod files;
+mod matrix;
+mod verify;
+
+pub use checksums::{
+	ChecksumEntry,
+	ChunkRef,
+	DiscChecksums,
+	TrackSummary,
+};
+pub use drive::{
+	DriveOffset,
+	DriveOffsets,
+};
+#[cfg(feature = "fetch")] pub use fetch::FetchOptions;
+pub use matrix::OffsetMatrix;
+pub use verify::{
+	ArVersion,
+	TrackVerdict,
+	VerifyReport,
+};
+
 use crate::{
 	Cddb,
 	Toc,
@@ -12,9 +37,11 @@ use dactyl::traits::{
 	HexToUnsigned,
 };
 use std::{
+	borrow::Borrow,
 	collections::BTreeMap,
 	fmt,
 	ops::Range,
+	path::PathBuf,
 	str::FromStr,
 };
 
@@ -39,17 +66,21 @@ const DRIVE_OFFSET_OFFSET_RNG: Range<i16> = -2940..2941;
 
 
 #[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 /// # AccurateRip ID.
 ///
 /// This struct holds an [AccurateRip](http://accuraterip.com/) ID.
 ///
 /// Values of this type are returned by [`Toc::accuraterip_id`].
 ///
+/// Ordering is by the raw binary representation — track count, then the
+/// little-endian bytes of each of the three hashes — rather than the display
+/// string.
+///
 /// ## Examples
 ///
 /// ```
-/// use cdtoc::Toc;
+/// use cdtoc::{AccurateRip, Toc};
 ///
 /// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
 /// let ar_id = toc.accuraterip_id();
@@ -66,6 +97,12 @@ const DRIVE_OFFSET_OFFSET_RNG: Range<i16> = -2940..2941;
 ///     <[u8; 13]>::from(ar_id),
 ///     [4, 154, 24, 2, 0, 51, 127, 8, 0, 4, 224, 2, 31],
 /// );
+///
+/// // Which can be converted back, too:
+/// assert_eq!(
+///     AccurateRip::try_from([4, 154, 24, 2, 0, 51, 127, 8, 0, 4, 224, 2, 31].as_slice()),
+///     Ok(ar_id),
+/// );
 /// ```
 pub struct AccurateRip([u8; 13]);
 
@@ -74,43 +111,85 @@ impl AsRef<[u8]> for AccurateRip {
 	fn as_ref(&self) -> &[u8] { &self.0 }
 }
 
+impl AsRef<[u8; 13]> for AccurateRip {
+	#[inline]
+	fn as_ref(&self) -> &[u8; 13] { &self.0 }
+}
+
+impl Borrow<[u8]> for AccurateRip {
+	#[inline]
+	fn borrow(&self) -> &[u8] { &self.0 }
+}
+
 impl From<AccurateRip> for [u8; 13] {
 	#[inline]
 	fn from(src: AccurateRip) -> Self { src.0 }
 }
 
+impl From<[u8; 13]> for AccurateRip {
+	#[inline]
+	/// # From Raw.
+	///
+	/// Note: this is an infallible, unchecked conversion; use the
+	/// `TryFrom<&[u8]>` implementation instead if the data might be garbage.
+	fn from(src: [u8; 13]) -> Self { Self(src) }
+}
+
+impl TryFrom<&[u8]> for AccurateRip {
+	type Error = TocError;
+
+	/// # From Raw Slice.
+	///
+	/// This will return an error unless the slice is exactly thirteen bytes
+	/// and has a plausible audio track count (`1..=99`).
+	fn try_from(src: &[u8]) -> Result<Self, Self::Error> {
+		let src = <[u8; 13]>::try_from(src).map_err(|_| TocError::AccurateRipDecode)?;
+		if (1..=99).contains(&src[0]) { Ok(Self(src)) }
+		else { Err(TocError::AccurateRipDecode) }
+	}
+}
+
 impl fmt::Display for AccurateRip {
+	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		let disc_id = self.encode();
-		std::str::from_utf8(disc_id.as_slice())
-			.map_err(|_| fmt::Error)
-			.and_then(|s| f.pad(s))
+		f.pad(Self::id_str(&self.encode()))
 	}
 }
 
 impl From<&Toc> for AccurateRip {
-	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[inline]
 	fn from(src: &Toc) -> Self {
+		Self::from_sectors(src.audio_sectors(), src.leadout(), src.cddb_id())
+	}
+}
+
+impl AccurateRip {
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # From Sectors.
+	///
+	/// Crunch the ID from the audio sectors, (chosen) leadout, and CDDB ID.
+	/// The track count is assumed to have already been validated.
+	fn from_sectors(audio: &[u32], leadout: u32, cddb: Cddb) -> Self {
 		let mut b: u32 = 0;
 		let mut c: u32 = 0;
 
-		let mut idx = 1;
-		for v in src.audio_sectors() {
+		let mut idx: u32 = 1;
+		for v in audio {
 			let off = v.saturating_sub(150);
-			b += off;
-			c += off.max(1) * idx;
+			b = b.wrapping_add(off);
+			c = c.wrapping_add(off.max(1).wrapping_mul(idx));
 			idx += 1;
 		}
 
 		// Add in the last part.
-		let leadout = src.leadout().saturating_sub(150);
+		let leadout = leadout.saturating_sub(150);
 
-		let b = (b + leadout).to_le_bytes();
-		let c = (c + leadout.max(1) * idx).to_le_bytes();
-		let d = u32::from(src.cddb_id()).to_le_bytes();
+		let b = b.wrapping_add(leadout).to_le_bytes();
+		let c = c.wrapping_add(leadout.max(1).wrapping_mul(idx)).to_le_bytes();
+		let d = u32::from(cddb).to_le_bytes();
 
 		Self([
-			src.audio_len() as u8,
+			audio.len() as u8,
 			b[0], b[1], b[2], b[3],
 			c[0], c[1], c[2], c[3],
 			d[0], d[1], d[2], d[3],
@@ -118,6 +197,42 @@ impl From<&Toc> for AccurateRip {
 	}
 }
 
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # AccurateRip Leadout.
+///
+/// AccurateRip IDs incorporate the disc's leadout, but for CD-Extra discs —
+/// where a data session follows the audio — there are two reasonable
+/// candidates, and software has not always agreed which to use.
+///
+/// Rippers that read the full table of contents from the drive, such as
+/// EAC and dBpoweramp, see the data session and its leadout, and CUETools
+/// does the same whenever it knows where the data track is. That is
+/// [`ArLeadout::DiscLeadout`], and what the AccurateRip database expects.
+///
+/// [`ArLeadout::AudioLeadout`] is what results from an audio-only view of
+/// the disc instead, such as a CUE sheet or image that doesn't mention the
+/// data track. It is provided for matching IDs that were calculated that
+/// way, not as an alternative for lookups.
+///
+/// This is used by [`Toc::accuraterip_id_with`] to pick one or the other.
+/// For audio-only and data-first discs, the two are equivalent.
+pub enum ArLeadout {
+	#[default]
+	/// # Disc Leadout.
+	///
+	/// Use the absolute leadout ([`Toc::leadout`]). This is what
+	/// [`Toc::accuraterip_id`] has always used.
+	DiscLeadout,
+
+	/// # Audio Leadout.
+	///
+	/// Use the end of the audio session instead, i.e. the start of the data
+	/// track minus the inter-session gap ([`Toc::audio_leadout`]). This
+	/// matches IDs calculated without knowledge of the data session.
+	AudioLeadout,
+}
+
 impl FromStr for AccurateRip {
 	type Err = TocError;
 	#[inline]
@@ -131,6 +246,11 @@ impl TryFrom<&str> for AccurateRip {
 }
 
 impl AccurateRip {
+	/// # Checksum Base URL.
+	///
+	/// This is the canonical base URL for the checksum [bin files](AccurateRip::checksum_url).
+	pub const CHECKSUM_BASE_URL: &'static str = "http://www.accuraterip.com/accuraterip/";
+
 	/// # Drive Offset Data URL.
 	///
 	/// The binary-encoded list of known AccurateRip drive offsets can be
@@ -139,6 +259,46 @@ impl AccurateRip {
 	/// The method [`AccurateRip::parse_drive_offsets`] can be used to parse
 	/// the raw data into a Rustful structure.
 	pub const DRIVE_OFFSET_URL: &'static str = "http://www.accuraterip.com/accuraterip/DriveOffsets.bin";
+
+	#[cfg(feature = "offsets-embedded")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "offsets-embedded")))]
+	#[must_use]
+	/// # Built-In Drive Offsets.
+	///
+	/// Return the drive offset table bundled with the crate.
+	///
+	/// The table is generated at build time from `skel/DriveOffsets.bin`, or
+	/// the file named by the `CDTOC_DRIVE_OFFSETS` environment variable, if
+	/// set. Invalid data fails the build.
+	///
+	/// **Important:** the checked-in `skel/DriveOffsets.bin` is currently a
+	/// four-drive placeholder, not a real AccurateRip snapshot, so has no
+	/// snapshot date. Builds using it emit a warning to that effect. Until a
+	/// real snapshot is bundled, set `CDTOC_DRIVE_OFFSETS` to a copy of the
+	/// [bin file](AccurateRip::DRIVE_OFFSET_URL) downloaded yourself.
+	///
+	/// Either way, the table will not include drives added to the database
+	/// after the snapshot was taken. If freshness matters, download the
+	/// current file at runtime and parse it with [`DriveOffsets::parse`]
+	/// instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::AccurateRip;
+	///
+	/// let offsets = AccurateRip::builtin_drive_offsets();
+	/// assert!(! offsets.is_empty());
+	/// ```
+	pub fn builtin_drive_offsets() -> &'static DriveOffsets {
+		/// # Generated Entries.
+		static RAW: &[(&str, &str, i16, u32, [u8; 30])] = include!(concat!(env!("OUT_DIR"), "/drive-offsets.rs"));
+
+		/// # Parsed Snapshot.
+		static PARSED: std::sync::OnceLock<DriveOffsets> = std::sync::OnceLock::new();
+
+		PARSED.get_or_init(|| DriveOffsets::from_builtin(RAW))
+	}
 }
 
 impl AccurateRip {
@@ -160,7 +320,34 @@ impl AccurateRip {
 	/// ```
 	pub const fn audio_len(&self) -> u8 { self.0[0] }
 
-	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	/// # AccurateRip Checksum Path.
+	///
+	/// This returns the relative path of the disc's checksum [bin file](AccurateRip::checksum_url),
+	/// i.e. everything after the base URL. This is useful for
+	/// maintaining a local cache laid out the same way as the AccurateRip
+	/// server.
+	///
+	/// See also [`AccurateRip::push_checksum_path`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	/// assert_eq!(
+	///     ar_id.checksum_path(),
+	///     "a/9/8/dBAR-004-0002189a-00087f33-1f02e004.bin",
+	/// );
+	/// ```
+	pub fn checksum_path(&self) -> String {
+		let mut out = String::with_capacity(45);
+		let _res = Self::write_checksum_path(&self.encode(), &mut out);
+		out
+	}
+
 	#[must_use]
 	/// # AccurateRip Checksum URL.
 	///
@@ -183,21 +370,153 @@ impl AccurateRip {
 	/// );
 	/// ```
 	pub fn checksum_url(&self) -> String {
-		// First things first, build the disc ID.
+		let mut out = String::with_capacity(84);
+		let _res = self.write_checksum_url(&mut out);
+		out
+	}
+
+	#[must_use]
+	/// # AccurateRip Checksum URL (Custom Base).
+	///
+	/// Same as [`AccurateRip::checksum_url`], but with a custom base URL, such
+	/// as a local mirror. A trailing slash will be added to the base if
+	/// missing.
+	///
+	/// You can also get this directly via [`Toc::accuraterip_checksum_url_with_base`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	/// assert_eq!(
+	///     ar_id.checksum_url_with_base("https://mirror.example.com/ar"),
+	///     "https://mirror.example.com/ar/a/9/8/dBAR-004-0002189a-00087f33-1f02e004.bin",
+	/// );
+	/// ```
+	pub fn checksum_url_with_base(&self, base: &str) -> String {
+		let mut out = String::with_capacity(base.len() + 46);
+		out.push_str(base);
+		if ! base.ends_with('/') { out.push('/'); }
+		let _res = Self::write_checksum_path(&self.encode(), &mut out);
+		out
+	}
+
+	/// # Write Checksum URL.
+	///
+	/// Same as [`AccurateRip::checksum_url`], but written to an arbitrary
+	/// [`fmt::Write`] implementor, avoiding the intermediary allocation.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	///
+	/// let mut out = String::new();
+	/// ar_id.write_checksum_url(&mut out).unwrap();
+	/// assert_eq!(out, ar_id.checksum_url());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will bubble up any errors from the writer.
+	pub fn write_checksum_url<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+		out.write_str(Self::CHECKSUM_BASE_URL)?;
+		Self::write_checksum_path(&self.encode(), out)
+	}
+
+	/// # Write ID.
+	///
+	/// Same as [`AccurateRip::to_string`](ToString::to_string), but written
+	/// to an arbitrary [`fmt::Write`] implementor, avoiding the intermediary
+	/// allocation.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	///
+	/// let mut out = String::from("AccurateRip: ");
+	/// ar_id.write_id(&mut out).unwrap();
+	/// assert_eq!(out, "AccurateRip: 004-0002189a-00087f33-1f02e004");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will bubble up any errors from the writer.
+	pub fn write_id<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+		out.write_str(Self::id_str(&self.encode()))
+	}
+
+	/// # Push Checksum Path.
+	///
+	/// Append the relative [checksum path](AccurateRip::checksum_path) to
+	/// an existing path — a cache directory, say — one component at a time,
+	/// using the platform's native separators.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	/// use std::path::PathBuf;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	///
+	/// let mut path = PathBuf::from("cache");
+	/// ar_id.push_checksum_path(&mut path);
+	/// assert_eq!(
+	///     path,
+	///     ["cache", "a", "9", "8", "dBAR-004-0002189a-00087f33-1f02e004.bin"]
+	///         .into_iter()
+	///         .collect::<PathBuf>(),
+	/// );
+	/// ```
+	pub fn push_checksum_path(&self, path: &mut PathBuf) {
 		let disc_id = self.encode();
-		debug_assert!(disc_id.is_ascii(), "Bug: AccurateRip ID is not ASCII?!");
+		let mut buf = [0_u8; 4];
+		for idx in [11, 10, 9] {
+			path.push(char::from(disc_id[idx]).encode_utf8(&mut buf));
+		}
 
-		let mut out = String::with_capacity(84);
-		out.push_str("http://www.accuraterip.com/accuraterip/");
-		out.push(char::from(disc_id[11]));
-		out.push('/');
-		out.push(char::from(disc_id[10]));
-		out.push('/');
-		out.push(char::from(disc_id[9]));
-		out.push_str("/dBAR-");
-		// Safety: all bytes are ASCII.
-		out.push_str(unsafe { std::str::from_utf8_unchecked(disc_id.as_slice()) });
-		out.push_str(".bin");
+		let mut name = String::with_capacity(39);
+		let _res = Self::write_checksum_file_name(&disc_id, &mut name);
+		path.push(name);
+	}
+
+	#[must_use]
+	/// # Checksum File Name.
+	///
+	/// Return the file name of the disc's checksum [bin file](AccurateRip::checksum_url),
+	/// exactly as the AccurateRip server names it.
+	///
+	/// The result can be converted back into an ID using
+	/// [`AccurateRip::from_filename`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{AccurateRip, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	/// assert_eq!(
+	///     ar_id.filename(),
+	///     "dBAR-004-0002189a-00087f33-1f02e004.bin",
+	/// );
+	/// assert_eq!(AccurateRip::from_filename(ar_id.filename()), Ok(ar_id));
+	/// ```
+	pub fn filename(&self) -> String {
+		let mut out = String::with_capacity(39);
+		let _res = Self::write_checksum_file_name(&self.encode(), &mut out);
 		out
 	}
 
@@ -257,14 +576,39 @@ impl AccurateRip {
 	/// assert_eq!(ar_str.parse::<AccurateRip>(), Ok(ar_id));
 	/// ```
 	///
+	/// Decoding is somewhat forgiving: surrounding whitespace, a trailing
+	/// period, and uppercase hex are all fine.
+	///
+	/// ```
+	/// use cdtoc::{AccurateRip, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	/// assert_eq!(
+	///     AccurateRip::decode(" 004-0002189A-00087F33-1F02E004.\n"),
+	///     Ok(ar_id),
+	/// );
+	/// ```
+	///
 	/// ## Errors
 	///
-	/// This will return an error if decoding fails.
+	/// This will return an error if decoding fails, or the track count is
+	/// outside `1..=99`.
 	pub fn decode<S>(src: S) -> Result<Self, TocError>
 	where S: AsRef<str> {
-		let src = src.as_ref().as_bytes();
-		if src.len() == 30 && src[3] == b'-' && src[12] == b'-' && src[21] == b'-' {
-			let a = u8::btou(&src[..3]).ok_or(TocError::AccurateRipDecode)?;
+		let src = src.as_ref().trim();
+		let src = src.strip_suffix('.').unwrap_or(src).as_bytes();
+		let Ok(mut src) = <[u8; 30]>::try_from(src) else {
+			return Err(TocError::AccurateRipDecode);
+		};
+
+		// Hex can be either case, but let's be consistent.
+		src.make_ascii_lowercase();
+
+		if src[3] == b'-' && src[12] == b'-' && src[21] == b'-' {
+			let a = u8::btou(&src[..3])
+				.filter(|n| (1..=99).contains(n))
+				.ok_or(TocError::AccurateRipDecode)?;
 			let b = u32::htou(&src[4..12])
 				.map(u32::to_le_bytes)
 				.ok_or(TocError::AccurateRipDecode)?;
@@ -285,6 +629,127 @@ impl AccurateRip {
 		else { Err(TocError::AccurateRipDecode) }
 	}
 
+	/// # From Offsets.
+	///
+	/// Compute an AccurateRip ID directly from raw sector positions — the
+	/// same values passed to [`Toc::from_parts`] — without building a
+	/// [`Toc`].
+	///
+	/// Unlike [`Toc::from_parts`], the only validation performed is a check
+	/// that there are between `1..=99` audio tracks, so out-of-spec values
+	/// that would otherwise be rejected can still be identified. For
+	/// well-formed values, the result is identical to [`Toc::accuraterip_id`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{AccurateRip, Toc};
+	///
+	/// let audio = vec![150, 11563, 25174, 45863];
+	/// let leadout = 55370;
+	/// let toc = Toc::from_parts(audio.clone(), None, leadout).unwrap();
+	///
+	/// assert_eq!(
+	///     AccurateRip::from_offsets(&audio, None, leadout),
+	///     Ok(toc.accuraterip_id()),
+	/// );
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if there are no audio tracks, or more than
+	/// `99`.
+	pub fn from_offsets(audio: &[u32], data: Option<u32>, leadout: u32)
+	-> Result<Self, TocError> {
+		let cddb = Cddb::from_offsets(audio, data, leadout)?;
+		Ok(Self::from_sectors(audio, leadout, cddb))
+	}
+
+	/// # From Filename.
+	///
+	/// Like [`AccurateRip::decode`], but more forgiving about the packaging.
+	/// In addition to plain IDs, this accepts checksum bin file names like
+	/// `dBAR-004-0002189a-00087f33-1f02e004.bin`, as well as full paths or
+	/// [URLs](AccurateRip::checksum_url) ending in such. Surrounding
+	/// whitespace is ignored.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{AccurateRip, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	///
+	/// assert_eq!(
+	///     AccurateRip::from_filename("dBAR-004-0002189a-00087f33-1f02e004.bin"),
+	///     Ok(ar_id),
+	/// );
+	/// assert_eq!(
+	///     AccurateRip::from_filename(ar_id.checksum_url()),
+	///     Ok(ar_id),
+	/// );
+	/// assert_eq!(
+	///     AccurateRip::from_filename("004-0002189a-00087f33-1f02e004"),
+	///     Ok(ar_id),
+	/// );
+	///
+	/// // The CDDB ID comes along for the ride.
+	/// assert_eq!(
+	///     AccurateRip::from_filename("dBAR-004-0002189a-00087f33-1f02e004.bin")
+	///         .map(|ar| ar.cddb_id()),
+	///     Ok(toc.cddb_id()),
+	/// );
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if decoding fails.
+	pub fn from_filename<S>(src: S) -> Result<Self, TocError>
+	where S: AsRef<str> {
+		let mut src = src.as_ref().trim().as_bytes();
+
+		// Strip off the directories, if any.
+		if let Some(pos) = src.iter().rposition(|b| matches!(b, b'/' | b'\\')) {
+			src = &src[pos + 1..];
+		}
+
+		// Strip the prefix and suffix, if any.
+		if src.len() > 5 && src[..5].eq_ignore_ascii_case(b"dbar-") {
+			src = &src[5..];
+		}
+		if let Some(pos) = src.len().checked_sub(4) {
+			if src[pos..].eq_ignore_ascii_case(b".bin") { src = &src[..pos]; }
+		}
+
+		std::str::from_utf8(src)
+			.map_err(|_| TocError::AccurateRipDecode)
+			.and_then(Self::decode)
+	}
+
+	#[must_use]
+	/// # Matches Table of Contents?
+	///
+	/// Returns `true` if this is the ID the table of contents would generate,
+	/// i.e. `toc.accuraterip_id() == self`.
+	///
+	/// Note: this only considers [`ArLeadout::DiscLeadout`]; for CD-Extra
+	/// discs, use [`Toc::accuraterip_id_with`] to check the alternative.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{AccurateRip, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let ar_id = AccurateRip::decode("004-0002189a-00087f33-1f02e004").unwrap();
+	/// assert!(ar_id.matches_toc(&toc));
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84B").unwrap();
+	/// assert!(! ar_id.matches_toc(&toc));
+	/// ```
+	pub fn matches_toc(&self, toc: &Toc) -> bool { *self == Self::from(toc) }
+
 	/// # Parse Checksums.
 	///
 	/// This will parse the v1 and v2 track checksums from a raw AccurateRip
@@ -302,31 +767,181 @@ impl AccurateRip {
 	/// This will return an error if parsing is unsuccessful, or the result is
 	/// empty.
 	pub fn parse_checksums(&self, bin: &[u8]) -> Result<Vec<BTreeMap<u32, u8>>, TocError> {
-		// We're expecting 0+ sections containing a 13-byte disc ID and a
-		// 9-byte checksum for each track.
-		let audio_len = self.audio_len() as usize;
-		let chunk_size = 13 + 9 * audio_len;
-		let mut out: Vec<BTreeMap<u32, u8>> = vec![BTreeMap::default(); audio_len];
-
-		for chunk in bin.chunks_exact(chunk_size) {
-			// Verify the chunk begins with the disc ID, and get to the meat.
-			let chunk = chunk.strip_prefix(&self.0).ok_or(TocError::Checksums)?;
-			// Update the list for each track, combining them if for some
-			// reason the same value appears twice.
-			for (k, v) in chunk.chunks_exact(9).enumerate() {
-				let crc = u32::from_le_bytes([v[1], v[2], v[3], v[4]]);
-				if crc != 0 {
-					let e = out[k].entry(crc).or_insert(0);
-					*e = e.saturating_add(v[0]);
-				}
-			}
-		}
+		let mut out: Vec<BTreeMap<u32, u8>> = vec![BTreeMap::default(); self.audio_len() as usize];
+		for chunk in self.checksum_chunks(bin) { fold_chunk(&mut out, &chunk?); }
 
 		// Consider it okay if we found at least one checksum.
 		if out.iter().any(|v| ! v.is_empty()) { Ok(out) }
 		else { Err(TocError::NoChecksums) }
 	}
 
+	/// # Parse Checksums (Lossy).
+	///
+	/// This is a tolerant version of [`AccurateRip::parse_checksums`] for
+	/// bins that may be partially corrupt, for example with a truncated
+	/// final chunk or a stray response for some other disc mixed in.
+	///
+	/// Rather than failing on the first bad chunk, it skips ahead to the
+	/// next occurrence of this disc's ID and carries on, returning whatever
+	/// it could parse along with the total number of bytes skipped. If the
+	/// latter is non-zero, you may want to re-download the bin.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	///
+	/// let mut bin = Vec::new();
+	/// bin.extend_from_slice(b"garbage");
+	/// bin.extend_from_slice(ar_id.as_ref());
+	/// bin.extend_from_slice(&[1, 1, 0, 0, 0, 0, 0, 0, 0]);
+	/// bin.extend_from_slice(&[1, 2, 0, 0, 0, 0, 0, 0, 0]);
+	/// bin.extend_from_slice(&<[u8; 13]>::from(ar_id)[..5]); // Truncated.
+	///
+	/// // The strict version will fail.
+	/// assert!(ar_id.parse_checksums(&bin).is_err());
+	///
+	/// // The lossy version will do its best.
+	/// let (parsed, skipped) = ar_id.parse_checksums_lossy(&bin).unwrap();
+	/// assert_eq!(parsed[0].get(&1), Some(&1));
+	/// assert_eq!(skipped, 7 + 5);
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if no checksums could be found.
+	pub fn parse_checksums_lossy(&self, bin: &[u8])
+	-> Result<(Vec<BTreeMap<u32, u8>>, usize), TocError> {
+		let chunk_size = 13 + 9 * self.audio_len() as usize;
+		let mut out: Vec<BTreeMap<u32, u8>> = vec![BTreeMap::default(); self.audio_len() as usize];
+		let mut skipped = 0;
+
+		let mut rest = bin;
+		while ! rest.is_empty() {
+			// A good chunk!
+			if chunk_size <= rest.len() && rest.starts_with(&self.0) {
+				let (chunk, next) = rest.split_at(chunk_size);
+				fold_chunk(&mut out, &ChunkRef::new(&chunk[13..]));
+				rest = next;
+			}
+			// Skip ahead to the next possible chunk, or the end.
+			else {
+				let skip = rest.windows(13)
+					.skip(1)
+					.position(|w| w == self.0)
+					.map_or(rest.len(), |p| p + 1);
+				skipped += skip;
+				rest = &rest[skip..];
+			}
+		}
+
+		if out.iter().any(|v| ! v.is_empty()) { Ok((out, skipped)) }
+		else { Err(TocError::NoChecksums) }
+	}
+
+	/// # Parse Checksums (Detailed).
+	///
+	/// This will parse the track checksums from a raw AccurateRip checksum
+	/// [bin file](AccurateRip::checksum_url), same as
+	/// [`AccurateRip::parse_checksums`], but returns a richer structure with
+	/// unsaturated confidences, the number of response chunks, etc.
+	///
+	/// See [`DiscChecksums`] for more details.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if parsing is unsuccessful, or the result is
+	/// empty.
+	pub fn parse_checksums_detailed(&self, bin: &[u8]) -> Result<DiscChecksums, TocError> {
+		self.collect_entries(bin).map(|(raw, chunks)| DiscChecksums::new(*self, raw, chunks))
+	}
+
+	/// # Parse Checksum Entries.
+	///
+	/// This will parse the individual track records from a raw AccurateRip
+	/// checksum [bin file](AccurateRip::checksum_url), keeping the confidence
+	/// and frame-450 (offset-detection) CRC alongside each checksum.
+	///
+	/// The return result is a vector — indexed by track number (`n-1`) — of
+	/// entries, in the order they appear in the bin. Unlike
+	/// [`AccurateRip::parse_checksums`], duplicate CRCs are _not_ combined,
+	/// since their frame-450 CRCs may differ.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	///
+	/// // A bin with a single response chunk.
+	/// let mut bin = <[u8; 13]>::from(ar_id).to_vec();
+	/// bin.extend_from_slice(&[5, 1, 2, 3, 4, 9, 8, 7, 6]);
+	/// bin.extend_from_slice(&[3, 4, 3, 2, 1, 0, 0, 0, 0]);
+	///
+	/// let parsed = ar_id.parse_checksum_entries(&bin).unwrap();
+	/// assert_eq!(parsed[0][0].crc(), 0x0403_0201);
+	/// assert_eq!(parsed[0][0].confidence(), 5);
+	/// assert_eq!(parsed[0][0].frame450(), Some(0x0607_0809));
+	///
+	/// // Zero means none.
+	/// assert_eq!(parsed[1][0].frame450(), None);
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if parsing is unsuccessful, or the result is
+	/// empty.
+	pub fn parse_checksum_entries(&self, bin: &[u8]) -> Result<Vec<Vec<ChecksumEntry>>, TocError> {
+		self.collect_entries(bin).map(|(out, _)| out)
+	}
+
+	/// # Checksum Chunks.
+	///
+	/// Return an iterator over the response chunks in a raw AccurateRip
+	/// checksum [bin file](AccurateRip::checksum_url), without allocating.
+	///
+	/// Each chunk is a 13-byte disc ID followed by a 9-byte record for each
+	/// track: confidence, CRC, frame-450 CRC. Chunks that don't begin with
+	/// this disc's ID yield an error; any trailing partial chunk is ignored.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	///
+	/// let mut bin = Vec::new();
+	/// for conf in [10, 20] {
+	///     bin.extend_from_slice(ar_id.as_ref());
+	///     bin.extend_from_slice(&[conf, 1, 0, 0, 0, 0, 0, 0, 0]);
+	///     bin.extend_from_slice(&[conf, 2, 0, 0, 0, 0, 0, 0, 0]);
+	/// }
+	///
+	/// // Add up the confidence for the first track's checksum.
+	/// let total: u32 = ar_id.checksum_chunks(&bin)
+	///     .filter_map(|c| c.ok()?.get(0))
+	///     .filter(|(crc, _)| *crc == 1)
+	///     .map(|(_, conf)| u32::from(conf))
+	///     .sum();
+	/// assert_eq!(total, 30);
+	/// ```
+	pub fn checksum_chunks<'a>(&'a self, bin: &'a [u8])
+	-> impl Iterator<Item=Result<ChunkRef<'a>, TocError>> + 'a {
+		let chunk_size = 13 + 9 * self.audio_len() as usize;
+		bin.chunks_exact(chunk_size).map(|chunk|
+			chunk.strip_prefix(&self.0)
+				.map(ChunkRef::new)
+				.ok_or(TocError::Checksums)
+		)
+	}
+
 	/// # Parse Drive Offsets.
 	///
 	/// This will parse the vendor, model, and sample read offset information
@@ -336,68 +951,23 @@ impl AccurateRip {
 	/// will not have a vendor, but entries without models are silently
 	/// ignored.
 	///
+	/// See also [`DriveOffsets`], an owned alternative with more forgiving
+	/// lookups.
+	///
 	/// ## Errors
 	///
 	/// This will return an error if parsing is unsuccessful, or the result is
 	/// empty.
 	pub fn parse_drive_offsets(raw: &[u8])
 	-> Result<BTreeMap<(&str, &str), i16>, TocError> {
-		/// # Block Size.
-		///
-		/// The size of each raw entry, in bytes.
-		const BLOCK_SIZE: usize = 69;
-
-		/// # Trim Callback.
-		///
-		/// This is used to trim both ASCII whitespace and control characters,
-		/// as the raw data isn't afraid to null-pad its entries.
-		const fn trim_vm(c: char) -> bool { c.is_ascii_whitespace() || c.is_ascii_control() }
-
 		// There should be thousands of blocks, but we _need_ at least one!
-		if raw.len() < BLOCK_SIZE { return Err(TocError::NoDriveOffsets); }
+		if raw.len() < drive::BLOCK_SIZE { return Err(TocError::NoDriveOffsets); }
 
-		// Entries come in blocks of 69 bytes. The first two bytes hold the
-		// little-endian offset; the next 32 hold the vendor/model; the rest
-		// we can ignore!
 		let mut out = BTreeMap::default();
-		for chunk in raw.chunks_exact(BLOCK_SIZE) {
-			// The offset is easy!
-			let offset = i16::from_le_bytes([chunk[0], chunk[1]]);
-
-			// The vendor/model come glued together with an inconsistent
-			// delimiter, so we have to work a bit to pull them apart.
-			let vm = std::str::from_utf8(&chunk[2..34])
-				.ok()
-				.filter(|vm| vm.is_ascii())
-				.ok_or(TocError::DriveOffsetDecode)?;
-
-			let (vendor, model) =
-				// If the vendor is missing, the string should begin "- ".
-				if let Some(model) = vm.strip_prefix("- ") {
-					("", model.trim_matches(trim_vm))
-				}
-				// Otherwise there should be a " - " separating the two, even
-				// in cases where the model is missing.
-				else {
-					let mut split = vm.splitn(2, " - ");
-					let vendor = split.next().ok_or(TocError::DriveOffsetDecode)?;
-					let model = split.next().unwrap_or("");
-					(vendor.trim_matches(trim_vm), model.trim_matches(trim_vm))
-				};
-
-			// Skip empty models.
-			if model.is_empty() {}
-			// Add the entry so long as the fields fit.
-			else if
-				DRIVE_OFFSET_OFFSET_RNG.contains(&offset) &&
-				vendor.len() <= DRIVE_OFFSET_VENDOR_MAX &&
-				model.len() <= DRIVE_OFFSET_MODEL_MAX &&
-				vendor.is_ascii() && model.is_ascii()
-			{
+		for chunk in raw.chunks_exact(drive::BLOCK_SIZE) {
+			if let Some((vendor, model, offset)) = drive::parse_block(chunk)? {
 				out.insert((vendor, model), offset);
 			}
-			// Otherwise the data's bad.
-			else { return Err(TocError::DriveOffsetDecode); }
 		}
 
 		// Return the results, unless they're empty.
@@ -451,6 +1021,64 @@ impl AccurateRip {
 }
 
 impl AccurateRip {
+	/// # Collect Entries.
+	///
+	/// Parse the individual track records, returning them along with the
+	/// number of chunks seen.
+	fn collect_entries(&self, bin: &[u8])
+	-> Result<(Vec<Vec<ChecksumEntry>>, usize), TocError> {
+		let mut out: Vec<Vec<ChecksumEntry>> = vec![Vec::new(); self.audio_len() as usize];
+		let mut chunks = 0;
+		for chunk in self.checksum_chunks(bin) {
+			for (k, v) in chunk?.records().enumerate() {
+				let v = <[u8; 9]>::try_from(v).map_err(|_| TocError::Checksums)?;
+				if let Some(entry) = ChecksumEntry::from_record(v) {
+					out[k].push(entry);
+				}
+			}
+			chunks += 1;
+		}
+
+		if out.iter().any(|v| ! v.is_empty()) { Ok((out, chunks)) }
+		else { Err(TocError::NoChecksums) }
+	}
+
+	/// # Write Checksum Path.
+	///
+	/// Write the relative checksum path — e.g.
+	/// `a/9/8/dBAR-004-0002189a-00087f33-1f02e004.bin` — to `out`.
+	fn write_checksum_path<W: fmt::Write>(disc_id: &[u8; 30], out: &mut W)
+	-> fmt::Result {
+		out.write_char(char::from(disc_id[11]))?;
+		out.write_char('/')?;
+		out.write_char(char::from(disc_id[10]))?;
+		out.write_char('/')?;
+		out.write_char(char::from(disc_id[9]))?;
+		out.write_char('/')?;
+		Self::write_checksum_file_name(disc_id, out)
+	}
+
+	/// # Write Checksum File Name.
+	///
+	/// Write the checksum bin file name — `dBAR-{id}.bin` — to `out`.
+	fn write_checksum_file_name<W: fmt::Write>(disc_id: &[u8; 30], out: &mut W)
+	-> fmt::Result {
+		out.write_str("dBAR-")?;
+		out.write_str(Self::id_str(disc_id))?;
+		out.write_str(".bin")
+	}
+
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[inline]
+	/// # Encoded ID as String.
+	///
+	/// Return the [encoded](AccurateRip::encode) ID as a string slice.
+	fn id_str(disc_id: &[u8; 30]) -> &str {
+		debug_assert!(disc_id.is_ascii(), "Bug: AccurateRip ID is not ASCII?!");
+		// Safety: all bytes are ASCII.
+		unsafe { std::str::from_utf8_unchecked(disc_id.as_slice()) }
+	}
+
 	#[inline]
 	/// # Encode to Buffer.
 	///
@@ -509,6 +1137,40 @@ impl Toc {
 	/// ```
 	pub fn accuraterip_id(&self) -> AccurateRip { AccurateRip::from(self) }
 
+	#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+	#[must_use]
+	/// # AccurateRip ID (Custom Leadout).
+	///
+	/// Same as [`Toc::accuraterip_id`], but with an explicit choice of
+	/// leadout. This only makes a difference for CD-Extra discs; see
+	/// [`ArLeadout`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{ArLeadout, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11").unwrap();
+	/// assert_eq!(
+	///     toc.accuraterip_id_with(ArLeadout::DiscLeadout),
+	///     toc.accuraterip_id(),
+	/// );
+	/// assert_eq!(
+	///     toc.accuraterip_id_with(ArLeadout::AudioLeadout).to_string(),
+	///     "010-000e6a37-007493cc-9a0bb60b",
+	/// );
+	/// ```
+	pub fn accuraterip_id_with(&self, leadout: ArLeadout) -> AccurateRip {
+		match leadout {
+			ArLeadout::DiscLeadout => AccurateRip::from(self),
+			ArLeadout::AudioLeadout => AccurateRip::from_sectors(
+				self.audio_sectors(),
+				self.audio_leadout(),
+				self.cddb_id(),
+			),
+		}
+	}
+
 	#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
 	#[must_use]
 	/// # AccurateRip Checksum URL.
@@ -532,6 +1194,29 @@ impl Toc {
 		self.accuraterip_id().checksum_url()
 	}
 
+	#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+	#[must_use]
+	/// # AccurateRip Checksum URL (Custom Base).
+	///
+	/// Same as [`Toc::accuraterip_checksum_url`], but with a custom base URL,
+	/// such as a local mirror. A trailing slash will be added to the base if
+	/// missing.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert_eq!(
+	///     toc.accuraterip_checksum_url_with_base("https://mirror.example.com/ar/"),
+	///     "https://mirror.example.com/ar/a/9/8/dBAR-004-0002189a-00087f33-1f02e004.bin",
+	/// );
+	/// ```
+	pub fn accuraterip_checksum_url_with_base(&self, base: &str) -> String {
+		self.accuraterip_id().checksum_url_with_base(base)
+	}
+
 	#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
 	/// # Parse Checksums.
 	///
@@ -551,9 +1236,26 @@ impl Toc {
 
 
 
+/// # Fold Chunk.
+///
+/// Add a chunk's checksums to the running `checksum => confidence` maps for
+/// each track, combining them if for some reason the same value appears
+/// twice. Zero (missing) checksums are skipped.
+fn fold_chunk(out: &mut [BTreeMap<u32, u8>], chunk: &ChunkRef) {
+	for (track, (crc, conf)) in out.iter_mut().zip(chunk.iter()) {
+		if crc != 0 {
+			let e = track.entry(crc).or_insert(0);
+			*e = e.saturating_add(conf);
+		}
+	}
+}
+
+
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::TocKind;
 
 	/// # Test Drive Offset Bin.
 	const OFFSET_BIN: &[u8] = &[155, 2, 80, 73, 79, 78, 69, 69, 82, 32, 32, 45, 32, 66, 68, 45, 82, 87, 32, 32, 32, 66, 68, 82, 45, 88, 49, 50, 0, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155, 2, 80, 73, 79, 78, 69, 69, 82, 32, 32, 45, 32, 66, 68, 45, 82, 87, 32, 32, 32, 66, 68, 82, 45, 88, 49, 50, 85, 0, 0, 0, 0, 0, 0, 201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155, 2, 80, 73, 79, 78, 69, 69, 82, 32, 32, 45, 32, 66, 68, 45, 82, 87, 32, 32, 32, 66, 68, 82, 45, 88, 49, 51, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155, 2, 80, 73, 79, 78, 69, 69, 82, 32, 32, 45, 32, 66, 68, 45, 82, 87, 32, 32, 32, 66, 68, 82, 45, 88, 49, 51, 85, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
@@ -587,11 +1289,550 @@ mod tests {
 			assert_eq!(ar_id.to_string(), id);
 			assert_eq!(ar_id.pretty_print(), id);
 
+			// And writing.
+			let mut buf = String::from("ID: ");
+			assert!(ar_id.write_id(&mut buf).is_ok());
+			assert_eq!(buf, format!("ID: {id}"));
+			buf.truncate(0);
+			assert!(ar_id.write_checksum_url(&mut buf).is_ok());
+			assert_eq!(buf, ar_id.checksum_url());
+			assert_eq!(format!("{ar_id:>32}"), format!("  {id}"));
+
 			// Test decoding three ways.
 			assert_eq!(AccurateRip::decode(id), Ok(ar_id));
 			assert_eq!(AccurateRip::try_from(id), Ok(ar_id));
 			assert_eq!(id.parse::<AccurateRip>(), Ok(ar_id));
+
+			// The path should match the URL exactly, and use the last three
+			// digits of the first hash, backwards.
+			let path = ar_id.checksum_path();
+			assert_eq!(path, format!("{}/{}/{}/dBAR-{id}.bin", &id[11..12], &id[10..11], &id[9..10]));
+			let mut path_buf = PathBuf::from("cache");
+			ar_id.push_checksum_path(&mut path_buf);
+			assert_eq!(path_buf, PathBuf::from("cache").join(&path));
+			assert!(ar_id.checksum_url().ends_with(&format!("/{path}")));
+
+			// The file name should round-trip.
+			let filename = ar_id.filename();
+			assert_eq!(filename, format!("dBAR-{id}.bin"));
+			assert!(path.ends_with(&filename));
+			assert_eq!(AccurateRip::from_filename(&filename), Ok(ar_id));
+
+			// The URL base should be interchangeable.
+			let url = ar_id.checksum_url();
+			assert_eq!(url, ar_id.checksum_url_with_base(AccurateRip::CHECKSUM_BASE_URL));
+			assert_eq!(url, toc.accuraterip_checksum_url());
+			let path = url.strip_prefix(AccurateRip::CHECKSUM_BASE_URL).expect("Wrong base.");
+			for base in ["https://localhost/ar", "https://localhost/ar/"] {
+				assert_eq!(
+					ar_id.checksum_url_with_base(base),
+					format!("https://localhost/ar/{path}"),
+				);
+				assert_eq!(
+					toc.accuraterip_checksum_url_with_base(base),
+					format!("https://localhost/ar/{path}"),
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn t_checksum_entries() {
+		// A synthetic three-chunk response for a four-track disc. The
+		// frame-450 values are just rotated copies of the CRCs; they are only
+		// here to make sure each field ends up in the right place.
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let ar_id = toc.accuraterip_id();
+		let mut bin = Vec::new();
+		for (conf, crcs) in [
+			(12_u8, [0x1111_1111_u32, 0x2222_2222, 0x3333_3333, 0x4444_4444]),
+			(3, [0x5555_5555, 0x2222_2222, 0, 0x6666_6666]),
+			(250, [0x1111_1111, 0x7777_7777, 0x8888_8888, 0x9999_9999]),
+		] {
+			bin.extend_from_slice(ar_id.as_ref());
+			for crc in crcs {
+				bin.push(conf);
+				bin.extend_from_slice(&crc.to_le_bytes());
+				bin.extend_from_slice(&crc.rotate_left(4).to_le_bytes());
+			}
+		}
+
+		let entries = ar_id.parse_checksum_entries(&bin).expect("Entries failed.");
+		assert_eq!(entries.len(), 4);
+		assert_eq!(entries[0].len(), 3);
+		assert_eq!(entries[2].len(), 2); // The zero was skipped.
+		for track in &entries {
+			for e in track {
+				assert_eq!(e.frame450(), Some(e.crc().rotate_left(4)));
+			}
+		}
+
+		// The aggregated version should agree.
+		let parsed = ar_id.parse_checksums(&bin).expect("Checksums failed.");
+		for (e, p) in entries.iter().zip(parsed.iter()) {
+			let mut sums = BTreeMap::<u32, u8>::new();
+			for e in e {
+				let v = sums.entry(e.crc()).or_insert(0);
+				*v = v.saturating_add(u8::try_from(e.confidence()).expect("Confidence overflow."));
+			}
+			assert_eq!(&sums, p);
 		}
+		assert_eq!(parsed[0].get(&0x1111_1111), Some(&255));
+
+		// The detailed version shouldn't saturate.
+		let detailed = ar_id.parse_checksums_detailed(&bin).expect("Detailed failed.");
+		assert_eq!(detailed.chunks(), 3);
+		assert_eq!(detailed.max_confidence(), 262);
+		assert_eq!(detailed.to_maps(), parsed);
+		assert_eq!(detailed.tracks()[0][0].crc(), 0x1111_1111);
+		assert_eq!(detailed.tracks()[0][0].confidence(), 262);
+		assert_eq!(detailed.tracks()[0][1].crc(), 0x5555_5555);
+		assert_eq!(detailed.tracks()[1][0].crc(), 0x7777_7777);
+		assert_eq!(detailed.tracks()[1][1].confidence(), 15);
+
+		// The raw chunks should line up too.
+		let chunks: Vec<ChunkRef> = ar_id.checksum_chunks(&bin)
+			.collect::<Result<_, _>>()
+			.expect("Chunks failed.");
+		assert_eq!(chunks.len(), 3);
+		assert_eq!(chunks[1].len(), 4);
+		assert_eq!(chunks[1].get(2), Some((0, 3)));
+		assert_eq!(chunks[1].get(4), None);
+		assert_eq!(
+			chunks[2].iter().collect::<Vec<_>>(),
+			[(0x1111_1111, 250), (0x7777_7777, 250), (0x8888_8888, 250), (0x9999_9999, 250)],
+		);
+
+		// A trailing partial chunk is ignored.
+		let mut bin2 = bin.clone();
+		bin2.extend_from_slice(&bin[..20]);
+		assert_eq!(ar_id.checksum_chunks(&bin2).count(), 3);
+		assert_eq!(ar_id.parse_checksums(&bin2).as_ref(), Ok(&parsed));
+
+		// The wrong disc ID is an error.
+		bin[0] = 5;
+		assert_eq!(ar_id.parse_checksums(&bin), Err(TocError::Checksums));
+		assert!(ar_id.checksum_chunks(&bin).next().is_some_and(|c| c.is_err()));
+		assert_eq!(ar_id.parse_checksum_entries(&bin), Err(TocError::Checksums));
+
+		// The lossy version should skip it.
+		let (lossy, skipped) = ar_id.parse_checksums_lossy(&bin).expect("Lossy failed.");
+		assert_eq!(skipped, 49);
+		assert_eq!(lossy[0].get(&0x1111_1111), Some(&250));
+		assert_eq!(lossy[0].get(&0x5555_5555), Some(&3));
+		bin[0] = 4;
+
+		// Lossy should match strict when there's nothing wrong.
+		assert_eq!(ar_id.parse_checksums_lossy(&bin), Ok((parsed.clone(), 0)));
+
+		// A response for another disc wedged in the middle, plus a
+		// truncated chunk at the end.
+		let other = Toc::from_cdtoc("2+96+1F4+2EE").expect("Invalid TOC.").accuraterip_id();
+		let mut bin2 = bin[..49].to_vec();
+		bin2.extend_from_slice(other.as_ref());
+		bin2.extend_from_slice(&[1; 18]);
+		bin2.extend_from_slice(&bin[49..]);
+		bin2.extend_from_slice(&bin[..30]);
+		assert_eq!(ar_id.parse_checksums_lossy(&bin2), Ok((parsed.clone(), 31 + 30)));
+
+		// Total garbage is still an error.
+		assert_eq!(ar_id.parse_checksums_lossy(&[0; 200]), Err(TocError::NoChecksums));
+		assert_eq!(ar_id.parse_checksums_lossy(&[]), Err(TocError::NoChecksums));
+	}
+
+	#[cfg(feature = "offsets-embedded")]
+	#[test]
+	fn t_builtin_drive_offsets() {
+		let offsets = AccurateRip::builtin_drive_offsets();
+		assert!(! offsets.is_empty());
+		assert_eq!(
+			DriveOffsets::parse(include_bytes!("../skel/DriveOffsets.bin")).as_ref(),
+			Ok(offsets),
+		);
+
+		// Same reference every time.
+		assert!(std::ptr::eq(offsets, AccurateRip::builtin_drive_offsets()));
+	}
+
+	#[test]
+	fn t_from_bytes() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A")
+			.expect("Invalid TOC.");
+		let ar = toc.accuraterip_id();
+		let raw = <[u8; 13]>::from(ar);
+
+		assert_eq!(AccurateRip::from(raw), ar);
+		assert_eq!(AccurateRip::try_from(raw.as_slice()), Ok(ar));
+
+		// Straight from a bin.
+		let mut bin = raw.to_vec();
+		bin.extend_from_slice(&[0; 36]);
+		assert_eq!(AccurateRip::try_from(&bin[..13]), Ok(ar));
+
+		// Wrong lengths.
+		assert_eq!(AccurateRip::try_from(&bin[..0]), Err(TocError::AccurateRipDecode));
+		assert_eq!(AccurateRip::try_from(&bin[..12]), Err(TocError::AccurateRipDecode));
+		assert_eq!(AccurateRip::try_from(&bin[..14]), Err(TocError::AccurateRipDecode));
+
+		// Bad track counts.
+		let mut bad = raw;
+		for n in [0, 100, 255] {
+			bad[0] = n;
+			assert_eq!(AccurateRip::try_from(bad.as_slice()), Err(TocError::AccurateRipDecode));
+		}
+		bad[0] = 99;
+		assert!(AccurateRip::try_from(bad.as_slice()).is_ok());
+	}
+
+	#[test]
+	fn t_summarize() {
+		// A three-track disc with ties, an empty track, and a one-hit wonder.
+		let toc = Toc::from_cdtoc("3+96+2EE+546+79E").expect("Invalid TOC.");
+		let ar_id = toc.accuraterip_id();
+		let mut bin = Vec::new();
+		for (c1, c2) in [(9_u32, 40_u32), (8, 30), (7, 40), (9, 30)] {
+			bin.extend_from_slice(ar_id.as_ref());
+			bin.push(5);
+			bin.extend_from_slice(&c1.to_le_bytes());
+			bin.extend_from_slice(&[0, 0, 0, 0]);
+			bin.push(3);
+			bin.extend_from_slice(&[0; 8]);
+			bin.push(6);
+			bin.extend_from_slice(&c2.to_le_bytes());
+			bin.extend_from_slice(&[0, 0, 0, 0]);
+		}
+
+		let detailed = ar_id.parse_checksums_detailed(&bin).expect("Parse failed.");
+		let summary = detailed.summarize();
+		assert_eq!(summary.len(), 3);
+
+		// Track one: 9 has ten, 8 and 7 have five; no ties.
+		assert_eq!(summary[0].best_crc(), Some(9));
+		assert_eq!(summary[0].best_confidence(), 10);
+		assert_eq!(summary[0].total_confidence(), 20);
+		assert_eq!(summary[0].distinct_crcs(), 3);
+
+		// Track two: empty.
+		assert_eq!(summary[1], TrackSummary::default());
+		assert_eq!(summary[1].best_crc(), None);
+		assert_eq!(summary[1].distinct_crcs(), 0);
+
+		// Track three: 30 and 40 tie at twelve; the lower wins.
+		assert_eq!(summary[2].best_crc(), Some(30));
+		assert_eq!(summary[2].best_confidence(), 12);
+		assert_eq!(summary[2].total_confidence(), 24);
+		assert_eq!(summary[2].distinct_crcs(), 2);
+
+		// The simple maps should give the same results.
+		let maps = ar_id.parse_checksums(&bin).expect("Parse failed.");
+		let summary2: Vec<TrackSummary> = maps.iter().map(TrackSummary::from).collect();
+		assert_eq!(summary, summary2);
+
+		// Ties should be broken the same way no matter the insertion order.
+		let mut map = BTreeMap::new();
+		for crc in [50_u32, 20, 40, 10, 30] {
+			map.insert(crc, 7);
+			assert_eq!(TrackSummary::from(&map).best_crc(), map.keys().next().copied());
+		}
+	}
+
+	#[test]
+	fn t_cache_bytes() {
+		// Same setup as the summary test, with some frame-450 CRCs and
+		// combined confidences beyond u8.
+		let toc = Toc::from_cdtoc("3+96+2EE+546+79E").expect("Invalid TOC.");
+		let ar_id = toc.accuraterip_id();
+		let mut bin = Vec::new();
+		for (c1, c2) in [(9_u32, 40_u32), (8, 30), (9, 40), (9, 30)] {
+			bin.extend_from_slice(ar_id.as_ref());
+			bin.push(200);
+			bin.extend_from_slice(&c1.to_le_bytes());
+			bin.extend_from_slice(&c2.to_le_bytes());
+			bin.push(3);
+			bin.extend_from_slice(&[0; 8]);
+			bin.push(6);
+			bin.extend_from_slice(&c2.to_le_bytes());
+			bin.extend_from_slice(&[0, 0, 0, 0]);
+		}
+		let detailed = ar_id.parse_checksums_detailed(&bin).expect("Parse failed.");
+		assert_eq!(detailed.id(), ar_id);
+		assert_eq!(detailed.tracks()[0][0].confidence(), 600);
+
+		// Round trip.
+		let cache = detailed.to_cache_bytes();
+		let restored = DiscChecksums::from_cache_bytes(&cache).expect("Cache failed.");
+		assert_eq!(restored, detailed);
+		assert_eq!(restored.to_maps(), ar_id.parse_checksums(&bin).expect("Parse failed."));
+
+		// Flipping any byte should fail.
+		for idx in 0..cache.len() {
+			for mask in [0x01_u8, 0x80, 0xFF] {
+				let mut bad = cache.clone();
+				bad[idx] ^= mask;
+				assert_eq!(
+					DiscChecksums::from_cache_bytes(&bad),
+					Err(TocError::ChecksumCache),
+					"Corruption at {idx} ({mask:02x}) was not detected.",
+				);
+			}
+		}
+
+		// As should truncation or padding.
+		for len in 0..cache.len() {
+			assert!(DiscChecksums::from_cache_bytes(&cache[..len]).is_err());
+		}
+		let mut long = cache.clone();
+		long.push(0);
+		assert!(DiscChecksums::from_cache_bytes(&long).is_err());
+
+		// A different version is stale, even if otherwise valid.
+		let mut stale = cache[..cache.len() - 4].to_vec();
+		stale[4] = 2;
+		let sum = checksums::cache_crc(&stale);
+		stale.extend_from_slice(&sum.to_le_bytes());
+		assert_eq!(DiscChecksums::from_cache_bytes(&stale), Err(TocError::ChecksumCache));
+
+		// A different disc decodes fine, but the ID tells the tale.
+		let other = Toc::from_cdtoc("3+96+2EE+546+7A0").expect("Invalid TOC.").accuraterip_id();
+		let mut bin2 = Vec::new();
+		bin2.extend_from_slice(other.as_ref());
+		bin2.extend_from_slice(&[1, 1, 0, 0, 0, 0, 0, 0, 0]);
+		bin2.extend_from_slice(&[1, 2, 0, 0, 0, 0, 0, 0, 0]);
+		bin2.extend_from_slice(&[1, 3, 0, 0, 0, 0, 0, 0, 0]);
+		let cache2 = other.parse_checksums_detailed(&bin2).expect("Parse failed.").to_cache_bytes();
+		let restored2 = DiscChecksums::from_cache_bytes(&cache2).expect("Cache failed.");
+		assert_eq!(restored2.id(), other);
+		assert_ne!(restored2.id(), ar_id);
+	}
+
+	#[test]
+	fn t_decode() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let ar_id = toc.accuraterip_id();
+		assert!(ar_id.matches_toc(&toc));
+
+		// Relaxations.
+		for src in [
+			"004-0002189a-00087f33-1f02e004",
+			"004-0002189A-00087F33-1F02E004",
+			"004-0002189a-00087F33-1f02E004",
+			"  004-0002189a-00087f33-1f02e004\t\n",
+			"004-0002189a-00087f33-1f02e004.",
+			" 004-0002189A-00087F33-1F02E004. ",
+		] {
+			assert_eq!(AccurateRip::decode(src), Ok(ar_id), "{src:?}");
+		}
+
+		// Rejections.
+		for src in [
+			"",
+			".",
+			"004-0002189a-00087f33-1f02e004..",
+			"004-0002189a-00087f33-1f02e004,",
+			".004-0002189a-00087f33-1f02e004",
+			"000-0002189a-00087f33-1f02e004",
+			"100-0002189a-00087f33-1f02e004",
+			"255-0002189a-00087f33-1f02e004",
+			"004 0002189a-00087f33-1f02e004",
+			"004-0002189a-00087f33-1f02e0g4",
+		] {
+			assert_eq!(AccurateRip::decode(src), Err(TocError::AccurateRipDecode), "{src:?}");
+		}
+
+		// The edges are fine.
+		assert!(AccurateRip::decode("001-0002189a-00087f33-1f02e004").is_ok());
+		assert!(AccurateRip::decode("099-0002189a-00087f33-1f02e004").is_ok());
+
+		// TOC mismatches.
+		let ar_id = AccurateRip::decode("005-0002189a-00087f33-1f02e004").expect("Decode failed.");
+		assert!(! ar_id.matches_toc(&toc));
+		let extra = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC.");
+		assert!(extra.accuraterip_id().matches_toc(&extra));
+		assert!(! extra.accuraterip_id_with(ArLeadout::AudioLeadout).matches_toc(&extra));
+	}
+
+	#[test]
+	fn t_from_offsets() {
+		for t in [
+			"D+96+3B5D+78E3+B441+EC83+134F4+17225+1A801+1EA5C+23B5B+27CEF+2B58B+2F974+35D56+514C8",
+			"4+96+2D2B+6256+B327+D84A",
+			"10+B6+5352+62AC+99D6+E218+12AC0+135E7+142E9+178B0+19D22+1B0D0+1E7FA+22882+247DB+27074+2A1BD+2C0FB",
+			"15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46",
+			"A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11",
+		] {
+			let mut toc = Toc::from_cdtoc(t).expect("Invalid TOC");
+			assert_eq!(
+				AccurateRip::from_offsets(toc.audio_sectors(), toc.data_sector(), toc.leadout()),
+				Ok(toc.accuraterip_id()),
+				"{t}",
+			);
+
+			// Try it with data in front too.
+			if toc.set_kind(TocKind::DataFirst).is_ok() {
+				assert_eq!(
+					AccurateRip::from_offsets(toc.audio_sectors(), toc.data_sector(), toc.leadout()),
+					Ok(toc.accuraterip_id()),
+					"{t}",
+				);
+			}
+		}
+
+		// Out-of-spec stuff should still work.
+		assert!(AccurateRip::from_offsets(&[0, 100, 50], None, 10).is_ok());
+		assert!(AccurateRip::from_offsets(&[u32::MAX; 99], Some(u32::MAX), u32::MAX).is_ok());
+
+		// But not bad track counts.
+		assert_eq!(AccurateRip::from_offsets(&[], None, 55370), Err(TocError::NoAudio));
+		assert_eq!(AccurateRip::from_offsets(&[150; 100], None, 55370), Err(TocError::TrackCount));
+	}
+
+	#[test]
+	fn t_leadout() {
+		// Audio-only discs should be unaffected.
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let ar_id = toc.accuraterip_id();
+		assert_eq!(toc.accuraterip_id_with(ArLeadout::DiscLeadout), ar_id);
+		assert_eq!(toc.accuraterip_id_with(ArLeadout::AudioLeadout), ar_id);
+
+		// CD-Extra discs should differ. Note these IDs were checked against
+		// an independent implementation of the algorithm, not taken from the
+		// AccurateRip database.
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC.");
+		let cddb = toc.cddb_id();
+		let disc = toc.accuraterip_id_with(ArLeadout::DiscLeadout);
+		let audio = toc.accuraterip_id_with(ArLeadout::AudioLeadout);
+		assert_eq!(disc, toc.accuraterip_id());
+		assert_eq!(disc.to_string(), format!("010-000f2e21-007cfeda-{cddb}"));
+		assert_eq!(audio.to_string(), format!("010-000e6a37-007493cc-{cddb}"));
+		assert_eq!(audio.cddb_id(), cddb);
+
+		// Data-first discs, too, should be unaffected.
+		let mut toc = toc;
+		toc.set_kind(TocKind::DataFirst).expect("Unable to set kind.");
+		assert_eq!(
+			toc.accuraterip_id_with(ArLeadout::DiscLeadout),
+			toc.accuraterip_id_with(ArLeadout::AudioLeadout),
+		);
+	}
+
+	#[test]
+	fn t_ord() {
+		use std::collections::{
+			BTreeSet,
+			HashSet,
+		};
+
+		// Generate a bunch of pseudo-random IDs.
+		let mut seed: u64 = 0x0bad_cafe;
+		let mut ids = Vec::with_capacity(500);
+		for _ in 0..500 {
+			let mut raw = [0_u8; 13];
+			for b in &mut raw { *b = crate::xorshift(&mut seed).to_le_bytes()[0]; }
+			raw[0] = raw[0] % 99 + 1;
+			ids.push(AccurateRip::from(raw));
+		}
+
+		// Ordering should match the bytes.
+		let set: BTreeSet<AccurateRip> = ids.iter().copied().collect();
+		let mut raw: Vec<[u8; 13]> = ids.iter().copied().map(<[u8; 13]>::from).collect();
+		raw.sort_unstable();
+		raw.dedup();
+		assert_eq!(set.len(), raw.len());
+		assert!(set.iter().map(|id| <[u8; 13]>::from(*id)).eq(raw.iter().copied()));
+		for pair in ids.windows(2) {
+			assert_eq!(pair[0].cmp(&pair[1]), <[u8; 13]>::from(pair[0]).cmp(&<[u8; 13]>::from(pair[1])));
+			assert_eq!(pair[0] == pair[1], pair[0].cmp(&pair[1]).is_eq());
+		}
+
+		// Borrowed lookups.
+		let hashed: HashSet<AccurateRip> = ids.iter().copied().collect();
+		for id in &ids {
+			let bytes: &[u8; 13] = id.as_ref();
+			assert!(set.contains(bytes.as_slice()));
+			assert!(hashed.contains(bytes.as_slice()));
+		}
+		assert!(! set.contains([0_u8; 13].as_slice()));
+		assert!(! hashed.contains([0_u8; 12].as_slice()));
+	}
+
+	#[test]
+	fn t_from_filename() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A")
+			.expect("Invalid TOC.");
+		let ar = toc.accuraterip_id();
+
+		for src in [
+			"004-0002189a-00087f33-1f02e004",
+			"  004-0002189a-00087f33-1f02e004\n",
+			"dBAR-004-0002189a-00087f33-1f02e004.bin",
+			"DBAR-004-0002189A-00087F33-1F02E004.BIN",
+			"dBAR-004-0002189a-00087f33-1f02e004",
+			"004-0002189a-00087f33-1f02e004.bin",
+			"/home/user/.cache/ar/dBAR-004-0002189a-00087f33-1f02e004.bin",
+			"C:\\AR\\dBAR-004-0002189a-00087f33-1f02e004.bin",
+			"http://www.accuraterip.com/accuraterip/a/9/8/dBAR-004-0002189a-00087f33-1f02e004.bin",
+			" https://www.accuraterip.com/accuraterip/a/9/8/dBAR-004-0002189a-00087f33-1f02e004.bin ",
+		] {
+			assert_eq!(AccurateRip::from_filename(src), Ok(ar), "{src:?}");
+		}
+		assert_eq!(AccurateRip::from_filename(ar.checksum_url()), Ok(ar));
+
+		// Near misses.
+		for src in [
+			"",
+			"dBAR-.bin",
+			"dBAR004-0002189a-00087f33-1f02e004.bin",
+			"dBAR-004-0002189a-00087f33-1f02e00.bin",
+			"dBAR-004-0002189g-00087f33-1f02e004.bin",
+			"dBAR-004-0002189a-00087f33-1f02e004.bin.bin",
+			"dBAR-004-0002189a-00087f33-1f02e004.bin/",
+			"xdBAR-004-0002189a-00087f33-1f02e004.bin",
+		] {
+			assert_eq!(
+				AccurateRip::from_filename(src),
+				Err(TocError::AccurateRipDecode),
+				"{src:?}",
+			);
+		}
+	}
+
+	#[test]
+	fn t_drive_offsets_bin() {
+		// Build a larger table with a mix of vendorless, duplicate, and empty
+		// entries, and junk in the reserved bytes.
+		let mut seed: u64 = 0x2468_ace1;
+		let mut raw = Vec::new();
+		for i in 0..500_u16 {
+			let n = crate::xorshift(&mut seed) as u32;
+
+			let offset = (n % 2941) as i16 - 1470;
+			let name = match i % 7 {
+				0 => format!("- MODEL {:05}", i / 2),
+				1 => format!("VENDOR{}  - ", i % 3),
+				_ => format!("VENDOR{} - MODEL {:05}", i % 3, i / 2),
+			};
+			raw.extend_from_slice(&offset.to_le_bytes());
+			raw.extend_from_slice(name.as_bytes());
+			raw.resize(raw.len() + 33 - name.len(), 0);
+			raw.extend_from_slice(&n.to_le_bytes());
+			raw.extend(n.to_be_bytes().iter().cycle().take(30));
+		}
+
+		let offsets = DriveOffsets::parse(&raw).expect("Drive offset parsing failed.");
+		let parsed = AccurateRip::parse_drive_offsets(&raw)
+			.expect("Drive offset parsing failed.");
+		assert_eq!(offsets.len(), parsed.len());
+		assert!(offsets.len() < 500);
+		assert!(offsets.iter().any(|e| e.vendor().is_empty()));
+		assert!(offsets.iter().any(|e| e.reserved() != &[0; 30]));
+
+		let bin = offsets.to_bin();
+		assert_eq!(bin.len(), offsets.len() * 69);
+		let offsets2 = DriveOffsets::parse(&bin).expect("Drive offset parsing failed.");
+		assert_eq!(offsets, offsets2);
+		assert_eq!(bin, offsets2.to_bin());
 	}
 
 	#[test]
@@ -606,5 +1847,62 @@ mod tests {
 		let offset = parsed.get(&("PIONEER", "BD-RW   BDR-X13U"))
 			.expect("Unable to find BDR-X13U offset.");
 		assert_eq!(*offset, 667);
+
+		// The owned version should have the same stuff.
+		let offsets = DriveOffsets::parse(OFFSET_BIN).expect("Drive offset parsing failed.");
+		assert_eq!(offsets.len(), parsed.len());
+		for (e, (k, v)) in offsets.iter().zip(parsed.iter()) {
+			assert_eq!((e.vendor(), e.model()), *k);
+			assert_eq!(e.offset(), *v);
+		}
+
+		// Loose lookups.
+		for (vendor, model) in [
+			("PIONEER", "BD-RW   BDR-X13U"),
+			("pioneer", "bd-rw bdr-x13u"),
+			("  Pioneer ", "BD-RW\tBDR-X13U"),
+		] {
+			let e = offsets.get(vendor, model).expect("Missing drive.");
+			assert_eq!(e.offset(), 667);
+			assert_eq!(e.model(), "BD-RW   BDR-X13U");
+		}
+		assert!(offsets.get("PIONEER", "BD-RW BDR-X1").is_none());
+		assert!(offsets.get("", "BD-RW BDR-X13U").is_none());
+
+		// Fuzzy searches.
+		// The X13 also matches, but is less specific.
+		let found = offsets.find("PIONEER BD-RW   BDR-X13U 1.01");
+		assert_eq!(found.len(), 2);
+		assert_eq!(found[0].model(), "BD-RW   BDR-X13U");
+		assert_eq!(found[1].model(), "BD-RW   BDR-X13");
+		let found = offsets.find("pioneer bd-rw bdr-x12");
+		assert_eq!(found.len(), 1);
+		assert_eq!(found[0].model(), "BD-RW   BDR-X12");
+		assert!(offsets.find("PLEXTOR").is_empty());
+
+		// Submission counts.
+		let e = offsets.get("PIONEER", "BD-RW BDR-X12").expect("Missing drive.");
+		assert_eq!(e.submissions(), 75);
+		assert_eq!(e.reserved(), &[0; 30]);
+		assert_eq!(offsets.get("PIONEER", "BD-RW BDR-X12U").map(DriveOffset::submissions), Some(201));
+		assert_eq!(offsets.get("PIONEER", "BD-RW BDR-X13").map(DriveOffset::submissions), Some(8));
+		assert_eq!(offsets.get("PIONEER", "BD-RW BDR-X13U").map(DriveOffset::submissions), Some(60));
+
+		// Round trip. The delimiters get normalized, so the first pass may
+		// differ from the source, but after that, the bytes should be stable.
+		let bin = offsets.to_bin();
+		assert_eq!(bin.len(), OFFSET_BIN.len());
+		assert_eq!(DriveOffsets::parse(&bin).as_ref(), Ok(&offsets));
+		assert_eq!(DriveOffsets::parse(&bin).map(|o| o.to_bin()), Ok(bin));
+
+		// The bundled snapshot should survive a round trip too, whatever it
+		// happens to contain.
+		let skel = DriveOffsets::parse(include_bytes!("../skel/DriveOffsets.bin"))
+			.expect("Drive offset parsing failed.");
+		assert_eq!(DriveOffsets::parse(&skel.to_bin()).as_ref(), Ok(&skel));
+
+		// Errors.
+		assert_eq!(DriveOffsets::parse(&[]), Err(TocError::NoDriveOffsets));
+		assert_eq!(DriveOffsets::parse(&OFFSET_BIN[..68]), Err(TocError::NoDriveOffsets));
 	}
 }
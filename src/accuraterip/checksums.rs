@@ -0,0 +1,555 @@
+/*!
+# CDTOC: AccurateRip Checksums
+*/
+
+use crate::{
+	AccurateRip,
+	TocError,
+};
+use std::{
+	collections::BTreeMap,
+	slice::ChunksExact,
+};
+
+
+
+/// # Cache Magic.
+const CACHE_MAGIC: [u8; 4] = *b"cdAR";
+
+/// # Cache Format Version.
+///
+/// Bump this any time the cache layout changes.
+const CACHE_VERSION: u8 = 1;
+
+/// # Cache Header Size.
+///
+/// Magic, version, disc ID, and chunk count.
+const CACHE_HEADER_SIZE: usize = 4 + 1 + 13 + 4;
+
+/// # Cache Entry Size.
+///
+/// CRC, confidence, and frame-450 CRC.
+const CACHE_ENTRY_SIZE: usize = 4 + 2 + 4;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # AccurateRip Checksum Entry.
+///
+/// This holds a single track record from an AccurateRip checksum bin: the
+/// track CRC, its confidence, and — if present — the CRC of the track's
+/// 450th frame, which AccurateRip uses for offset detection.
+///
+/// Values of this type are returned by [`AccurateRip::parse_checksum_entries`](crate::AccurateRip::parse_checksum_entries),
+/// one per record, and — combined by CRC — as part of [`DiscChecksums`].
+pub struct ChecksumEntry {
+	/// # CRC.
+	crc: u32,
+
+	/// # Confidence.
+	confidence: u16,
+
+	/// # Frame 450 CRC.
+	frame450: u32,
+}
+
+impl ChecksumEntry {
+	/// # From Record.
+	///
+	/// Parse a 9-byte track record, returning `None` if the CRC is zero.
+	pub(super) const fn from_record(v: [u8; 9]) -> Option<Self> {
+		let crc = u32::from_le_bytes([v[1], v[2], v[3], v[4]]);
+		if crc == 0 { None }
+		else {
+			Some(Self {
+				crc,
+				confidence: v[0] as u16,
+				frame450: u32::from_le_bytes([v[5], v[6], v[7], v[8]]),
+			})
+		}
+	}
+
+	#[must_use]
+	/// # CRC.
+	///
+	/// Return the track checksum (v1 or v2).
+	pub const fn crc(&self) -> u32 { self.crc }
+
+	#[must_use]
+	/// # Confidence.
+	///
+	/// Return the number of submissions matching this record.
+	///
+	/// A single bin record can't hold more than `255`, but combined entries
+	/// (see [`DiscChecksums`]) can.
+	pub const fn confidence(&self) -> u16 { self.confidence }
+
+	#[must_use]
+	/// # Frame 450 CRC.
+	///
+	/// Return the CRC of the track's 450th frame, used for offset detection,
+	/// or `None` if the record doesn't have one.
+	pub const fn frame450(&self) -> Option<u32> {
+		if self.frame450 == 0 { None }
+		else { Some(self.frame450) }
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # AccurateRip Checksum Chunk (Borrowed).
+///
+/// This is a zero-copy view of a single response chunk from an AccurateRip
+/// checksum bin, holding one record for each track on the disc.
+///
+/// Values of this type are yielded by [`AccurateRip::checksum_chunks`](crate::AccurateRip::checksum_chunks).
+pub struct ChunkRef<'a> {
+	/// # Raw Track Records.
+	///
+	/// This is guaranteed to be a multiple of nine bytes.
+	raw: &'a [u8],
+}
+
+impl<'a> ChunkRef<'a> {
+	/// # New.
+	///
+	/// The caller must ensure the length is a multiple of nine.
+	pub(super) const fn new(raw: &'a [u8]) -> Self { Self { raw } }
+
+	#[must_use]
+	/// # Get Track Record.
+	///
+	/// Return the `(crc, confidence)` pair for the track at the given index
+	/// (`n-1`), if any. A CRC of zero indicates no data.
+	pub fn get(&self, idx: usize) -> Option<(u32, u8)> {
+		let v = self.raw.get(idx * 9..idx * 9 + 9)?;
+		Some((u32::from_le_bytes([v[1], v[2], v[3], v[4]]), v[0]))
+	}
+
+	#[must_use]
+	/// # Is Empty?
+	pub const fn is_empty(&self) -> bool { self.raw.is_empty() }
+
+	#[must_use]
+	/// # Iterate Track Records.
+	///
+	/// Return an iterator over the `(crc, confidence)` pairs for each track,
+	/// in order. A CRC of zero indicates no data.
+	pub fn iter(&self) -> impl ExactSizeIterator<Item=(u32, u8)> + 'a {
+		self.records().map(|v| (u32::from_le_bytes([v[1], v[2], v[3], v[4]]), v[0]))
+	}
+
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the number of track records in the chunk.
+	pub const fn len(&self) -> usize { self.raw.len() / 9 }
+
+	/// # Raw Records.
+	pub(super) fn records(&self) -> ChunksExact<'a, u8> { self.raw.chunks_exact(9) }
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # AccurateRip Disc Checksums.
+///
+/// This holds the parsed contents of an AccurateRip checksum bin, as
+/// returned by [`AccurateRip::parse_checksums_detailed`](crate::AccurateRip::parse_checksums_detailed).
+///
+/// Each track's entries are combined by CRC — adding up their confidences
+/// without saturation — and sorted by confidence, highest first, with ties
+/// going to the lower CRC. When combined records disagree about the
+/// frame-450 CRC, the first non-zero one wins.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::Toc;
+///
+/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+/// let ar_id = toc.accuraterip_id();
+///
+/// // A bin with two response chunks that agree about the first track.
+/// let mut bin = Vec::new();
+/// for (conf, crc2) in [(200, 5), (100, 6)] {
+///     bin.extend_from_slice(ar_id.as_ref());
+///     bin.extend_from_slice(&[conf, 1, 0, 0, 0, 0, 0, 0, 0]);
+///     bin.extend_from_slice(&[conf, crc2, 0, 0, 0, 0, 0, 0, 0]);
+/// }
+///
+/// let parsed = ar_id.parse_checksums_detailed(&bin).unwrap();
+/// assert_eq!(parsed.chunks(), 2);
+/// assert_eq!(parsed.max_confidence(), 300);
+///
+/// // No saturation here!
+/// assert_eq!(parsed.tracks()[0][0].crc(), 1);
+/// assert_eq!(parsed.tracks()[0][0].confidence(), 300);
+///
+/// // Sorted by confidence.
+/// let crcs: Vec<u32> = parsed.tracks()[1].iter().map(|e| e.crc()).collect();
+/// assert_eq!(crcs, [5, 6]);
+/// ```
+pub struct DiscChecksums {
+	/// # Disc ID.
+	id: AccurateRip,
+
+	/// # Per-Track Entries.
+	tracks: Vec<Vec<ChecksumEntry>>,
+
+	/// # Number of Chunks.
+	chunks: usize,
+}
+
+impl DiscChecksums {
+	/// # New.
+	///
+	/// Combine and sort raw per-track records.
+	pub(super) fn new(id: AccurateRip, raw: Vec<Vec<ChecksumEntry>>, chunks: usize) -> Self {
+		let tracks = raw.into_iter()
+			.map(|entries| {
+				let mut combined = BTreeMap::<u32, ChecksumEntry>::new();
+				for e in entries {
+					combined.entry(e.crc)
+						.and_modify(|c| {
+							c.confidence = c.confidence.saturating_add(e.confidence);
+							if c.frame450 == 0 { c.frame450 = e.frame450; }
+						})
+						.or_insert(e);
+				}
+
+				let mut out: Vec<ChecksumEntry> = combined.into_values().collect();
+				out.sort_by(|a, b| b.confidence.cmp(&a.confidence).then(a.crc.cmp(&b.crc)));
+				out
+			})
+			.collect();
+
+		Self { id, tracks, chunks }
+	}
+
+	/// # From Cache Bytes.
+	///
+	/// Rebuild an instance from the compact binary format produced by
+	/// [`DiscChecksums::to_cache_bytes`].
+	///
+	/// The cache embeds the disc ID, so be sure to compare
+	/// [`DiscChecksums::id`] against the disc you're actually working with
+	/// before trusting the results.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{DiscChecksums, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	///
+	/// let mut bin = Vec::new();
+	/// bin.extend_from_slice(ar_id.as_ref());
+	/// bin.extend_from_slice(&[20, 1, 0, 0, 0, 0, 0, 0, 0]);
+	/// bin.extend_from_slice(&[20, 2, 0, 0, 0, 0, 0, 0, 0]);
+	/// let parsed = ar_id.parse_checksums_detailed(&bin).unwrap();
+	///
+	/// let cache = parsed.to_cache_bytes();
+	/// let restored = DiscChecksums::from_cache_bytes(&cache).unwrap();
+	/// assert_eq!(restored.id(), ar_id);
+	/// assert_eq!(restored, parsed);
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the data is truncated, corrupt, or was
+	/// written by an incompatible version of this library.
+	pub fn from_cache_bytes(src: &[u8]) -> Result<Self, TocError> {
+		// Check the magic and version.
+		if
+			src.len() < CACHE_HEADER_SIZE + 4 ||
+			src[..4] != CACHE_MAGIC ||
+			src[4] != CACHE_VERSION
+		{
+			return Err(TocError::ChecksumCache);
+		}
+
+		// Check the integrity.
+		let (body, sum) = src.split_at(src.len() - 4);
+		if cache_crc(body).to_le_bytes() != sum { return Err(TocError::ChecksumCache); }
+
+		let id = AccurateRip::try_from(&body[5..18]).map_err(|_| TocError::ChecksumCache)?;
+		let chunks = usize::try_from(u32::from_le_bytes([body[18], body[19], body[20], body[21]]))
+			.map_err(|_| TocError::ChecksumCache)?;
+
+		let mut rest = &body[CACHE_HEADER_SIZE..];
+		let mut tracks = Vec::with_capacity(usize::from(id.audio_len()));
+		for _ in 0..id.audio_len() {
+			let [a, b, ..] = *rest else { return Err(TocError::ChecksumCache); };
+			let len = usize::from(u16::from_le_bytes([a, b])) * CACHE_ENTRY_SIZE;
+			let raw = rest.get(2..len + 2).ok_or(TocError::ChecksumCache)?;
+			rest = &rest[len + 2..];
+
+			let entries: Vec<ChecksumEntry> = raw.chunks_exact(CACHE_ENTRY_SIZE)
+				.map(|v| ChecksumEntry {
+					crc: u32::from_le_bytes([v[0], v[1], v[2], v[3]]),
+					confidence: u16::from_le_bytes([v[4], v[5]]),
+					frame450: u32::from_le_bytes([v[6], v[7], v[8], v[9]]),
+				})
+				.collect();
+
+			// Enforce the same invariants as a freshly-parsed set.
+			if
+				entries.iter().any(|e| e.crc == 0) ||
+				entries.windows(2).any(|w|
+					w[1].confidence > w[0].confidence ||
+					(w[1].confidence == w[0].confidence && w[1].crc <= w[0].crc)
+				)
+			{
+				return Err(TocError::ChecksumCache);
+			}
+
+			tracks.push(entries);
+		}
+
+		// There shouldn't be anything left over.
+		if rest.is_empty() { Ok(Self { id, tracks, chunks }) }
+		else { Err(TocError::ChecksumCache) }
+	}
+
+	#[must_use]
+	/// # Number of Chunks.
+	///
+	/// Return the number of response chunks — i.e. distinct pressings — in
+	/// the bin.
+	pub const fn chunks(&self) -> usize { self.chunks }
+
+	#[must_use]
+	/// # Disc ID.
+	///
+	/// Return the AccurateRip ID of the disc these checksums belong to.
+	pub const fn id(&self) -> AccurateRip { self.id }
+
+	#[must_use]
+	/// # Maximum Confidence.
+	///
+	/// Return the highest confidence of any entry on the disc.
+	pub fn max_confidence(&self) -> u16 {
+		self.tracks.iter()
+			.filter_map(|t| t.first().map(ChecksumEntry::confidence))
+			.max()
+			.unwrap_or(0)
+	}
+
+	#[must_use]
+	/// # Summarize.
+	///
+	/// Return a [`TrackSummary`] for each track, indexed by track number
+	/// (`n-1`).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+	/// let ar_id = toc.accuraterip_id();
+	///
+	/// let mut bin = Vec::new();
+	/// for (conf, crc2) in [(200, 5), (100, 6), (50, 7)] {
+	///     bin.extend_from_slice(ar_id.as_ref());
+	///     bin.extend_from_slice(&[conf, 1, 0, 0, 0, 0, 0, 0, 0]);
+	///     bin.extend_from_slice(&[conf, crc2, 0, 0, 0, 0, 0, 0, 0]);
+	/// }
+	///
+	/// let summary = ar_id.parse_checksums_detailed(&bin).unwrap().summarize();
+	/// assert_eq!(summary[0].best_crc(), Some(1));
+	/// assert_eq!(summary[0].best_confidence(), 350);
+	/// assert_eq!(summary[0].total_confidence(), 350);
+	/// assert_eq!(summary[0].distinct_crcs(), 1);
+	///
+	/// assert_eq!(summary[1].best_crc(), Some(5));
+	/// assert_eq!(summary[1].best_confidence(), 200);
+	/// assert_eq!(summary[1].total_confidence(), 350);
+	/// assert_eq!(summary[1].distinct_crcs(), 3);
+	/// ```
+	pub fn summarize(&self) -> Vec<TrackSummary> {
+		self.tracks.iter()
+			.map(|t| {
+				// Entries are already sorted, so the first is the best.
+				let (best_crc, best_confidence) = t.first()
+					.map_or((0, 0), |e| (e.crc, e.confidence));
+				TrackSummary {
+					best_crc,
+					best_confidence,
+					total_confidence: t.iter().map(|e| u32::from(e.confidence)).sum(),
+					distinct_crcs: t.len(),
+				}
+			})
+			.collect()
+	}
+
+	#[must_use]
+	/// # To Cache Bytes.
+	///
+	/// Encode the checksums — along with the disc ID — into a compact,
+	/// versioned binary format suitable for caching to disk, to be read back
+	/// later with [`DiscChecksums::from_cache_bytes`].
+	///
+	/// The format includes a checksum of its own, so corruption is detected
+	/// rather than silently producing bad data.
+	pub fn to_cache_bytes(&self) -> Vec<u8> {
+		let len = CACHE_HEADER_SIZE + 4 + self.tracks.iter()
+			.map(|t| 2 + t.len() * CACHE_ENTRY_SIZE)
+			.sum::<usize>();
+
+		let mut out = Vec::with_capacity(len);
+		out.extend_from_slice(&CACHE_MAGIC);
+		out.push(CACHE_VERSION);
+		out.extend_from_slice(<[u8; 13]>::from(self.id).as_slice());
+		out.extend_from_slice(&u32::try_from(self.chunks).unwrap_or(u32::MAX).to_le_bytes());
+
+		for t in &self.tracks {
+			// Combined entries are unique by CRC, so this won't realistically
+			// overflow, but just in case.
+			let count = u16::try_from(t.len()).unwrap_or(u16::MAX);
+			out.extend_from_slice(&count.to_le_bytes());
+			for e in t.iter().take(usize::from(count)) {
+				out.extend_from_slice(&e.crc.to_le_bytes());
+				out.extend_from_slice(&e.confidence.to_le_bytes());
+				out.extend_from_slice(&e.frame450.to_le_bytes());
+			}
+		}
+
+		let sum = cache_crc(&out);
+		out.extend_from_slice(&sum.to_le_bytes());
+		out
+	}
+
+	#[must_use]
+	/// # Tracks.
+	///
+	/// Return the entries for each track, indexed by track number (`n-1`).
+	pub fn tracks(&self) -> &[Vec<ChecksumEntry>] { &self.tracks }
+
+	#[must_use]
+	/// # To Maps.
+	///
+	/// Convert to the simpler `checksum => confidence` structure returned by
+	/// [`AccurateRip::parse_checksums`](crate::AccurateRip::parse_checksums),
+	/// saturating the confidences at `255`.
+	pub fn to_maps(&self) -> Vec<BTreeMap<u32, u8>> {
+		self.tracks.iter()
+			.map(|t| t.iter()
+				.map(|e| (e.crc, u8::try_from(e.confidence).unwrap_or(u8::MAX)))
+				.collect()
+			)
+			.collect()
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # AccurateRip Track Summary.
+///
+/// This holds the highlights of a single track's AccurateRip checksums: the
+/// best (highest-confidence) CRC, its confidence, the total confidence
+/// across all entries, and the number of distinct CRCs.
+///
+/// When two or more CRCs tie for the highest confidence — which happens on
+/// discs with few submissions — the lowest CRC wins, so results are
+/// deterministic.
+///
+/// Values of this type are returned by [`DiscChecksums::summarize`], but can
+/// also be built from the simpler maps returned by [`AccurateRip::parse_checksums`](crate::AccurateRip::parse_checksums).
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::TrackSummary;
+/// use std::collections::BTreeMap;
+///
+/// let map: BTreeMap<u32, u8> = [(7, 10), (3, 10), (5, 2)].into_iter().collect();
+/// let summary = TrackSummary::from(&map);
+///
+/// // A tie; the lower CRC wins.
+/// assert_eq!(summary.best_crc(), Some(3));
+/// assert_eq!(summary.best_confidence(), 10);
+/// assert_eq!(summary.total_confidence(), 22);
+/// assert_eq!(summary.distinct_crcs(), 3);
+/// ```
+pub struct TrackSummary {
+	/// # Best CRC.
+	///
+	/// This is zero if there are no entries.
+	best_crc: u32,
+
+	/// # Best Confidence.
+	best_confidence: u16,
+
+	/// # Total Confidence.
+	total_confidence: u32,
+
+	/// # Distinct CRCs.
+	distinct_crcs: usize,
+}
+
+impl From<&BTreeMap<u32, u8>> for TrackSummary {
+	fn from(src: &BTreeMap<u32, u8>) -> Self {
+		let mut out = Self::default();
+		for (&crc, &conf) in src {
+			// Zero isn't a real CRC.
+			if crc == 0 { continue; }
+
+			// Maps are sorted by CRC, so only strictly better confidences
+			// should replace the current best.
+			let conf = u16::from(conf);
+			if out.best_crc == 0 || out.best_confidence < conf {
+				out.best_crc = crc;
+				out.best_confidence = conf;
+			}
+			out.total_confidence += u32::from(conf);
+			out.distinct_crcs += 1;
+		}
+		out
+	}
+}
+
+impl TrackSummary {
+	#[must_use]
+	/// # Best CRC.
+	///
+	/// Return the highest-confidence CRC, or `None` if the track has no
+	/// entries. Ties go to the lowest CRC.
+	pub const fn best_crc(&self) -> Option<u32> {
+		if self.best_crc == 0 { None }
+		else { Some(self.best_crc) }
+	}
+
+	#[must_use]
+	/// # Best Confidence.
+	///
+	/// Return the confidence of the best CRC.
+	pub const fn best_confidence(&self) -> u16 { self.best_confidence }
+
+	#[must_use]
+	/// # Distinct CRCs.
+	///
+	/// Return the number of different CRCs on record for the track.
+	pub const fn distinct_crcs(&self) -> usize { self.distinct_crcs }
+
+	#[must_use]
+	/// # Total Confidence.
+	///
+	/// Return the sum of all confidences for the track.
+	pub const fn total_confidence(&self) -> u32 { self.total_confidence }
+}
+
+
+
+/// # Cache CRC.
+///
+/// Return the CRC-32 (IEEE) of the data, used to detect cache corruption.
+pub(super) const fn cache_crc(src: &[u8]) -> u32 { crate::crc32::checksum(src) }
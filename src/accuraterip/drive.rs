@@ -0,0 +1,381 @@
+/*!
+# CDTOC: AccurateRip Drive Offsets
+*/
+
+use crate::TocError;
+use super::{
+	DRIVE_OFFSET_MODEL_MAX,
+	DRIVE_OFFSET_OFFSET_RNG,
+	DRIVE_OFFSET_VENDOR_MAX,
+};
+use std::collections::BTreeMap;
+
+
+
+/// # Block Size.
+///
+/// The size of each raw entry, in bytes.
+///
+/// Each block comprises a little-endian `i16` offset, a null-padded 33-byte
+/// "vendor - model" string, a little-endian `u32` submission count, and 30
+/// bytes of (mostly unused) percentage data.
+pub(super) const BLOCK_SIZE: usize = 69;
+
+/// # Name Size.
+///
+/// The size of the vendor/model field, including its null terminator.
+const NAME_SIZE: usize = 33;
+
+/// # Reserved Size.
+///
+/// The size of the trailing reserved field.
+const RESERVED_SIZE: usize = 30;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # Drive Offset.
+///
+/// This holds a single entry from the AccurateRip drive offset list: the
+/// drive's vendor (which may be empty), model, read offset (in samples), and
+/// submission count.
+///
+/// See [`DriveOffsets`] for more details.
+pub struct DriveOffset {
+	/// # Vendor.
+	vendor: String,
+
+	/// # Model.
+	model: String,
+
+	/// # Read Offset.
+	offset: i16,
+
+	/// # Submissions.
+	submissions: u32,
+
+	/// # Reserved Bytes.
+	///
+	/// These aren't used for anything, but are kept for round-trip purposes.
+	reserved: [u8; RESERVED_SIZE],
+}
+
+impl DriveOffset {
+	#[must_use]
+	/// # Vendor.
+	///
+	/// Note: this may be empty.
+	pub fn vendor(&self) -> &str { &self.vendor }
+
+	#[must_use]
+	/// # Model.
+	pub fn model(&self) -> &str { &self.model }
+
+	#[must_use]
+	/// # Read Offset.
+	///
+	/// Return the drive's read offset, in samples.
+	pub const fn offset(&self) -> i16 { self.offset }
+
+	#[must_use]
+	/// # Reserved Bytes.
+	///
+	/// Return the raw trailing bytes from the entry's block. (AccurateRip
+	/// does not currently do anything meaningful with these.)
+	pub const fn reserved(&self) -> &[u8; 30] { &self.reserved }
+
+	#[must_use]
+	/// # Submission Count.
+	///
+	/// Return the number of submissions backing the entry. Higher counts
+	/// indicate more trustworthy offsets.
+	pub const fn submissions(&self) -> u32 { self.submissions }
+}
+
+impl DriveOffset {
+	/// # Write Block.
+	///
+	/// Append the entry to `out` in the original 69-byte layout.
+	fn write_block(&self, out: &mut Vec<u8>) {
+		let start = out.len();
+		out.extend_from_slice(&self.offset.to_le_bytes());
+
+		// The name, as "vendor - model" or "- model".
+		if ! self.vendor.is_empty() {
+			out.extend_from_slice(self.vendor.as_bytes());
+			out.push(b' ');
+		}
+		out.extend_from_slice(b"- ");
+		out.extend_from_slice(self.model.as_bytes());
+		out.resize(start + 2 + NAME_SIZE, 0);
+
+		out.extend_from_slice(&self.submissions.to_le_bytes());
+		out.extend_from_slice(&self.reserved);
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # Drive Offsets.
+///
+/// This is an owned, searchable version of the AccurateRip drive offset
+/// list ([bin file](crate::AccurateRip::DRIVE_OFFSET_URL)).
+///
+/// Unlike [`AccurateRip::parse_drive_offsets`](crate::AccurateRip::parse_drive_offsets),
+/// lookups are forgiving of case and whitespace differences, since the
+/// strings reported by operating systems rarely match the list exactly.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use cdtoc::DriveOffsets;
+///
+/// // Load the raw data from somewhere.
+/// let raw: Vec<u8> = std::fs::read("DriveOffsets.bin").unwrap();
+/// let offsets = DriveOffsets::parse(&raw).unwrap();
+///
+/// // Case and spacing don't matter.
+/// let drive = offsets.get("pioneer", "bd-rw bdr-x13u").unwrap();
+/// assert_eq!(drive.offset(), 667);
+///
+/// // Neither does the separation of vendor and model.
+/// let found = offsets.find("PIONEER BD-RW   BDR-X13U 1.01");
+/// assert_eq!(found[0].offset(), 667);
+/// ```
+pub struct DriveOffsets {
+	/// # Entries.
+	///
+	/// These are sorted and deduplicated by normalized vendor/model.
+	entries: Vec<DriveOffset>,
+
+	/// # Normalized Keys.
+	///
+	/// The normalized vendor/model for each entry, in the same order.
+	keys: Vec<(String, String)>,
+}
+
+impl<'a> IntoIterator for &'a DriveOffsets {
+	type Item = &'a DriveOffset;
+	type IntoIter = std::slice::Iter<'a, DriveOffset>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter { self.entries.iter() }
+}
+
+impl DriveOffsets {
+	/// # Parse.
+	///
+	/// Parse the raw AccurateRip offset list. Entries without models are
+	/// silently ignored; if the same drive appears more than once, the last
+	/// entry wins.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if parsing is unsuccessful, or the result is
+	/// empty.
+	pub fn parse(raw: &[u8]) -> Result<Self, TocError> {
+		// There should be thousands of blocks, but we _need_ at least one!
+		if raw.len() < BLOCK_SIZE { return Err(TocError::NoDriveOffsets); }
+
+		let mut map = BTreeMap::<(String, String), DriveOffset>::new();
+		for chunk in raw.chunks_exact(BLOCK_SIZE) {
+			if let Some((vendor, model, offset)) = parse_block(chunk)? {
+				// Everything after the name.
+				let (submissions, reserved) = chunk[2 + NAME_SIZE..]
+					.split_first_chunk::<4>()
+					.and_then(|(s, r)| Some((
+						u32::from_le_bytes(*s),
+						<[u8; RESERVED_SIZE]>::try_from(r).ok()?,
+					)))
+					.ok_or(TocError::DriveOffsetDecode)?;
+
+				map.insert(
+					(normalize(vendor), normalize(model)),
+					DriveOffset {
+						vendor: vendor.to_owned(),
+						model: model.to_owned(),
+						offset,
+						submissions,
+						reserved,
+					},
+				);
+			}
+		}
+
+		if map.is_empty() { return Err(TocError::NoDriveOffsets); }
+
+		let (keys, entries) = map.into_iter().unzip();
+		Ok(Self { entries, keys })
+	}
+
+	#[cfg(feature = "offsets-embedded")]
+	/// # From Built-In Entries.
+	///
+	/// Build the table from the entries generated by the build script, which
+	/// have already been validated, deduplicated, and sorted.
+	pub(super) fn from_builtin(src: &[(&str, &str, i16, u32, [u8; RESERVED_SIZE])]) -> Self {
+		let (keys, entries) = src.iter()
+			.map(|&(vendor, model, offset, submissions, reserved)| (
+				(normalize(vendor), normalize(model)),
+				DriveOffset {
+					vendor: vendor.to_owned(),
+					model: model.to_owned(),
+					offset,
+					submissions,
+					reserved,
+				},
+			))
+			.unzip();
+		Self { entries, keys }
+	}
+
+	#[must_use]
+	/// # Find.
+	///
+	/// Search for drives matching a single "vendor model" string, such as
+	/// those reported by operating systems. An entry matches if both its
+	/// vendor (if any) and model appear within the string, ignoring case
+	/// and whitespace differences.
+	///
+	/// Matches are returned most-specific first, i.e. by descending length
+	/// of the matched vendor and model.
+	pub fn find(&self, reported: &str) -> Vec<&DriveOffset> {
+		let reported = normalize(reported);
+		let mut out: Vec<(usize, &DriveOffset)> = self.keys.iter()
+			.zip(&self.entries)
+			.filter(|((v, m), _)| reported.contains(m.as_str()) && reported.contains(v.as_str()))
+			.map(|((v, m), e)| (v.len() + m.len(), e))
+			.collect();
+		out.sort_by_key(|(len, _)| std::cmp::Reverse(*len));
+		out.into_iter().map(|(_, e)| e).collect()
+	}
+
+	#[must_use]
+	/// # Get.
+	///
+	/// Return the entry for a specific vendor and model, ignoring case and
+	/// whitespace differences. Use an empty vendor for entries without one.
+	pub fn get(&self, vendor: &str, model: &str) -> Option<&DriveOffset> {
+		let key = (normalize(vendor), normalize(model));
+		let idx = self.keys.binary_search(&key).ok()?;
+		self.entries.get(idx)
+	}
+
+	#[must_use]
+	/// # Is Empty?
+	pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+	/// # Iterate Entries.
+	///
+	/// Return an iterator over all entries, sorted by (normalized) vendor
+	/// and model.
+	pub fn iter(&self) -> std::slice::Iter<'_, DriveOffset> { self.entries.iter() }
+
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the number of entries.
+	pub fn len(&self) -> usize { self.entries.len() }
+
+	#[must_use]
+	/// # To Bin.
+	///
+	/// Re-serialize the entries using the original AccurateRip layout, e.g.
+	/// for local caching. The result can be fed back through
+	/// [`DriveOffsets::parse`] to recover an identical set.
+	///
+	/// Note: the vendor/model delimiters are normalized in the process, so
+	/// the output will not necessarily be byte-for-byte identical to the
+	/// source.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use cdtoc::DriveOffsets;
+	///
+	/// let raw: Vec<u8> = std::fs::read("DriveOffsets.bin").unwrap();
+	/// let offsets = DriveOffsets::parse(&raw).unwrap();
+	/// assert_eq!(
+	///     DriveOffsets::parse(&offsets.to_bin()),
+	///     Ok(offsets),
+	/// );
+	/// ```
+	pub fn to_bin(&self) -> Vec<u8> {
+		let mut out = Vec::with_capacity(self.entries.len() * BLOCK_SIZE);
+		for e in &self.entries { e.write_block(&mut out); }
+		out
+	}
+}
+
+
+
+/// # Normalize.
+///
+/// Lowercase the string and collapse all whitespace runs to single spaces.
+fn normalize(src: &str) -> String {
+	let mut out = String::with_capacity(src.len());
+	for word in src.split_whitespace() {
+		if ! out.is_empty() { out.push(' '); }
+		for c in word.chars() { out.push(c.to_ascii_lowercase()); }
+	}
+	out
+}
+
+/// # Parse Block.
+///
+/// Parse the vendor, model, and offset from a single 69-byte block,
+/// returning `None` if the model is empty.
+///
+/// ## Errors
+///
+/// This will return an error if the data is bad.
+pub(super) fn parse_block(chunk: &[u8]) -> Result<Option<(&str, &str, i16)>, TocError> {
+	/// # Trim Callback.
+	///
+	/// This is used to trim both ASCII whitespace and control characters,
+	/// as the raw data isn't afraid to null-pad its entries.
+	const fn trim_vm(c: char) -> bool { c.is_ascii_whitespace() || c.is_ascii_control() }
+
+	// The first two bytes hold the little-endian offset; the next 32 hold
+	// the vendor/model; the rest we can ignore!
+	if chunk.len() < 34 { return Err(TocError::DriveOffsetDecode); }
+	let offset = i16::from_le_bytes([chunk[0], chunk[1]]);
+
+	// The vendor/model come glued together with an inconsistent
+	// delimiter, so we have to work a bit to pull them apart.
+	let vm = std::str::from_utf8(&chunk[2..34])
+		.ok()
+		.filter(|vm| vm.is_ascii())
+		.ok_or(TocError::DriveOffsetDecode)?;
+
+	let (vendor, model) =
+		// If the vendor is missing, the string should begin "- ".
+		if let Some(model) = vm.strip_prefix("- ") {
+			("", model.trim_matches(trim_vm))
+		}
+		// Otherwise there should be a " - " separating the two, even
+		// in cases where the model is missing.
+		else {
+			let mut split = vm.splitn(2, " - ");
+			let vendor = split.next().ok_or(TocError::DriveOffsetDecode)?;
+			let model = split.next().unwrap_or("");
+			(vendor.trim_matches(trim_vm), model.trim_matches(trim_vm))
+		};
+
+	// Skip empty models.
+	if model.is_empty() { Ok(None) }
+	// Return the entry so long as the fields fit.
+	else if
+		DRIVE_OFFSET_OFFSET_RNG.contains(&offset) &&
+		vendor.len() <= DRIVE_OFFSET_VENDOR_MAX &&
+		model.len() <= DRIVE_OFFSET_MODEL_MAX &&
+		vendor.is_ascii() && model.is_ascii()
+	{
+		Ok(Some((vendor, model, offset)))
+	}
+	// Otherwise the data's bad.
+	else { Err(TocError::DriveOffsetDecode) }
+}
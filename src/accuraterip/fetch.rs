@@ -0,0 +1,310 @@
+/*!
+# CDTOC: AccurateRip Fetch
+*/
+
+use crate::FetchError;
+use super::{
+	AccurateRip,
+	DriveOffsets,
+};
+use std::{
+	collections::BTreeMap,
+	io::Read,
+	time::Duration,
+};
+
+
+
+/// # Maximum Response Size.
+///
+/// Checksum files are tiny and the drive offset table is only a few hundred
+/// kilobytes, so anything larger than this is surely a mistake.
+const MAX_SIZE: usize = 16 * 1024 * 1024;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # Fetch Options.
+///
+/// This holds the request settings used by [`AccurateRip::fetch_checksums_with`]
+/// and [`AccurateRip::fetch_drive_offsets_with`].
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::FetchOptions;
+/// use std::time::Duration;
+///
+/// let opts = FetchOptions::default()
+///     .with_timeout(Duration::from_secs(5))
+///     .with_user_agent("MyRipper/1.0");
+/// assert_eq!(opts.timeout(), Duration::from_secs(5));
+/// assert_eq!(opts.user_agent(), "MyRipper/1.0");
+/// ```
+pub struct FetchOptions {
+	/// # Timeout.
+	timeout: Duration,
+
+	/// # User Agent.
+	user_agent: String,
+}
+
+impl Default for FetchOptions {
+	fn default() -> Self {
+		Self {
+			timeout: Self::DEFAULT_TIMEOUT,
+			user_agent: Self::DEFAULT_USER_AGENT.to_owned(),
+		}
+	}
+}
+
+impl FetchOptions {
+	/// # Default Timeout.
+	pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+	/// # Default User Agent.
+	pub const DEFAULT_USER_AGENT: &'static str = concat!("cdtoc/", env!("CARGO_PKG_VERSION"));
+
+	#[must_use]
+	/// # With Timeout.
+	///
+	/// Set the overall time limit for each request.
+	pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = timeout;
+		self
+	}
+
+	#[must_use]
+	/// # With User Agent.
+	///
+	/// Set the `User-Agent` header sent with each request.
+	pub fn with_user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+		self.user_agent = user_agent.into();
+		self
+	}
+
+	#[must_use]
+	/// # Timeout.
+	pub const fn timeout(&self) -> Duration { self.timeout }
+
+	#[must_use]
+	/// # User Agent.
+	pub fn user_agent(&self) -> &str { &self.user_agent }
+}
+
+impl FetchOptions {
+	/// # Download.
+	///
+	/// Download the URL, returning the raw response body.
+	fn get(&self, url: &str) -> Result<Vec<u8>, FetchError> {
+		let agent = ureq::AgentBuilder::new()
+			.timeout(self.timeout)
+			.user_agent(&self.user_agent)
+			.build();
+
+		let res = agent.get(url).call().map_err(|e| match e {
+			ureq::Error::Status(404, _) => FetchError::NotFound,
+			ureq::Error::Status(code, _) => FetchError::Http(code),
+			ureq::Error::Transport(_) => FetchError::Network,
+		})?;
+
+		let mut out = Vec::new();
+		res.into_reader()
+			.take(MAX_SIZE as u64 + 1)
+			.read_to_end(&mut out)
+			.map_err(|_| FetchError::Network)?;
+
+		if MAX_SIZE < out.len() { Err(FetchError::TooLarge) }
+		else { Ok(out) }
+	}
+}
+
+
+
+impl AccurateRip {
+	#[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+	/// # Fetch Checksums.
+	///
+	/// Download the checksum [bin file](AccurateRip::checksum_url) for this
+	/// disc and parse it with [`AccurateRip::parse_checksums`], using the
+	/// default [`FetchOptions`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use cdtoc::{FetchError, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// match toc.accuraterip_id().fetch_checksums() {
+	///     Ok(checksums) => assert_eq!(checksums.len(), 4),
+	///     Err(FetchError::NotFound) => println!("Not in the database."),
+	///     Err(e) => panic!("{e}"),
+	/// }
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return [`FetchError::NotFound`] if the disc is not in the
+	/// database, or a different error if the request fails or the response
+	/// cannot be parsed.
+	pub fn fetch_checksums(&self) -> Result<Vec<BTreeMap<u32, u8>>, FetchError> {
+		self.fetch_checksums_with(&FetchOptions::default())
+	}
+
+	#[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+	/// # Fetch Checksums (Custom Options).
+	///
+	/// Same as [`AccurateRip::fetch_checksums`], but with custom request
+	/// settings.
+	///
+	/// ## Errors
+	///
+	/// This will return [`FetchError::NotFound`] if the disc is not in the
+	/// database, or a different error if the request fails or the response
+	/// cannot be parsed.
+	pub fn fetch_checksums_with(&self, opts: &FetchOptions)
+	-> Result<Vec<BTreeMap<u32, u8>>, FetchError> {
+		let raw = opts.get(&self.checksum_url())?;
+		self.parse_checksums(&raw).map_err(FetchError::Parse)
+	}
+
+	#[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+	/// # Fetch Drive Offsets.
+	///
+	/// Download the current [drive offset table](AccurateRip::DRIVE_OFFSET_URL)
+	/// and parse it with [`DriveOffsets::parse`], using the default
+	/// [`FetchOptions`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use cdtoc::AccurateRip;
+	///
+	/// let offsets = AccurateRip::fetch_drive_offsets().unwrap();
+	/// assert!(! offsets.is_empty());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the request fails or the response cannot
+	/// be parsed.
+	pub fn fetch_drive_offsets() -> Result<DriveOffsets, FetchError> {
+		Self::fetch_drive_offsets_with(&FetchOptions::default())
+	}
+
+	#[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+	/// # Fetch Drive Offsets (Custom Options).
+	///
+	/// Same as [`AccurateRip::fetch_drive_offsets`], but with custom request
+	/// settings.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the request fails or the response cannot
+	/// be parsed.
+	pub fn fetch_drive_offsets_with(opts: &FetchOptions)
+	-> Result<DriveOffsets, FetchError> {
+		let raw = opts.get(Self::DRIVE_OFFSET_URL)?;
+		DriveOffsets::parse(&raw).map_err(FetchError::Parse)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		Toc,
+		TocError,
+	};
+	use std::{
+		io::Write,
+		net::TcpListener,
+	};
+
+	/// # Serve Once.
+	///
+	/// Answer a single request on a local port with the given status and
+	/// body, returning the URL to hit.
+	fn serve(status: &'static str, body: Vec<u8>) -> String {
+		let listener = TcpListener::bind("127.0.0.1:0").expect("Unable to bind.");
+		let url = format!("http://{}/", listener.local_addr().expect("Missing address."));
+		std::thread::spawn(move || {
+			let (mut stream, _) = listener.accept().expect("Unable to accept.");
+
+			// Drain the request headers.
+			let mut req = Vec::new();
+			let mut buf = [0_u8; 1024];
+			while ! req.ends_with(b"\r\n\r\n") {
+				let Ok(len) = stream.read(&mut buf) else { return; };
+				if len == 0 { return; }
+				req.extend_from_slice(&buf[..len]);
+			}
+
+			let _res = write!(
+				stream,
+				"HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+				body.len(),
+			);
+			let _res = stream.write_all(&body);
+		});
+		url
+	}
+
+	#[test]
+	fn t_options() {
+		let opts = FetchOptions::default();
+		assert_eq!(opts.timeout(), FetchOptions::DEFAULT_TIMEOUT);
+		assert!(opts.user_agent().starts_with("cdtoc/"));
+
+		let opts = opts.with_timeout(Duration::from_secs(1)).with_user_agent("Foo/1.0");
+		assert_eq!(opts.timeout(), Duration::from_secs(1));
+		assert_eq!(opts.user_agent(), "Foo/1.0");
+	}
+
+	#[test]
+	fn t_get() {
+		let opts = FetchOptions::default().with_timeout(Duration::from_secs(5));
+
+		// Success.
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let ar = toc.accuraterip_id();
+		let mut bin = <[u8; 13]>::from(ar).to_vec();
+		for crc in 1..=4_u32 {
+			bin.push(7);
+			bin.extend_from_slice(&crc.to_le_bytes());
+			bin.extend_from_slice(&[0; 4]);
+		}
+		let raw = opts.get(&serve("200 OK", bin.clone())).expect("Fetch failed.");
+		assert_eq!(raw, bin);
+		assert_eq!(
+			ar.parse_checksums(&raw).map(|c| c.len()),
+			Ok(4),
+		);
+
+		// Missing.
+		assert_eq!(
+			opts.get(&serve("404 Not Found", Vec::new())),
+			Err(FetchError::NotFound),
+		);
+
+		// Other status.
+		assert_eq!(
+			opts.get(&serve("500 Internal Server Error", Vec::new())),
+			Err(FetchError::Http(500)),
+		);
+
+		// Nobody home.
+		let url = {
+			let listener = TcpListener::bind("127.0.0.1:0").expect("Unable to bind.");
+			format!("http://{}/", listener.local_addr().expect("Missing address."))
+		};
+		assert_eq!(opts.get(&url), Err(FetchError::Network));
+
+		// Parse errors should be passed through as-is.
+		let err = FetchError::Parse(TocError::Checksums);
+		assert_eq!(std::error::Error::source(&err).map(ToString::to_string), Some(TocError::Checksums.to_string()));
+	}
+}
@@ -0,0 +1,445 @@
+/*!
+# CDTOC: AccurateRip Audio Files
+*/
+
+use crate::{
+	SignedDuration,
+	Toc,
+	TocError,
+};
+use super::{
+	AccurateRip,
+	OffsetMatrix,
+};
+use std::path::Path;
+
+
+
+/// # Buffer Size.
+///
+/// The number of sample pairs to collect before pushing them to the matrix.
+const BUF_SIZE: usize = 4096;
+
+
+
+impl AccurateRip {
+	#[cfg_attr(docsrs, doc(cfg(any(feature = "claxon", feature = "hound"))))]
+	/// # Checksum Audio Files.
+	///
+	/// Calculate the v1 and v2 AccurateRip checksums — in that order — for
+	/// a file-based rip, one file per audio track.
+	///
+	/// Each file must be 16-bit, 44.1 kHz stereo, and contain exactly as many
+	/// samples as the table of contents says the corresponding track should.
+	///
+	/// The format is determined by the file extension. WAV files are
+	/// supported when the `hound` crate feature is enabled, FLAC files when
+	/// the `claxon` feature is.
+	///
+	/// The results can be passed straight to [`AccurateRip::verify`] for
+	/// comparison with the database.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use cdtoc::{AccurateRip, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let checksums = AccurateRip::checksum_files(&toc, &[
+	///     "01.flac",
+	///     "02.flac",
+	///     "03.flac",
+	///     "04.flac",
+	/// ]).unwrap();
+	/// assert_eq!(checksums.len(), 4);
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the number of files doesn't match the
+	/// number of audio tracks, or any file is unreadable, in an unsupported
+	/// format, or the wrong length.
+	pub fn checksum_files<P: AsRef<Path>>(toc: &Toc, files: &[P])
+	-> Result<Vec<(u32, u32)>, TocError> {
+		let len = toc.audio_len();
+		if files.len() != len {
+			// Tocs are capped at 99 tracks, so this won't actually saturate.
+			let audio_len = u8::try_from(len).unwrap_or(u8::MAX);
+			return Err(TocError::ChecksumCount(audio_len, files.len()));
+		}
+
+		let mut matrix = OffsetMatrix::new(toc, [SignedDuration::ZERO]).with_v2();
+		let mut buf = Vec::with_capacity(BUF_SIZE);
+		for (track, file) in toc.audio_tracks().zip(files) {
+			let mut feed = Feed {
+				num: track.number(),
+				expected: track.samples(),
+				matrix: &mut matrix,
+				buf: &mut buf,
+			};
+			feed.read(file.as_ref())?;
+		}
+
+		(1..=len)
+			.map(|n| {
+				matrix.v1(n, SignedDuration::ZERO)
+					.zip(matrix.v2(n, SignedDuration::ZERO))
+					.ok_or(TocError::Checksums)
+			})
+			.collect()
+	}
+}
+
+
+
+/// # Sample Feeder.
+///
+/// This reads the samples from a single track file into the matrix.
+struct Feed<'a> {
+	/// # Track Number.
+	num: u8,
+
+	/// # Expected Sample Count.
+	expected: u64,
+
+	/// # Matrix.
+	matrix: &'a mut OffsetMatrix,
+
+	/// # Buffer.
+	buf: &'a mut Vec<u32>,
+}
+
+impl Feed<'_> {
+	/// # Read File.
+	///
+	/// Dispatch the file to the appropriate reader based on its extension.
+	fn read(&mut self, path: &Path) -> Result<(), TocError> {
+		let ext = path.extension()
+			.and_then(std::ffi::OsStr::to_str)
+			.unwrap_or("");
+
+		#[cfg(feature = "hound")]
+		if ext.eq_ignore_ascii_case("wav") { return self.read_wav(path); }
+
+		#[cfg(feature = "claxon")]
+		if ext.eq_ignore_ascii_case("flac") { return self.read_flac(path); }
+
+		Err(TocError::AudioFileFormat(self.num))
+	}
+
+	#[cfg(feature = "claxon")]
+	/// # Read FLAC.
+	fn read_flac(&mut self, path: &Path) -> Result<(), TocError> {
+		let mut reader = claxon::FlacReader::open(path)
+			.map_err(|_| TocError::AudioFileRead(self.num))?;
+
+		let info = reader.streaminfo();
+		if
+			info.channels != 2 ||
+			info.sample_rate != 44_100 ||
+			info.bits_per_sample != 16
+		{
+			return Err(TocError::AudioFileFormat(self.num));
+		}
+
+		// The header might not know the length, but if it does, it had
+		// better be right!
+		if let Some(found) = info.samples {
+			self.check_len(found)?;
+		}
+
+		self.push(reader.samples().map(|s| s.ok().and_then(|s| i16::try_from(s).ok())))
+	}
+
+	#[cfg(feature = "hound")]
+	/// # Read WAV.
+	fn read_wav(&mut self, path: &Path) -> Result<(), TocError> {
+		let mut reader = hound::WavReader::open(path)
+			.map_err(|_| TocError::AudioFileRead(self.num))?;
+
+		let spec = reader.spec();
+		if
+			spec.channels != 2 ||
+			spec.sample_rate != 44_100 ||
+			spec.bits_per_sample != 16 ||
+			spec.sample_format != hound::SampleFormat::Int
+		{
+			return Err(TocError::AudioFileFormat(self.num));
+		}
+
+		self.check_len(u64::from(reader.duration()))?;
+		self.push(reader.samples::<i16>().map(Result::ok))
+	}
+
+	/// # Check Length.
+	///
+	/// Make sure the sample count matches the table of contents.
+	const fn check_len(&self, found: u64) -> Result<(), TocError> {
+		if found == self.expected { Ok(()) }
+		else { Err(TocError::AudioFileSamples(self.num, self.expected, found)) }
+	}
+
+	/// # Push Samples.
+	///
+	/// Pair up the interleaved samples and push them to the matrix.
+	fn push<I>(&mut self, mut samples: I) -> Result<(), TocError>
+	where I: Iterator<Item=Option<i16>> {
+		let mut found: u64 = 0;
+		self.buf.clear();
+		while let Some(l) = samples.next() {
+			let l = l.ok_or(TocError::AudioFileRead(self.num))?.to_le_bytes();
+			let r = samples.next()
+				.flatten()
+				.ok_or(TocError::AudioFileRead(self.num))?
+				.to_le_bytes();
+
+			// Don't go over!
+			found += 1;
+			if self.expected < found { break; }
+
+			self.buf.push(u32::from_le_bytes([l[0], l[1], r[0], r[1]]));
+			if self.buf.len() == BUF_SIZE {
+				self.matrix.push(self.buf);
+				self.buf.clear();
+			}
+		}
+
+		if ! self.buf.is_empty() {
+			self.matrix.push(self.buf);
+			self.buf.clear();
+		}
+
+		self.check_len(found)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::path::PathBuf;
+
+	/// # Test Disc.
+	const TOC: &str = "3+96+E6+14A+1C2";
+
+	/// # Temporary Directory.
+	///
+	/// Return a fresh scratch directory for the named test.
+	fn tmp_dir(name: &str) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("cdtoc-{}-{name}", std::process::id()));
+		let _res = std::fs::remove_dir_all(&dir);
+		std::fs::create_dir_all(&dir).expect("Unable to create temporary directory.");
+		dir
+	}
+
+	/// # Pseudo-Random Audio.
+	///
+	/// Return interleaved samples for each track, along with the expected
+	/// checksums.
+	fn audio(toc: &Toc) -> (Vec<Vec<i16>>, Vec<(u32, u32)>) {
+		let mut seed: u64 = 0x1234_5678;
+		let mut matrix = OffsetMatrix::new(toc, [SignedDuration::ZERO]).with_v2();
+		let mut out = Vec::new();
+		for t in toc.audio_tracks() {
+			let mut track = Vec::new();
+			let mut packed = Vec::new();
+			for _ in 0..t.samples() {
+				let n = crate::xorshift(&mut seed) as u32;
+				let [a, b, c, d] = n.to_le_bytes();
+				track.push(i16::from_le_bytes([a, b]));
+				track.push(i16::from_le_bytes([c, d]));
+				packed.push(n);
+			}
+			matrix.push(&packed);
+			out.push(track);
+		}
+
+		let expected = (1..=toc.audio_len())
+			.map(|n| (
+				matrix.v1(n, SignedDuration::ZERO).expect("Missing v1."),
+				matrix.v2(n, SignedDuration::ZERO).expect("Missing v2."),
+			))
+			.collect();
+
+		(out, expected)
+	}
+
+	#[test]
+	fn t_unsupported() {
+		let toc = Toc::from_cdtoc(TOC).expect("Invalid TOC.");
+		assert_eq!(
+			AccurateRip::checksum_files(&toc, &["01.mp3", "02.mp3", "03.mp3"]),
+			Err(TocError::AudioFileFormat(1)),
+		);
+		assert_eq!(
+			AccurateRip::checksum_files(&toc, &["01.mp3", "02.mp3"]),
+			Err(TocError::ChecksumCount(3, 2)),
+		);
+	}
+
+	#[cfg(feature = "hound")]
+	#[test]
+	fn t_wav() {
+		/// # Write WAV.
+		fn write(path: &Path, samples: &[i16], channels: u16, sample_rate: u32) {
+			let spec = hound::WavSpec {
+				channels,
+				sample_rate,
+				bits_per_sample: 16,
+				sample_format: hound::SampleFormat::Int,
+			};
+			let mut writer = hound::WavWriter::create(path, spec).expect("Unable to create WAV.");
+			for s in samples { writer.write_sample(*s).expect("Unable to write sample."); }
+			writer.finalize().expect("Unable to finalize WAV.");
+		}
+
+		let toc = Toc::from_cdtoc(TOC).expect("Invalid TOC.");
+		let (tracks, expected) = audio(&toc);
+		let dir = tmp_dir("wav");
+		let files: Vec<PathBuf> = (1..=3).map(|n| dir.join(format!("{n:02}.wav"))).collect();
+		for (file, samples) in files.iter().zip(&tracks) {
+			write(file, samples, 2, 44_100);
+		}
+
+		assert_eq!(AccurateRip::checksum_files(&toc, &files), Ok(expected));
+
+		// Too short.
+		write(&files[1], &tracks[1][2..], 2, 44_100);
+		assert_eq!(
+			AccurateRip::checksum_files(&toc, &files),
+			Err(TocError::AudioFileSamples(2, 58_800, 58_799)),
+		);
+
+		// Wrong format.
+		write(&files[1], &tracks[1], 2, 48_000);
+		assert_eq!(
+			AccurateRip::checksum_files(&toc, &files),
+			Err(TocError::AudioFileFormat(2)),
+		);
+		write(&files[1], &tracks[1], 1, 44_100);
+		assert_eq!(
+			AccurateRip::checksum_files(&toc, &files),
+			Err(TocError::AudioFileFormat(2)),
+		);
+
+		// Missing.
+		std::fs::remove_file(&files[2]).expect("Unable to remove file.");
+		write(&files[1], &tracks[1], 2, 44_100);
+		assert_eq!(
+			AccurateRip::checksum_files(&toc, &files),
+			Err(TocError::AudioFileRead(3)),
+		);
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[cfg(feature = "claxon")]
+	#[test]
+	fn t_flac() {
+		/// # CRC-8 (Poly 0x07).
+		fn crc8(src: &[u8]) -> u8 {
+			let mut crc = 0_u8;
+			for &b in src {
+				crc ^= b;
+				for _ in 0..8 {
+					crc = if crc & 0x80 == 0 { crc << 1 } else { (crc << 1) ^ 0x07 };
+				}
+			}
+			crc
+		}
+
+		/// # CRC-16 (Poly 0x8005).
+		fn crc16(src: &[u8]) -> u16 {
+			let mut crc = 0_u16;
+			for &b in src {
+				crc ^= u16::from(b) << 8;
+				for _ in 0..8 {
+					crc = if crc & 0x8000 == 0 { crc << 1 } else { (crc << 1) ^ 0x8005 };
+				}
+			}
+			crc
+		}
+
+		/// # Write FLAC.
+		///
+		/// This writes a bare-bones stereo FLAC using verbatim subframes.
+		fn write(path: &Path, samples: &[i16], sample_rate: u32) {
+			const BLOCK: usize = 4096;
+			let total = (samples.len() / 2) as u64;
+
+			let mut out = b"fLaC".to_vec();
+
+			// STREAMINFO.
+			out.extend_from_slice(&[0x80, 0, 0, 34]);
+			out.extend_from_slice(&(BLOCK as u16).to_be_bytes());
+			out.extend_from_slice(&(BLOCK as u16).to_be_bytes());
+			out.extend_from_slice(&[0; 6]);
+			let packed: u64 =
+				(u64::from(sample_rate) << 44) |
+				(1 << 41) |
+				(15 << 36) |
+				total;
+			out.extend_from_slice(&packed.to_be_bytes());
+			out.extend_from_slice(&[0; 16]);
+
+			// Frames.
+			for (idx, block) in samples.chunks(BLOCK * 2).enumerate() {
+				let start = out.len();
+				let rate = if sample_rate == 44_100 { 0b1001 } else { 0b1010 };
+				out.extend_from_slice(&[0xFF, 0xF8, 0b0111_0000 | rate, 0b0001_1000]);
+				out.push(idx as u8);
+				out.extend_from_slice(&((block.len() / 2 - 1) as u16).to_be_bytes());
+				out.push(crc8(&out[start..]));
+
+				// Verbatim subframes, left then right.
+				for channel in 0..2 {
+					out.push(0b0000_0010);
+					for pair in block.chunks_exact(2) {
+						out.extend_from_slice(&pair[channel].to_be_bytes());
+					}
+				}
+
+				let crc = crc16(&out[start..]);
+				out.extend_from_slice(&crc.to_be_bytes());
+			}
+
+			std::fs::write(path, out).expect("Unable to write FLAC.");
+		}
+
+		let toc = Toc::from_cdtoc(TOC).expect("Invalid TOC.");
+		let (tracks, expected) = audio(&toc);
+		let dir = tmp_dir("flac");
+		let files: Vec<PathBuf> = (1..=3).map(|n| dir.join(format!("{n:02}.flac"))).collect();
+		for (file, samples) in files.iter().zip(&tracks) {
+			write(file, samples, 44_100);
+		}
+
+		assert_eq!(AccurateRip::checksum_files(&toc, &files), Ok(expected));
+
+		// Too long.
+		let mut long = tracks[0].clone();
+		long.extend_from_slice(&[0, 0]);
+		write(&files[0], &long, 44_100);
+		assert_eq!(
+			AccurateRip::checksum_files(&toc, &files),
+			Err(TocError::AudioFileSamples(1, 47_040, 47_041)),
+		);
+
+		// Wrong format.
+		write(&files[0], &tracks[0], 48_000);
+		assert_eq!(
+			AccurateRip::checksum_files(&toc, &files),
+			Err(TocError::AudioFileFormat(1)),
+		);
+
+		// Garbage.
+		std::fs::write(&files[0], b"fLaC but not really").expect("Unable to write file.");
+		assert_eq!(
+			AccurateRip::checksum_files(&toc, &files),
+			Err(TocError::AudioFileRead(1)),
+		);
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+}
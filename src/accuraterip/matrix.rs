@@ -0,0 +1,444 @@
+/*!
+# CDTOC: AccurateRip Offset Matrix
+*/
+
+use crate::{
+	SignedDuration,
+	Toc,
+};
+
+
+
+/// # Samples Per Sector.
+const SAMPLES_PER_SECTOR: u64 = 588;
+
+/// # Skipped Samples.
+///
+/// AccurateRip ignores the first five sectors' worth of samples — minus one —
+/// from the first track, and the last five sectors' worth from the last.
+const SKIP: u64 = SAMPLES_PER_SECTOR * 5;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone)]
+/// # AccurateRip Offset Matrix.
+///
+/// This struct calculates the AccurateRip v1 checksums for every track at
+/// any number of read offsets in a single pass, making it possible to
+/// figure out which offset — if any — a rip (or pressing) lines up with.
+///
+/// Audio is fed in via [`OffsetMatrix::push`] as a stream of stereo sample
+/// pairs packed into `u32`s — i.e. each four-byte chunk of the raw
+/// little-endian PCM — starting with the first sample of the first audio
+/// track and ending with the last sample before the audio leadout. Anything
+/// before or after that is treated as silence.
+///
+/// The v1 math is linear, so the matrix only needs to remember a couple
+/// running sums at each track boundary (±offset), rather than the audio
+/// itself; the cost is essentially the same whether it is asked for one
+/// offset or six thousand.
+///
+/// The v2 math is not linear, so must be tracked separately for each offset.
+/// This is disabled by default, but can be enabled via
+/// [`OffsetMatrix::with_v2`]. Use short lists!
+///
+/// Offsets follow the same convention as [`Track::sector_range_with_offset`](crate::Track::sector_range_with_offset):
+/// a positive offset means a track's audio begins that many samples _later_
+/// in the stream.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{OffsetMatrix, SignedDuration, Toc};
+///
+/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+/// let offsets = (-5..=5).map(SignedDuration::from_samples);
+/// let mut matrix = OffsetMatrix::new(&toc, offsets).with_v2();
+///
+/// // Feed it the audio, in as many or few chunks as you like.
+/// let samples: Vec<u32> = (0..matrix.total_samples() as u32)
+///     .map(|n| n.wrapping_mul(0x9E37_79B9))
+///     .collect();
+/// for chunk in samples.chunks(10_000) { matrix.push(chunk); }
+///
+/// // Look up the checksums.
+/// let zero = SignedDuration::ZERO;
+/// let v1 = matrix.v1(1, zero).unwrap();
+/// let v2 = matrix.v2(1, zero).unwrap();
+/// assert_ne!(v1, v2);
+///
+/// // Unrequested offsets and nonexistent tracks have nothing to say.
+/// assert!(matrix.v1(1, SignedDuration::from_samples(6)).is_none());
+/// assert!(matrix.v1(3, zero).is_none());
+/// ```
+pub struct OffsetMatrix {
+	/// # Track Ranges.
+	///
+	/// The stream range of each track, in samples.
+	tracks: Vec<MatrixTrack>,
+
+	/// # Offsets.
+	///
+	/// Sorted, deduplicated, in samples.
+	offsets: Vec<i64>,
+
+	/// # Total Samples.
+	total: u64,
+
+	/// # Current Position.
+	pos: u64,
+
+	/// # Running Sum.
+	sum: u32,
+
+	/// # Running Weighted Sum.
+	wsum: u32,
+
+	/// # Marks.
+	///
+	/// The sorted, deduplicated stream positions the running sums need to
+	/// be remembered at.
+	marks: Vec<u64>,
+
+	/// # Sums at Each Mark.
+	sums: Vec<(u32, u32)>,
+
+	/// # V2 High Sums.
+	///
+	/// If enabled, this holds the high half of the v2 checksum for each
+	/// offset, each track.
+	v2: Option<Vec<u32>>,
+}
+
+impl OffsetMatrix {
+	#[must_use]
+	/// # New.
+	///
+	/// Prepare a new matrix for the disc and offsets (in samples).
+	///
+	/// Duplicate offsets are ignored.
+	pub fn new<I>(toc: &Toc, offsets: I) -> Self
+	where I: IntoIterator<Item=SignedDuration> {
+		let leadin = u64::from(toc.audio_leadin());
+		let total = (u64::from(toc.audio_leadout()) - leadin) * SAMPLES_PER_SECTOR;
+		let last = toc.audio_len() - 1;
+
+		let tracks: Vec<MatrixTrack> = toc.audio_tracks()
+			.enumerate()
+			.map(|(idx, t)| {
+				let rng = t.sector_range();
+				let start = (u64::from(rng.start) - leadin) * SAMPLES_PER_SECTOR;
+				let end = (u64::from(rng.end) - leadin) * SAMPLES_PER_SECTOR;
+				let len = end - start;
+				MatrixTrack {
+					start,
+					end,
+					lo: if idx == 0 { SKIP } else { 1 },
+					hi: if idx == last { len.saturating_sub(SKIP) } else { len },
+				}
+			})
+			.collect();
+
+		let mut offsets: Vec<i64> = offsets.into_iter().map(SignedDuration::samples).collect();
+		offsets.sort_unstable();
+		offsets.dedup();
+
+		// Figure out where the sums will need to be noted.
+		let mut marks = Vec::with_capacity(tracks.len() * offsets.len() * 2);
+		for t in &tracks {
+			for &o in &offsets {
+				let (a, b) = t.bounds(o, total);
+				marks.push(a);
+				marks.push(b);
+			}
+		}
+		marks.sort_unstable();
+		marks.dedup();
+
+		Self {
+			tracks,
+			offsets,
+			total,
+			pos: 0,
+			sum: 0,
+			wsum: 0,
+			sums: Vec::with_capacity(marks.len()),
+			marks,
+			v2: None,
+		}
+	}
+
+	#[must_use]
+	/// # With V2.
+	///
+	/// Enable the calculation of v2 checksums.
+	///
+	/// Unlike v1, the v2 checksums have to be computed independently for
+	/// each offset, so the cost grows linearly with the number of offsets.
+	pub fn with_v2(mut self) -> Self {
+		if self.v2.is_none() {
+			self.v2.replace(vec![0; self.tracks.len() * self.offsets.len()]);
+		}
+		self
+	}
+
+	#[must_use]
+	/// # Total Samples.
+	///
+	/// Return the total number of samples expected by [`OffsetMatrix::push`].
+	pub const fn total_samples(&self) -> u64 { self.total }
+
+	#[must_use]
+	/// # Is Finished?
+	///
+	/// Returns `true` once all of the expected samples have been pushed.
+	pub const fn is_finished(&self) -> bool { self.total <= self.pos }
+
+	/// # Offsets.
+	///
+	/// Return an iterator over the (unique) offsets being calculated, in
+	/// order.
+	pub fn offsets(&self) -> impl ExactSizeIterator<Item=SignedDuration> + '_ {
+		self.offsets.iter().copied().map(SignedDuration::from_samples)
+	}
+
+	/// # Push Samples.
+	///
+	/// Feed the next chunk of audio samples into the matrix. Each `u32`
+	/// should hold one stereo pair as read from little-endian PCM, i.e.
+	/// `u32::from_le_bytes([l0, l1, r0, r1])`.
+	///
+	/// Samples beyond [`OffsetMatrix::total_samples`] are ignored.
+	pub fn push(&mut self, samples: &[u32]) {
+		let remaining = usize::try_from(self.total - self.pos).unwrap_or(usize::MAX);
+		let samples = &samples[..samples.len().min(remaining)];
+
+		if let Some(mut hi) = self.v2.take() {
+			self.push_v2(samples, &mut hi);
+			self.v2.replace(hi);
+		}
+
+		let mut samples = samples;
+		while ! samples.is_empty() {
+			// Note the sums at the current position if needed.
+			let next = self.record_marks();
+
+			// Crunch up to the next mark.
+			let len = usize::try_from(next - self.pos).map_or(samples.len(), |n| n.min(samples.len()));
+			let (chunk, rest) = samples.split_at(len);
+			let mut sum = self.sum;
+			let mut wsum = self.wsum;
+			#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+			let mut j = self.pos as u32;
+			for &x in chunk {
+				sum = sum.wrapping_add(x);
+				wsum = wsum.wrapping_add(j.wrapping_mul(x));
+				j = j.wrapping_add(1);
+			}
+			self.sum = sum;
+			self.wsum = wsum;
+			self.pos += len as u64;
+			samples = rest;
+		}
+
+		// Catch any final marks.
+		self.record_marks();
+	}
+
+	#[must_use]
+	/// # V1 Checksum.
+	///
+	/// Return the v1 checksum for the given track number at the given
+	/// offset, or `None` if the track or offset were not part of the matrix,
+	/// or not all samples have been pushed.
+	pub fn v1(&self, track: usize, offset: SignedDuration) -> Option<u32> {
+		if ! self.is_finished() { return None; }
+		let trk = self.tracks.get(track.checked_sub(1)?)?;
+		let off = offset.samples();
+		self.offsets.binary_search(&off).ok()?;
+
+		let (a, b) = trk.bounds(off, self.total);
+		if b <= a { return Some(0); }
+		let (sa, wa) = self.sums[self.marks.binary_search(&a).ok()?];
+		let (sb, wb) = self.sums[self.marks.binary_search(&b).ok()?];
+
+		// The multiplier for stream position j is j - c.
+		#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+		#[expect(clippy::cast_sign_loss, reason = "False positive.")]
+		let c = (trk.start as u32).wrapping_add(off as u32).wrapping_sub(1);
+		Some(
+			wb.wrapping_sub(wa)
+				.wrapping_sub(c.wrapping_mul(sb.wrapping_sub(sa)))
+		)
+	}
+
+	#[must_use]
+	/// # V2 Checksum.
+	///
+	/// Return the v2 checksum for the given track number at the given
+	/// offset, or `None` if v2 calculations were not enabled, the track or
+	/// offset were not part of the matrix, or not all samples have been
+	/// pushed.
+	pub fn v2(&self, track: usize, offset: SignedDuration) -> Option<u32> {
+		let hi = self.v2.as_ref()?;
+		let v1 = self.v1(track, offset)?;
+		let k = self.offsets.binary_search(&offset.samples()).ok()?;
+		let hi = hi[k * self.tracks.len() + track - 1];
+		Some(v1.wrapping_add(hi))
+	}
+}
+
+impl OffsetMatrix {
+	/// # Record Marks.
+	///
+	/// Save the running sums if the current position is a mark, returning
+	/// the position of the next mark (or the end).
+	fn record_marks(&mut self) -> u64 {
+		while let Some(&next) = self.marks.get(self.sums.len()) {
+			if next == self.pos { self.sums.push((self.sum, self.wsum)); }
+			else { return next; }
+		}
+		self.total
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[expect(clippy::cast_possible_wrap, reason = "False positive.")]
+	#[expect(clippy::cast_sign_loss, reason = "False positive.")]
+	/// # Push V2.
+	///
+	/// Add the high halves of each sample's v2 product to the appropriate
+	/// track/offset slot.
+	fn push_v2(&self, samples: &[u32], hi: &mut [u32]) {
+		let len = self.tracks.len();
+		let pos = self.pos as i64;
+		for (k, &o) in self.offsets.iter().enumerate() {
+			let hi = &mut hi[k * len..(k + 1) * len];
+
+			// Nominal positions for this chunk.
+			let first = pos.saturating_sub(o);
+			let mut t = 0;
+			for (n, &x) in samples.iter().enumerate() {
+				let q = first.saturating_add(n as i64);
+				if q < 0 { continue; }
+				let q = q as u64;
+
+				// Find the track.
+				while t < len && self.tracks[t].end <= q { t += 1; }
+				if t == len { break; }
+
+				let track = &self.tracks[t];
+				let m = q - track.start + 1;
+				if track.lo <= m && m <= track.hi {
+					let p = u64::from(m as u32) * u64::from(x);
+					hi[t] = hi[t].wrapping_add((p >> 32) as u32);
+				}
+			}
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Matrix Track.
+struct MatrixTrack {
+	/// # Stream Start (Inclusive).
+	start: u64,
+
+	/// # Stream End (Exclusive).
+	end: u64,
+
+	/// # First Counted Multiplier.
+	lo: u64,
+
+	/// # Last Counted Multiplier.
+	hi: u64,
+}
+
+impl MatrixTrack {
+	#[expect(clippy::cast_possible_wrap, reason = "False positive.")]
+	#[expect(clippy::cast_sign_loss, reason = "False positive.")]
+	/// # Stream Bounds.
+	///
+	/// Return the (clamped) stream range — start inclusive, end exclusive —
+	/// of the samples that count toward the checksum at the given offset.
+	const fn bounds(&self, offset: i64, total: u64) -> (u64, u64) {
+		/// # Clamp.
+		const fn clamp(v: i64, total: u64) -> u64 {
+			if v <= 0 { 0 }
+			else if total <= v as u64 { total }
+			else { v as u64 }
+		}
+
+		let base = (self.start as i64).saturating_add(offset);
+		let a = clamp(base.saturating_add(self.lo as i64 - 1), total);
+		let b = clamp(base.saturating_add(self.hi as i64), total);
+		(a, b)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// # Naive Checksums.
+	///
+	/// Compute the v1 and v2 checksums for a single track/offset the slow
+	/// and obvious way.
+	fn naive(samples: &[u32], t: &MatrixTrack, offset: i64) -> (u32, u32) {
+		let mut v1: u32 = 0;
+		let mut hi: u32 = 0;
+		for m in t.lo..=t.hi {
+			let j = t.start as i64 + offset + m as i64 - 1;
+			let x = usize::try_from(j).ok()
+				.and_then(|j| samples.get(j))
+				.copied()
+				.unwrap_or(0);
+			let p = m * u64::from(x);
+			v1 = v1.wrapping_add(p as u32);
+			hi = hi.wrapping_add((p >> 32) as u32);
+		}
+		(v1, v1.wrapping_add(hi))
+	}
+
+	#[test]
+	fn t_matrix() {
+		let toc = Toc::from_cdtoc("3+96+E6+14A+1C2").expect("Invalid TOC.");
+		let offsets = [-2940, -667, -30, -1, 0, 1, 6, 30, 667, 2940, 100_000];
+
+		// Pseudo-random audio.
+		let mut seed: u64 = 0x1234_5678;
+		let mut samples = Vec::new();
+		let mut matrix = OffsetMatrix::new(&toc, offsets.iter().copied().map(SignedDuration::from_samples))
+			.with_v2();
+		for _ in 0..matrix.total_samples() {
+			samples.push(crate::xorshift(&mut seed) as u32);
+		}
+
+		// Push in uneven chunks.
+		assert!(matrix.v1(1, SignedDuration::ZERO).is_none());
+		for chunk in samples.chunks(7777) { matrix.push(chunk); }
+		assert!(matrix.is_finished());
+		assert_eq!(matrix.offsets().len(), offsets.len());
+
+		for (idx, t) in matrix.tracks.iter().enumerate() {
+			for o in offsets {
+				let (v1, v2) = naive(&samples, t, o);
+				let offset = SignedDuration::from_samples(o);
+				assert_eq!(matrix.v1(idx + 1, offset), Some(v1), "V1 track {} offset {o}", idx + 1);
+				assert_eq!(matrix.v2(idx + 1, offset), Some(v2), "V2 track {} offset {o}", idx + 1);
+			}
+		}
+
+		// The v2 stuff is optional.
+		let mut matrix = OffsetMatrix::new(&toc, [SignedDuration::ZERO]);
+		matrix.push(&samples);
+		assert!(matrix.v1(2, SignedDuration::ZERO).is_some());
+		assert!(matrix.v2(2, SignedDuration::ZERO).is_none());
+		assert!(matrix.v1(0, SignedDuration::ZERO).is_none());
+		assert!(matrix.v1(4, SignedDuration::ZERO).is_none());
+	}
+}
@@ -0,0 +1,323 @@
+/*!
+# CDTOC: AccurateRip Verification
+*/
+
+use crate::{
+	AccurateRip,
+	TocError,
+};
+use std::{
+	collections::BTreeMap,
+	fmt,
+};
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # AccurateRip Checksum Version.
+pub enum ArVersion {
+	/// # Version One.
+	V1,
+
+	/// # Version Two.
+	V2,
+}
+
+impl fmt::Display for ArVersion {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl ArVersion {
+	#[must_use]
+	/// # As Str.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::ArVersion;
+	///
+	/// assert_eq!(ArVersion::V1.as_str(), "AR v1");
+	/// assert_eq!(ArVersion::V2.as_str(), "AR v2");
+	/// ```
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::V1 => "AR v1",
+			Self::V2 => "AR v2",
+		}
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Track Verdict.
+///
+/// The AccurateRip verification result for a single track, as returned by
+/// [`VerifyReport::tracks`].
+pub enum TrackVerdict {
+	/// # Accurately Ripped.
+	///
+	/// The computed checksum matched a database entry.
+	AccuratelyRipped {
+		/// # Matching Checksum Version.
+		version: ArVersion,
+
+		/// # Confidence.
+		confidence: u8,
+	},
+
+	/// # Not In Database.
+	///
+	/// AccurateRip has no checksums for the track.
+	NotInDatabase,
+
+	/// # Mismatch.
+	///
+	/// AccurateRip has checksums for the track, but none of them matched.
+	Mismatch {
+		/// # Highest Confidence in the Database.
+		best_confidence_seen: u8,
+	},
+}
+
+impl fmt::Display for TrackVerdict {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::AccuratelyRipped { version, confidence } =>
+				write!(f, "accurately ripped (confidence {confidence})  [{version}]"),
+			Self::NotInDatabase => f.write_str("not present in database"),
+			Self::Mismatch { best_confidence_seen } =>
+				write!(f, "cannot be verified as accurate (confidence {best_confidence_seen})"),
+		}
+	}
+}
+
+impl TrackVerdict {
+	#[must_use]
+	/// # Is Accurate?
+	pub const fn is_accurate(&self) -> bool {
+		matches!(self, Self::AccuratelyRipped { .. })
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "accuraterip")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # AccurateRip Verification Report.
+///
+/// This holds the per-track results of [`AccurateRip::verify`].
+///
+/// Its `Display` implementation produces an EAC-style summary, one line per
+/// track followed by the overall result.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{Toc, TrackVerdict};
+///
+/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+/// let ar_id = toc.accuraterip_id();
+///
+/// // Pretend this came from a bin.
+/// let parsed = vec![
+///     [(0x1234_5678, 12_u8), (0xABCD_EF01, 3)].into_iter().collect(),
+///     [(0x0BAD_F00D, 7_u8)].into_iter().collect(),
+/// ];
+///
+/// // Pretend this came from the rip.
+/// let computed = [(0x1111_1111, 0x1234_5678), (0x2222_2222, 0x3333_3333)];
+///
+/// let report = ar_id.verify(&computed, &parsed).unwrap();
+/// assert!(report.tracks()[0].is_accurate());
+/// assert_eq!(
+///     report.tracks()[1],
+///     TrackVerdict::Mismatch { best_confidence_seen: 7 },
+/// );
+/// assert_eq!(
+///     report.to_string(),
+///     "Track  1  accurately ripped (confidence 12)  [AR v2]
+/// Track  2  cannot be verified as accurate (confidence 7)
+///
+/// 1 of 2 tracks accurately ripped.",
+/// );
+/// ```
+pub struct VerifyReport {
+	/// # Per-Track Results.
+	tracks: Vec<TrackVerdict>,
+}
+
+impl fmt::Display for VerifyReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (idx, v) in self.tracks.iter().enumerate() {
+			writeln!(f, "Track {:>2}  {v}", idx + 1)?;
+		}
+		f.write_str("\n")?;
+
+		let total = self.tracks.len();
+		let accurate = self.accurate();
+		if accurate == total { f.write_str("All tracks accurately ripped.") }
+		else if self.tracks.iter().all(|v| matches!(v, TrackVerdict::NotInDatabase)) {
+			f.write_str("None of the tracks are present in the AccurateRip database.")
+		}
+		else { write!(f, "{accurate} of {total} tracks accurately ripped.") }
+	}
+}
+
+impl VerifyReport {
+	#[must_use]
+	/// # Accurate Count.
+	///
+	/// Return the number of accurately-ripped tracks.
+	pub fn accurate(&self) -> usize {
+		self.tracks.iter().filter(|v| v.is_accurate()).count()
+	}
+
+	#[must_use]
+	/// # All Accurate?
+	///
+	/// Returns `true` if every track was accurately ripped.
+	pub fn is_accurate(&self) -> bool {
+		self.tracks.iter().all(TrackVerdict::is_accurate)
+	}
+
+	#[must_use]
+	/// # Track Verdicts.
+	///
+	/// Return the results, indexed by track number (`n-1`).
+	pub fn tracks(&self) -> &[TrackVerdict] { &self.tracks }
+}
+
+
+
+impl AccurateRip {
+	/// # Verify.
+	///
+	/// Match locally computed `(v1, v2)` track checksums against the data
+	/// returned by [`AccurateRip::parse_checksums`], producing a
+	/// [`VerifyReport`].
+	///
+	/// A v2 match is preferred over a v1 match, should both exist.
+	///
+	/// AccurateRip uses zero to mean "no checksum", so a computed checksum of
+	/// zero is treated as missing and will never match anything.
+	///
+	/// See [`VerifyReport`] for an example.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the number of computed checksums or
+	/// parsed tracks differs from the disc's track count.
+	pub fn verify(&self, computed: &[(u32, u32)], parsed: &[BTreeMap<u32, u8>])
+	-> Result<VerifyReport, TocError> {
+		let audio_len = self.audio_len();
+		for len in [computed.len(), parsed.len()] {
+			if len != usize::from(audio_len) {
+				return Err(TocError::ChecksumCount(audio_len, len));
+			}
+		}
+
+		let tracks = computed.iter()
+			.zip(parsed)
+			.map(|(&(v1, v2), db)| {
+				let find = |crc: u32| if crc == 0 { None } else { db.get(&crc) };
+				if let Some(&confidence) = find(v2) {
+					TrackVerdict::AccuratelyRipped { version: ArVersion::V2, confidence }
+				}
+				else if let Some(&confidence) = find(v1) {
+					TrackVerdict::AccuratelyRipped { version: ArVersion::V1, confidence }
+				}
+				else if let Some(&best_confidence_seen) = db.values().max() {
+					TrackVerdict::Mismatch { best_confidence_seen }
+				}
+				else { TrackVerdict::NotInDatabase }
+			})
+			.collect();
+
+		Ok(VerifyReport { tracks })
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Toc;
+
+	#[test]
+	fn t_verify() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let ar_id = toc.accuraterip_id();
+
+		let parsed: Vec<BTreeMap<u32, u8>> = vec![
+			[(1, 10), (2, 20)].into_iter().collect(),
+			[(3, 30), (4, 40)].into_iter().collect(),
+			BTreeMap::new(),
+			[(5, 50), (6, 60)].into_iter().collect(),
+		];
+		let computed = [(1, 2), (3, 99), (7, 8), (9, 10)];
+
+		let report = ar_id.verify(&computed, &parsed).expect("Verify failed.");
+		assert_eq!(
+			report.tracks(),
+			&[
+				TrackVerdict::AccuratelyRipped { version: ArVersion::V2, confidence: 20 },
+				TrackVerdict::AccuratelyRipped { version: ArVersion::V1, confidence: 30 },
+				TrackVerdict::NotInDatabase,
+				TrackVerdict::Mismatch { best_confidence_seen: 60 },
+			],
+		);
+		assert_eq!(report.accurate(), 2);
+		assert!(! report.is_accurate());
+		assert!(report.to_string().ends_with("\n\n2 of 4 tracks accurately ripped."));
+
+		// All good. Missing (zero) v2 checksums fall back to v1.
+		let report = ar_id.verify(&[(1, 0), (3, 0), (0, 7), (5, 0)], &[
+			parsed[0].clone(),
+			parsed[1].clone(),
+			[(7, 1)].into_iter().collect(),
+			parsed[3].clone(),
+		]).expect("Verify failed.");
+		assert!(report.is_accurate());
+		assert!(report.to_string().ends_with("\n\nAll tracks accurately ripped."));
+
+		// A computed zero is no checksum at all, and shouldn't match anything,
+		// even a (bogus) zero in the database.
+		let zero: BTreeMap<u32, u8> = [(0, 9)].into_iter().collect();
+		let report = ar_id.verify(&[(0, 0); 4], &[
+			zero.clone(),
+			zero,
+			BTreeMap::new(),
+			parsed[3].clone(),
+		]).expect("Verify failed.");
+		assert_eq!(
+			report.tracks(),
+			&[
+				TrackVerdict::Mismatch { best_confidence_seen: 9 },
+				TrackVerdict::Mismatch { best_confidence_seen: 9 },
+				TrackVerdict::NotInDatabase,
+				TrackVerdict::Mismatch { best_confidence_seen: 60 },
+			],
+		);
+		assert_eq!(report.accurate(), 0);
+
+		// Nothing at all.
+		let report = ar_id.verify(&computed, &vec![BTreeMap::new(); 4])
+			.expect("Verify failed.");
+		assert!(report.to_string().ends_with("\n\nNone of the tracks are present in the AccurateRip database."));
+
+		// Count mismatches.
+		assert_eq!(
+			ar_id.verify(&computed[..3], &parsed),
+			Err(TocError::ChecksumCount(4, 3)),
+		);
+		assert_eq!(
+			ar_id.verify(&computed, &parsed[..2]),
+			Err(TocError::ChecksumCount(4, 2)),
+		);
+	}
+}
@@ -2,12 +2,30 @@
 # CDTOC: CDDB
 */
 
+mod gnudb;
+mod session;
+mod xmcd;
+
+pub use gnudb::GnudbHello;
+pub use session::{
+	CddbMatch,
+	CddbReply,
+	CddbSession,
+	CddbStatus,
+};
+pub use xmcd::{
+	Xmcd,
+	XmcdMeta,
+	XmcdVerify,
+};
+
 use crate::{
 	Toc,
 	TocError,
 };
 use dactyl::traits::HexToUnsigned;
 use std::{
+	cmp::Ordering,
 	fmt,
 	hash,
 	str::FromStr,
@@ -23,6 +41,8 @@ use std::{
 ///
 /// Values of this type are returned by [`Toc::cddb_id`].
 ///
+/// Ordering is by the underlying `u32`.
+///
 /// ## Examples
 ///
 /// ```
@@ -43,14 +63,67 @@ use std::{
 ///     520_282_116,
 /// );
 /// ```
+///
+/// The canonical string form is lowercase, but decoding and string
+/// comparisons are case-insensitive, and the alternate flag can be used to
+/// print it uppercase:
+///
+/// ```
+/// use cdtoc::Toc;
+///
+/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+/// let cddb_id = toc.cddb_id();
+/// assert_eq!(format!("{cddb_id:#}"), "1F02E004");
+/// assert_eq!(cddb_id, "1f02e004");
+/// assert_eq!(cddb_id, "1F02E004");
+/// ```
 pub struct Cddb(pub(crate) u32);
 
 impl Eq for Cddb {}
 
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # CDDB Data Track Mode.
+///
+/// The canonical CDDB algorithm counts a disc's data track — if any — as a
+/// regular track, but some software only considers the audio, producing
+/// different IDs for CD-Extra and data-first discs.
+///
+/// [`CddbMode::IncludeData`] follows the canonical algorithm; reach for
+/// [`CddbMode::AudioOnly`] when matching IDs computed from a table of
+/// contents that never knew about the data track, such as one rebuilt from
+/// the audio files alone.
+///
+/// This is used by [`Toc::cddb_id_with`] to pick one or the other. For
+/// audio-only discs, the two are equivalent.
+pub enum CddbMode {
+	#[default]
+	/// # Include Data.
+	///
+	/// Count the data track, per the canonical algorithm. This is what
+	/// [`Toc::cddb_id`] has always used.
+	IncludeData,
+
+	/// # Audio Only.
+	///
+	/// Ignore the data track entirely, computing the ID as though the audio
+	/// tracks were the whole disc. The track count and checksum skip the
+	/// data track, and the playing time runs from the first audio track to
+	/// the end of the audio ([`Toc::audio_leadout`]).
+	AudioOnly,
+}
+
 impl fmt::Display for Cddb {
+	/// # Display.
+	///
+	/// IDs are printed in their canonical lowercase form, unless the
+	/// alternate flag (`{:#}`) is used, in which case they're uppercase.
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		let mut buf = [b'0'; 8];
 		faster_hex::hex_encode_fallback(self.0.to_be_bytes().as_slice(), &mut buf);
+		if f.alternate() { buf.make_ascii_uppercase(); }
 		std::str::from_utf8(buf.as_slice())
 			.map_err(|_| fmt::Error)
 			.and_then(|s| f.pad(s))
@@ -68,32 +141,184 @@ impl hash::Hash for Cddb {
 	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write_u32(self.0); }
 }
 
+impl Ord for Cddb {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering { self.0.cmp(&other.0) }
+}
+
 impl PartialEq for Cddb {
 	#[inline]
 	fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
 }
 
+impl PartialEq<str> for Cddb {
+	/// # String Equality.
+	///
+	/// Compare against a string, case-insensitively, by decoding it.
+	fn eq(&self, other: &str) -> bool {
+		Self::decode(other).is_ok_and(|other| self.0 == other.0)
+	}
+}
+
+impl PartialEq<&str> for Cddb {
+	#[inline]
+	fn eq(&self, other: &&str) -> bool { <Self as PartialEq<str>>::eq(self, other) }
+}
+
+impl PartialEq<Cddb> for str {
+	#[inline]
+	fn eq(&self, other: &Cddb) -> bool { other == self }
+}
+
+impl PartialEq<Cddb> for &str {
+	#[inline]
+	fn eq(&self, other: &Cddb) -> bool { other == *self }
+}
+
+impl PartialOrd for Cddb {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
 impl From<Cddb> for u32 {
 	#[inline]
 	fn from(src: Cddb) -> Self { src.0 }
 }
 
 impl From<&Toc> for Cddb {
-	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[inline]
 	fn from(src: &Toc) -> Self {
-		let mut len = src.audio_len();
+		Self::from_sectors(src.audio_sectors(), src.data_sector(), src.leadin(), src.leadout())
+	}
+}
+
+impl Cddb {
+	#[must_use]
+	/// # New.
+	///
+	/// Wrap a raw `u32` CDDB ID.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Cddb, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert_eq!(Cddb::new(0x1f02_e004), toc.cddb_id());
+	/// ```
+	pub const fn new(raw: u32) -> Self { Self(raw) }
+
+	#[must_use]
+	/// # As `u32`.
+	///
+	/// Return the ID as a raw `u32`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert_eq!(toc.cddb_id().as_u32(), 0x1f02_e004);
+	/// ```
+	pub const fn as_u32(&self) -> u32 { self.0 }
+
+	#[must_use]
+	/// # Parts.
+	///
+	/// Return the three components packed into the ID: the checksum byte,
+	/// the total playing time in seconds, and the number of tracks
+	/// (including any data track).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let (checksum, seconds, tracks) = toc.cddb_id().parts();
+	/// assert_eq!(checksum, 0x1f);
+	/// assert_eq!(seconds, 736);
+	/// assert_eq!(tracks, 4);
+	/// ```
+	pub const fn parts(&self) -> (u8, u16, u8) {
+		let [a, b1, b2, c] = self.0.to_be_bytes();
+		(a, u16::from_be_bytes([b1, b2]), c)
+	}
+}
+
+impl Cddb {
+	/// # From Offsets.
+	///
+	/// Compute a CDDB ID directly from raw sector positions — the same
+	/// values passed to [`Toc::from_parts`] — without building a [`Toc`].
+	///
+	/// Unlike [`Toc::from_parts`], the only validation performed is a check
+	/// that there are between `1..=99` audio tracks, so historical or
+	/// out-of-spec values that would otherwise be rejected can still be
+	/// identified. For well-formed values, the result is identical to
+	/// [`Toc::cddb_id`].
+	///
+	/// Note that the algorithm works with absolute positions, so values that
+	/// have been normalized (i.e. with the 150-sector lead-in subtracted)
+	/// will produce different IDs than their un-normalized equivalents.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Cddb, Toc};
+	///
+	/// let audio = vec![150, 11563, 25174, 45863];
+	/// let leadout = 55370;
+	/// let toc = Toc::from_parts(audio.clone(), None, leadout).unwrap();
+	///
+	/// assert_eq!(
+	///     Cddb::from_offsets(&audio, None, leadout),
+	///     Ok(toc.cddb_id()),
+	/// );
+	///
+	/// // Toc won't accept a zero-based leadin, but this will.
+	/// assert!(Toc::from_parts(vec![0, 11413], None, 20000).is_err());
+	/// assert!(Cddb::from_offsets(&[0, 11413], None, 20000).is_ok());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if there are no audio tracks, or more than
+	/// `99`.
+	pub fn from_offsets(audio: &[u32], data: Option<u32>, leadout: u32)
+	-> Result<Self, TocError> {
+		if audio.is_empty() { return Err(TocError::NoAudio); }
+		if 99 < audio.len() { return Err(TocError::TrackCount); }
+
+		// The ID starts from the data session for data-first discs.
+		let leadin = match data {
+			Some(d) if d < audio[0] => d,
+			_ => audio[0],
+		};
+		Ok(Self::from_sectors(audio, data, leadin, leadout))
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # From Sectors.
+	///
+	/// Crunch the ID from the raw parts of a table of contents. No validation
+	/// is performed.
+	pub(crate) fn from_sectors(audio: &[u32], data: Option<u32>, leadin: u32, leadout: u32)
+	-> Self {
+		let mut len = audio.len();
 		let mut a: u32 = 0;
 
 		// Add the audio positions.
 		let mut buf = itoa::Buffer::new();
-		for v in src.audio_sectors() {
+		for v in audio {
 			for b in buf.format(v.wrapping_div(75)).bytes() {
 				a += u32::from(b ^ b'0');
 			}
 		}
 
 		// Add the data position.
-		if let Some(v) = src.data_sector() {
+		if let Some(v) = data {
 			len += 1;
 			for b in buf.format(v.wrapping_div(75)).bytes() {
 				a += u32::from(b ^ b'0');
@@ -102,7 +327,7 @@ impl From<&Toc> for Cddb {
 
 		// The three parts we need.
 		let a = (a % 255) as u8;
-		let b = ((src.leadout().wrapping_div(75) - src.leadin().wrapping_div(75)) as u16).to_be_bytes();
+		let b = (leadout.wrapping_div(75).wrapping_sub(leadin.wrapping_div(75)) as u16).to_be_bytes();
 		let c = len as u8;
 
 		// Shove it into a single u32.
@@ -125,6 +350,10 @@ impl Cddb {
 	///
 	/// Convert a CDDB ID string back into a [`Cddb`] instance.
 	///
+	/// Decoding is strict: after trimming surrounding whitespace, the string
+	/// must comprise exactly eight hex digits (case-insensitive). See
+	/// [`Cddb::decode_lenient`] for the old, anything-goes behavior.
+	///
 	/// ## Examples
 	///
 	/// ```
@@ -147,6 +376,10 @@ impl Cddb {
 	/// let cddb_str = cddb_id.to_string();
 	/// assert_eq!(Cddb::try_from(cddb_str.as_str()), Ok(cddb_id));
 	/// assert_eq!(cddb_str.parse::<Cddb>(), Ok(cddb_id));
+	///
+	/// // Junk is rejected.
+	/// assert!(Cddb::decode("1f").is_err());
+	/// assert!(Cddb::decode("00000000000001f02e004").is_err());
 	/// ```
 	///
 	/// ## Errors
@@ -154,11 +387,145 @@ impl Cddb {
 	/// This will return an error if decoding fails.
 	pub fn decode<S>(src: S) -> Result<Self, TocError>
 	where S: AsRef<str> {
-		let src = src.as_ref().as_bytes();
+		let src = src.as_ref().trim().as_bytes();
+		if src.len() == 8 { Self::decode_lenient_bytes(src) }
+		else { Err(TocError::CddbDecode) }
+	}
+
+	/// # Decode (Lenient).
+	///
+	/// Same as [`Cddb::decode`], but accepts any hex string that fits in a
+	/// `u32`, regardless of length. This was the behavior of `decode` in
+	/// earlier releases; it should only be used for compatibility with
+	/// sloppy data sources.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Cddb;
+	///
+	/// assert_eq!(Cddb::decode_lenient("1f"), Ok(Cddb::new(0x1f)));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if decoding fails.
+	pub fn decode_lenient<S>(src: S) -> Result<Self, TocError>
+	where S: AsRef<str> { Self::decode_lenient_bytes(src.as_ref().as_bytes()) }
+
+	/// # Decode (Bytes).
+	///
+	/// Parse the hex, whatever its length.
+	fn decode_lenient_bytes(src: &[u8]) -> Result<Self, TocError> {
 		u32::htou(src).map(Self).ok_or(TocError::CddbDecode)
 	}
+
+	#[must_use]
+	/// # Is Plausible?
+	///
+	/// Returns `true` if the packed components look like they came from a
+	/// real disc: a track count between `1..=100` (99 audio tracks plus a
+	/// data track), and a non-zero playing time.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Cddb, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert!(toc.cddb_id().is_plausible());
+	///
+	/// // No tracks.
+	/// assert!(! Cddb::new(0x1f02_e000).is_plausible());
+	///
+	/// // No time.
+	/// assert!(! Cddb::new(0x1f00_0004).is_plausible());
+	/// ```
+	pub const fn is_plausible(&self) -> bool {
+		let (_, seconds, tracks) = self.parts();
+		0 != seconds && 0 != tracks && tracks <= 100
+	}
 }
 
+impl Cddb {
+	/// # Query Command.
+	///
+	/// Return the freedb/gnudb `cddb query` command line for this ID, e.g.
+	/// `cddb query <id> <tracks> <offsets…> <seconds>`.
+	///
+	/// Per the protocol, the track count and offsets include any data
+	/// track — whether it comes before or after the audio — and the final
+	/// field is the total disc length in seconds, measured from the start of
+	/// the disc (i.e. including the two-second lead-in).
+	///
+	/// The table of contents must be the one this ID was computed from.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let cddb_id = toc.cddb_id();
+	/// assert_eq!(
+	///     cddb_id.query_command(&toc).unwrap(),
+	///     "cddb query 1f02e004 4 150 11563 25174 45863 738",
+	/// );
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the table of contents does not hash to
+	/// this ID.
+	pub fn query_command(&self, toc: &Toc) -> Result<String, TocError> {
+		if Self::from(toc) != *self { return Err(TocError::CddbMismatch); }
+
+		let mut out = String::with_capacity(64);
+		self.push_query(toc, ' ', &mut out);
+		Ok(out)
+	}
+}
+
+
+
+impl Cddb {
+	/// # Push Query.
+	///
+	/// Write the `cddb query` command for the table of contents to the
+	/// buffer, using the given separator between words.
+	fn push_query(self, toc: &Toc, sep: char, out: &mut String) {
+		let sectors = query_sectors(toc);
+		out.reserve(24 + sectors.len() * 7);
+		out.push_str("cddb");
+		out.push(sep);
+		out.push_str("query");
+		out.push(sep);
+		out.push_str(&self.to_string());
+
+		let mut buf = itoa::Buffer::new();
+		out.push(sep);
+		out.push_str(buf.format(sectors.len()));
+		for v in sectors {
+			out.push(sep);
+			out.push_str(buf.format(v));
+		}
+		out.push(sep);
+		out.push_str(buf.format(toc.leadout().wrapping_div(75)));
+	}
+}
+
+/// # Query Sectors.
+///
+/// Return the starting sectors of all tracks — audio and data — in order, as
+/// used by the freedb protocol.
+fn query_sectors(toc: &Toc) -> Vec<u32> {
+	let mut out = toc.audio_sectors().to_vec();
+	if let Some(v) = toc.data_sector() {
+		out.push(v);
+		out.sort_unstable();
+	}
+	out
+}
 
 
 impl Toc {
@@ -190,6 +557,39 @@ impl Toc {
 	/// );
 	/// ```
 	pub fn cddb_id(&self) -> Cddb { Cddb::from(self) }
+
+	#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+	#[must_use]
+	/// # CDDB ID (Custom Mode).
+	///
+	/// Same as [`Toc::cddb_id`], but with an explicit choice of data track
+	/// handling. This only makes a difference for discs with a data track;
+	/// see [`CddbMode`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{CddbMode, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11").unwrap();
+	/// assert_eq!(
+	///     toc.cddb_id_with(CddbMode::IncludeData),
+	///     toc.cddb_id(),
+	/// );
+	/// assert_eq!(
+	///     toc.cddb_id_with(CddbMode::AudioOnly).to_string(),
+	///     "8909190a",
+	/// );
+	/// ```
+	pub fn cddb_id_with(&self, mode: CddbMode) -> Cddb {
+		match mode {
+			CddbMode::IncludeData => Cddb::from(self),
+			CddbMode::AudioOnly => {
+				let audio = self.audio_sectors();
+				Cddb::from_sectors(audio, None, audio[0], self.audio_leadout())
+			},
+		}
+	}
 }
 
 
@@ -230,6 +630,198 @@ mod tests {
 			assert_eq!(Cddb::decode(id), Ok(cddb_id));
 			assert_eq!(Cddb::try_from(id), Ok(cddb_id));
 			assert_eq!(id.parse::<Cddb>(), Ok(cddb_id));
+			assert!(cddb_id.is_plausible());
+
+			// Case and whitespace shouldn't matter.
+			assert_eq!(Cddb::decode(id.to_ascii_uppercase()), Ok(cddb_id));
+			assert_eq!(Cddb::decode(format!(" {id}\n")), Ok(cddb_id));
+		}
+	}
+
+	#[test]
+	fn t_case() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC");
+		let cddb_id = toc.cddb_id();
+
+		// Decoding should work regardless of case.
+		for s in ["1f02e004", "1F02E004", "1f02E004", "1F02e004"] {
+			assert_eq!(Cddb::decode(s), Ok(cddb_id), "{s}");
+			assert_eq!(Cddb::decode_lenient(s), Ok(cddb_id), "{s}");
+
+			// Ditto for comparisons, both ways.
+			assert_eq!(cddb_id, s);
+			assert_eq!(cddb_id, *s);
+			assert_eq!(s, cddb_id);
+			assert_eq!(*s, cddb_id);
+		}
+
+		// Non-matches.
+		for s in ["1f02e005", "1f02e00", "nope", ""] {
+			assert_ne!(cddb_id, s, "{s}");
+			assert_ne!(s, cddb_id, "{s}");
+		}
+
+		// Display.
+		assert_eq!(cddb_id.to_string(), "1f02e004");
+		assert_eq!(format!("{cddb_id:#}"), "1F02E004");
+		assert_eq!(format!("{cddb_id:>10}"), "  1f02e004");
+		assert_eq!(format!("{cddb_id:<#10}"), "1F02E004  ");
+	}
+
+	#[test]
+	fn t_decode_strict() {
+		for bad in ["", "1f", "1f02e00", "1f02e0045", "00000000000001f02e004", "1f02e0g4", "1f 02e004"] {
+			assert_eq!(Cddb::decode(bad), Err(TocError::CddbDecode), "{bad}");
+		}
+
+		// The lenient version is more forgiving.
+		assert_eq!(Cddb::decode_lenient("1f"), Ok(Cddb(0x1f)));
+		assert_eq!(Cddb::decode_lenient("00000000000001f02e004"), Ok(Cddb(0x1f02_e004)));
+		assert!(Cddb::decode_lenient("1f02e0g4").is_err());
+
+		// Plausibility.
+		assert!(Cddb(0x0000_0101).is_plausible());
+		assert!(Cddb(0x0000_0164).is_plausible());
+		assert!(! Cddb(0x0000_0165).is_plausible());
+		assert!(! Cddb(0x0000_0100).is_plausible());
+		assert!(! Cddb(0xff00_0001).is_plausible());
+	}
+
+	#[test]
+	fn t_query_command() {
+		for (t, cmd) in [
+			(
+				"4+96+2D2B+6256+B327+D84A",
+				"cddb query 1f02e004 4 150 11563 25174 45863 738",
+			),
+			// CD-Extra; the data session is counted.
+			(
+				"A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11",
+				"cddb query 9a0bb60b 11 150 14167 26989 50767 68115 85410 106120 121770 136100 161870 186287 3000",
+			),
+			// Data first; same layout as the first, so the same query.
+			(
+				"3+2D2B+6256+B327+D84A+X96",
+				"cddb query 1f02e004 4 150 11563 25174 45863 738",
+			),
+		] {
+			let toc = Toc::from_cdtoc(t).expect("Invalid TOC");
+			let cddb_id = toc.cddb_id();
+			assert_eq!(cddb_id.query_command(&toc).as_deref(), Ok(cmd));
+		}
+
+		// The wrong TOC should fail.
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC");
+		let other = Toc::from_cdtoc("4+96+2D2B+6256+B327+E84A").expect("Invalid TOC");
+		assert_eq!(toc.cddb_id().query_command(&other), Err(TocError::CddbMismatch));
+	}
+
+	#[test]
+	fn t_parts() {
+		for t in [
+			"D+96+3B5D+78E3+B441+EC83+134F4+17225+1A801+1EA5C+23B5B+27CEF+2B58B+2F974+35D56+514C8",
+			"4+96+2D2B+6256+B327+D84A",
+			"10+B6+5352+62AC+99D6+E218+12AC0+135E7+142E9+178B0+19D22+1B0D0+1E7FA+22882+247DB+27074+2A1BD+2C0FB",
+			"15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46",
+			"A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11",
+			"3+2D2B+6256+B327+D84A+X96",
+		] {
+			let toc = Toc::from_cdtoc(t).expect("Invalid TOC");
+			let cddb_id = toc.cddb_id();
+			assert_eq!(Cddb::new(cddb_id.as_u32()), cddb_id);
+			assert_eq!(cddb_id.as_u32(), u32::from(cddb_id));
+
+			// Work out the parts the long way.
+			let sectors = query_sectors(&toc);
+			let sum: u32 = sectors.iter()
+				.map(|v| (v / 75).to_string().bytes().map(|b| u32::from(b - b'0')).sum::<u32>())
+				.sum();
+			let seconds = toc.leadout() / 75 - toc.leadin() / 75;
+
+			let (a, b, c) = cddb_id.parts();
+			assert_eq!(u32::from(a), sum % 255, "{t}");
+			assert_eq!(u32::from(b), seconds, "{t}");
+			assert_eq!(usize::from(c), sectors.len(), "{t}");
+			assert_eq!(usize::from(c), toc.audio_len() + usize::from(toc.data_sector().is_some()), "{t}");
+		}
+	}
+
+	#[test]
+	fn t_from_offsets() {
+		for t in [
+			"D+96+3B5D+78E3+B441+EC83+134F4+17225+1A801+1EA5C+23B5B+27CEF+2B58B+2F974+35D56+514C8",
+			"4+96+2D2B+6256+B327+D84A",
+			"10+B6+5352+62AC+99D6+E218+12AC0+135E7+142E9+178B0+19D22+1B0D0+1E7FA+22882+247DB+27074+2A1BD+2C0FB",
+			"15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46",
+			"A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11",
+			"3+2D2B+6256+B327+D84A+X96",
+		] {
+			let toc = Toc::from_cdtoc(t).expect("Invalid TOC");
+			assert_eq!(
+				Cddb::from_offsets(toc.audio_sectors(), toc.data_sector(), toc.leadout()),
+				Ok(toc.cddb_id()),
+				"{t}",
+			);
+		}
+
+		// Zero-based and otherwise out-of-spec stuff should still work.
+		assert_eq!(
+			Cddb::from_offsets(&[0, 11413, 25024, 45713], None, 55220).map(|c| c.parts()),
+			Ok((0x20, 736, 4)),
+		);
+		assert!(Cddb::from_offsets(&[0, 100, 50], None, 10).is_ok());
+		assert!(Cddb::from_offsets(&[u32::MAX; 99], Some(u32::MAX), u32::MAX).is_ok());
+
+		// But not bad track counts.
+		assert_eq!(Cddb::from_offsets(&[], None, 55370), Err(TocError::NoAudio));
+		assert_eq!(Cddb::from_offsets(&[150; 100], None, 55370), Err(TocError::TrackCount));
+	}
+
+	#[test]
+	fn t_mode() {
+		// These were checked against an independent implementation of the
+		// algorithm rather than taken from a live database.
+		for (t, include, audio) in [
+			// Audio-only; no difference.
+			("4+96+2D2B+6256+B327+D84A", "1f02e004", "1f02e004"),
+			// CD-Extra; the count, checksum, and leadout change.
+			(
+				"A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11",
+				"9a0bb60b",
+				"8909190a",
+			),
+			// Data-first; the count, checksum, and leadin change.
+			("3+2D2B+6256+B327+D84A+X96", "1f02e004", "1d024803"),
+		] {
+			let toc = Toc::from_cdtoc(t).expect("Invalid TOC");
+			assert_eq!(toc.cddb_id_with(CddbMode::default()), toc.cddb_id());
+			assert_eq!(toc.cddb_id_with(CddbMode::IncludeData).to_string(), include);
+			assert_eq!(toc.cddb_id_with(CddbMode::AudioOnly).to_string(), audio);
+		}
+
+		// An audio-only ID should match the same disc sans data.
+		let toc = Toc::from_cdtoc("3+2D2B+6256+B327+D84A+X96").expect("Invalid TOC");
+		let bare = Toc::from_cdtoc("3+2D2B+6256+B327+D84A").expect("Invalid TOC");
+		assert_eq!(toc.cddb_id_with(CddbMode::AudioOnly), bare.cddb_id());
+	}
+
+	#[test]
+	fn t_ord() {
+		use std::collections::BTreeSet;
+
+		// Generate a bunch of pseudo-random IDs.
+		let mut seed: u64 = 0x0bad_cafe;
+		let ids: Vec<Cddb> = (0..500).map(|_| Cddb(crate::xorshift(&mut seed) as u32)).collect();
+
+		// Ordering should match the numbers.
+		let set: BTreeSet<Cddb> = ids.iter().copied().collect();
+		let mut raw: Vec<u32> = ids.iter().copied().map(u32::from).collect();
+		raw.sort_unstable();
+		raw.dedup();
+		assert!(set.iter().map(|id| u32::from(*id)).eq(raw.iter().copied()));
+		for pair in ids.windows(2) {
+			assert_eq!(pair[0].cmp(&pair[1]), pair[0].0.cmp(&pair[1].0));
+			assert_eq!(pair[0] == pair[1], pair[0].cmp(&pair[1]).is_eq());
 		}
 	}
 }
@@ -0,0 +1,230 @@
+/*!
+# CDTOC: CDDB gnudb
+*/
+
+use crate::{
+	Cddb,
+	Toc,
+};
+
+
+
+/// # Base Lookup URL.
+const BASE_URL: &str = "http://gnudb.gnudb.org/~cddb/cddb.cgi?cmd=";
+
+/// # Protocol Level.
+const PROTO: &str = "&proto=6";
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # gnudb Hello.
+///
+/// The CDDB protocol requires every request to identify the user, host,
+/// client name, and client version. This struct holds those details for
+/// [`Toc::gnudb_query_url`] and [`Toc::gnudb_read_url`].
+///
+/// The [`Default`] implementation uses `anonymous`, `localhost`, and this
+/// crate's name and version.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::GnudbHello;
+///
+/// let hello = GnudbHello::new("jane", "example.com", "My Ripper", "1.0");
+/// assert_eq!(hello.user(), "jane");
+/// assert_eq!(hello.host(), "example.com");
+/// assert_eq!(hello.client(), "My Ripper");
+/// assert_eq!(hello.version(), "1.0");
+/// ```
+pub struct GnudbHello {
+	/// # User.
+	user: String,
+
+	/// # Host.
+	host: String,
+
+	/// # Client Name.
+	client: String,
+
+	/// # Client Version.
+	version: String,
+}
+
+impl Default for GnudbHello {
+	fn default() -> Self {
+		Self::new("anonymous", "localhost", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+	}
+}
+
+impl GnudbHello {
+	#[must_use]
+	/// # New.
+	pub fn new<A, B, C, D>(user: A, host: B, client: C, version: D) -> Self
+	where A: Into<String>, B: Into<String>, C: Into<String>, D: Into<String> {
+		Self {
+			user: user.into(),
+			host: host.into(),
+			client: client.into(),
+			version: version.into(),
+		}
+	}
+
+	#[must_use]
+	/// # Client Name.
+	pub fn client(&self) -> &str { &self.client }
+
+	#[must_use]
+	/// # Host.
+	pub fn host(&self) -> &str { &self.host }
+
+	#[must_use]
+	/// # User.
+	pub fn user(&self) -> &str { &self.user }
+
+	#[must_use]
+	/// # Client Version.
+	pub fn version(&self) -> &str { &self.version }
+
+	/// # Push Hello and Protocol.
+	///
+	/// Append the `hello` and `proto` query parameters to the URL. The fields
+	/// are separated by literal `+` signs, so anything else gets
+	/// percent-encoded, including spaces.
+	fn push_params(&self, out: &mut String) {
+		out.push_str("&hello=");
+		push_encoded(&self.user, out);
+		out.push('+');
+		push_encoded(&self.host, out);
+		out.push('+');
+		push_encoded(&self.client, out);
+		out.push('+');
+		push_encoded(&self.version, out);
+		out.push_str(PROTO);
+	}
+}
+
+
+
+impl Toc {
+	#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+	#[must_use]
+	/// # gnudb Query URL.
+	///
+	/// Return the [gnudb](https://gnudb.org/) HTTP URL for the `cddb query`
+	/// command corresponding to the table of contents.
+	///
+	/// See also [`Cddb::query_command`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{GnudbHello, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let hello = GnudbHello::new("jane", "example.com", "My Ripper", "1.0");
+	/// assert_eq!(
+	///     toc.gnudb_query_url(&hello),
+	///     "http://gnudb.gnudb.org/~cddb/cddb.cgi?cmd=cddb+query+1f02e004+4+150+11563+25174+45863+738&hello=jane+example.com+My%20Ripper+1.0&proto=6",
+	/// );
+	/// ```
+	pub fn gnudb_query_url(&self, hello: &GnudbHello) -> String {
+		let mut out = String::with_capacity(160);
+		out.push_str(BASE_URL);
+		Cddb::from(self).push_query(self, '+', &mut out);
+		hello.push_params(&mut out);
+		out
+	}
+
+	#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+	#[must_use]
+	/// # gnudb Read URL.
+	///
+	/// Return the [gnudb](https://gnudb.org/) HTTP URL for the `cddb read`
+	/// command corresponding to the table of contents, i.e. the follow-up
+	/// request for the full [xmcd](crate::Xmcd) record once a query has
+	/// identified the category.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{GnudbHello, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let hello = GnudbHello::new("jane", "example.com", "My Ripper", "1.0");
+	/// assert_eq!(
+	///     toc.gnudb_read_url("rock", &hello),
+	///     "http://gnudb.gnudb.org/~cddb/cddb.cgi?cmd=cddb+read+rock+1f02e004&hello=jane+example.com+My%20Ripper+1.0&proto=6",
+	/// );
+	/// ```
+	pub fn gnudb_read_url(&self, category: &str, hello: &GnudbHello) -> String {
+		let mut out = String::with_capacity(128);
+		out.push_str(BASE_URL);
+		out.push_str("cddb+read+");
+		push_encoded(category.trim(), &mut out);
+		out.push('+');
+		out.push_str(&self.cddb_id().to_string());
+		hello.push_params(&mut out);
+		out
+	}
+}
+
+
+
+/// # Push Percent-Encoded.
+///
+/// Append the string to the buffer, percent-encoding everything but the
+/// RFC 3986 unreserved characters.
+fn push_encoded(src: &str, out: &mut String) {
+	/// # Hex Digits.
+	const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+	for b in src.bytes() {
+		if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+			out.push(char::from(b));
+		}
+		else {
+			out.push('%');
+			out.push(char::from(HEX[usize::from(b >> 4)]));
+			out.push(char::from(HEX[usize::from(b & 0x0f)]));
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_gnudb() {
+		let hello = GnudbHello::new("jane doe", "my+host", "Ripper™", "1.0/beta&x=y");
+		let encoded = "&hello=jane%20doe+my%2Bhost+Ripper%E2%84%A2+1.0%2Fbeta%26x%3Dy&proto=6";
+
+		// CD-Extra; the data track is included.
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC.");
+		assert_eq!(
+			toc.gnudb_query_url(&hello),
+			format!("{BASE_URL}cddb+query+9a0bb60b+11+150+14167+26989+50767+68115+85410+106120+121770+136100+161870+186287+3000{encoded}"),
+		);
+		assert_eq!(
+			toc.gnudb_read_url(" new age ", &hello),
+			format!("{BASE_URL}cddb+read+new%20age+9a0bb60b{encoded}"),
+		);
+
+		// The query should otherwise match the command.
+		let command = toc.cddb_id().query_command(&toc).expect("Query failed.");
+		assert!(toc.gnudb_query_url(&hello).contains(&command.replace(' ', "+")));
+
+		// The defaults.
+		let hello = GnudbHello::default();
+		assert_eq!(hello.user(), "anonymous");
+		assert_eq!(hello.host(), "localhost");
+		assert_eq!(hello.client(), "cdtoc");
+		assert_eq!(hello.version(), env!("CARGO_PKG_VERSION"));
+	}
+}
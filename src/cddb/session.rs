@@ -0,0 +1,522 @@
+/*!
+# CDTOC: CDDB Session
+*/
+
+use crate::{
+	Cddb,
+	GnudbHello,
+	Toc,
+	TocError,
+};
+use std::fmt;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # CDDB Session.
+///
+/// This is a transport-agnostic helper for speaking the classic CDDB
+/// protocol (CDDBP or HTTP): it builds the command strings, keeps track of
+/// the negotiated protocol level, and leaves the actual networking to you.
+///
+/// Server responses can be parsed with [`CddbReply::parse`] and, for
+/// queries, [`CddbMatch::parse`].
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{CddbReply, CddbSession, CddbStatus, GnudbHello, Toc};
+///
+/// let hello = GnudbHello::new("jane", "example.com", "MyRipper", "1.0");
+/// let mut session = CddbSession::new(hello);
+/// assert_eq!(session.hello_command(), "cddb hello jane example.com MyRipper 1.0");
+///
+/// // Negotiate the protocol level.
+/// assert_eq!(session.proto(), 1);
+/// assert_eq!(session.proto_command(6), "proto 6");
+/// session.accept_proto("201 OK, CDDB protocol level now: 6").unwrap();
+/// assert_eq!(session.proto(), 6);
+///
+/// // Query.
+/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+/// assert_eq!(
+///     session.query_command(&toc),
+///     "cddb query 1f02e004 4 150 11563 25174 45863 738",
+/// );
+///
+/// // Branch on the response.
+/// let reply = CddbReply::parse("200 rock 1f02e004 Artist / Album").unwrap();
+/// assert_eq!(reply.status(), CddbStatus::Ok);
+/// ```
+pub struct CddbSession {
+	/// # Hello Details.
+	hello: GnudbHello,
+
+	/// # Protocol Level.
+	proto: u8,
+}
+
+impl CddbSession {
+	/// # Default Protocol Level.
+	///
+	/// Servers start every session at level one.
+	pub const DEFAULT_PROTO: u8 = 1;
+
+	#[must_use]
+	/// # New.
+	pub const fn new(hello: GnudbHello) -> Self {
+		Self { hello, proto: Self::DEFAULT_PROTO }
+	}
+
+	#[must_use]
+	/// # Hello Details.
+	pub const fn hello(&self) -> &GnudbHello { &self.hello }
+
+	#[must_use]
+	/// # Protocol Level.
+	///
+	/// Return the current (negotiated) protocol level.
+	pub const fn proto(&self) -> u8 { self.proto }
+}
+
+impl CddbSession {
+	#[must_use]
+	/// # Hello Command.
+	///
+	/// Return the `cddb hello` handshake command.
+	///
+	/// The protocol separates arguments with spaces, so any whitespace within
+	/// the individual fields is replaced with underscores.
+	pub fn hello_command(&self) -> String {
+		let mut out = String::with_capacity(64);
+		out.push_str("cddb hello");
+		for v in [
+			self.hello.user(),
+			self.hello.host(),
+			self.hello.client(),
+			self.hello.version(),
+		] {
+			out.push(' ');
+			push_word(v, &mut out);
+		}
+		out
+	}
+
+	#[must_use]
+	/// # Protocol Command.
+	///
+	/// Return the `proto` command requesting the given level. The session's
+	/// own level isn't updated until the server agrees; see
+	/// [`CddbSession::accept_proto`].
+	pub fn proto_command(&self, level: u8) -> String { format!("proto {level}") }
+
+	#[must_use]
+	/// # Query Command.
+	///
+	/// Return the `cddb query` command for the table of contents. This is
+	/// equivalent to [`Cddb::query_command`].
+	pub fn query_command(&self, toc: &Toc) -> String {
+		let mut out = String::with_capacity(64);
+		Cddb::from(toc).push_query(toc, ' ', &mut out);
+		out
+	}
+
+	#[must_use]
+	/// # Read Command.
+	///
+	/// Return the `cddb read` command for the given category and ID, as
+	/// reported by a query response (see [`CddbMatch`]).
+	pub fn read_command(&self, category: &str, id: Cddb) -> String {
+		let mut out = String::with_capacity(32);
+		out.push_str("cddb read ");
+		push_word(category, &mut out);
+		out.push(' ');
+		out.push_str(&id.to_string());
+		out
+	}
+
+	#[must_use]
+	/// # Quit Command.
+	pub const fn quit_command(&self) -> &'static str { "quit" }
+
+	/// # Accept Protocol Response.
+	///
+	/// Parse the server's response to a [`proto`](CddbSession::proto_command)
+	/// command, updating and returning the session's protocol level.
+	///
+	/// Both success (`201`) and "already at level" (`502`) responses are
+	/// accepted.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the response is any other kind, or
+	/// doesn't end with the new level.
+	pub fn accept_proto(&mut self, line: &str) -> Result<u8, TocError> {
+		let reply = CddbReply::parse(line)?;
+		if matches!(reply.code(), 201 | 502) {
+			let level = reply.message()
+				.rsplit(|c: char| c.is_whitespace() || c == ':')
+				.find(|v| ! v.is_empty())
+				.and_then(|v| v.trim_end_matches('.').parse::<u8>().ok())
+				.ok_or(TocError::CddbResponse)?;
+			self.proto = level;
+			Ok(level)
+		}
+		else { Err(TocError::CddbResponse) }
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # CDDB Response Status.
+///
+/// This is a typed version of a CDDB server's numeric response code; see
+/// [`CddbReply`].
+///
+/// Some codes mean slightly different things depending on the command, but
+/// the general gist is the same.
+pub enum CddbStatus {
+	/// # OK (`200`, `201`).
+	///
+	/// For queries, `200` indicates a single exact match, described in the
+	/// rest of the line.
+	Ok,
+
+	/// # No Match (`202`).
+	NoMatch,
+
+	/// # Data Follows (`210`).
+	///
+	/// For reads, the xmcd record follows. For queries, multiple exact
+	/// matches follow. Either way, the data is terminated by a `.` line.
+	DataFollows,
+
+	/// # Inexact Matches Follow (`211`).
+	///
+	/// The matches are terminated by a `.` line.
+	InexactMatches,
+
+	/// # Not Found (`401`).
+	NotFound,
+
+	/// # Server Error (`402`, `530`).
+	ServerError,
+
+	/// # Database Corrupt (`403`).
+	DatabaseCorrupt,
+
+	/// # No Handshake (`409`).
+	///
+	/// A `cddb hello` is required first.
+	NoHandshake,
+
+	/// # Syntax Error (`500`).
+	SyntaxError,
+
+	/// # Illegal Protocol Level (`501`).
+	IllegalProto,
+
+	/// # Protocol Already Set (`502`).
+	ProtoAlreadySet,
+
+	/// # Other.
+	///
+	/// Some other code, of which there are many.
+	Other(u16),
+}
+
+impl From<u16> for CddbStatus {
+	fn from(src: u16) -> Self {
+		match src {
+			200 | 201 => Self::Ok,
+			202 => Self::NoMatch,
+			210 => Self::DataFollows,
+			211 => Self::InexactMatches,
+			401 => Self::NotFound,
+			402 | 530 => Self::ServerError,
+			403 => Self::DatabaseCorrupt,
+			409 => Self::NoHandshake,
+			500 => Self::SyntaxError,
+			501 => Self::IllegalProto,
+			502 => Self::ProtoAlreadySet,
+			n => Self::Other(n),
+		}
+	}
+}
+
+impl CddbStatus {
+	#[must_use]
+	/// # Is Success?
+	///
+	/// Returns `true` for `2xx` responses.
+	pub const fn is_success(self) -> bool {
+		matches!(self, Self::Ok | Self::NoMatch | Self::DataFollows | Self::InexactMatches) ||
+		matches!(self, Self::Other(200..=299))
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # CDDB Response Line.
+///
+/// This holds the parsed first line of a CDDB server response: the numeric
+/// code, its [typed equivalent](CddbStatus), and the rest of the message.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{CddbReply, CddbStatus};
+///
+/// let reply = CddbReply::parse("211 close matches found").unwrap();
+/// assert_eq!(reply.code(), 211);
+/// assert_eq!(reply.status(), CddbStatus::InexactMatches);
+/// assert_eq!(reply.message(), "close matches found");
+/// assert!(reply.has_data());
+/// ```
+pub struct CddbReply {
+	/// # Code.
+	code: u16,
+
+	/// # Message.
+	message: String,
+}
+
+impl fmt::Display for CddbReply {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.message.is_empty() { write!(f, "{}", self.code) }
+		else { write!(f, "{} {}", self.code, self.message) }
+	}
+}
+
+impl CddbReply {
+	/// # Parse.
+	///
+	/// Parse a response line, i.e. a three-digit code optionally followed by
+	/// a space and message. Trailing line breaks are ignored.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the line doesn't begin with a valid
+	/// code.
+	pub fn parse(line: &str) -> Result<Self, TocError> {
+		let line = line.trim_end_matches(['\r', '\n']);
+		let (code, message) = line.split_at_checked(3).ok_or(TocError::CddbResponse)?;
+		if
+			! code.bytes().all(|b| b.is_ascii_digit()) ||
+			! (message.is_empty() || message.starts_with(' ')) ||
+			code.starts_with('0')
+		{
+			return Err(TocError::CddbResponse);
+		}
+
+		let code = code.parse::<u16>().map_err(|_| TocError::CddbResponse)?;
+		Ok(Self { code, message: message.trim().to_owned() })
+	}
+
+	#[must_use]
+	/// # Code.
+	pub const fn code(&self) -> u16 { self.code }
+
+	#[must_use]
+	/// # Has Data?
+	///
+	/// Returns `true` if additional lines — terminated by a `.` — follow
+	/// this one, as indicated by a `1` in the middle digit of the code.
+	pub const fn has_data(&self) -> bool { (self.code / 10) % 10 == 1 }
+
+	#[must_use]
+	/// # Message.
+	///
+	/// Return the text following the code, if any.
+	pub fn message(&self) -> &str { &self.message }
+
+	#[must_use]
+	/// # Status.
+	pub fn status(&self) -> CddbStatus { CddbStatus::from(self.code) }
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # CDDB Query Match.
+///
+/// This holds a single query match — category, ID, and title — as found in
+/// the message of a `200` query response, or the lines following a `210` or
+/// `211` one. The category and ID can be passed straight to
+/// [`CddbSession::read_command`].
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{CddbMatch, CddbReply};
+///
+/// // Single exact match.
+/// let reply = CddbReply::parse("200 rock 1f02e004 Artist / Album").unwrap();
+/// let matched = CddbMatch::parse(reply.message()).unwrap();
+/// assert_eq!(matched.category(), "rock");
+/// assert_eq!(matched.id().to_string(), "1f02e004");
+/// assert_eq!(matched.title(), "Artist / Album");
+/// ```
+pub struct CddbMatch {
+	/// # Category.
+	category: String,
+
+	/// # ID.
+	id: Cddb,
+
+	/// # Title.
+	title: String,
+}
+
+impl CddbMatch {
+	/// # Parse.
+	///
+	/// Parse a `category discid title` line.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the category or ID are missing or
+	/// invalid.
+	pub fn parse(line: &str) -> Result<Self, TocError> {
+		let mut parts = line.trim().splitn(3, ' ');
+		let category = parts.next()
+			.filter(|v| ! v.is_empty())
+			.ok_or(TocError::CddbResponse)?;
+		let id = parts.next()
+			.ok_or(TocError::CddbResponse)
+			.and_then(|v| Cddb::decode(v).map_err(|_| TocError::CddbResponse))?;
+		let title = parts.next().unwrap_or("").trim();
+
+		Ok(Self {
+			category: category.to_owned(),
+			id,
+			title: title.to_owned(),
+		})
+	}
+
+	#[must_use]
+	/// # Category.
+	pub fn category(&self) -> &str { &self.category }
+
+	#[must_use]
+	/// # ID.
+	pub const fn id(&self) -> Cddb { self.id }
+
+	#[must_use]
+	/// # Title.
+	///
+	/// Return the disc title, typically formatted `Artist / Album`.
+	pub fn title(&self) -> &str { &self.title }
+}
+
+
+
+/// # Push Word.
+///
+/// Append a single protocol argument, replacing any whitespace with
+/// underscores so it stays a single argument.
+fn push_word(src: &str, out: &mut String) {
+	for c in src.trim().chars() {
+		if c.is_whitespace() { out.push('_'); }
+		else { out.push(c); }
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_session() {
+		let hello = GnudbHello::new("jane doe", "example.com", " My\tRipper ", "1.0");
+		let mut session = CddbSession::new(hello.clone());
+		assert_eq!(session.hello(), &hello);
+		assert_eq!(session.proto(), CddbSession::DEFAULT_PROTO);
+		assert_eq!(session.hello_command(), "cddb hello jane_doe example.com My_Ripper 1.0");
+		assert_eq!(session.quit_command(), "quit");
+
+		// Protocol negotiation.
+		assert_eq!(session.accept_proto("201 OK, CDDB protocol level now: 5\r\n"), Ok(5));
+		assert_eq!(session.proto(), 5);
+		assert_eq!(session.accept_proto("502 Protocol level already 6."), Ok(6));
+		assert_eq!(session.proto(), 6);
+		assert_eq!(session.accept_proto("501 Illegal protocol level."), Err(TocError::CddbResponse));
+		assert_eq!(session.accept_proto("201 OK"), Err(TocError::CddbResponse));
+		assert_eq!(session.proto(), 6);
+
+		// Query and read commands should match the standalone builders.
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC.");
+		let cddb_id = toc.cddb_id();
+		assert_eq!(Ok(session.query_command(&toc)), cddb_id.query_command(&toc));
+		assert_eq!(session.read_command(" misc ", cddb_id), "cddb read misc 9a0bb60b");
+	}
+
+	#[test]
+	fn t_reply() {
+		for (line, code, status, message, data) in [
+			("200 rock 1f02e004 Artist / Album", 200, CddbStatus::Ok, "rock 1f02e004 Artist / Album", false),
+			("201 OK, CDDB protocol level now: 6", 201, CddbStatus::Ok, "OK, CDDB protocol level now: 6", false),
+			("202 No match found.\r\n", 202, CddbStatus::NoMatch, "No match found.", false),
+			("210 Found exact matches, list follows (until terminating `.')", 210, CddbStatus::DataFollows, "Found exact matches, list follows (until terminating `.')", true),
+			("211 close matches found", 211, CddbStatus::InexactMatches, "close matches found", true),
+			("401 Specified CDDB entry not found.", 401, CddbStatus::NotFound, "Specified CDDB entry not found.", false),
+			("402 Server error.", 402, CddbStatus::ServerError, "Server error.", false),
+			("403 Database entry is corrupt.", 403, CddbStatus::DatabaseCorrupt, "Database entry is corrupt.", false),
+			("409 No handshake.", 409, CddbStatus::NoHandshake, "No handshake.", false),
+			("500 Command syntax error.", 500, CddbStatus::SyntaxError, "Command syntax error.", false),
+			("501 Illegal protocol level.", 501, CddbStatus::IllegalProto, "Illegal protocol level.", false),
+			("502 Protocol level already 6.", 502, CddbStatus::ProtoAlreadySet, "Protocol level already 6.", false),
+			("530 Server error, server too busy.", 530, CddbStatus::ServerError, "Server error, server too busy.", false),
+			("230 Goodbye", 230, CddbStatus::Other(230), "Goodbye", false),
+			("200", 200, CddbStatus::Ok, "", false),
+		] {
+			let reply = CddbReply::parse(line).expect("Reply failed.");
+			assert_eq!(reply.code(), code, "{line}");
+			assert_eq!(reply.status(), status, "{line}");
+			assert_eq!(reply.message(), message, "{line}");
+			assert_eq!(reply.has_data(), data, "{line}");
+			assert_eq!(reply.status().is_success(), (200..300).contains(&code), "{line}");
+			assert_eq!(reply.to_string(), line.trim_end(), "{line}");
+		}
+
+		for bad in ["", "20", "2000 Nope", "abc def", "020 Nope", "20x Nope", "200Nope"] {
+			assert_eq!(CddbReply::parse(bad), Err(TocError::CddbResponse), "{bad}");
+		}
+	}
+
+	#[test]
+	fn t_match() {
+		// Inexact match lines.
+		let raw = "211 close matches found\nrock 1f02e004 Artist / Album\nmisc 1f02e005 Artist / Album (Remaster)\n.\n";
+		let mut lines = raw.lines();
+		let reply = CddbReply::parse(lines.next().expect("Missing line.")).expect("Reply failed.");
+		assert!(reply.has_data());
+
+		let matches = lines.take_while(|l| *l != ".")
+			.map(CddbMatch::parse)
+			.collect::<Result<Vec<_>, _>>()
+			.expect("Match failed.");
+		assert_eq!(matches.len(), 2);
+		assert_eq!(matches[1].category(), "misc");
+		assert_eq!(matches[1].id(), Cddb(0x1f02_e005));
+		assert_eq!(matches[1].title(), "Artist / Album (Remaster)");
+
+		// No title is fine.
+		let m = CddbMatch::parse("rock 1f02e004").expect("Match failed.");
+		assert_eq!(m.title(), "");
+
+		// Bad stuff isn't.
+		for bad in ["", "rock", "rock nope Title", " 1f02e004 Title"] {
+			assert!(CddbMatch::parse(bad).is_err(), "{bad}");
+		}
+	}
+}
@@ -0,0 +1,778 @@
+/*!
+# CDTOC: CDDB Xmcd
+*/
+
+use crate::{
+	Cddb,
+	Toc,
+	TocError,
+};
+use std::{
+	collections::BTreeMap,
+	fmt,
+};
+
+
+
+/// # Maximum Line Length.
+///
+/// Per the spec, xmcd lines may not exceed this many bytes; longer values
+/// are split across repeated keys.
+const MAX_LINE: usize = 256;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # Xmcd Record.
+///
+/// This holds the structured contents of a freedb/gnudb xmcd record, i.e.
+/// the body of a successful `cddb read` response.
+///
+/// Repeated keys — used by the format to split long values across lines —
+/// are concatenated, and the standard `\n`, `\t`, and `\\` escapes are
+/// decoded.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{Toc, Xmcd};
+///
+/// let raw = concat!(
+///     "# xmcd\n",
+///     "#\n",
+///     "# Track frame offsets:\n",
+///     "#    150\n",
+///     "#    11563\n",
+///     "#    25174\n",
+///     "#    45863\n",
+///     "#\n",
+///     "# Disc length: 738 seconds\n",
+///     "#\n",
+///     "DISCID=1f02e004\n",
+///     "DTITLE=The Artist / The Album\n",
+///     "DYEAR=1999\n",
+///     "DGENRE=Rock\n",
+///     "TTITLE0=One\n",
+///     "TTITLE1=Two\n",
+///     "TTITLE2=Three, Part\n",
+///     "TTITLE2= One\n",
+///     "TTITLE3=Four\n",
+///     "EXTD=\n",
+///     "PLAYORDER=\n",
+/// );
+///
+/// let xmcd = Xmcd::parse(raw).unwrap();
+/// assert_eq!(xmcd.artist(), "The Artist");
+/// assert_eq!(xmcd.title(), "The Album");
+/// assert_eq!(xmcd.year(), Some(1999));
+/// assert_eq!(xmcd.genre(), Some("Rock"));
+/// assert_eq!(xmcd.tracks(), ["One", "Two", "Three, Part One", "Four"]);
+/// assert_eq!(xmcd.offsets(), [150, 11563, 25174, 45863]);
+/// assert_eq!(xmcd.disc_length(), Some(738));
+///
+/// // Check it against a local disc.
+/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+/// assert!(xmcd.matches(&toc));
+/// ```
+pub struct Xmcd {
+	/// # Disc IDs.
+	disc_ids: Vec<Cddb>,
+
+	/// # Artist.
+	artist: String,
+
+	/// # Title.
+	title: String,
+
+	/// # Year.
+	year: Option<u16>,
+
+	/// # Genre.
+	genre: Option<String>,
+
+	/// # Track Titles.
+	tracks: Vec<String>,
+
+	/// # Extended Data.
+	extd: String,
+
+	/// # Track Frame Offsets.
+	offsets: Vec<u32>,
+
+	/// # Disc Length (Seconds).
+	disc_length: Option<u32>,
+}
+
+impl fmt::Display for Xmcd {
+	/// # Display.
+	///
+	/// Print the record in the xmcd file format, folding any overly-long
+	/// values across multiple lines.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("# xmcd\n#\n# Track frame offsets:\n")?;
+		for v in &self.offsets { writeln!(f, "#\t{v}")?; }
+		f.write_str("#\n")?;
+		if let Some(v) = self.disc_length {
+			writeln!(f, "# Disc length: {v} seconds\n#")?;
+		}
+		writeln!(
+			f,
+			"# Revision: 0\n# Submitted via: {} {}\n#",
+			env!("CARGO_PKG_NAME"),
+			env!("CARGO_PKG_VERSION"),
+		)?;
+
+		let ids = self.disc_ids.iter()
+			.map(ToString::to_string)
+			.collect::<Vec<_>>()
+			.join(",");
+		write_field(f, "DISCID", &ids)?;
+
+		if self.artist == self.title { write_field(f, "DTITLE", &self.title)?; }
+		else { write_field(f, "DTITLE", &format!("{} / {}", self.artist, self.title))?; }
+
+		write_field(f, "DYEAR", &self.year.map_or_else(String::new, |y| y.to_string()))?;
+		write_field(f, "DGENRE", self.genre.as_deref().unwrap_or(""))?;
+		for (idx, v) in self.tracks.iter().enumerate() {
+			write_field(f, &format!("TTITLE{idx}"), v)?;
+		}
+		write_field(f, "EXTD", &self.extd)?;
+		for idx in 0..self.tracks.len() {
+			write_field(f, &format!("EXTT{idx}"), "")?;
+		}
+		write_field(f, "PLAYORDER", "")
+	}
+}
+
+impl Xmcd {
+	#[must_use]
+	/// # From Table of Contents.
+	///
+	/// Build a new record — e.g. for submission — from a table of contents
+	/// and some basic metadata. Use [`Xmcd::to_string`](ToString::to_string)
+	/// to get the file contents.
+	///
+	/// The frame offsets, disc length, and ID come from the table of
+	/// contents, which also determines the number of track titles; any
+	/// missing titles are left blank, and extras are ignored. Per the spec,
+	/// data tracks count.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Toc, Xmcd, XmcdMeta};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let meta = XmcdMeta::new("The Artist", "The Album")
+	///     .with_year(1999)
+	///     .with_genre("Rock")
+	///     .with_tracks(["One", "Two", "Three", "Four"]);
+	/// let xmcd = Xmcd::from_toc(&toc, &meta);
+	/// assert!(xmcd.matches(&toc));
+	///
+	/// let raw = xmcd.to_string();
+	/// assert!(raw.starts_with("# xmcd\n"));
+	/// assert!(raw.contains("\nDISCID=1f02e004\n"));
+	/// assert!(raw.contains("\nDTITLE=The Artist / The Album\n"));
+	/// assert!(raw.contains("\nTTITLE3=Four\n"));
+	/// assert_eq!(Xmcd::parse(&raw), Ok(xmcd));
+	/// ```
+	pub fn from_toc(toc: &Toc, meta: &XmcdMeta) -> Self {
+		let offsets = super::query_sectors(toc);
+		let tracks = (0..offsets.len())
+			.map(|idx| meta.tracks.get(idx).cloned().unwrap_or_default())
+			.collect();
+
+		Self {
+			disc_ids: vec![toc.cddb_id()],
+			artist: meta.artist.clone(),
+			title: meta.title.clone(),
+			year: meta.year,
+			genre: meta.genre.clone(),
+			tracks,
+			extd: String::new(),
+			offsets,
+			disc_length: Some(toc.leadout().wrapping_div(75)),
+		}
+	}
+
+	/// # Parse.
+	///
+	/// Parse an xmcd record. A leading server status line (e.g. `210 …`) and
+	/// trailing `.` terminator, if present, are ignored.
+	///
+	/// See [`Xmcd`] for an example.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the `DISCID` or `DTITLE` fields are
+	/// missing or invalid, or the track titles are not numbered
+	/// contiguously from zero.
+	pub fn parse(src: &str) -> Result<Self, TocError> {
+		let mut offsets = Vec::new();
+		let mut disc_length = None;
+		let mut in_offsets = false;
+		let mut fields = BTreeMap::<&str, String>::new();
+		let mut tracks = BTreeMap::<usize, String>::new();
+
+		for line in src.lines() {
+			// Comments might hold the offsets or length.
+			if let Some(line) = line.strip_prefix('#') {
+				let line = line.trim();
+				if in_offsets {
+					if let Ok(v) = line.parse::<u32>() {
+						offsets.push(v);
+						continue;
+					}
+					in_offsets = false;
+				}
+
+				if line.eq_ignore_ascii_case("Track frame offsets:") { in_offsets = true; }
+				else if let Some(v) = line.strip_prefix("Disc length:") {
+					disc_length = v.split_whitespace().next().and_then(|v| v.parse().ok());
+				}
+				continue;
+			}
+
+			// Everything else should be a key/value pair; status lines and
+			// the terminator won't be.
+			in_offsets = false;
+			let Some((k, v)) = line.split_once('=') else { continue; };
+			let k = k.trim();
+			if let Some(n) = k.strip_prefix("TTITLE") {
+				let n = n.parse::<usize>().map_err(|_| TocError::XmcdDecode)?;
+				tracks.entry(n).or_default().push_str(v);
+			}
+			else { fields.entry(k).or_default().push_str(v); }
+		}
+
+		// The disc ID(s) are required.
+		let disc_ids = fields.get("DISCID")
+			.ok_or(TocError::XmcdDecode)?
+			.split(',')
+			.map(|v| Cddb::decode(v.trim()).map_err(|_| TocError::XmcdDecode))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		// As is the title. If there's no separator, the artist and title are
+		// the same.
+		let dtitle = fields.get("DTITLE").map(|v| unescape(v)).ok_or(TocError::XmcdDecode)?;
+		let (artist, title) = match dtitle.split_once(" / ") {
+			Some((a, t)) => (a.trim().to_owned(), t.trim().to_owned()),
+			None => (dtitle.trim().to_owned(), dtitle.trim().to_owned()),
+		};
+
+		// The tracks must be numbered from zero without gaps.
+		if tracks.keys().enumerate().any(|(k, n)| k != *n) {
+			return Err(TocError::XmcdDecode);
+		}
+		let tracks = tracks.into_values().map(|v| unescape(&v)).collect();
+
+		let year = fields.get("DYEAR").and_then(|v| v.trim().parse::<u16>().ok());
+		let genre = fields.get("DGENRE")
+			.map(|v| unescape(v.trim()))
+			.filter(|v| ! v.is_empty());
+		let extd = fields.get("EXTD").map(|v| unescape(v)).unwrap_or_default();
+
+		Ok(Self {
+			disc_ids,
+			artist,
+			title,
+			year,
+			genre,
+			tracks,
+			extd,
+			offsets,
+			disc_length,
+		})
+	}
+}
+
+impl Xmcd {
+	#[must_use]
+	/// # Artist.
+	///
+	/// Return the disc artist, i.e. the part of `DTITLE` before the ` / `.
+	pub fn artist(&self) -> &str { &self.artist }
+
+	#[must_use]
+	/// # Disc IDs.
+	///
+	/// Return the `DISCID` value(s). Records can be shared by more than one
+	/// ID.
+	pub fn disc_ids(&self) -> &[Cddb] { &self.disc_ids }
+
+	#[must_use]
+	/// # Disc Length.
+	///
+	/// Return the disc length in seconds, if present in the comments.
+	pub const fn disc_length(&self) -> Option<u32> { self.disc_length }
+
+	#[must_use]
+	/// # Extended Data.
+	///
+	/// Return the `EXTD` value, which may be empty.
+	pub fn extd(&self) -> &str { &self.extd }
+
+	#[must_use]
+	/// # Genre.
+	///
+	/// Return the `DGENRE` value, if any.
+	pub fn genre(&self) -> Option<&str> { self.genre.as_deref() }
+
+	#[must_use]
+	/// # Track Frame Offsets.
+	///
+	/// Return the track frame offsets listed in the comments, if any.
+	pub fn offsets(&self) -> &[u32] { &self.offsets }
+
+	#[must_use]
+	/// # Title.
+	///
+	/// Return the disc title, i.e. the part of `DTITLE` after the ` / `.
+	pub fn title(&self) -> &str { &self.title }
+
+	#[must_use]
+	/// # Track Titles.
+	///
+	/// Return the track titles, in order.
+	pub fn tracks(&self) -> &[String] { &self.tracks }
+
+	#[must_use]
+	/// # Year.
+	///
+	/// Return the `DYEAR` value, if any.
+	pub const fn year(&self) -> Option<u16> { self.year }
+}
+
+impl Xmcd {
+	#[must_use]
+	/// # Matches Table of Contents?
+	///
+	/// Returns `true` if the record's `DISCID`(s) include the one for the
+	/// table of contents, and the embedded frame offsets — and disc length,
+	/// if present — match it exactly.
+	///
+	/// See [`Xmcd`] for an example, or [`Xmcd::verify`] for a more nuanced
+	/// answer.
+	pub fn matches(&self, toc: &Toc) -> bool {
+		matches!(self.verify(toc), XmcdVerify::Exact)
+	}
+
+	#[must_use]
+	/// # Verify Against Table of Contents.
+	///
+	/// Cross-check the record against the table of contents, returning an
+	/// [`XmcdVerify`] describing how well they line up.
+	///
+	/// CDDB IDs are only 32 bits and mostly derived from timings rounded to
+	/// the second, so different discs — or different pressings of the same
+	/// one — can easily share an ID. When that happens, the record's frame
+	/// offsets (and disc length) are needed to tell them apart.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Toc, Xmcd, XmcdVerify};
+	///
+	/// let xmcd = Xmcd::parse(concat!(
+	///     "# Track frame offsets:\n",
+	///     "#    150\n",
+	///     "#    11563\n",
+	///     "#    25174\n",
+	///     "#    45863\n",
+	///     "#\n",
+	///     "# Disc length: 738 seconds\n",
+	///     "DISCID=1f02e004\n",
+	///     "DTITLE=The Artist / The Album\n",
+	/// )).unwrap();
+	///
+	/// // A perfect match.
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert_eq!(xmcd.verify(&toc), XmcdVerify::Exact);
+	///
+	/// // Same CDDB ID, different disc.
+	/// let toc = Toc::from_cdtoc("4+96+2D2C+6256+B327+D84A").unwrap();
+	/// assert_eq!(xmcd.verify(&toc), XmcdVerify::IdOnly);
+	///
+	/// // Different everything.
+	/// let toc = Toc::from_cdtoc("3+96+2D2B+6256+B327").unwrap();
+	/// assert_eq!(xmcd.verify(&toc), XmcdVerify::Mismatch);
+	/// ```
+	pub fn verify(&self, toc: &Toc) -> XmcdVerify {
+		if ! self.disc_ids.contains(&toc.cddb_id()) { XmcdVerify::Mismatch }
+		else if
+			self.offsets == super::query_sectors(toc) &&
+			self.disc_length.is_none_or(|v| v == toc.leadout().wrapping_div(75))
+		{
+			XmcdVerify::Exact
+		}
+		else { XmcdVerify::IdOnly }
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Xmcd Verification Result.
+///
+/// This is returned by [`Xmcd::verify`].
+pub enum XmcdVerify {
+	/// # Exact Match.
+	///
+	/// The `DISCID`(s) include the table of contents' CDDB ID, and the frame
+	/// offsets — and disc length, if present — match exactly.
+	Exact,
+
+	/// # ID Match Only.
+	///
+	/// The CDDB ID matches, but the offsets do not (or are missing from the
+	/// record), so this may be a different disc or pressing that happens to
+	/// hash the same.
+	IdOnly,
+
+	/// # No Match.
+	///
+	/// The record's `DISCID`(s) do not include the table of contents' CDDB
+	/// ID.
+	Mismatch,
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "cddb")))]
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # Xmcd Metadata.
+///
+/// This holds the basic disc metadata used by [`Xmcd::from_toc`].
+///
+/// See [`Xmcd::from_toc`] for an example.
+pub struct XmcdMeta {
+	/// # Artist.
+	artist: String,
+
+	/// # Title.
+	title: String,
+
+	/// # Year.
+	year: Option<u16>,
+
+	/// # Genre.
+	genre: Option<String>,
+
+	/// # Track Titles.
+	tracks: Vec<String>,
+}
+
+impl XmcdMeta {
+	#[must_use]
+	/// # New.
+	///
+	/// Start with the disc artist and title.
+	pub fn new<A, T>(artist: A, title: T) -> Self
+	where A: Into<String>, T: Into<String> {
+		Self {
+			artist: artist.into(),
+			title: title.into(),
+			..Self::default()
+		}
+	}
+
+	#[must_use]
+	/// # With Genre.
+	pub fn with_genre<S: Into<String>>(mut self, genre: S) -> Self {
+		let genre: String = genre.into();
+		self.genre = if genre.is_empty() { None } else { Some(genre) };
+		self
+	}
+
+	#[must_use]
+	/// # With Track Titles.
+	///
+	/// Set the track titles, in order.
+	pub fn with_tracks<I, S>(mut self, tracks: I) -> Self
+	where I: IntoIterator<Item=S>, S: Into<String> {
+		self.tracks = tracks.into_iter().map(Into::into).collect();
+		self
+	}
+
+	#[must_use]
+	/// # With Year.
+	pub const fn with_year(mut self, year: u16) -> Self {
+		self.year = Some(year);
+		self
+	}
+}
+
+
+
+/// # Escape.
+///
+/// Encode newlines, tabs, and backslashes per the xmcd format.
+fn escape(src: &str) -> String {
+	let mut out = String::with_capacity(src.len());
+	for c in src.chars() {
+		match c {
+			'\n' => { out.push_str("\\n"); },
+			'\t' => { out.push_str("\\t"); },
+			'\\' => { out.push_str("\\\\"); },
+			// Other control characters have no business here.
+			c if c.is_control() => {},
+			c => { out.push(c); },
+		}
+	}
+	out
+}
+
+/// # Write Field.
+///
+/// Write an escaped `KEY=value` line, splitting it across multiple lines
+/// with the same key if it would otherwise exceed [`MAX_LINE`] bytes.
+/// Splits never break up a character or an escape sequence.
+fn write_field(f: &mut fmt::Formatter<'_>, key: &str, value: &str) -> fmt::Result {
+	let value = escape(value);
+	let max = MAX_LINE - key.len() - 1;
+
+	let mut rest = value.as_str();
+	loop {
+		let mut end = rest.len().min(max);
+		while ! rest.is_char_boundary(end) { end -= 1; }
+
+		// Don't leave a dangling escape.
+		let slashes = rest.as_bytes()[..end].iter().rev().take_while(|b| **b == b'\\').count();
+		if slashes % 2 == 1 { end -= 1; }
+
+		writeln!(f, "{key}={}", &rest[..end])?;
+		rest = &rest[end..];
+		if rest.is_empty() { return Ok(()); }
+	}
+}
+
+/// # Unescape.
+///
+/// Decode the `\n`, `\t`, and `\\` escapes used by the xmcd format.
+fn unescape(src: &str) -> String {
+	if ! src.contains('\\') { return src.to_owned(); }
+
+	let mut out = String::with_capacity(src.len());
+	let mut chars = src.chars();
+	while let Some(c) = chars.next() {
+		if c == '\\' {
+			match chars.next() {
+				Some('n') => { out.push('\n'); },
+				Some('t') => { out.push('\t'); },
+				Some(c) => { out.push(c); },
+				None => { out.push('\\'); },
+			}
+		}
+		else { out.push(c); }
+	}
+	out
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// # CD-Extra Sample.
+	const EXTRA: &str = "210 misc 9a0bb60b CD database entry follows (until terminating `.')
+# xmcd
+#
+# Track frame offsets:
+#        150
+#        14167
+#        26989
+#        50767
+#        68115
+#        85410
+#        106120
+#        121770
+#        136100
+#        161870
+#        186287
+#
+# Disc length: 3000 secs
+#
+# Revision: 2
+# Submitted via: nobody 1.0
+#
+DISCID=9a0bb60b,9a0bb60c
+DTITLE=Some Band
+DYEAR=
+DGENRE=
+TTITLE0=Track\\tOne
+TTITLE1=Track Two
+TTITLE2=Track Three
+TTITLE3=Track Four
+TTITLE4=Track Five
+TTITLE5=Track Six
+TTITLE6=Track Seven
+TTITLE7=Track Eight
+TTITLE8=Track Nine
+TTITLE9=Track Ten
+TTITLE10=Data
+EXTD=Line one.\\nLine
+EXTD= two.
+PLAYORDER=
+.
+";
+
+	#[test]
+	fn t_xmcd() {
+		let xmcd = Xmcd::parse(EXTRA).expect("Parse failed.");
+		assert_eq!(
+			xmcd.disc_ids(),
+			[Cddb(0x9a0b_b60b), Cddb(0x9a0b_b60c)],
+		);
+
+		// No separator.
+		assert_eq!(xmcd.artist(), "Some Band");
+		assert_eq!(xmcd.title(), "Some Band");
+
+		// Empty values.
+		assert_eq!(xmcd.year(), None);
+		assert_eq!(xmcd.genre(), None);
+
+		// Escapes and continuations.
+		assert_eq!(xmcd.tracks().len(), 11);
+		assert_eq!(xmcd.tracks()[0], "Track\tOne");
+		assert_eq!(xmcd.tracks()[10], "Data");
+		assert_eq!(xmcd.extd(), "Line one.\nLine two.");
+
+		assert_eq!(xmcd.offsets().len(), 11);
+		assert_eq!(xmcd.offsets()[10], 186_287);
+		assert_eq!(xmcd.disc_length(), Some(3000));
+
+		// The CD-Extra should match, including the data track.
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC.");
+		assert!(xmcd.matches(&toc));
+
+		// But not a different disc.
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		assert!(! xmcd.matches(&toc));
+	}
+
+	#[test]
+	fn t_xmcd_matches() {
+		let base = "# Track frame offsets:\n#\t150\n#\t11563\n#\t25174\n#\t45863\n#\n";
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+
+		// Good, with or without the length.
+		for extra in ["", "# Disc length: 738 seconds\n"] {
+			let raw = format!("{base}{extra}DISCID=1f02e004\nDTITLE=A / B\n");
+			assert!(Xmcd::parse(&raw).expect("Parse failed.").matches(&toc));
+		}
+
+		// Bad length.
+		let raw = format!("{base}# Disc length: 739 seconds\nDISCID=1f02e004\nDTITLE=A / B\n");
+		assert!(! Xmcd::parse(&raw).expect("Parse failed.").matches(&toc));
+
+		// Bad ID.
+		let raw = format!("{base}DISCID=1f02e005\nDTITLE=A / B\n");
+		assert!(! Xmcd::parse(&raw).expect("Parse failed.").matches(&toc));
+
+		// No offsets.
+		let raw = "DISCID=1f02e004\nDTITLE=A / B\n";
+		assert!(! Xmcd::parse(raw).expect("Parse failed.").matches(&toc));
+	}
+
+	#[test]
+	fn t_xmcd_verify() {
+		// These two discs differ by a single frame in the second track, so
+		// share a CDDB ID.
+		let toc1 = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let toc2 = Toc::from_cdtoc("4+96+2D2C+6256+B327+D84A").expect("Invalid TOC.");
+		assert_eq!(toc1.cddb_id(), toc2.cddb_id());
+		assert_ne!(toc1, toc2);
+
+		let xmcd1 = Xmcd::from_toc(&toc1, &XmcdMeta::new("A", "B"));
+		let xmcd2 = Xmcd::from_toc(&toc2, &XmcdMeta::new("C", "D"));
+		assert_eq!(xmcd1.verify(&toc1), XmcdVerify::Exact);
+		assert_eq!(xmcd1.verify(&toc2), XmcdVerify::IdOnly);
+		assert_eq!(xmcd2.verify(&toc1), XmcdVerify::IdOnly);
+		assert_eq!(xmcd2.verify(&toc2), XmcdVerify::Exact);
+
+		// Round trips should work the same way.
+		let parsed = Xmcd::parse(&xmcd1.to_string()).expect("Parse failed.");
+		assert_eq!(parsed.verify(&toc1), XmcdVerify::Exact);
+		assert_eq!(parsed.verify(&toc2), XmcdVerify::IdOnly);
+
+		// Multiple IDs; only one need match.
+		let xmcd = Xmcd::parse(EXTRA).expect("Parse failed.");
+		assert_eq!(xmcd.disc_ids().len(), 2);
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC.");
+		assert_eq!(xmcd.verify(&toc), XmcdVerify::Exact);
+		assert_eq!(xmcd.verify(&toc1), XmcdVerify::Mismatch);
+
+		// Missing offsets can't be confirmed.
+		let xmcd = Xmcd::parse("DISCID=1f02e004\nDTITLE=A / B\n").expect("Parse failed.");
+		assert_eq!(xmcd.verify(&toc1), XmcdVerify::IdOnly);
+	}
+
+	#[test]
+	fn t_xmcd_write() {
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC.");
+
+		// Some long, awkward titles.
+		let long = "Ünïcödé—and a very long title, ".repeat(20);
+		let slashes = format!("{}\\\tback\\slash\n", "x".repeat(245));
+		let meta = XmcdMeta::new("Artist", "Album")
+			.with_genre("Jazz")
+			.with_tracks(["One", &long, &slashes]);
+
+		let xmcd = Xmcd::from_toc(&toc, &meta);
+		assert!(xmcd.matches(&toc));
+		assert_eq!(xmcd.tracks().len(), 11);
+		assert_eq!(xmcd.tracks()[1], long);
+		assert_eq!(xmcd.tracks()[2], slashes);
+		assert_eq!(xmcd.tracks()[3], "");
+		assert_eq!(xmcd.year(), None);
+
+		// Check the formatting.
+		let raw = xmcd.to_string();
+		assert!(raw.starts_with("# xmcd\n#\n# Track frame offsets:\n#\t150\n#\t14167\n"));
+		assert!(raw.contains("\n#\t186287\n#\n# Disc length: 3000 seconds\n#\n"));
+		assert!(raw.contains("\nDISCID=9a0bb60b\nDTITLE=Artist / Album\nDYEAR=\nDGENRE=Jazz\nTTITLE0=One\n"));
+		assert!(raw.contains("\nTTITLE10=\nEXTD=\nEXTT0=\n"));
+		assert!(raw.ends_with("\nEXTT10=\nPLAYORDER=\n"));
+
+		// No line should be too long, and the long title should be split.
+		assert!(raw.lines().all(|line| line.len() <= MAX_LINE));
+		assert!(1 < raw.lines().filter(|line| line.starts_with("TTITLE1=")).count());
+		assert!(1 < raw.lines().filter(|line| line.starts_with("TTITLE2=")).count());
+
+		// The escape shouldn't have been split.
+		assert!(raw.lines().all(|line| ! line.starts_with("TTITLE2=t")));
+
+		// And it should all come back the same.
+		assert_eq!(Xmcd::parse(&raw), Ok(xmcd));
+
+		// Same artist and title collapse.
+		let xmcd = Xmcd::from_toc(&toc, &XmcdMeta::new("Same", "Same"));
+		assert!(xmcd.to_string().contains("\nDTITLE=Same\n"));
+	}
+
+	#[test]
+	fn t_xmcd_invalid() {
+		for raw in [
+			// Missing stuff.
+			"",
+			"DTITLE=A / B\n",
+			"DISCID=1f02e004\n",
+			// Bad ID.
+			"DISCID=nope\nDTITLE=A / B\n",
+			// Gaps.
+			"DISCID=1f02e004\nDTITLE=A / B\nTTITLE0=One\nTTITLE2=Three\n",
+			"DISCID=1f02e004\nDTITLE=A / B\nTTITLE1=Two\n",
+			"DISCID=1f02e004\nDTITLE=A / B\nTTITLEX=Two\n",
+		] {
+			assert_eq!(Xmcd::parse(raw), Err(TocError::XmcdDecode), "{raw}");
+		}
+	}
+}
@@ -0,0 +1,82 @@
+/*!
+# CDTOC: CRC-32
+
+This is the standard CRC-32 (IEEE), shared by the AccurateRip checksum
+cache and the CTDB checksummer.
+*/
+
+#![expect(clippy::redundant_pub_crate, reason = "False positive.")]
+
+
+
+/// # Initial Value.
+pub(super) const INIT: u32 = u32::MAX;
+
+/// # Lookup Table.
+const TABLE: [u32; 256] = {
+	let mut out = [0_u32; 256];
+	let mut i = 0;
+	while i < 256 {
+		#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+		let mut crc = i as u32;
+		let mut j = 0;
+		while j < 8 {
+			crc = if crc & 1 == 0 { crc >> 1 } else { (crc >> 1) ^ 0xEDB8_8320 };
+			j += 1;
+		}
+		out[i] = crc;
+		i += 1;
+	}
+	out
+};
+
+
+
+#[must_use]
+/// # Update.
+///
+/// Add some bytes to a running (unfinalized) CRC, starting from [`INIT`].
+pub(super) const fn update(mut crc: u32, src: &[u8]) -> u32 {
+	let mut i = 0;
+	while i < src.len() {
+		crc = TABLE[((crc ^ src[i] as u32) & 0xFF) as usize] ^ (crc >> 8);
+		i += 1;
+	}
+	crc
+}
+
+#[must_use]
+/// # Finalize.
+///
+/// Convert a running CRC into the final checksum.
+pub(super) const fn finalize(crc: u32) -> u32 { ! crc }
+
+#[cfg(any(test, feature = "accuraterip"))]
+#[must_use]
+/// # Checksum.
+///
+/// Return the CRC-32 of the data in one go.
+pub(super) const fn checksum(src: &[u8]) -> u32 { finalize(update(INIT, src)) }
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_checksum() {
+		// The standard check values.
+		assert_eq!(checksum(b""), 0);
+		assert_eq!(checksum(b"1234"), 0x9BE3_E0A3);
+		assert_eq!(checksum(b"123456789"), 0xCBF4_3926);
+		assert_eq!(
+			checksum(b"The quick brown fox jumps over the lazy dog"),
+			0x414F_A339,
+		);
+
+		// Piecemeal updates should match.
+		let crc = update(update(INIT, b"12345"), b"6789");
+		assert_eq!(finalize(crc), 0xCBF4_3926);
+	}
+}
@@ -2,34 +2,70 @@
 # CDTOC: CUETools Database
 */
 
+mod b64;
+mod checksummer;
+mod lookup;
+mod parity;
+mod response;
+mod submit;
+mod summary;
+
+pub use checksummer::{
+	CtdbChecksummer,
+	CtdbDiscChecksummer,
+};
+pub use lookup::{
+	CtdbLookupOptions,
+	CtdbMetadataLevel,
+};
+pub use parity::CtdbParity;
+pub use response::{
+	CtdbEntry,
+	CtdbEntryRef,
+	CtdbMetadata,
+	CtdbResponse,
+};
+pub use submit::{
+	CtdbRequest,
+	CtdbSubmission,
+};
+pub use summary::CtdbTrackSummary;
+
 use crate::{
 	ShaB64,
 	Toc,
 	TocError,
 	TocKind,
 };
-use dactyl::traits::HexToUnsigned;
-use std::collections::BTreeMap;
+use std::{
+	borrow::Cow,
+	collections::BTreeMap,
+};
+use crate::xml::{
+	Tag,
+	Tags,
+};
 
 
 
-/// # Stereo Sample Chunk Size.
+/// # Base URL.
 ///
-/// Each CDDA sample has a 16-bit left and 16-bit right value; combined they're
-/// four bytes.
-const CHUNK_SIZE: usize = 4;
-
-
+/// The server answers on both `http` and `https`; the latter is preferred.
+const BASE_URL: &str = "https://db.cuetools.net/";
 
 impl Toc {
 	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
-	#[expect(clippy::missing_panics_doc, reason = "Panic is unreachable.")]
 	#[must_use]
 	/// # CUETools Database ID.
 	///
 	/// This returns the [CUETools Database](http://cue.tools/wiki/CUETools_Database) ID
 	/// corresponding to the table of contents.
 	///
+	/// Like CUETools, only the audio tracks are considered, with positions
+	/// taken relative to the first audio track — not the first track — and
+	/// ending at the [audio leadout](Toc::audio_leadout). Data tracks, whether
+	/// leading or trailing, only affect the [lookup](Toc::ctdb_checksum_url).
+	///
 	/// ## Examples
 	///
 	/// ```
@@ -42,60 +78,41 @@ impl Toc {
 	/// );
 	/// ```
 	pub fn ctdb_id(&self) -> ShaB64 {
-		use sha1::Digest;
-		let mut sha = sha1::Sha1::new();
-		let mut src = [b'0'; CHUNK_SIZE * 4]; // Four raw u32s.
-		let mut dst = [b'0'; CHUNK_SIZE * 8]; // Four hexed u32s.
-
 		// Split the leadin from the rest of the sectors.
 		let [leadin, sectors @ ..] = self.audio_sectors() else { unreachable!() };
-		let len = sectors.len();
-		let rem = len % CHUNK_SIZE;
-
-		// Process the sector positions in batches of four to leverage SSE hex
-		// optimizations.
-		for v in sectors.chunks_exact(CHUNK_SIZE) {
-			// Copy the values to the source buffer.
-			for (s_chunk, v) in src.chunks_exact_mut(4).zip(v.iter().map(|n| n - leadin)) {
-				s_chunk.copy_from_slice(v.to_be_bytes().as_slice());
-			}
-
-			// Encode and hash, en masse.
-			faster_hex::hex_encode(src.as_slice(), &mut dst).unwrap();
-			dst.make_ascii_uppercase();
-			sha.update(dst.as_slice());
-		}
-
-		// Handle the remaining sectors, if any, and the leadout.
-		if rem == 0 {
-			let dst2 = &mut dst[..8];
-			faster_hex::hex_encode_fallback((self.audio_leadout() - leadin).to_be_bytes().as_slice(), dst2);
-			dst2.make_ascii_uppercase();
-			sha.update(dst2);
-		}
-		else {
-			// Copy the values to the source buffer.
-			for (s_chunk, v) in src.chunks_exact_mut(4).zip(
-				sectors[len - rem..].iter().map(|n| n - leadin)
-					.chain(std::iter::once(self.audio_leadout() - leadin))
-			) {
-				s_chunk.copy_from_slice(v.to_be_bytes().as_slice());
-			}
-
-			// Encode and hash, en masse.
-			let src_to = rem * 4 + 4;
-			let dst2 = &mut dst[..src_to * 2];
-			faster_hex::hex_encode(&src[..src_to], dst2).unwrap();
-			dst2.make_ascii_uppercase();
-			sha.update(dst2);
-		}
+		ctdb_hash(*leadin, sectors, self.audio_leadout())
+	}
 
-		// And padding for a total of 99 tracks.
-		let padding = 99 - len;
-		if padding != 0 { sha.update(&crate::ZEROES[..padding * 8]); }
+	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	/// # CUETools Database Hash Input.
+	///
+	/// Return the exact string hashed to produce the [CTDB ID](Toc::ctdb_id),
+	/// mostly for debugging disagreements with other software.
+	///
+	/// This consists of the (uppercase hex) positions of the second and
+	/// subsequent audio tracks and the audio leadout, all relative to the
+	/// first audio track, zero-padded to a total of 100 entries, or 800
+	/// characters.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let input = toc.ctdb_hash_input();
+	/// assert_eq!(input.len(), 800);
+	/// assert!(input.starts_with("00002C95000061C00000B2910000D7B4"));
+	/// assert!(input[32..].bytes().all(|b| b == b'0'));
+	/// ```
+	pub fn ctdb_hash_input(&self) -> String {
+		let [leadin, sectors @ ..] = self.audio_sectors() else { unreachable!() };
+		let out = ctdb_hash_buf(*leadin, sectors, self.audio_leadout()).to_vec();
 
-		// Run it through base64 and we're done!
-		ShaB64::from(sha)
+		// Safety: hex is ASCII.
+		unsafe { String::from_utf8_unchecked(out) }
 	}
 
 	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
@@ -106,6 +123,13 @@ impl Toc {
 	/// provided it is actually _in_ the CTDB. (If it isn't, their server will
 	/// return a `404` or empty XML document.)
 	///
+	/// Note: the URL uses `https`. The server also answers plain `http`
+	/// requests, which can be obtained via [`Toc::ctdb_checksum_url_with_base`]
+	/// if needed.
+	///
+	/// To disable fuzzy matching or request metadata, see
+	/// [`Toc::ctdb_checksum_url_opts`].
+	///
 	/// ## Examples
 	///
 	/// ```
@@ -114,39 +138,101 @@ impl Toc {
 	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
 	/// assert_eq!(
 	///     toc.ctdb_checksum_url(),
-	///     "http://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=0:11413:25024:45713:55220",
+	///     "https://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=0:11413:25024:45713:55220",
 	/// );
 	/// ```
 	pub fn ctdb_checksum_url(&self) -> String {
-		let mut url = "http://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=".to_owned();
-		let mut buf = itoa::Buffer::new();
-
-		// Leading data?
-		if matches!(self.kind, TocKind::DataFirst) {
-			url.push('-');
-			url.push_str(buf.format(self.data - 150));
-			url.push(':');
-		}
-
-		// Each audio track relative to the first.
-		for v in &self.audio {
-			url.push_str(buf.format(v - 150));
-			url.push(':');
-		}
-
-		// Trailing data?
-		if matches!(self.kind, TocKind::CDExtra) {
-			url.push('-');
-			url.push_str(buf.format(self.data - 150));
-			url.push(':');
-		}
-
-		// And the leadout.
-		url.push_str(buf.format(self.leadout - 150));
+		self.ctdb_checksum_url_opts(&CtdbLookupOptions::default())
+	}
 
+	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+	#[must_use]
+	/// # CUETools Database Checksum URL (Custom Base).
+	///
+	/// Same as [`Toc::ctdb_checksum_url`], but with a custom base URL, such as
+	/// a caching proxy. A trailing slash will be added to the base if missing.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert_eq!(
+	///     toc.ctdb_checksum_url_with_base("http://localhost:8080/ctdb"),
+	///     "http://localhost:8080/ctdb/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=0:11413:25024:45713:55220",
+	/// );
+	/// ```
+	pub fn ctdb_checksum_url_with_base(&self, base: &str) -> String {
+		let mut url = String::with_capacity(base.len() + 128);
+		url.push_str(base);
+		if ! base.ends_with('/') { url.push('/'); }
+		self.push_ctdb_lookup(CtdbLookupOptions::default(), &mut url);
 		url
 	}
 
+	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+	/// # CTDB Entries.
+	///
+	/// Return an iterator over the `<entry>` elements of an XML CTDB
+	/// [lookup](Toc::ctdb_checksum_url), parsed lazily and without allocating
+	/// (unless an attribute contains XML entities).
+	///
+	/// This is useful when only some of the entries are needed; the server
+	/// lists them by confidence, highest first, so for popular discs it is
+	/// often enough to look at the first few and stop.
+	///
+	/// Entries missing a confidence or track checksums are skipped, the same
+	/// as with [`Toc::ctdb_parse_checksums`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+	/// let xml = r#"<ctdb>
+	///   <entry confidence="20" crc32="1" toc="0:350:600" trackcrcs="0000000a 0000000b" />
+	///   <entry confidence="5" crc32="2" trackcrcs="0000000a 0000000c" />
+	/// </ctdb>"#;
+	///
+	/// let best = toc.ctdb_entries(xml).next().unwrap().unwrap();
+	/// assert_eq!(best.confidence(), 20);
+	/// assert_eq!(best.toc(), Some("0:350:600"));
+	/// assert_eq!(best.track_crcs().collect::<Vec<_>>(), [10, 11]);
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// An error is yielded — and iteration ends — if a tag is left
+	/// unterminated or an entry's values are invalid. Either way, it will be
+	/// a [`TocError::CtdbTag`] with the line number of the offending tag.
+	pub fn ctdb_entries<'a>(&self, xml: &'a str)
+	-> impl Iterator<Item=Result<CtdbEntryRef<'a>, TocError>> + 'a {
+		let audio_len = self.audio_len();
+		let mut tags = Tags::new(xml);
+		let mut done = false;
+		std::iter::from_fn(move || {
+			if done { return None; }
+			loop {
+				let tag = match tags.next()? {
+					Ok(tag) => tag,
+					Err(line) => {
+						done = true;
+						return Some(Err(TocError::CtdbTag(line)));
+					},
+				};
+				if tag.local_name() != "entry" || tag.is_closing() { continue; }
+
+				let Some((confidence, crcs)) = parse_entry(&tag) else { continue; };
+				let out = CtdbEntryRef::new(&confidence, crcs, tag.attr("toc"), audio_len)
+					.map_err(|_| TocError::CtdbTag(tag.line(xml)));
+				done = out.is_err();
+				return Some(out);
+			}
+		})
+	}
+
 	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
 	/// # Parse Checksums.
 	///
@@ -155,29 +241,27 @@ impl Toc {
 	/// The return result is a vector — indexed by track number (`n-1`) — of
 	/// `checksum => confidence` pairs.
 	///
+	/// Entries may span multiple lines, list their attributes in any order,
+	/// and use the standard XML entities. Unknown attributes are ignored, as
+	/// are entries missing a confidence or track checksums.
+	///
 	/// ## Errors
 	///
 	/// This method uses naive parsing so does not worry about strict XML
-	/// validation, but will return an error if other parsing errors are
-	/// encountered or no checksums are found.
+	/// validation, but will return an error if a tag is left unterminated or
+	/// an entry's values are invalid — [`TocError::CtdbTag`], with the line
+	/// number — or no checksums are found.
 	pub fn ctdb_parse_checksums(&self, xml: &str) -> Result<Vec<BTreeMap<u32, u16>>, TocError> {
-		let audio_len = self.audio_len();
-		let mut out: Vec<BTreeMap<u32, u16>> = vec![BTreeMap::default(); audio_len];
-
-		for line in xml.lines() {
-			if let Some((confidence, crcs)) = parse_entry(line.trim()) {
-				let confidence: u16 = confidence.parse().map_err(|_| TocError::Checksums)?;
-				let mut id = 0;
-				for chk in crcs.split_ascii_whitespace() {
-					let crc = u32::htou(chk.as_bytes()).ok_or(TocError::Checksums)?;
-					if crc != 0 {
-						let e = out[id].entry(crc).or_insert(0);
-						*e = e.saturating_add(confidence);
-					}
-					id += 1;
+		let mut out: Vec<BTreeMap<u32, u16>> = vec![BTreeMap::default(); self.audio_len()];
+
+		for entry in self.ctdb_entries(xml) {
+			let entry = entry?;
+			let confidence = entry.confidence();
+			for (track, crc) in out.iter_mut().zip(entry.track_crcs()) {
+				if crc != 0 {
+					let e = track.entry(crc).or_insert(0);
+					*e = e.saturating_add(confidence);
 				}
-
-				if id != audio_len { return Err(TocError::Checksums); }
 			}
 		}
 
@@ -185,36 +269,190 @@ impl Toc {
 		if out.iter().any(|v| ! v.is_empty()) { Ok(out) }
 		else { Err(TocError::NoChecksums) }
 	}
+
+	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+	/// # Parse Checksums (Bytes).
+	///
+	/// Same as [`Toc::ctdb_parse_checksums`], but for a raw response body.
+	///
+	/// A leading UTF-8 byte-order mark is stripped, and any invalid UTF-8 —
+	/// such as Windows-1252 metadata — is replaced rather than rejected. The
+	/// checksum attributes are pure ASCII, so are unaffected either way.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+	/// let body = b"\xEF\xBB\xBF<ctdb><entry confidence=\"3\" trackcrcs=\"0a 0b\" /></ctdb>";
+	/// let parsed = toc.ctdb_parse_checksums_bytes(body).unwrap();
+	/// assert_eq!(parsed[0].get(&10), Some(&3));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error under the same conditions as
+	/// [`Toc::ctdb_parse_checksums`].
+	pub fn ctdb_parse_checksums_bytes(&self, body: &[u8]) -> Result<Vec<BTreeMap<u32, u16>>, TocError> {
+		let body = body.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(body);
+		self.ctdb_parse_checksums(&String::from_utf8_lossy(body))
+	}
 }
 
 
 
-/// # Parse XML Entry.
-///
-/// This returns the value subslices corresponding to the "confidence" and
-/// "trackcrcs" attributes.
-fn parse_entry(line: &str) -> Option<(&str, &str)> {
-	if line.starts_with("<entry ") {
-		let confidence = parse_attr(line, " confidence=\"")?;
-		let crcs = parse_attr(line, " trackcrcs=\"")?;
-		Some((confidence, crcs))
+impl Toc {
+	/// # Push CTDB TOC.
+	///
+	/// Append the colon-separated, zero-based sector positions CTDB uses to
+	/// identify a disc. Data tracks are written as negative values.
+	fn push_ctdb_toc(&self, out: &mut String) {
+		let mut buf = itoa::Buffer::new();
+
+		// Leading data?
+		if matches!(self.kind, TocKind::DataFirst) {
+			out.push('-');
+			out.push_str(buf.format(self.data - 150));
+			out.push(':');
+		}
+
+		// Each audio track relative to the first.
+		for v in &self.audio {
+			out.push_str(buf.format(v - 150));
+			out.push(':');
+		}
+
+		// Trailing data?
+		if matches!(self.kind, TocKind::CDExtra) {
+			out.push('-');
+			out.push_str(buf.format(self.data - 150));
+			out.push(':');
+		}
+
+		// And the leadout.
+		out.push_str(buf.format(self.leadout - 150));
 	}
-	else { None }
 }
 
-/// # Parse Entry Value.
+
+
+impl ShaB64 {
+	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+	/// # From CTDB Offsets.
+	///
+	/// Compute a CUETools Database ID directly from raw sector positions —
+	/// the same values passed to [`Toc::from_parts`] — without building a
+	/// [`Toc`].
+	///
+	/// Unlike [`Toc::from_parts`], the only validation performed is a check
+	/// that there are between `1..=99` audio tracks, so historical or
+	/// out-of-spec values that would otherwise be rejected can still be
+	/// identified. For well-formed values, the result is identical to
+	/// [`Toc::ctdb_id`].
+	///
+	/// Since out-of-spec positions can't be relied upon to say what kind of
+	/// disc they came from, the [`TocKind`] must be given explicitly. For
+	/// [CD-Extra](TocKind::CDExtra) discs, the audio is taken to end `11,400`
+	/// sectors before the data session begins; for the other kinds, the
+	/// data session (if any) has no bearing on the ID.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{ShaB64, Toc, TocKind};
+	///
+	/// let audio = vec![150, 11563, 25174, 45863];
+	/// let leadout = 55370;
+	/// let toc = Toc::from_parts(audio.clone(), None, leadout).unwrap();
+	///
+	/// assert_eq!(
+	///     ShaB64::from_ctdb_offsets(TocKind::Audio, &audio, None, leadout),
+	///     Ok(toc.ctdb_id()),
+	/// );
+	///
+	/// // Toc won't accept a zero-based leadin, but this will. Since CTDB
+	/// // positions are relative to the first track, the ID is the same.
+	/// assert!(Toc::from_parts(vec![0, 11413, 25024, 45713], None, 55220).is_err());
+	/// assert_eq!(
+	///     ShaB64::from_ctdb_offsets(TocKind::Audio, &[0, 11413, 25024, 45713], None, 55220),
+	///     Ok(toc.ctdb_id()),
+	/// );
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if there are no audio tracks, or more than
+	/// `99`, or if the presence of a data session doesn't agree with the
+	/// kind.
+	pub fn from_ctdb_offsets(
+		kind: TocKind,
+		audio: &[u32],
+		data: Option<u32>,
+		leadout: u32,
+	) -> Result<Self, TocError> {
+		let [leadin, sectors @ ..] = audio else { return Err(TocError::NoAudio); };
+		if 99 < audio.len() { return Err(TocError::TrackCount); }
+
+		// CD-Extra audio ends before the data session.
+		let leadout = match (kind, data) {
+			(TocKind::Audio, None) | (TocKind::DataFirst, Some(_)) => leadout,
+			(TocKind::CDExtra, Some(d)) => d.saturating_sub(11_400),
+			_ => return Err(TocError::Format(kind)),
+		};
+		Ok(ctdb_hash(*leadin, sectors, leadout))
+	}
+}
+
+
+
+/// # CTDB Hash.
 ///
-/// This naively parses an attribute value from a tag, returning the subslice
-/// corresponding to its value if non-empty.
+/// Compute the CTDB ID from the first audio track's position, the positions
+/// of the rest, and the audio leadout. This is the shared core of
+/// [`Toc::ctdb_id`] and [`ShaB64::from_ctdb_offsets`].
 ///
-/// But that's okay; there shouldn't be!
-fn parse_attr<'a>(mut line: &'a str, attr: &'static str) -> Option<&'a str> {
-	let start = line.find(attr)?;
-	line = &line[start + attr.len()..];
-	let end = line.find('"')?;
-
-	if 0 < end { Some(line[..end].trim()) }
-	else { None }
+/// The caller must ensure there are no more than 98 sectors.
+fn ctdb_hash(leadin: u32, sectors: &[u32], leadout: u32) -> ShaB64 {
+	use sha1::Digest;
+	let mut sha = sha1::Sha1::new();
+	sha.update(ctdb_hash_buf(leadin, sectors, leadout).as_slice());
+	ShaB64::from(sha)
+}
+
+/// # CTDB Hash Buffer.
+///
+/// Return the uppercase hex string hashed by [`ctdb_hash`]: the positions
+/// of the second and subsequent audio tracks and the audio leadout, relative
+/// to the first track, zero-padded to a total of 100 entries.
+///
+/// The caller must ensure there are no more than 98 sectors.
+fn ctdb_hash_buf(leadin: u32, sectors: &[u32], leadout: u32) -> [u8; 800] {
+	let mut raw = [0_u8; 400];
+	let mut len = 0;
+	for (dst, v) in raw.chunks_exact_mut(4).zip(
+		sectors.iter().copied()
+			.chain(std::iter::once(leadout))
+			.map(|n| n.wrapping_sub(leadin))
+	) {
+		dst.copy_from_slice(v.to_be_bytes().as_slice());
+		len += 4;
+	}
+
+	// Hex-encode everything en masse, leaving the rest as zero-padding.
+	let mut out = [b'0'; 800];
+	faster_hex::hex_encode_upper(&raw[..len], &mut out[..len * 2]).unwrap();
+	out
+}
+
+/// # Parse XML Entry.
+///
+/// This returns the (unescaped) values of the "confidence" and "trackcrcs"
+/// attributes, if both are present and non-empty.
+fn parse_entry<'a>(tag: &Tag<'a>) -> Option<(Cow<'a, str>, Cow<'a, str>)> {
+	let confidence = tag.attr("confidence").filter(|v| ! v.trim().is_empty())?;
+	let crcs = tag.attr("trackcrcs").filter(|v| ! v.trim().is_empty())?;
+	Some((confidence, crcs))
 }
 
 
@@ -229,39 +467,50 @@ mod tests {
 			(
 				"18+B6+3CE3+7C6F+B2BD+E47F+1121C+15865+175E0+1AED9+1E159+20BF9+235FC+259EF+2826E+29B62+2ED67+311B1+3396B+36ACB+3916B+3BB75+3D60A+40AA6+422FE+48B68+4E4CB",
 				"sBOUSHYC0oLdQZtAEQcmnc3V3Ak-",
-				"http://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=32:15437:31705:45607:58345:70022:88015:95562:110147:123075:133987:144742:153945:164312:170700:191697:200987:211157:223797:233685:244447:251252:264720:270952:-297682:320565",
+				"https://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=32:15437:31705:45607:58345:70022:88015:95562:110147:123075:133987:144742:153945:164312:170700:191697:200987:211157:223797:233685:244447:251252:264720:270952:-297682:320565",
 			),
 			(
 				"D+96+3B5D+78E3+B441+EC83+134F4+17225+1A801+1EA5C+23B5B+27CEF+2B58B+2F974+35D56+514C8",
 				"gmEsiU5wvQFA1Nq9YE_posiwgK8-",
-				"http://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=0:15047:30797:45995:60397:78942:94607:108395:125382:146117:162905:177397:194782:-220352:332850",
+				"https://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=0:15047:30797:45995:60397:78942:94607:108395:125382:146117:162905:177397:194782:-220352:332850",
 			),
 			(
 				"4+96+2D2B+6256+B327+D84A",
 				"VukMWWItblELRM.CEFpXxw0FlME-",
-				"http://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=0:11413:25024:45713:55220",
+				"https://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=0:11413:25024:45713:55220",
 			),
 			(
 				"10+B6+5352+62AC+99D6+E218+12AC0+135E7+142E9+178B0+19D22+1B0D0+1E7FA+22882+247DB+27074+2A1BD+2C0FB",
 				"iL4EZ56YD5WmG..M4v5qzPG0cFY-",
-				"http://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=32:21180:25110:39232:57730:76330:79185:82515:96282:105612:110650:124772:141292:149317:159710:172327:180325",
+				"https://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=32:21180:25110:39232:57730:76330:79185:82515:96282:105612:110650:124772:141292:149317:159710:172327:180325",
 			),
 			(
 				"15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46",
 				"8geCxI4CSyw_ydvHWGmPQUGF1UE-",
-				"http://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=9192:11094:19038:29394:38510:46846:57819:69690:76516:83243:93209:103737:111481:126900:135914:144791:153607:161959:173770:186780:196683:207536",
+				"https://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=9192:11094:19038:29394:38510:46846:57819:69690:76516:83243:93209:103737:111481:126900:135914:144791:153607:161959:173770:186780:196683:207536",
 			),
 			(
 				"63+96+12D9+5546+A8A2+CAAA+128BF+17194+171DF+1722A+17275+172C0+1730B+17356+173A1+173EC+17437+17482+174CD+17518+17563+175AE+175F9+17644+1768F+176DA+17725+17770+177BB+17806+17851+1789C+178E7+17932+1797D+179C8+17A13+17A5E+17AA9+17AF4+17B3F+17B8A+17BD5+17C20+17C6B+17CB6+17D01+17D4C+17D97+17DE2+17E2D+17E78+17EC3+17F0E+17F59+17FA4+17FEF+1803A+18085+180D0+1811B+18166+181B1+181FC+18247+18292+182DD+18328+18373+183BE+18409+18454+1849F+184EA+18535+18580+185CB+18616+18661+186AC+186F7+18742+1878D+187D8+18823+1886E+188B9+18904+1894F+1899A+189E5+18A30+18A7B+18AC6+18B11+18B5C+18BA7+18BF2+18C38+1ECDC+246E9",
 				"okpTZ4Yt2noZkGqbBLte3FfkyVs-",
-				"http://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=0:4675:21680:43020:51732:75817:94462:94537:94612:94687:94762:94837:94912:94987:95062:95137:95212:95287:95362:95437:95512:95587:95662:95737:95812:95887:95962:96037:96112:96187:96262:96337:96412:96487:96562:96637:96712:96787:96862:96937:97012:97087:97162:97237:97312:97387:97462:97537:97612:97687:97762:97837:97912:97987:98062:98137:98212:98287:98362:98437:98512:98587:98662:98737:98812:98887:98962:99037:99112:99187:99262:99337:99412:99487:99562:99637:99712:99787:99862:99937:100012:100087:100162:100237:100312:100387:100462:100537:100612:100687:100762:100837:100912:100987:101062:101137:101212:101282:126022:149075",
+				"https://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc=0:4675:21680:43020:51732:75817:94462:94537:94612:94687:94762:94837:94912:94987:95062:95137:95212:95287:95362:95437:95512:95587:95662:95737:95812:95887:95962:96037:96112:96187:96262:96337:96412:96487:96562:96637:96712:96787:96862:96937:97012:97087:97162:97237:97312:97387:97462:97537:97612:97687:97762:97837:97912:97987:98062:98137:98212:98287:98362:98437:98512:98587:98662:98737:98812:98887:98962:99037:99112:99187:99262:99337:99412:99487:99562:99637:99712:99787:99862:99937:100012:100087:100162:100237:100312:100387:100462:100537:100612:100687:100762:100837:100912:100987:101062:101137:101212:101282:126022:149075",
 			),
 		] {
 			let toc = Toc::from_cdtoc(t).expect("Invalid TOC");
 			let ctdb_id = toc.ctdb_id();
 			assert_eq!(ctdb_id.to_string(), id);
 			assert_eq!(ctdb_id.pretty_print(), id);
+			assert_eq!(ctdb_id.to_array(), ctdb_id.to_string().as_bytes());
+
+			// The exposed hash input should hash to the same thing.
+			let input = toc.ctdb_hash_input();
+			assert_eq!(input.len(), 800);
+			assert!(input.bytes().all(|b| matches!(b, b'0'..=b'9' | b'A'..=b'F')));
+			assert_eq!(ShaB64::from(<sha1::Sha1 as sha1::Digest>::new_with_prefix(&input)), ctdb_id);
 			assert_eq!(toc.ctdb_checksum_url(), lookup);
+			assert_eq!(
+				toc.ctdb_checksum_url_with_base("http://db.cuetools.net"),
+				lookup.replacen("https:", "http:", 1),
+			);
 
 			// Test decoding three ways.
 			assert_eq!(ShaB64::decode(id), Ok(ctdb_id));
@@ -269,4 +518,251 @@ mod tests {
 			assert_eq!(id.parse::<ShaB64>(), Ok(ctdb_id));
 		}
 	}
+
+	#[test]
+	fn t_ctdb_from_offsets() {
+		for t in [
+			"18+B6+3CE3+7C6F+B2BD+E47F+1121C+15865+175E0+1AED9+1E159+20BF9+235FC+259EF+2826E+29B62+2ED67+311B1+3396B+36ACB+3916B+3BB75+3D60A+40AA6+422FE+48B68+4E4CB",
+			"D+96+3B5D+78E3+B441+EC83+134F4+17225+1A801+1EA5C+23B5B+27CEF+2B58B+2F974+35D56+514C8",
+			"4+96+2D2B+6256+B327+D84A",
+			"10+B6+5352+62AC+99D6+E218+12AC0+135E7+142E9+178B0+19D22+1B0D0+1E7FA+22882+247DB+27074+2A1BD+2C0FB",
+			"15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46",
+			"A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11",
+			"A+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11+X96",
+			"3+2D2B+6256+B327+D84A+X96",
+			"63+96+12D9+5546+A8A2+CAAA+128BF+17194+171DF+1722A+17275+172C0+1730B+17356+173A1+173EC+17437+17482+174CD+17518+17563+175AE+175F9+17644+1768F+176DA+17725+17770+177BB+17806+17851+1789C+178E7+17932+1797D+179C8+17A13+17A5E+17AA9+17AF4+17B3F+17B8A+17BD5+17C20+17C6B+17CB6+17D01+17D4C+17D97+17DE2+17E2D+17E78+17EC3+17F0E+17F59+17FA4+17FEF+1803A+18085+180D0+1811B+18166+181B1+181FC+18247+18292+182DD+18328+18373+183BE+18409+18454+1849F+184EA+18535+18580+185CB+18616+18661+186AC+186F7+18742+1878D+187D8+18823+1886E+188B9+18904+1894F+1899A+189E5+18A30+18A7B+18AC6+18B11+18B5C+18BA7+18BF2+18C38+1ECDC+246E9",
+		] {
+			let toc = Toc::from_cdtoc(t).expect("Invalid TOC");
+			assert_eq!(
+				ShaB64::from_ctdb_offsets(toc.kind(), toc.audio_sectors(), toc.data_sector(), toc.leadout()),
+				Ok(toc.ctdb_id()),
+				"{t}",
+			);
+		}
+
+		// A sub-150 leadin; the positions are relative, so the ID matches the
+		// normal version.
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC");
+		assert_eq!(
+			ShaB64::from_ctdb_offsets(TocKind::Audio, &[0, 11413, 25024, 45713], None, 55220),
+			Ok(toc.ctdb_id()),
+		);
+		assert_eq!(
+			ShaB64::from_ctdb_offsets(TocKind::Audio, &[32, 11445, 25056, 45745], None, 55252),
+			Ok(toc.ctdb_id()),
+		);
+
+		// The kind decides what the data session means, even when the
+		// positions alone would suggest otherwise.
+		let audio = [150, 11563, 25174, 45863];
+		let extra = ShaB64::from_ctdb_offsets(TocKind::CDExtra, &audio, Some(66_770), 88_000);
+		assert_eq!(extra, ShaB64::from_ctdb_offsets(TocKind::Audio, &audio, None, 55_370));
+		assert_eq!(
+			ShaB64::from_ctdb_offsets(TocKind::DataFirst, &audio, Some(66_770), 55_370),
+			Ok(toc.ctdb_id()),
+		);
+		assert_eq!(
+			ShaB64::from_ctdb_offsets(TocKind::CDExtra, &audio, Some(100), 55_370),
+			ShaB64::from_ctdb_offsets(TocKind::Audio, &audio, None, 0),
+		);
+
+		// The data session has to agree with the kind.
+		for (kind, data) in [
+			(TocKind::Audio, Some(66_770)),
+			(TocKind::CDExtra, None),
+			(TocKind::DataFirst, None),
+		] {
+			assert_eq!(
+				ShaB64::from_ctdb_offsets(kind, &audio, data, 55_370),
+				Err(TocError::Format(kind)),
+			);
+		}
+
+		// Garbage in, garbage out, but no panics.
+		assert!(ShaB64::from_ctdb_offsets(TocKind::Audio, &[100, 50, 10], None, 5).is_ok());
+		assert!(ShaB64::from_ctdb_offsets(TocKind::CDExtra, &[u32::MAX; 99], Some(0), u32::MAX).is_ok());
+
+		// But not bad track counts.
+		assert_eq!(ShaB64::from_ctdb_offsets(TocKind::Audio, &[], None, 55370), Err(TocError::NoAudio));
+		assert_eq!(ShaB64::from_ctdb_offsets(TocKind::Audio, &[150; 100], None, 55370), Err(TocError::TrackCount));
+	}
+
+	#[test]
+	fn t_ctdb_mixed() {
+		// CUETools identifies discs by the audio tracks alone, relative to the
+		// first audio track; data tracks only show up in the lookup, as
+		// negative entries in disc order.
+		//
+		// Note: these IDs and lookup parameters were not taken from CUETools
+		// or the live database; they were checked against an independent
+		// implementation of the algorithm instead.
+		for (t, kind, id, toc_param) in [
+			(
+				"A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11",
+				TocKind::CDExtra,
+				"M5Rif8wh5XV2ylpUK7jxjzZm9ao-",
+				"0:14017:26839:50617:67965:85260:105970:121620:135950:161720:-186137:224891",
+			),
+			(
+				"A+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11+X96",
+				TocKind::DataFirst,
+				"iHWQa4.g4HCn_L6vyngWLluFsRU-",
+				"-0:14017:26839:50617:67965:85260:105970:121620:135950:161720:186137:224891",
+			),
+			(
+				"3+2D2B+6256+B327+D84A+X96",
+				TocKind::DataFirst,
+				"AHGyL_3fVmeosWbrp2FODwOeCKo-",
+				"-0:11413:25024:45713:55220",
+			),
+		] {
+			let toc = Toc::from_cdtoc(t).expect("Invalid TOC.");
+			assert_eq!(toc.kind(), kind, "{t}");
+			assert_eq!(toc.ctdb_id().to_string(), id, "{t}");
+			assert_eq!(
+				toc.ctdb_checksum_url(),
+				format!("https://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=1&toc={toc_param}"),
+				"{t}",
+			);
+		}
+	}
+
+	#[test]
+	fn t_ctdb_parse_checksums() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+
+		// Wrapped and reordered entries, escaped metadata, unknown attributes,
+		// and an entry with no checksums.
+		let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<ctdb xmlns="http://db.cuetools.net/ns/mmd-1.0#" xmlns:ext="http://db.cuetools.net/ns/ext-1.0#">
+  <entry confidence="12" crc32="5fe36f4e" hasparity="/parity/1287443" id="1287443" npar="8" stride="5880" syndrome="" toc="0:11413:25024:45713:55220" trackcrcs="a1b2c3d4 0badf00d 12345678 deadbeef" />
+  <entry trackcrcs="a1b2c3d4 00000000
+                    12345678 c0ffee00"
+         id="1301122"
+         confidence="3" />
+  <entry confidence="1" trackcrcs="" />
+  <metadata source="freedb" artist="Tom &quot;T&quot; Jones &amp; Co." album="Say &apos;Hi&apos; &gt; Bye">
+    <track name="&quot;One&quot;" />
+  </metadata>
+</ctdb>"#;
+
+		let parsed = toc.ctdb_parse_checksums(xml).expect("Parse failed.");
+		assert_eq!(parsed.len(), 4);
+		assert_eq!(parsed[0].get(&0xa1b2_c3d4), Some(&15));
+		assert_eq!(parsed[1].get(&0x0bad_f00d), Some(&12));
+		assert_eq!(parsed[1].len(), 1);
+		assert_eq!(parsed[2].get(&0x1234_5678), Some(&15));
+		assert_eq!(parsed[3].get(&0xdead_beef), Some(&12));
+		assert_eq!(parsed[3].get(&0xc0ff_ee00), Some(&3));
+
+		// The structured parser should agree, skipping the same entry.
+		let res = toc.ctdb_parse_response(xml).expect("Parse failed.");
+		assert_eq!(res.entries().len(), 2);
+		let mut from_res = vec![BTreeMap::<u32, u16>::new(); 4];
+		for entry in res.entries() {
+			for (track, &crc) in from_res.iter_mut().zip(entry.track_crcs()) {
+				if crc != 0 {
+					let e = track.entry(crc).or_insert(0);
+					*e = e.saturating_add(entry.confidence());
+				}
+			}
+		}
+		assert_eq!(from_res, parsed);
+
+		// Nothing.
+		assert_eq!(
+			toc.ctdb_parse_checksums(r#"<?xml version="1.0"?><ctdb></ctdb>"#),
+			Err(TocError::NoChecksums),
+		);
+
+		// Broken stuff.
+		for bad in [
+			// Too few.
+			r#"<ctdb><entry confidence="1" trackcrcs="01 02 03" /></ctdb>"#,
+			// Too many.
+			r#"<ctdb><entry confidence="1" trackcrcs="01 02 03 04 05" /></ctdb>"#,
+			// Bad CRC.
+			r#"<ctdb><entry confidence="1" trackcrcs="01 02 03 nope" /></ctdb>"#,
+			// Bad confidence.
+			r#"<ctdb><entry confidence="lots" trackcrcs="01 02 03 04" /></ctdb>"#,
+		] {
+			assert_eq!(toc.ctdb_parse_checksums(bad), Err(TocError::CtdbTag(1)), "{bad}");
+		}
+
+		// Invalid entries report the line they started on too.
+		assert_eq!(
+			toc.ctdb_parse_checksums("<ctdb>\n<entry confidence=\"1\" trackcrcs=\"01 02 03 04\" />\n<entry\n confidence=\"1\" trackcrcs=\"01 02 03\" />\n</ctdb>"),
+			Err(TocError::CtdbTag(3)),
+		);
+
+		// Unterminated tags report the line they started on.
+		assert_eq!(
+			toc.ctdb_parse_checksums("<ctdb>\n<entry confidence=\"1\" trackcrcs=\"01 02 03 04\""),
+			Err(TocError::CtdbTag(2)),
+		);
+	}
+
+	#[test]
+	fn t_ctdb_parse_checksums_bytes() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let expected = toc.ctdb_parse_checksums(
+			r#"<ctdb><entry confidence="12" trackcrcs="a1b2c3d4 0badf00d 12345678 deadbeef" /></ctdb>"#
+		).expect("Parse failed.");
+
+		// With a BOM.
+		let body = b"\xEF\xBB\xBF<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<ctdb>\n  <entry confidence=\"12\" trackcrcs=\"a1b2c3d4 0badf00d 12345678 deadbeef\" />\n</ctdb>";
+		assert_eq!(toc.ctdb_parse_checksums_bytes(body).as_ref(), Ok(&expected));
+
+		// With a stray Latin-1 byte in the metadata.
+		let body = b"<ctdb>\n  <entry confidence=\"12\" trackcrcs=\"a1b2c3d4 0badf00d 12345678 deadbeef\" />\n  <metadata source=\"freedb\" artist=\"Bj\xF6rk\" album=\"Debut\" />\n</ctdb>";
+		assert_eq!(toc.ctdb_parse_checksums_bytes(body).as_ref(), Ok(&expected));
+
+		// Both, with the Latin-1 before the entry.
+		let body = b"\xEF\xBB\xBF<ctdb><metadata artist=\"Sigur R\xF3s\" /><entry confidence=\"12\" trackcrcs=\"a1b2c3d4 0badf00d 12345678 deadbeef\" /></ctdb>";
+		assert_eq!(toc.ctdb_parse_checksums_bytes(body), Ok(expected));
+
+		// Still strict about the checksums.
+		assert_eq!(
+			toc.ctdb_parse_checksums_bytes(b"<ctdb><entry confidence=\"1\" trackcrcs=\"01 02 03 \xF6\" /></ctdb>"),
+			Err(TocError::CtdbTag(1)),
+		);
+		assert_eq!(toc.ctdb_parse_checksums_bytes(b"\xEF\xBB\xBF"), Err(TocError::NoChecksums));
+	}
+
+	#[test]
+	fn t_ctdb_entries() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let xml = r#"<ctdb>
+  <entry confidence="12" toc="0:11413:25024:45713:55220" trackcrcs="a1b2c3d4 0badf00d 12345678 deadbeef" />
+  <entry confidence="" trackcrcs="01 02 03 04" />
+  <entry confidence="3" trackcrcs="a1b2c3d4 00000000
+    12345678 c0ffee00" />
+  <entry confidence="1" trackcrcs="01 02 03" />
+  <entry confidence="1" trackcrcs="01 02 03 04" />
+</ctdb>"#;
+
+		let mut iter = toc.ctdb_entries(xml);
+		let entry = iter.next().expect("Missing entry.").expect("Invalid entry.");
+		assert_eq!(entry.confidence(), 12);
+		assert_eq!(entry.toc(), Some("0:11413:25024:45713:55220"));
+		assert_eq!(
+			entry.track_crcs().collect::<Vec<_>>(),
+			[0xa1b2_c3d4, 0x0bad_f00d, 0x1234_5678, 0xdead_beef],
+		);
+
+		// The empty confidence is skipped.
+		let entry = iter.next().expect("Missing entry.").expect("Invalid entry.");
+		assert_eq!(entry.confidence(), 3);
+		assert_eq!(entry.toc(), None);
+		assert_eq!(entry.track_crcs().collect::<Vec<_>>(), [0xa1b2_c3d4, 0, 0x1234_5678, 0xc0ff_ee00]);
+
+		// The short entry is an error, and nothing comes after it.
+		assert_eq!(iter.next(), Some(Err(TocError::CtdbTag(6))));
+		assert!(iter.next().is_none());
+
+		// Early exit works even if the document is broken further on.
+		let broken = format!("{xml}<entry confidence=\"1\" trackcrcs=\"01 02 03 04\"");
+		assert_eq!(toc.ctdb_entries(&broken).take(2).filter(Result::is_ok).count(), 2);
+		assert_eq!(toc.ctdb_entries(&broken).last(), Some(Err(TocError::CtdbTag(6))));
+	}
 }
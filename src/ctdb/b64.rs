@@ -0,0 +1,103 @@
+/*!
+# CDTOC: CTDB Base64
+*/
+
+
+
+/// # Alphabet.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+
+
+/// # Encode.
+///
+/// Encode the data using the standard (padded) base64 alphabet.
+pub(super) fn encode(src: &[u8]) -> String {
+	let mut out = String::with_capacity(src.len().div_ceil(3) * 4);
+	for chunk in src.chunks(3) {
+		let n = chunk.iter()
+			.enumerate()
+			.fold(0_u32, |acc, (k, &b)| acc | (u32::from(b) << (16 - k * 8)));
+		for k in 0..4 {
+			if k <= chunk.len() {
+				out.push(char::from(ALPHABET[((n >> (18 - k * 6)) & 63) as usize]));
+			}
+			else { out.push('='); }
+		}
+	}
+	out
+}
+
+/// # Decode.
+///
+/// Decode standard base64, ignoring whitespace. Padding is optional.
+#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+pub(super) fn decode(src: &str) -> Option<Vec<u8>> {
+	let mut out = Vec::with_capacity(src.len() / 4 * 3);
+	let mut buf = 0_u32;
+	let mut bits = 0_u8;
+	let mut pad = false;
+	for b in src.bytes() {
+		if b.is_ascii_whitespace() { continue; }
+		if b == b'=' {
+			pad = true;
+			continue;
+		}
+		// Nothing but padding can follow padding.
+		if pad { return None; }
+
+		let v = match b {
+			b'A'..=b'Z' => b - b'A',
+			b'a'..=b'z' => b - b'a' + 26,
+			b'0'..=b'9' => b - b'0' + 52,
+			b'+' => 62,
+			b'/' => 63,
+			_ => return None,
+		};
+		buf = (buf << 6) | u32::from(v);
+		bits += 6;
+		if 8 <= bits {
+			bits -= 8;
+			out.push((buf >> bits) as u8);
+		}
+	}
+
+	// A single dangling character is never valid.
+	if bits == 6 { None }
+	else { Some(out) }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_base64() {
+		for (raw, expected) in [
+			("", ""),
+			("f", "Zg=="),
+			("fo", "Zm8="),
+			("foo", "Zm9v"),
+			("foob", "Zm9vYg=="),
+			("fooba", "Zm9vYmE="),
+			("foobar", "Zm9vYmFy"),
+		] {
+			assert_eq!(encode(raw.as_bytes()), expected, "{raw}");
+			assert_eq!(decode(expected).as_deref(), Some(raw.as_bytes()), "{raw}");
+			assert_eq!(
+				decode(expected.trim_end_matches('=')).as_deref(),
+				Some(raw.as_bytes()),
+				"{raw}",
+			);
+		}
+		assert_eq!(encode(&[0xff, 0xfe, 0xfd]), "//79");
+		assert_eq!(decode("//79"), Some(vec![0xff, 0xfe, 0xfd]));
+		assert_eq!(decode("Zm9v\nYmFy"), Some(b"foobar".to_vec()));
+
+		for bad in ["Z", "Zm9vY", "Zm=9", "Zm9v!"] {
+			assert!(decode(bad).is_none(), "{bad}");
+		}
+	}
+}
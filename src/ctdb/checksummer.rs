@@ -0,0 +1,322 @@
+/*!
+# CDTOC: CTDB Checksummer
+*/
+
+use crate::{
+	crc32,
+	Toc,
+	Track,
+};
+
+
+
+/// # Skipped Values.
+///
+/// CTDB ignores the first five sectors of the first track and the last five
+/// sectors of the last track, i.e. `588 * 5` stereo samples, or twice that
+/// many `i16` values.
+const SKIP: usize = 588 * 5 * 2;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # CTDB Track Checksummer.
+///
+/// This calculates the CUETools Database CRC32 for a single audio track, for
+/// comparison against the `trackcrcs` of a [lookup](Toc::ctdb_checksum_url)
+/// response.
+///
+/// The checksum is a standard CRC-32 (IEEE) of the track's raw
+/// little-endian 16-bit PCM, excluding the first five sectors (2,940 stereo
+/// samples) of the disc's first audio track and the last five sectors of its
+/// last one. This is the "offset-tolerant" windowing CUETools uses for CTDB
+/// track checksums; other windowing variants are not supported.
+///
+/// Samples are fed in via [`CtdbChecksummer::update`] as interleaved
+/// left/right `i16` values, in as many or few chunks as you like. Anything
+/// beyond the track's length is ignored.
+///
+/// To checksum a whole disc in one go, see [`CtdbDiscChecksummer`].
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{CtdbChecksummer, Toc};
+///
+/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+/// let track = toc.audio_track(2).unwrap();
+/// let mut chk = CtdbChecksummer::new(track);
+/// assert_eq!(chk.total_samples(), track.samples());
+///
+/// // Feed it the audio.
+/// let samples = vec![0_i16; track.samples() as usize * 2];
+/// for chunk in samples.chunks(4096) { chk.update(chunk); }
+/// assert!(chk.is_finished());
+///
+/// let _crc = chk.finalize();
+/// ```
+pub struct CtdbChecksummer {
+	/// # Running CRC.
+	crc: u32,
+
+	/// # Position (`i16` Values).
+	pos: usize,
+
+	/// # Window Start (`i16` Values).
+	start: usize,
+
+	/// # Window End (`i16` Values).
+	end: usize,
+
+	/// # Total Length (`i16` Values).
+	len: usize,
+}
+
+impl CtdbChecksummer {
+	#[must_use]
+	/// # New.
+	///
+	/// Prepare a new checksummer for the track. The track's position on the
+	/// disc determines which — if any — samples get skipped.
+	pub fn new(track: Track) -> Self {
+		let pos = track.position();
+		Self::new_window(track.samples(), pos.is_first(), pos.is_last())
+	}
+
+	/// # New (Window).
+	///
+	/// Prepare a checksummer for an arbitrary run of samples, optionally
+	/// skipping the first and/or last five sectors.
+	fn new_window(samples: u64, skip_start: bool, skip_end: bool) -> Self {
+		let len = usize::try_from(samples.saturating_mul(2)).unwrap_or(usize::MAX);
+		let start = if skip_start { SKIP.min(len) } else { 0 };
+		let end = if skip_end { len.saturating_sub(SKIP).max(start) } else { len };
+		Self { crc: crc32::INIT, pos: 0, start, end, len }
+	}
+
+	/// # Update.
+	///
+	/// Add interleaved left/right samples to the checksum.
+	pub fn update(&mut self, samples: &[i16]) {
+		let from = self.pos;
+		let to = from.saturating_add(samples.len());
+		self.pos = to;
+
+		let a = self.start.clamp(from, to);
+		let b = self.end.clamp(a, to);
+		for v in &samples[a - from..b - from] {
+			self.crc = crc32::update(self.crc, &v.to_le_bytes());
+		}
+	}
+
+	#[must_use]
+	/// # Finalize.
+	///
+	/// Return the CRC32.
+	pub const fn finalize(self) -> u32 { crc32::finalize(self.crc) }
+
+	#[must_use]
+	/// # Is Finished?
+	///
+	/// Returns `true` once all of the track's samples have been received.
+	pub const fn is_finished(&self) -> bool { self.len <= self.pos }
+
+	#[must_use]
+	/// # Total Samples.
+	///
+	/// Return the number of stereo samples the track is expected to have.
+	pub const fn total_samples(&self) -> u64 { (self.len / 2) as u64 }
+
+	/// # Remaining Values.
+	///
+	/// Return the number of `i16` values still expected.
+	const fn remaining(&self) -> usize { self.len.saturating_sub(self.pos) }
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # CTDB Disc Checksummer.
+///
+/// This calculates the CUETools Database CRC32s for every audio track, as
+/// well as the whole-disc CRC32 (an entry's `crc32`), in a single pass.
+///
+/// Audio is fed in via [`CtdbDiscChecksummer::update`] as interleaved
+/// left/right `i16` values, starting with the first sample of the first
+/// audio track and ending with the last sample before the audio leadout.
+///
+/// The disc checksum covers all of the audio (but not any data tracks),
+/// minus the same first and last five sectors excluded from the track
+/// checksums; see [`CtdbChecksummer`] for more details.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{CtdbDiscChecksummer, Toc};
+///
+/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+/// let mut chk = CtdbDiscChecksummer::new(&toc);
+///
+/// let samples = vec![0_i16; chk.total_samples() as usize * 2];
+/// for chunk in samples.chunks(4096) { chk.update(chunk); }
+/// assert!(chk.is_finished());
+///
+/// let (tracks, disc) = chk.finalize();
+/// assert_eq!(tracks.len(), 2);
+/// ```
+pub struct CtdbDiscChecksummer {
+	/// # Track Checksummers.
+	tracks: Vec<CtdbChecksummer>,
+
+	/// # Current Track Index.
+	idx: usize,
+
+	/// # Disc Checksummer.
+	disc: CtdbChecksummer,
+}
+
+impl CtdbDiscChecksummer {
+	#[must_use]
+	/// # New.
+	pub fn new(toc: &Toc) -> Self {
+		let tracks: Vec<CtdbChecksummer> = toc.audio_tracks()
+			.map(CtdbChecksummer::new)
+			.collect();
+		let samples = tracks.iter().map(CtdbChecksummer::total_samples).sum();
+		Self {
+			tracks,
+			idx: 0,
+			disc: CtdbChecksummer::new_window(samples, true, true),
+		}
+	}
+
+	/// # Update.
+	///
+	/// Add interleaved left/right samples to the checksums.
+	pub fn update(&mut self, mut samples: &[i16]) {
+		self.disc.update(samples);
+
+		while ! samples.is_empty() {
+			let Some(track) = self.tracks.get_mut(self.idx) else { break; };
+			let (a, b) = samples.split_at(track.remaining().min(samples.len()));
+			track.update(a);
+			samples = b;
+			if track.is_finished() { self.idx += 1; }
+		}
+	}
+
+	#[must_use]
+	/// # Finalize.
+	///
+	/// Return the CRC32s for each track — indexed by track number (`n-1`) —
+	/// along with the CRC32 for the whole disc.
+	pub fn finalize(self) -> (Vec<u32>, u32) {
+		(
+			self.tracks.into_iter().map(CtdbChecksummer::finalize).collect(),
+			self.disc.finalize(),
+		)
+	}
+
+	#[must_use]
+	/// # Is Finished?
+	///
+	/// Returns `true` once all of the disc's audio samples have been
+	/// received.
+	pub const fn is_finished(&self) -> bool { self.disc.is_finished() }
+
+	#[must_use]
+	/// # Total Samples.
+	///
+	/// Return the number of stereo samples the disc's audio is expected to
+	/// have.
+	pub const fn total_samples(&self) -> u64 { self.disc.total_samples() }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// # One-Shot CRC-32.
+	///
+	/// Checksum a run of samples in one go, with no windowing.
+	fn crc32(src: &[i16]) -> u32 {
+		let bytes: Vec<u8> = src.iter().flat_map(|v| v.to_le_bytes()).collect();
+		crate::crc32::checksum(&bytes)
+	}
+
+	/// # Sample Audio.
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	fn samples(len: usize) -> Vec<i16> {
+		(0..len as u32).map(|n| n.wrapping_mul(0x9E37_79B9) as i16).collect()
+	}
+
+	#[test]
+	fn t_crc() {
+		// The standard check value.
+		let mut chk = CtdbChecksummer::new_window(2, false, false);
+		chk.update(&[
+			i16::from_le_bytes([b'1', b'2']),
+			i16::from_le_bytes([b'3', b'4']),
+		]);
+		assert_eq!(chk.finalize(), 0x9BE3_E0A3); // CRC-32 of "1234".
+		assert_eq!(crc32(&[
+			i16::from_le_bytes([b'1', b'2']),
+			i16::from_le_bytes([b'3', b'4']),
+		]), 0x9BE3_E0A3);
+	}
+
+	#[test]
+	fn t_checksummer() {
+		// Three tracks: 350, 250, and 1000 sectors.
+		let toc = Toc::from_cdtoc("3+96+1F4+2EE+6D6").expect("Invalid TOC.");
+		let mut disc = CtdbDiscChecksummer::new(&toc);
+		let total = usize::try_from(disc.total_samples()).expect("Too big.");
+		assert_eq!(total, 1600 * 588);
+
+		let audio = samples(total * 2);
+
+		// Work out the expected values the slow way.
+		let t1 = 350 * 588 * 2;
+		let t2 = t1 + 250 * 588 * 2;
+		let expected_tracks = vec![
+			crc32(&audio[SKIP..t1]),
+			crc32(&audio[t1..t2]),
+			crc32(&audio[t2..audio.len() - SKIP]),
+		];
+		let expected_disc = crc32(&audio[SKIP..audio.len() - SKIP]);
+
+		// Feed the disc in odd-sized chunks that don't line up with anything.
+		for chunk in audio.chunks(12_345) {
+			assert!(! disc.is_finished());
+			disc.update(chunk);
+		}
+		assert!(disc.is_finished());
+		assert_eq!(disc.finalize(), (expected_tracks.clone(), expected_disc));
+
+		// The individual track checksummers should agree.
+		let mut from = 0;
+		for (track, expected) in toc.audio_tracks().zip(expected_tracks) {
+			let mut chk = CtdbChecksummer::new(track);
+			let to = from + usize::try_from(chk.total_samples()).expect("Too big.") * 2;
+			chk.update(&audio[from..to]);
+			assert!(chk.is_finished());
+
+			// Extra data should be ignored.
+			chk.update(&[1, 2, 3]);
+			assert_eq!(chk.finalize(), expected);
+			from = to;
+		}
+
+		// A single track is both first and last.
+		let toc = Toc::from_cdtoc("1+96+2EE").expect("Invalid TOC.");
+		let track = toc.audio_track(1).expect("Missing track.");
+		let audio = samples(usize::try_from(track.samples()).expect("Too big.") * 2);
+		let mut chk = CtdbChecksummer::new(track);
+		chk.update(&audio);
+		assert_eq!(chk.finalize(), crc32(&audio[SKIP..audio.len() - SKIP]));
+	}
+}
@@ -0,0 +1,243 @@
+/*!
+# CDTOC: CTDB Lookup
+*/
+
+use crate::Toc;
+use std::fmt;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # CTDB Metadata Level.
+///
+/// This controls how much — if any — metadata the server should include in
+/// a lookup response. See [`CtdbLookupOptions::with_metadata`].
+pub enum CtdbMetadataLevel {
+	#[default]
+	/// # None.
+	///
+	/// Don't ask for metadata. The `metadata` parameter is omitted.
+	None,
+
+	/// # Fast.
+	///
+	/// Only consult the fastest sources.
+	Fast,
+
+	/// # Default.
+	///
+	/// The server's default search.
+	Default,
+
+	/// # Extensive.
+	///
+	/// Consult every available source.
+	Extensive,
+}
+
+impl fmt::Display for CtdbMetadataLevel {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl CtdbMetadataLevel {
+	#[must_use]
+	/// # As Str.
+	///
+	/// Return the value as it appears in the lookup URL. (For
+	/// [`CtdbMetadataLevel::None`], this is an empty string.)
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::None => "",
+			Self::Fast => "fast",
+			Self::Default => "default",
+			Self::Extensive => "extensive",
+		}
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # CTDB Lookup Options.
+///
+/// This holds the query settings used by [`Toc::ctdb_checksum_url_opts`].
+///
+/// The [`Default`] matches [`Toc::ctdb_checksum_url`]: CTDB entries and
+/// fuzzy matching enabled, no metadata.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{CtdbLookupOptions, CtdbMetadataLevel};
+///
+/// let opts = CtdbLookupOptions::default()
+///     .with_fuzzy(false)
+///     .with_metadata(CtdbMetadataLevel::Fast);
+/// assert!(opts.ctdb());
+/// assert!(! opts.fuzzy());
+/// assert_eq!(opts.metadata(), CtdbMetadataLevel::Fast);
+/// ```
+pub struct CtdbLookupOptions {
+	/// # CTDB Entries.
+	ctdb: bool,
+
+	/// # Fuzzy Matching.
+	fuzzy: bool,
+
+	/// # Metadata.
+	metadata: CtdbMetadataLevel,
+}
+
+impl Default for CtdbLookupOptions {
+	fn default() -> Self {
+		Self {
+			ctdb: true,
+			fuzzy: true,
+			metadata: CtdbMetadataLevel::None,
+		}
+	}
+}
+
+impl CtdbLookupOptions {
+	#[must_use]
+	/// # With CTDB Entries.
+	///
+	/// Enable or disable the checksum (and parity) entries (`ctdb`). Disabling
+	/// this is only useful for metadata-only lookups.
+	pub const fn with_ctdb(mut self, ctdb: bool) -> Self {
+		self.ctdb = ctdb;
+		self
+	}
+
+	#[must_use]
+	/// # With Fuzzy Matching.
+	///
+	/// Enable or disable fuzzy table-of-contents matching (`fuzzy`), i.e.
+	/// entries for other pressings of the disc. Disable for exact-only
+	/// verification.
+	pub const fn with_fuzzy(mut self, fuzzy: bool) -> Self {
+		self.fuzzy = fuzzy;
+		self
+	}
+
+	#[must_use]
+	/// # With Metadata.
+	///
+	/// Set the metadata level (`metadata`).
+	pub const fn with_metadata(mut self, metadata: CtdbMetadataLevel) -> Self {
+		self.metadata = metadata;
+		self
+	}
+
+	#[must_use]
+	/// # CTDB Entries?
+	pub const fn ctdb(&self) -> bool { self.ctdb }
+
+	#[must_use]
+	/// # Fuzzy Matching?
+	pub const fn fuzzy(&self) -> bool { self.fuzzy }
+
+	#[must_use]
+	/// # Metadata Level.
+	pub const fn metadata(&self) -> CtdbMetadataLevel { self.metadata }
+}
+
+
+
+impl Toc {
+	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+	#[must_use]
+	/// # CUETools Database Checksum URL (Custom Options).
+	///
+	/// Same as [`Toc::ctdb_checksum_url`], but with custom query settings.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{CtdbLookupOptions, CtdbMetadataLevel, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let opts = CtdbLookupOptions::default()
+	///     .with_fuzzy(false)
+	///     .with_metadata(CtdbMetadataLevel::Extensive);
+	/// assert_eq!(
+	///     toc.ctdb_checksum_url_opts(&opts),
+	///     "https://db.cuetools.net/lookup2.php?version=3&ctdb=1&fuzzy=0&metadata=extensive&toc=0:11413:25024:45713:55220",
+	/// );
+	/// ```
+	pub fn ctdb_checksum_url_opts(&self, opts: &CtdbLookupOptions) -> String {
+		let mut url = String::with_capacity(160);
+		url.push_str(super::BASE_URL);
+		self.push_ctdb_lookup(*opts, &mut url);
+		url
+	}
+
+	/// # Push CTDB Lookup.
+	///
+	/// Append the lookup path and query to a base URL.
+	pub(super) fn push_ctdb_lookup(&self, opts: CtdbLookupOptions, out: &mut String) {
+		out.push_str("lookup2.php?version=3&ctdb=");
+		out.push(if opts.ctdb { '1' } else { '0' });
+		out.push_str("&fuzzy=");
+		out.push(if opts.fuzzy { '1' } else { '0' });
+		if ! matches!(opts.metadata, CtdbMetadataLevel::None) {
+			out.push_str("&metadata=");
+			out.push_str(opts.metadata.as_str());
+		}
+		out.push_str("&toc=");
+		self.push_ctdb_toc(out);
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_lookup_opts() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let base = "https://db.cuetools.net/lookup2.php?version=3";
+		let tail = "&toc=0:11413:25024:45713:55220";
+
+		// The default should match the regular method.
+		assert_eq!(
+			toc.ctdb_checksum_url_opts(&CtdbLookupOptions::default()),
+			toc.ctdb_checksum_url(),
+		);
+
+		for (opts, query) in [
+			(CtdbLookupOptions::default(), "&ctdb=1&fuzzy=1"),
+			(CtdbLookupOptions::default().with_ctdb(false), "&ctdb=0&fuzzy=1"),
+			(CtdbLookupOptions::default().with_fuzzy(false), "&ctdb=1&fuzzy=0"),
+			(
+				CtdbLookupOptions::default().with_metadata(CtdbMetadataLevel::Fast),
+				"&ctdb=1&fuzzy=1&metadata=fast",
+			),
+			(
+				CtdbLookupOptions::default().with_metadata(CtdbMetadataLevel::Default),
+				"&ctdb=1&fuzzy=1&metadata=default",
+			),
+			(
+				CtdbLookupOptions::default()
+					.with_ctdb(false)
+					.with_fuzzy(false)
+					.with_metadata(CtdbMetadataLevel::Extensive),
+				"&ctdb=0&fuzzy=0&metadata=extensive",
+			),
+			(
+				CtdbLookupOptions::default().with_metadata(CtdbMetadataLevel::None),
+				"&ctdb=1&fuzzy=1",
+			),
+		] {
+			assert_eq!(toc.ctdb_checksum_url_opts(&opts), format!("{base}{query}{tail}"));
+		}
+
+		// Display.
+		assert_eq!(CtdbMetadataLevel::Extensive.to_string(), "extensive");
+		assert_eq!(format!("{:>6}", CtdbMetadataLevel::Fast), "  fast");
+	}
+}
@@ -0,0 +1,134 @@
+/*!
+# CDTOC: CTDB Parity
+*/
+
+use crate::TocError;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # CTDB Parity.
+///
+/// This holds the raw Reed–Solomon parity data for a disc, as found in CTDB
+/// [entries](crate::CtdbEntry::parity).
+///
+/// The data is stored as `npar` rows of `stride * 2` little-endian 16-bit
+/// symbols (the stride is given in stereo samples). This struct only checks
+/// that the length adds up; it does not interpret the symbols.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::CtdbParity;
+///
+/// // Eight rows of 588 stereo samples.
+/// let raw = vec![0_u8; 8 * 588 * 2 * 2];
+/// let parity = CtdbParity::from_bytes(8, 588, &raw).unwrap();
+/// assert_eq!(parity.npar(), 8);
+/// assert_eq!(parity.stride(), 588);
+/// assert_eq!(parity.to_bytes(), raw);
+///
+/// // The length has to match.
+/// assert!(CtdbParity::from_bytes(8, 589, &raw).is_err());
+/// ```
+pub struct CtdbParity {
+	/// # Parity Symbols.
+	npar: u16,
+
+	/// # Stride (Samples).
+	stride: u32,
+
+	/// # Parity Data.
+	parity: Vec<u16>,
+}
+
+impl CtdbParity {
+	/// # From Base64.
+	///
+	/// Parse base64-encoded parity data, as found in CTDB responses.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the data cannot be decoded, or its length
+	/// does not match `npar` and `stride`.
+	pub fn from_base64(npar: u16, stride: u32, src: &str) -> Result<Self, TocError> {
+		let raw = super::b64::decode(src).ok_or(TocError::CtdbParity)?;
+		Self::from_bytes(npar, stride, &raw)
+	}
+
+	/// # From Bytes.
+	///
+	/// Parse raw parity data, a sequence of little-endian `u16` symbols.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the length does not match `npar` and
+	/// `stride`.
+	pub fn from_bytes(npar: u16, stride: u32, src: &[u8]) -> Result<Self, TocError> {
+		let width = usize::try_from(stride).map_err(|_| TocError::CtdbParity)? * 2;
+		if
+			npar == 0 ||
+			width == 0 ||
+			src.len() != width * usize::from(npar) * 2
+		{
+			return Err(TocError::CtdbParity);
+		}
+
+		let parity = src.chunks_exact(2)
+			.map(|v| u16::from_le_bytes([v[0], v[1]]))
+			.collect();
+		Ok(Self { npar, stride, parity })
+	}
+
+	#[must_use]
+	/// # Parity Symbols.
+	///
+	/// Return the number of parity rows.
+	pub const fn npar(&self) -> u16 { self.npar }
+
+	#[must_use]
+	/// # Stride.
+	///
+	/// Return the stride, in stereo samples.
+	pub const fn stride(&self) -> u32 { self.stride }
+
+	#[must_use]
+	/// # To Bytes.
+	///
+	/// Return the raw parity data as little-endian `u16` symbols, suitable for
+	/// [`CtdbSubmission::with_parity`](crate::CtdbSubmission::with_parity).
+	pub fn to_bytes(&self) -> Vec<u8> {
+		self.parity.iter().flat_map(|v| v.to_le_bytes()).collect()
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_parity_bytes() {
+		let bytes: Vec<u8> = (0..8 * 200 * 2_u32)
+			.map(|n| n.wrapping_mul(0x9E37_79B9).to_le_bytes()[3])
+			.collect();
+		let parity = CtdbParity::from_bytes(8, 100, &bytes).expect("Parse failed.");
+		assert_eq!(parity.npar(), 8);
+		assert_eq!(parity.stride(), 100);
+		assert_eq!(parity.parity[0], u16::from_le_bytes([bytes[0], bytes[1]]));
+
+		// Round trip through bytes and base64.
+		assert_eq!(parity.to_bytes(), bytes);
+		let b64 = super::super::b64::encode(&bytes);
+		assert_eq!(CtdbParity::from_base64(8, 100, &b64).as_ref(), Ok(&parity));
+
+		// Bad data.
+		assert_eq!(CtdbParity::from_bytes(8, 101, &bytes), Err(TocError::CtdbParity));
+		assert_eq!(CtdbParity::from_bytes(0, 100, &bytes), Err(TocError::CtdbParity));
+		assert_eq!(CtdbParity::from_bytes(8, 0, &[]), Err(TocError::CtdbParity));
+		assert_eq!(CtdbParity::from_bytes(8, 100, &bytes[1..]), Err(TocError::CtdbParity));
+		assert_eq!(CtdbParity::from_base64(8, 100, "nope!"), Err(TocError::CtdbParity));
+	}
+}
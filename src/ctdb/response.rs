@@ -0,0 +1,705 @@
+/*!
+# CDTOC: CTDB Response
+*/
+
+use crate::{
+	CtdbParity,
+	Toc,
+	TocError,
+};
+use dactyl::traits::HexToUnsigned;
+use super::parse_entry;
+use std::{
+	borrow::Cow,
+	collections::BTreeMap,
+};
+use crate::xml::{
+	Tag,
+	Tags,
+};
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # CTDB Response.
+///
+/// This holds the structured contents of a CTDB [lookup](Toc::ctdb_checksum_url)
+/// response, as returned by [`Toc::ctdb_parse_response`].
+pub struct CtdbResponse {
+	/// # Entries.
+	entries: Vec<CtdbEntry>,
+
+	/// # Metadata.
+	metadata: Vec<CtdbMetadata>,
+}
+
+impl CtdbResponse {
+	#[must_use]
+	/// # Entries.
+	///
+	/// Return the checksum entries, in the order the server sent them.
+	pub fn entries(&self) -> &[CtdbEntry] { &self.entries }
+
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if there are neither entries nor metadata.
+	pub fn is_empty(&self) -> bool { self.entries.is_empty() && self.metadata.is_empty() }
+
+	#[must_use]
+	/// # Metadata.
+	///
+	/// Return the metadata blocks, if any.
+	pub fn metadata(&self) -> &[CtdbMetadata] { &self.metadata }
+
+	#[must_use]
+	/// # Pressing Offsets.
+	///
+	/// Return the distinct [pressing offsets](CtdbEntry::offset_samples) seen
+	/// across the entries, ordered by their combined confidence, highest
+	/// first. Ties go to the offset closest to zero, then the lower value.
+	///
+	/// An offset of zero — i.e. the same pressing as the lookup — is included
+	/// if any entries have it.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+	/// let xml = r#"<ctdb>
+	///   <entry confidence="5" trackcrcs="0a 0b" />
+	///   <entry confidence="9" offset="-588" trackcrcs="0c 0d" />
+	///   <entry confidence="2" trackcrcs="0e 0f" />
+	/// </ctdb>"#;
+	///
+	/// let res = toc.ctdb_parse_response(xml).unwrap();
+	/// assert_eq!(res.pressing_offsets(), [-588, 0]);
+	/// ```
+	pub fn pressing_offsets(&self) -> Vec<i32> {
+		let mut totals: BTreeMap<i32, u32> = BTreeMap::new();
+		for e in &self.entries {
+			*totals.entry(e.offset).or_insert(0) += u32::from(e.confidence);
+		}
+
+		let mut out: Vec<(i32, u32)> = totals.into_iter().collect();
+		out.sort_by(|a, b| b.1.cmp(&a.1)
+			.then_with(|| a.0.unsigned_abs().cmp(&b.0.unsigned_abs()))
+			.then_with(|| a.0.cmp(&b.0))
+		);
+		out.into_iter().map(|(k, _)| k).collect()
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # CTDB Entry.
+///
+/// This holds the details of a single `<entry>` from a CTDB lookup response,
+/// i.e. one distinct rip of the disc and the number of times it has been
+/// submitted.
+pub struct CtdbEntry {
+	/// # ID.
+	id: u32,
+
+	/// # Disc CRC32.
+	crc32: u32,
+
+	/// # Confidence.
+	confidence: u16,
+
+	/// # Parity Size.
+	npar: u16,
+
+	/// # Pressing Offset (Samples).
+	offset: i32,
+
+	/// # Parity Stride.
+	stride: u32,
+
+	/// # Parity Data.
+	parity: Option<CtdbParity>,
+
+	/// # Parity URL.
+	parity_url: Option<String>,
+
+	/// # TOC.
+	toc: String,
+
+	/// # Track CRC32s.
+	track_crcs: Vec<u32>,
+}
+
+impl CtdbEntry {
+	#[must_use]
+	/// # Confidence.
+	///
+	/// Return the number of submissions matching this entry.
+	pub const fn confidence(&self) -> u16 { self.confidence }
+
+	#[must_use]
+	/// # Disc CRC32.
+	///
+	/// Return the CRC32 of the entire disc.
+	pub const fn crc32(&self) -> u32 { self.crc32 }
+
+	#[must_use]
+	/// # ID.
+	///
+	/// Return the database's own ID for the entry.
+	pub const fn id(&self) -> u32 { self.id }
+
+	#[must_use]
+	/// # Parity Size.
+	///
+	/// Return the number of parity symbols per stride, i.e. `npar`.
+	pub const fn npar(&self) -> u16 { self.npar }
+
+	#[must_use]
+	/// # Pressing Offset.
+	///
+	/// Return the offset, in samples, between this entry's pressing and the
+	/// table of contents used for the lookup (`offset`). This is zero for
+	/// the same pressing, or if the server didn't say.
+	///
+	/// Shifting a rip by this many samples should line it up with the
+	/// entry's checksums.
+	pub const fn offset_samples(&self) -> i32 { self.offset }
+
+	#[must_use]
+	/// # Parity Data.
+	///
+	/// Return the parity data, if it was included inline (`parity`).
+	pub const fn parity(&self) -> Option<&CtdbParity> { self.parity.as_ref() }
+
+	#[must_use]
+	/// # Parity URL.
+	///
+	/// Return the (server-relative) location of the parity data, if the
+	/// server has it but didn't include it inline (`hasparity`).
+	pub fn parity_url(&self) -> Option<&str> { self.parity_url.as_deref() }
+
+	#[must_use]
+	/// # Parity Stride.
+	pub const fn stride(&self) -> u32 { self.stride }
+
+	#[must_use]
+	/// # TOC.
+	///
+	/// Return the raw `toc` attribute, formatted the same way as the `toc`
+	/// query parameter of the [lookup URL](Toc::ctdb_checksum_url). This may
+	/// differ from the table of contents used for the lookup when the match
+	/// was fuzzy.
+	pub fn toc(&self) -> &str { &self.toc }
+
+	#[must_use]
+	/// # Track CRC32s.
+	///
+	/// Return the CRC32 of each audio track, in order. A value of zero means
+	/// the checksum is unknown.
+	pub fn track_crcs(&self) -> &[u32] { &self.track_crcs }
+}
+
+impl CtdbEntry {
+	/// # From Tag.
+	///
+	/// Parse an entry from its tag, verifying the track count.
+	///
+	/// Like [`Toc::ctdb_parse_checksums`], entries missing a confidence or
+	/// track checksums are skipped, returning `None`.
+	fn from_tag(tag: &Tag, audio_len: usize) -> Result<Option<Self>, TocError> {
+		let Some((confidence, track_crcs)) = parse_entry(tag) else { return Ok(None); };
+		let confidence = confidence.trim().parse::<u16>().map_err(|_| TocError::Checksums)?;
+
+		let track_crcs = track_crcs
+			.split_ascii_whitespace()
+			.map(|v| u32::htou(v.as_bytes()))
+			.collect::<Option<Vec<u32>>>()
+			.ok_or(TocError::Checksums)?;
+		if track_crcs.len() != audio_len { return Err(TocError::Checksums); }
+
+		let id = parse_opt(tag, "id", |v| v.parse::<u32>().ok())?;
+		let crc32 = parse_opt(tag, "crc32", |v| u32::htou(v.as_bytes()))?;
+		let npar = parse_opt(tag, "npar", |v| v.parse::<u16>().ok())?;
+		let offset = parse_opt(tag, "offset", |v| v.parse::<i32>().ok())?;
+		let stride = parse_opt(tag, "stride", |v| v.parse::<u32>().ok())?;
+		let toc = tag.attr("toc").map_or_else(String::new, |v| v.trim().to_owned());
+
+		let parity = match tag.attr("parity") {
+			Some(v) if ! v.trim().is_empty() => Some(CtdbParity::from_base64(npar, stride, &v)?),
+			_ => None,
+		};
+		let parity_url = tag.attr("hasparity")
+			.map(|v| v.trim().to_owned())
+			.filter(|v| ! v.is_empty());
+
+		Ok(Some(Self {
+			id, crc32, confidence, npar, offset, stride, parity, parity_url, toc,
+			track_crcs,
+		}))
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # CTDB Entry (Borrowed).
+///
+/// This is a lightweight view of a single `<entry>` from a CTDB lookup
+/// response, holding just the values needed for verification. The track
+/// checksums are validated up front but left in their original string form.
+///
+/// Values of this type are yielded by [`Toc::ctdb_entries`].
+pub struct CtdbEntryRef<'a> {
+	/// # Confidence.
+	confidence: u16,
+
+	/// # Track CRC32s (Raw).
+	///
+	/// This is guaranteed to hold one valid hex value per audio track.
+	crcs: Cow<'a, str>,
+
+	/// # TOC.
+	toc: Option<Cow<'a, str>>,
+}
+
+impl<'a> CtdbEntryRef<'a> {
+	/// # New.
+	///
+	/// Parse the confidence and verify the checksum list has one valid value
+	/// for each of `audio_len` tracks.
+	pub(super) fn new(
+		confidence: &str,
+		crcs: Cow<'a, str>,
+		toc: Option<Cow<'a, str>>,
+		audio_len: usize,
+	) -> Result<Self, TocError> {
+		let confidence: u16 = confidence.trim().parse().map_err(|_| TocError::Checksums)?;
+
+		let mut len = 0;
+		for chk in crcs.split_ascii_whitespace() {
+			if u32::htou(chk.as_bytes()).is_none() { return Err(TocError::Checksums); }
+			len += 1;
+		}
+		if len != audio_len { return Err(TocError::Checksums); }
+
+		Ok(Self { confidence, crcs, toc })
+	}
+
+	#[must_use]
+	/// # Confidence.
+	///
+	/// Return the number of submissions matching this entry.
+	pub const fn confidence(&self) -> u16 { self.confidence }
+
+	#[must_use]
+	/// # TOC.
+	///
+	/// Return the raw `toc` attribute, if any. See [`CtdbEntry::toc`].
+	pub fn toc(&self) -> Option<&str> { self.toc.as_deref().map(str::trim) }
+
+	/// # Track CRC32s.
+	///
+	/// Return an iterator over the CRC32 of each audio track, in order. A
+	/// value of zero means the checksum is unknown.
+	pub fn track_crcs(&self) -> impl Iterator<Item=u32> + '_ {
+		self.crcs.split_ascii_whitespace()
+			.map(|v| u32::htou(v.as_bytes()).unwrap_or_default())
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # CTDB Metadata.
+///
+/// This holds the details of a single `<metadata>` block from a CTDB lookup
+/// response. CTDB aggregates these from other sources (MusicBrainz,
+/// Discogs, freedb, etc.), so a response may contain several.
+pub struct CtdbMetadata {
+	/// # Source.
+	source: String,
+
+	/// # Source ID.
+	id: String,
+
+	/// # Artist.
+	artist: String,
+
+	/// # Album.
+	album: String,
+
+	/// # Year.
+	year: Option<u16>,
+
+	/// # Genre.
+	genre: Option<String>,
+
+	/// # Disc Number.
+	disc_number: Option<u16>,
+
+	/// # Disc Count.
+	disc_count: Option<u16>,
+
+	/// # Relevance.
+	relevance: Option<u8>,
+
+	/// # Track Titles.
+	tracks: Vec<String>,
+}
+
+impl CtdbMetadata {
+	#[must_use]
+	/// # Album.
+	pub fn album(&self) -> &str { &self.album }
+
+	#[must_use]
+	/// # Artist.
+	pub fn artist(&self) -> &str { &self.artist }
+
+	#[must_use]
+	/// # Disc Count.
+	pub const fn disc_count(&self) -> Option<u16> { self.disc_count }
+
+	#[must_use]
+	/// # Disc Number.
+	pub const fn disc_number(&self) -> Option<u16> { self.disc_number }
+
+	#[must_use]
+	/// # Genre.
+	pub fn genre(&self) -> Option<&str> { self.genre.as_deref() }
+
+	#[must_use]
+	/// # Source ID.
+	///
+	/// Return the release ID used by the [source](CtdbMetadata::source).
+	pub fn id(&self) -> &str { &self.id }
+
+	#[must_use]
+	/// # Relevance.
+	///
+	/// Return the server's relevance score (`0..=100`), if any.
+	pub const fn relevance(&self) -> Option<u8> { self.relevance }
+
+	#[must_use]
+	/// # Source.
+	///
+	/// Return the name of the metadata source, e.g. `musicbrainz`.
+	pub fn source(&self) -> &str { &self.source }
+
+	#[must_use]
+	/// # Track Titles.
+	pub fn tracks(&self) -> &[String] { &self.tracks }
+
+	#[must_use]
+	/// # Year.
+	pub const fn year(&self) -> Option<u16> { self.year }
+}
+
+impl CtdbMetadata {
+	/// # From Tag.
+	fn from_tag(tag: &Tag) -> Self {
+		let text = |k: &str| tag.attr(k).map_or_else(String::new, |v| v.trim().to_owned());
+		let num = |k: &str| tag.attr(k).and_then(|v| v.trim().parse::<u16>().ok());
+
+		Self {
+			source: text("source"),
+			id: text("id"),
+			artist: text("artist"),
+			album: text("album"),
+			year: num("year").filter(|v| *v != 0),
+			genre: Some(text("genre")).filter(|v| ! v.is_empty()),
+			disc_number: num("discnumber"),
+			disc_count: num("disccount"),
+			relevance: tag.attr("relevance").and_then(|v| v.trim().parse::<u8>().ok()),
+			tracks: Vec::new(),
+		}
+	}
+}
+
+
+
+impl Toc {
+	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+	/// # Parse Response.
+	///
+	/// This will parse all of the entries and metadata from an XML CTDB
+	/// [lookup](Toc::ctdb_checksum_url) response.
+	///
+	/// If you only need the track checksums, [`Toc::ctdb_parse_checksums`] is
+	/// lighter.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+	/// <ctdb xmlns="http://db.cuetools.net/ns/mmd-1.0#">
+	///   <entry confidence="7" crc32="5fe36f4e" id="1287443" npar="8" stride="5880" toc="0:11413:25024:45713:55220" trackcrcs="a1b2c3d4 0badf00d 12345678 deadbeef" />
+	///   <metadata source="musicbrainz" id="abc" artist="Simon &amp; Garfunkel" album="Bookends" year="1968" relevance="100">
+	///     <track name="Save the Life of My Child" />
+	///   </metadata>
+	/// </ctdb>"#;
+	///
+	/// let res = toc.ctdb_parse_response(xml).unwrap();
+	/// let entry = &res.entries()[0];
+	/// assert_eq!(entry.confidence(), 7);
+	/// assert_eq!(entry.crc32(), 0x5fe3_6f4e);
+	/// assert_eq!(entry.track_crcs()[3], 0xdead_beef);
+	///
+	/// let meta = &res.metadata()[0];
+	/// assert_eq!(meta.artist(), "Simon & Garfunkel");
+	/// assert_eq!(meta.year(), Some(1968));
+	/// assert_eq!(meta.tracks(), ["Save the Life of My Child"]);
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// Like [`Toc::ctdb_parse_checksums`], this uses naive parsing so does
+	/// not worry about strict XML validation, but will return an error if a
+	/// tag is left unterminated or an entry is malformed or has the wrong
+	/// number of tracks — [`TocError::CtdbTag`], with the line number — or
+	/// nothing at all is found. Entries missing a confidence or track
+	/// checksums are skipped.
+	pub fn ctdb_parse_response(&self, xml: &str) -> Result<CtdbResponse, TocError> {
+		let audio_len = self.audio_len();
+		let mut out = CtdbResponse::default();
+		let mut in_meta = false;
+
+		for tag in Tags::new(xml) {
+			let tag = tag.map_err(TocError::CtdbTag)?;
+			match (tag.local_name(), tag.is_closing()) {
+				("entry", false) => {
+					match CtdbEntry::from_tag(&tag, audio_len) {
+						Ok(Some(entry)) => { out.entries.push(entry); },
+						Ok(None) => {},
+						Err(TocError::Checksums) => return Err(TocError::CtdbTag(tag.line(xml))),
+						Err(e) => return Err(e),
+					}
+				},
+				("metadata", false) => {
+					out.metadata.push(CtdbMetadata::from_tag(&tag));
+					in_meta = ! tag.is_self_closing();
+				},
+				("metadata", true) => { in_meta = false; },
+				("track", false) if in_meta => {
+					if let Some(meta) = out.metadata.last_mut() {
+						meta.tracks.push(
+							tag.attr("name").map_or_else(String::new, |v| v.trim().to_owned())
+						);
+					}
+				},
+				_ => {},
+			}
+		}
+
+		if out.is_empty() { Err(TocError::NoChecksums) }
+		else { Ok(out) }
+	}
+}
+
+
+
+/// # Parse Optional Attribute.
+///
+/// Parse the attribute with the callback if present, returning the default
+/// value if not, or an error if present but invalid.
+fn parse_opt<T, F>(tag: &Tag, name: &str, cb: F) -> Result<T, TocError>
+where T: Default, F: Fn(&str) -> Option<T> {
+	tag.attr(name).map_or_else(
+		|| Ok(T::default()),
+		|v| cb(v.trim()).ok_or(TocError::Checksums),
+	)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// # Sample Response.
+	///
+	/// This includes non-ASCII and escaped metadata, and an entry with its
+	/// attributes wrapped across lines.
+	///
+	/// Note: this is hand-written to follow the `lookup2.php` (version 3)
+	/// format rather than captured from the server, so the IDs, checksums,
+	/// and metadata are made up.
+	const SAMPLE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<ctdb xmlns="http://db.cuetools.net/ns/mmd-1.0#" xmlns:ext="http://db.cuetools.net/ns/ext-1.0#">
+  <entry confidence="12" crc32="5fe36f4e" hasparity="/parity/1287443" id="1287443" npar="8" stride="5880" syndrome="" toc="0:11413:25024:45713:55220" trackcrcs="a1b2c3d4 0badf00d 12345678 deadbeef" />
+  <entry confidence="1" crc32="00ff00ff" id="1301122"
+         npar="16" stride="5880" toc="0:11413:25024:45713:55220"
+         trackcrcs="a1b2c3d4 00000000 12345678 c0ffee00" />
+  <metadata source="musicbrainz" id="2e4c8d2c-b1c8-4e1e-9a4b-5c7b2e8a1f00" artist="Sigur Rós" album="Ágætis byrjun &amp; &quot;Más&quot;" year="1999" genre="Post-Rock" discnumber="1" disccount="1" relevance="97">
+    <track name="Intro" />
+    <track name="Svefn-g-englar" />
+    <track name="Starálfur" />
+    <track name="Flugufrelsarinn" />
+    <label name="Smekkleysa" catno="SM 88" />
+    <release country="IS" date="1999-06-12" />
+  </metadata>
+  <metadata source="freedb" id="rock/1f02e004" artist="Sigur Ros" album="Agaetis Byrjun" year="0" />
+</ctdb>"#;
+
+	#[test]
+	fn t_parse_response() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let res = toc.ctdb_parse_response(SAMPLE).expect("Parse failed.");
+		assert!(! res.is_empty());
+
+		// Entries.
+		assert_eq!(res.entries().len(), 2);
+		let entry = &res.entries()[0];
+		assert_eq!(entry.id(), 1_287_443);
+		assert_eq!(entry.confidence(), 12);
+		assert_eq!(entry.crc32(), 0x5fe3_6f4e);
+		assert_eq!(entry.npar(), 8);
+		assert_eq!(entry.stride(), 5880);
+		assert!(entry.parity().is_none());
+		assert_eq!(entry.parity_url(), Some("/parity/1287443"));
+		assert_eq!(entry.toc(), "0:11413:25024:45713:55220");
+		assert_eq!(entry.track_crcs(), [0xa1b2_c3d4, 0x0bad_f00d, 0x1234_5678, 0xdead_beef]);
+
+		let entry = &res.entries()[1];
+		assert_eq!(entry.id(), 1_301_122);
+		assert_eq!(entry.confidence(), 1);
+		assert_eq!(entry.npar(), 16);
+		assert!(entry.parity_url().is_none());
+		assert_eq!(entry.track_crcs(), [0xa1b2_c3d4, 0, 0x1234_5678, 0xc0ff_ee00]);
+
+		// Metadata.
+		assert_eq!(res.metadata().len(), 2);
+		let meta = &res.metadata()[0];
+		assert_eq!(meta.source(), "musicbrainz");
+		assert_eq!(meta.id(), "2e4c8d2c-b1c8-4e1e-9a4b-5c7b2e8a1f00");
+		assert_eq!(meta.artist(), "Sigur Rós");
+		assert_eq!(meta.album(), "Ágætis byrjun & \"Más\"");
+		assert_eq!(meta.year(), Some(1999));
+		assert_eq!(meta.genre(), Some("Post-Rock"));
+		assert_eq!(meta.disc_number(), Some(1));
+		assert_eq!(meta.disc_count(), Some(1));
+		assert_eq!(meta.relevance(), Some(97));
+		assert_eq!(meta.tracks(), ["Intro", "Svefn-g-englar", "Starálfur", "Flugufrelsarinn"]);
+
+		let meta = &res.metadata()[1];
+		assert_eq!(meta.source(), "freedb");
+		assert_eq!(meta.year(), None);
+		assert_eq!(meta.genre(), None);
+		assert_eq!(meta.relevance(), None);
+		assert!(meta.tracks().is_empty());
+	}
+
+	#[test]
+	fn t_parse_response_bad() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+
+		// Nothing.
+		assert_eq!(
+			toc.ctdb_parse_response(r#"<?xml version="1.0"?><ctdb></ctdb>"#),
+			Err(TocError::NoChecksums),
+		);
+
+		for bad in [
+			// Wrong track count.
+			r#"<ctdb><entry confidence="1" trackcrcs="01 02 03" /></ctdb>"#,
+			// Bad CRC.
+			r#"<ctdb><entry confidence="1" trackcrcs="01 02 03 nope" /></ctdb>"#,
+			// Bad optional value.
+			r#"<ctdb><entry confidence="1" npar="lots" trackcrcs="01 02 03 04" /></ctdb>"#,
+			// Bad offset.
+			r#"<ctdb><entry confidence="1" offset="+-3" trackcrcs="01 02 03 04" /></ctdb>"#,
+		] {
+			assert_eq!(toc.ctdb_parse_response(bad), Err(TocError::CtdbTag(1)), "{bad}");
+		}
+
+		// The line number is that of the offending tag.
+		assert_eq!(
+			toc.ctdb_parse_response("<ctdb>\n<entry confidence=\"1\" trackcrcs=\"01 02 03 04\" />\n<entry\n confidence=\"1\" trackcrcs=\"01 02 03\" />\n</ctdb>"),
+			Err(TocError::CtdbTag(3)),
+		);
+
+		// Entries missing a confidence or checksums are skipped.
+		for skip in [
+			r#"<ctdb><entry trackcrcs="01 02 03 04" /></ctdb>"#,
+			r#"<ctdb><entry confidence="1" trackcrcs="" /></ctdb>"#,
+		] {
+			assert_eq!(toc.ctdb_parse_response(skip), Err(TocError::NoChecksums), "{skip}");
+		}
+
+		// Unterminated.
+		assert_eq!(
+			toc.ctdb_parse_response(r#"<ctdb><entry confidence="1" trackcrcs="01 02 03 04""#),
+			Err(TocError::CtdbTag(1)),
+		);
+	}
+
+	#[test]
+	fn t_pressing_offsets() {
+		// A disc with several pressings: the lookup TOC's own, one a frame
+		// earlier, and one slightly later.
+		//
+		// Note: this response is hand-written, not captured from the server;
+		// the IDs, checksums, and offsets are made up.
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<ctdb xmlns="http://db.cuetools.net/ns/mmd-1.0#" xmlns:ext="http://db.cuetools.net/ns/ext-1.0#">
+  <entry confidence="41" crc32="5fe36f4e" id="1287443" npar="8" stride="5880" toc="0:11413:25024:45713:55220" trackcrcs="a1b2c3d4 0badf00d 12345678 deadbeef" />
+  <entry confidence="37" crc32="0e4f8a21" id="1411870" npar="8" offset="-588" stride="5880" toc="0:11413:25024:45713:55220" trackcrcs="7c1d22e0 5a5a0f0f 0cafe123 3b9a1e47" />
+  <entry confidence="12" crc32="9d2c4b10" id="1502311" npar="8" offset="1176" stride="5880" toc="0:11413:25024:45713:55220" trackcrcs="11223344 55667788 99aabbcc ddeeff00" />
+  <entry confidence="6" crc32="77e01a3c" id="1530007" npar="8" offset="-588" stride="5880" toc="0:11413:25024:45713:55220" trackcrcs="7c1d22e0 5a5a0f0f 0cafe123 00000000" />
+  <entry confidence="1" crc32="00ff00ff" id="1601122" npar="16" offset="588" stride="5880" toc="0:11413:25024:45713:55220" trackcrcs="a1b2c3d4 00000000 12345678 c0ffee00" />
+</ctdb>"#;
+
+		let res = toc.ctdb_parse_response(xml).expect("Parse failed.");
+		let offsets: Vec<i32> = res.entries().iter().map(CtdbEntry::offset_samples).collect();
+		assert_eq!(offsets, [0, -588, 1176, -588, 588]);
+
+		// -588 wins with 37 + 6.
+		assert_eq!(res.pressing_offsets(), [-588, 0, 1176, 588]);
+
+		// Ties go to the smaller magnitude, then the lower value.
+		let xml = r#"<ctdb>
+  <entry confidence="3" offset="900" trackcrcs="01 02 03 04" />
+  <entry confidence="3" offset="-5" trackcrcs="01 02 03 04" />
+  <entry confidence="3" offset="5" trackcrcs="01 02 03 04" />
+  <entry confidence="3" trackcrcs="01 02 03 04" />
+</ctdb>"#;
+		let res = toc.ctdb_parse_response(xml).expect("Parse failed.");
+		assert_eq!(res.pressing_offsets(), [0, -5, 5, 900]);
+
+		// Nothing.
+		assert!(CtdbResponse::default().pressing_offsets().is_empty());
+	}
+
+	#[test]
+	fn t_parse_response_parity() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let bytes: Vec<u8> = (0..4 * 3 * 2 * 2_u8).map(|n| n.wrapping_mul(37)).collect();
+		let parity = CtdbParity::from_bytes(4, 3, &bytes).expect("Invalid parity.");
+		let b64 = super::super::b64::encode(&bytes);
+
+		let raw = format!(
+			r#"<ctdb><entry confidence="2" npar="4" stride="3" parity="{b64}" trackcrcs="01 02 03 04" /></ctdb>"#
+		);
+		let res = toc.ctdb_parse_response(&raw).expect("Parse failed.");
+		let entry = &res.entries()[0];
+		assert_eq!(entry.parity(), Some(&parity));
+		assert!(entry.parity_url().is_none());
+
+		// The length has to match npar and stride.
+		let raw = format!(
+			r#"<ctdb><entry confidence="2" npar="8" stride="3" parity="{b64}" trackcrcs="01 02 03 04" /></ctdb>"#
+		);
+		assert_eq!(toc.ctdb_parse_response(&raw), Err(TocError::CtdbParity));
+	}
+}
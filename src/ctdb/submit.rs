@@ -0,0 +1,375 @@
+/*!
+# CDTOC: CTDB Submission
+*/
+
+use crate::{
+	Toc,
+	TocError,
+};
+use std::fmt::Write;
+
+
+
+/// # Submission URL.
+const SUBMIT_URL: &str = "https://db.cuetools.net/submit2.php";
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # CTDB Submission.
+///
+/// This holds the rip details used by [`Toc::ctdb_submit_request`] to build
+/// a CTDB submission.
+///
+/// The checksums should come from a [`CtdbDiscChecksummer`](crate::CtdbDiscChecksummer).
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::CtdbSubmission;
+///
+/// let sub = CtdbSubmission::new(vec![0xa1b2_c3d4, 0x0bad_f00d], 0x5fe3_6f4e)
+///     .with_drive_name("PLEXTOR DVDR PX-716A")
+///     .with_quality(95);
+/// assert_eq!(sub.confidence(), 1);
+/// assert_eq!(sub.quality(), 95);
+/// ```
+pub struct CtdbSubmission {
+	/// # Track CRC32s.
+	track_crcs: Vec<u32>,
+
+	/// # Disc CRC32.
+	crc32: u32,
+
+	/// # Confidence.
+	confidence: u16,
+
+	/// # Quality.
+	quality: u8,
+
+	/// # Parity.
+	parity: Option<(u16, Vec<u8>)>,
+
+	/// # Drive Name.
+	drive_name: Option<String>,
+
+	/// # User Agent.
+	user_agent: String,
+}
+
+impl CtdbSubmission {
+	/// # Default User Agent.
+	pub const DEFAULT_USER_AGENT: &'static str = concat!("cdtoc/", env!("CARGO_PKG_VERSION"));
+
+	#[must_use]
+	/// # New.
+	///
+	/// Start a new submission from the per-track and whole-disc CRC32s. The
+	/// confidence defaults to `1` and the quality to `100`.
+	pub fn new(track_crcs: Vec<u32>, crc32: u32) -> Self {
+		Self {
+			track_crcs,
+			crc32,
+			confidence: 1,
+			quality: 100,
+			parity: None,
+			drive_name: None,
+			user_agent: Self::DEFAULT_USER_AGENT.to_owned(),
+		}
+	}
+
+	#[must_use]
+	/// # With Confidence.
+	///
+	/// Set the number of matching rips this submission represents.
+	pub const fn with_confidence(mut self, confidence: u16) -> Self {
+		self.confidence = confidence;
+		self
+	}
+
+	#[must_use]
+	/// # With Drive Name.
+	pub fn with_drive_name<S: Into<String>>(mut self, drive_name: S) -> Self {
+		self.drive_name = Some(drive_name.into());
+		self
+	}
+
+	#[must_use]
+	/// # With Parity.
+	///
+	/// Attach the raw parity data and the number of parity symbols (`npar`)
+	/// used to generate it.
+	pub fn with_parity(mut self, npar: u16, parity: Vec<u8>) -> Self {
+		self.parity = Some((npar, parity));
+		self
+	}
+
+	#[must_use]
+	/// # With Quality.
+	///
+	/// Set the rip quality as a percentage. Values above `100` are clamped.
+	pub fn with_quality(mut self, quality: u8) -> Self {
+		self.quality = quality.min(100);
+		self
+	}
+
+	#[must_use]
+	/// # With User Agent.
+	pub fn with_user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+		self.user_agent = user_agent.into();
+		self
+	}
+
+	#[must_use]
+	/// # Confidence.
+	pub const fn confidence(&self) -> u16 { self.confidence }
+
+	#[must_use]
+	/// # Disc CRC32.
+	pub const fn crc32(&self) -> u32 { self.crc32 }
+
+	#[must_use]
+	/// # Drive Name.
+	pub fn drive_name(&self) -> Option<&str> { self.drive_name.as_deref() }
+
+	#[must_use]
+	/// # Parity.
+	///
+	/// Return the `npar` value and raw parity data, if any.
+	pub fn parity(&self) -> Option<(u16, &[u8])> {
+		self.parity.as_ref().map(|(n, p)| (*n, p.as_slice()))
+	}
+
+	#[must_use]
+	/// # Quality.
+	pub const fn quality(&self) -> u8 { self.quality }
+
+	#[must_use]
+	/// # Track CRC32s.
+	pub fn track_crcs(&self) -> &[u32] { &self.track_crcs }
+
+	#[must_use]
+	/// # User Agent.
+	pub fn user_agent(&self) -> &str { &self.user_agent }
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # CTDB Request.
+///
+/// This holds everything needed to send a CTDB submission with the HTTP
+/// client of your choice: a `POST` to [`CtdbRequest::url`] with a
+/// [`CtdbRequest::CONTENT_TYPE`] body of [`CtdbRequest::body`].
+///
+/// See [`Toc::ctdb_submit_request`] for more details.
+pub struct CtdbRequest {
+	/// # URL.
+	url: String,
+
+	/// # Form Fields.
+	fields: Vec<(&'static str, String)>,
+
+	/// # Encoded Body.
+	body: String,
+}
+
+impl CtdbRequest {
+	/// # Content Type.
+	pub const CONTENT_TYPE: &'static str = "application/x-www-form-urlencoded";
+
+	#[must_use]
+	/// # Body.
+	///
+	/// Return the form fields, URL-encoded.
+	pub fn body(&self) -> &str { &self.body }
+
+	#[must_use]
+	/// # Form Fields.
+	///
+	/// Return the (unencoded) form fields as ordered key/value pairs, for
+	/// clients that prefer to do their own encoding.
+	pub fn fields(&self) -> &[(&'static str, String)] { &self.fields }
+
+	#[must_use]
+	/// # URL.
+	pub fn url(&self) -> &str { &self.url }
+}
+
+
+
+impl Toc {
+	#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+	/// # CTDB Submission Request.
+	///
+	/// Build the HTTP request needed to submit a rip to the CUETools
+	/// Database. No networking is done here; the request can be sent with any
+	/// client.
+	///
+	/// The form fields are, in order:
+	///
+	/// | Field | Value |
+	/// | ----- | ----- |
+	/// | `toc` | The table of contents, formatted as in the [lookup URL](Toc::ctdb_checksum_url). |
+	/// | `crc32` | The whole-disc CRC32, as eight lowercase hex digits. |
+	/// | `trackcrcs` | The track CRC32s, as space-separated eight-digit hex. |
+	/// | `confidence` | The confidence. |
+	/// | `quality` | The quality (`0..=100`). |
+	/// | `npar` | The number of parity symbols, if parity is included. |
+	/// | `parity` | The base64-encoded parity data, if included. |
+	/// | `drivename` | The drive name, if known. |
+	/// | `useragent` | The client name and version. |
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{CtdbRequest, CtdbSubmission, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let sub = CtdbSubmission::new(
+	///     vec![0xa1b2_c3d4, 0x0bad_f00d, 0x1234_5678, 0xdead_beef],
+	///     0x5fe3_6f4e,
+	/// )
+	///     .with_drive_name("PLEXTOR DVDR PX-716A")
+	///     .with_user_agent("MyRipper/1.0");
+	///
+	/// let req = toc.ctdb_submit_request(&sub).unwrap();
+	/// assert_eq!(req.url(), "https://db.cuetools.net/submit2.php");
+	/// assert_eq!(CtdbRequest::CONTENT_TYPE, "application/x-www-form-urlencoded");
+	/// assert_eq!(
+	///     req.body(),
+	///     "toc=0%3A11413%3A25024%3A45713%3A55220&crc32=5fe36f4e&trackcrcs=a1b2c3d4+0badf00d+12345678+deadbeef&confidence=1&quality=100&drivename=PLEXTOR+DVDR+PX-716A&useragent=MyRipper%2F1.0",
+	/// );
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the number of track checksums does not
+	/// match the number of audio tracks.
+	pub fn ctdb_submit_request(&self, sub: &CtdbSubmission) -> Result<CtdbRequest, TocError> {
+		let audio_len = self.audio_len();
+		if sub.track_crcs.len() != audio_len {
+			return Err(TocError::ChecksumCount(
+				u8::try_from(audio_len).unwrap_or(u8::MAX),
+				sub.track_crcs.len(),
+			));
+		}
+
+		let mut toc = String::with_capacity(128);
+		self.push_ctdb_toc(&mut toc);
+
+		let mut crcs = String::with_capacity(sub.track_crcs.len() * 9);
+		for (k, v) in sub.track_crcs.iter().enumerate() {
+			if k != 0 { crcs.push(' '); }
+			let _res = write!(crcs, "{v:08x}");
+		}
+
+		let mut fields = vec![
+			("toc", toc),
+			("crc32", format!("{:08x}", sub.crc32)),
+			("trackcrcs", crcs),
+			("confidence", sub.confidence.to_string()),
+			("quality", sub.quality.to_string()),
+		];
+		if let Some((npar, parity)) = &sub.parity {
+			fields.push(("npar", npar.to_string()));
+			fields.push(("parity", super::b64::encode(parity)));
+		}
+		if let Some(drive) = &sub.drive_name {
+			fields.push(("drivename", drive.clone()));
+		}
+		fields.push(("useragent", sub.user_agent.clone()));
+
+		let mut body = String::with_capacity(256);
+		for (k, v) in &fields {
+			if ! body.is_empty() { body.push('&'); }
+			body.push_str(k);
+			body.push('=');
+			push_form_encoded(v, &mut body);
+		}
+
+		Ok(CtdbRequest { url: SUBMIT_URL.to_owned(), fields, body })
+	}
+}
+
+
+
+/// # Push Form-Encoded.
+///
+/// Append the value to the buffer using `application/x-www-form-urlencoded`
+/// rules: spaces become `+`, and anything else outside the unreserved set is
+/// percent-encoded.
+fn push_form_encoded(src: &str, out: &mut String) {
+	/// # Hex Digits.
+	const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+	for b in src.bytes() {
+		if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'*') {
+			out.push(char::from(b));
+		}
+		else if b == b' ' { out.push('+'); }
+		else {
+			out.push('%');
+			out.push(char::from(HEX[usize::from(b >> 4)]));
+			out.push(char::from(HEX[usize::from(b & 0x0f)]));
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_submit() {
+		// CD-Extra, with parity and an awkward drive name.
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC.");
+		let sub = CtdbSubmission::new((1..=10).collect(), 0xffff_0000)
+			.with_confidence(3)
+			.with_quality(250)
+			.with_parity(8, b"foobar".to_vec())
+			.with_drive_name("HL-DT-ST BD-RE  WH16NS40 (1.02)");
+		assert_eq!(sub.quality(), 100);
+		assert_eq!(sub.user_agent(), CtdbSubmission::DEFAULT_USER_AGENT);
+		assert_eq!(sub.parity(), Some((8, b"foobar".as_slice())));
+
+		let req = toc.ctdb_submit_request(&sub).expect("Request failed.");
+		assert_eq!(req.url(), SUBMIT_URL);
+
+		let mut url = String::new();
+		toc.push_ctdb_toc(&mut url);
+		assert!(toc.ctdb_checksum_url().ends_with(&format!("toc={url}")));
+
+		assert_eq!(
+			req.fields().iter().map(|(k, _)| *k).collect::<Vec<_>>(),
+			["toc", "crc32", "trackcrcs", "confidence", "quality", "npar", "parity", "drivename", "useragent"],
+		);
+		assert_eq!(req.fields()[0].1, "0:14017:26839:50617:67965:85260:105970:121620:135950:161720:-186137:224891");
+		assert_eq!(req.fields()[1].1, "ffff0000");
+		assert_eq!(req.fields()[2].1, "00000001 00000002 00000003 00000004 00000005 00000006 00000007 00000008 00000009 0000000a");
+		assert_eq!(req.fields()[3].1, "3");
+		assert_eq!(req.fields()[4].1, "100");
+		assert_eq!(req.fields()[5].1, "8");
+		assert_eq!(req.fields()[6].1, "Zm9vYmFy");
+		assert_eq!(
+			req.body(),
+			concat!(
+				"toc=0%3A14017%3A26839%3A50617%3A67965%3A85260%3A105970%3A121620%3A135950%3A161720%3A-186137%3A224891",
+				"&crc32=ffff0000",
+				"&trackcrcs=00000001+00000002+00000003+00000004+00000005+00000006+00000007+00000008+00000009+0000000a",
+				"&confidence=3&quality=100&npar=8&parity=Zm9vYmFy",
+				"&drivename=HL-DT-ST+BD-RE++WH16NS40+%281.02%29",
+				"&useragent=cdtoc%2F", env!("CARGO_PKG_VERSION"),
+			),
+		);
+
+		// Wrong track count.
+		let sub = CtdbSubmission::new(vec![1, 2, 3], 0);
+		assert_eq!(toc.ctdb_submit_request(&sub), Err(TocError::ChecksumCount(10, 3)));
+	}
+}
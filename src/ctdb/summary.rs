@@ -0,0 +1,218 @@
+/*!
+# CDTOC: CTDB Summary
+*/
+
+use std::collections::BTreeMap;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ctdb")))]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # CTDB Track Summary.
+///
+/// This holds the highlights of a single track's CTDB checksums: the best
+/// (highest-confidence) CRC, its confidence, the total confidence across all
+/// entries, and the number of distinct CRCs.
+///
+/// When two or more CRCs tie for the highest confidence, the lowest CRC wins,
+/// so results are deterministic. Tracks without any checksums have no best
+/// CRC, zero confidence, and an [agreement](CtdbTrackSummary::agreement) of
+/// zero.
+///
+/// Values of this type can be built from the maps returned by
+/// [`Toc::ctdb_parse_checksums`](crate::Toc::ctdb_parse_checksums), either
+/// individually via [`From`] or all at once via
+/// [`CtdbTrackSummary::summarize`].
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::CtdbTrackSummary;
+/// use std::collections::BTreeMap;
+///
+/// let map: BTreeMap<u32, u16> = [(7, 100), (3, 100), (5, 50)].into_iter().collect();
+/// let summary = CtdbTrackSummary::from(&map);
+///
+/// // A tie; the lower CRC wins.
+/// assert_eq!(summary.best_crc(), Some(3));
+/// assert_eq!(summary.best_confidence(), 100);
+/// assert_eq!(summary.total_confidence(), 250);
+/// assert_eq!(summary.distinct_crcs(), 3);
+/// assert_eq!(summary.agreement(), 0.4);
+/// ```
+pub struct CtdbTrackSummary {
+	/// # Best CRC.
+	///
+	/// This is zero if there are no entries.
+	best_crc: u32,
+
+	/// # Best Confidence.
+	best_confidence: u16,
+
+	/// # Total Confidence.
+	total_confidence: u32,
+
+	/// # Distinct CRCs.
+	distinct_crcs: usize,
+}
+
+impl From<&BTreeMap<u32, u16>> for CtdbTrackSummary {
+	fn from(src: &BTreeMap<u32, u16>) -> Self {
+		let mut out = Self::default();
+		for (&crc, &conf) in src {
+			// Zero isn't a real CRC.
+			if crc == 0 { continue; }
+
+			// Maps are sorted by CRC, so only strictly better confidences
+			// should replace the current best.
+			if out.best_crc == 0 || out.best_confidence < conf {
+				out.best_crc = crc;
+				out.best_confidence = conf;
+			}
+			out.total_confidence += u32::from(conf);
+			out.distinct_crcs += 1;
+		}
+		out
+	}
+}
+
+impl CtdbTrackSummary {
+	#[must_use]
+	/// # Summarize.
+	///
+	/// Return a summary for each track in the output of
+	/// [`Toc::ctdb_parse_checksums`](crate::Toc::ctdb_parse_checksums),
+	/// indexed by track number (`n-1`).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{CtdbTrackSummary, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("2+96+1F4+2EE").unwrap();
+	/// let xml = r#"<ctdb>
+	///   <entry confidence="197" trackcrcs="0000000a 0000000b" />
+	///   <entry confidence="15" trackcrcs="0000000a 0000000c" />
+	/// </ctdb>"#;
+	///
+	/// let parsed = toc.ctdb_parse_checksums(xml).unwrap();
+	/// let summary = CtdbTrackSummary::summarize(&parsed);
+	/// assert_eq!(summary.len(), 2);
+	///
+	/// assert_eq!(summary[0].best_crc(), Some(10));
+	/// assert_eq!(summary[0].agreement(), 1.0);
+	///
+	/// // Track 2: 93% of 212 submissions agree.
+	/// assert_eq!(summary[1].best_crc(), Some(11));
+	/// assert_eq!(summary[1].best_confidence(), 197);
+	/// assert_eq!(summary[1].total_confidence(), 212);
+	/// assert_eq!((summary[1].agreement() * 100.0).round(), 93.0);
+	/// ```
+	pub fn summarize(parsed: &[BTreeMap<u32, u16>]) -> Vec<Self> {
+		parsed.iter().map(Self::from).collect()
+	}
+
+	#[must_use]
+	/// # Agreement.
+	///
+	/// Return the share of the total confidence held by the best CRC, from
+	/// `0.0` to `1.0`. Tracks without any checksums return `0.0`.
+	pub fn agreement(&self) -> f32 {
+		if self.total_confidence == 0 { 0.0 }
+		else {
+			#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+			{
+				(f64::from(self.best_confidence) / f64::from(self.total_confidence)) as f32
+			}
+		}
+	}
+
+	#[must_use]
+	/// # Best CRC.
+	///
+	/// Return the highest-confidence CRC, or `None` if the track has no
+	/// entries. Ties go to the lowest CRC.
+	pub const fn best_crc(&self) -> Option<u32> {
+		if self.best_crc == 0 { None }
+		else { Some(self.best_crc) }
+	}
+
+	#[must_use]
+	/// # Best Confidence.
+	///
+	/// Return the confidence of the best CRC.
+	pub const fn best_confidence(&self) -> u16 { self.best_confidence }
+
+	#[must_use]
+	/// # Distinct CRCs.
+	///
+	/// Return the number of different CRCs on record for the track.
+	pub const fn distinct_crcs(&self) -> usize { self.distinct_crcs }
+
+	#[must_use]
+	/// # Total Confidence.
+	///
+	/// Return the sum of all confidences for the track. Unlike the parsed
+	/// maps, this does not saturate.
+	pub const fn total_confidence(&self) -> u32 { self.total_confidence }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_summarize() {
+		let parsed: Vec<BTreeMap<u32, u16>> = vec![
+			// Clear winner.
+			[(0xdead_beef, 300), (0x1234_5678, 100)].into_iter().collect(),
+			// Three-way tie.
+			[(9, 5), (4, 5), (6, 5)].into_iter().collect(),
+			// Nothing.
+			BTreeMap::new(),
+			// A zero CRC is no CRC.
+			[(0, 50), (8, 1)].into_iter().collect(),
+			// Only zero.
+			[(0, 50)].into_iter().collect(),
+			// Big numbers.
+			[(1, u16::MAX), (2, u16::MAX), (3, 1)].into_iter().collect(),
+		];
+
+		let summary = CtdbTrackSummary::summarize(&parsed);
+		assert_eq!(summary.len(), parsed.len());
+
+		assert_eq!(summary[0].best_crc(), Some(0xdead_beef));
+		assert_eq!(summary[0].best_confidence(), 300);
+		assert_eq!(summary[0].total_confidence(), 400);
+		assert_eq!(summary[0].distinct_crcs(), 2);
+		assert_eq!(summary[0].agreement(), 0.75);
+
+		assert_eq!(summary[1].best_crc(), Some(4));
+		assert_eq!(summary[1].best_confidence(), 5);
+		assert_eq!(summary[1].total_confidence(), 15);
+		assert_eq!(summary[1].distinct_crcs(), 3);
+		assert!((summary[1].agreement() - 1.0 / 3.0).abs() < f32::EPSILON);
+
+		for s in [summary[2], summary[4]] {
+			assert_eq!(s, CtdbTrackSummary::default());
+			assert_eq!(s.best_crc(), None);
+			assert_eq!(s.best_confidence(), 0);
+			assert_eq!(s.total_confidence(), 0);
+			assert_eq!(s.distinct_crcs(), 0);
+			assert_eq!(s.agreement(), 0.0);
+		}
+
+		assert_eq!(summary[3].best_crc(), Some(8));
+		assert_eq!(summary[3].total_confidence(), 1);
+		assert_eq!(summary[3].agreement(), 1.0);
+
+		assert_eq!(summary[5].best_crc(), Some(1));
+		assert_eq!(summary[5].total_confidence(), u32::from(u16::MAX) * 2 + 1);
+		assert!(summary[5].agreement() < 0.5);
+
+		// Empty in, empty out.
+		assert!(CtdbTrackSummary::summarize(&[]).is_empty());
+	}
+}
@@ -11,7 +11,15 @@ use std::{
 
 
 #[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
 /// # Error Type.
+///
+/// Many variants carry a little context — the offending value, track number,
+/// etc. — which is included in their [`Display`](fmt::Display) output.
+///
+/// New variants may be added from time to time; callers who only need to
+/// know roughly what went wrong can use helpers like [`TocError::is_parse`]
+/// rather than matching every case.
 pub enum TocError {
 	/// # CDDA Sample Rate.
 	///
@@ -33,6 +41,17 @@ pub enum TocError {
 	/// parsed).
 	Checksums,
 
+	/// # Duration Conversion.
+	///
+	/// The value cannot be represented exactly as a CD sector duration.
+	DurationConversion,
+
+	/// # Invalid Duration String.
+	///
+	/// Duration strings must be formatted like `Dd HH:MM:SS+FF`, with the
+	/// day part being optional.
+	DurationParse,
+
 	/// # Invalid Format For Operation.
 	///
 	/// This is a catch-all error used when a given disc format is incompatible
@@ -41,8 +60,15 @@ pub enum TocError {
 
 	/// # Leadin Too Small.
 	///
-	/// Audio CDs require a leadin of at least `150`.
-	LeadinSize,
+	/// Audio CDs require a leadin of at least `150`. The payload is the
+	/// offending leadin.
+	LeadinSize(u32),
+
+	/// # Invalid MSF.
+	///
+	/// MSF seconds must be less than `60`, frames less than `75`, and the
+	/// total sector count must fit within a [`u32`].
+	Msf,
 
 	/// # No Audio.
 	///
@@ -66,22 +92,48 @@ pub enum TocError {
 	/// Audio CD sectors must be sequentially ordered and non-overlapping, and
 	/// the data session, if any, must come either immediately before or after
 	/// the audio set. The leadout must be larger than every other sector.
-	SectorOrder,
+	///
+	/// The payload is the first offending pair: a sector, followed by the
+	/// sector that should have come after it, but didn't.
+	SectorOrder(u32, u32),
 
 	/// # Sector Size.
 	///
-	/// Sector values cannot exceed [`u32::MAX`].
-	SectorSize,
+	/// Sector values cannot exceed [`u32::MAX`]. The payload is the offending
+	/// value, saturating at [`u64::MAX`].
+	SectorSize(u64),
 
 	/// # Track Count.
 	///
 	/// Audio CDs support a maximum of 99 tracks.
 	TrackCount,
 
+	/// # Track Number.
+	///
+	/// Audio CD track numbers must be between `1..=99`. The payload is the
+	/// offending number.
+	TrackNumber(usize),
+
+	/// # Track Position Decode.
+	TrackPositionDecode,
+
 	#[cfg(feature = "accuraterip")]
 	/// # AccurateRip Decode.
 	AccurateRipDecode,
 
+	#[cfg(feature = "accuraterip")]
+	/// # Checksum Cache.
+	///
+	/// A cached checksum set is truncated, corrupt, or from an incompatible
+	/// format version.
+	ChecksumCache,
+
+	#[cfg(any(feature = "accuraterip", feature = "ctdb"))]
+	/// # Checksum Track Count.
+	///
+	/// The number of tracks in a set of checksums does not match the disc.
+	ChecksumCount(u8, usize),
+
 	#[cfg(feature = "accuraterip")]
 	/// # Drive Offset Decode.
 	DriveOffsetDecode,
@@ -90,10 +142,79 @@ pub enum TocError {
 	/// # No Drive Offsets.
 	NoDriveOffsets,
 
+	#[cfg(any(feature = "claxon", feature = "hound"))]
+	/// # Audio File Format.
+	///
+	/// The track file is not 16-bit, 44.1 kHz stereo, or is otherwise
+	/// unsupported.
+	AudioFileFormat(u8),
+
+	#[cfg(any(feature = "claxon", feature = "hound"))]
+	/// # Audio File Read.
+	///
+	/// The track file could not be opened or decoded. The payload is the
+	/// track number.
+	///
+	/// The underlying `hound`/`claxon` error is not kept: those are neither
+	/// `Copy` nor comparable, and `TocError` is both — the `const`
+	/// constructors depend on it — so [`Error::source`] has nothing to
+	/// return. Callers needing the details can open the file themselves.
+	AudioFileRead(u8),
+
+	#[cfg(any(feature = "claxon", feature = "hound"))]
+	/// # Audio File Samples.
+	///
+	/// The track file does not have the expected number of samples.
+	AudioFileSamples(u8, u64, u64),
+
 	#[cfg(feature = "cddb")]
 	/// # CDDB Decode.
 	CddbDecode,
 
+	#[cfg(feature = "cddb")]
+	/// # CDDB Mismatch.
+	///
+	/// The CDDB ID does not match the table of contents.
+	CddbMismatch,
+
+	#[cfg(feature = "cddb")]
+	/// # CDDB Response.
+	///
+	/// The CDDB server response could not be parsed or was unexpected.
+	CddbResponse,
+
+	#[cfg(feature = "cddb")]
+	/// # Xmcd Decode.
+	XmcdDecode,
+
+	#[cfg(feature = "ctdb")]
+	/// # CTDB Parity.
+	///
+	/// The parity data is malformed or does not match its parameters.
+	CtdbParity,
+
+	#[cfg(feature = "ctdb")]
+	/// # CTDB Tag.
+	///
+	/// A tag in a CTDB response was left unterminated, or an entry's values
+	/// are invalid. The payload is the (one-based) line number the offending
+	/// tag started on.
+	CtdbTag(usize),
+
+	#[cfg(feature = "musicbrainz")]
+	/// # MusicBrainz Response.
+	///
+	/// The MusicBrainz server response could not be parsed or was unexpected.
+	MusicBrainzResponse,
+
+	#[cfg(feature = "serde")]
+	/// # Unknown.
+	///
+	/// This is a catch-all for deserialized errors of an unrecognized type,
+	/// such as those from a newer version of this crate, or belonging to
+	/// crate features that aren't enabled.
+	Unknown,
+
 	#[cfg(feature = "sha1")]
 	/// # SHA1/Base64 Decode.
 	ShaB64Decode,
@@ -105,23 +226,267 @@ impl fmt::Display for TocError {
 			Self::CDDASampleCount => "Invalid CDDA sample count.",
 			Self::CDTOCChars => "Invalid character(s), expecting only 0-9, A-F, +, and (rarely) X.",
 			Self::Checksums => "Unable to parse checksums.",
+			Self::DurationConversion => "The value cannot be represented as a CD duration.",
+			Self::DurationParse => "Invalid duration string.",
 			Self::Format(kind) => return write!(f, "This operation can't be applied to {kind} discs."),
-			Self::LeadinSize => "Leadin must be at least 150.",
+			Self::LeadinSize(n) => return write!(f, "Leadin must be at least 150, not {n}."),
+			Self::Msf => "Invalid MSF.",
 			Self::NoAudio => "At least one audio track is required.",
 			Self::NoChecksums => "No checksums were present.",
 			Self::SectorCount(expected, found) => return write!(f, "Expected {expected} audio sectors, found {found}."),
-			Self::SectorOrder => "Sectors are incorrectly ordered or overlap.",
-			Self::SectorSize => "Sector sizes may not exceed four bytes (u32).",
+			Self::SectorOrder(a, b) => return write!(f, "Sectors are incorrectly ordered or overlap; {b} should come after {a}."),
+			Self::SectorSize(n) => return write!(f, "Sector sizes may not exceed four bytes (u32), not {n}."),
 			Self::TrackCount => "The number of audio tracks must be between 1..=99.",
+			Self::TrackNumber(n) => return write!(f, "Track numbers must be between 1..=99, not {n}."),
+			Self::TrackPositionDecode => "Invalid track position string.",
 
 			#[cfg(feature = "accuraterip")] Self::AccurateRipDecode => "Invalid AccurateRip ID string.",
+			#[cfg(feature = "accuraterip")] Self::ChecksumCache => "Invalid or outdated checksum cache.",
+			#[cfg(any(feature = "accuraterip", feature = "ctdb"))] Self::ChecksumCount(expected, found) => return write!(f, "Expected checksums for {expected} tracks, found {found}."),
 			#[cfg(feature = "accuraterip")] Self::DriveOffsetDecode => "Unable to parse drive offsets.",
 			#[cfg(feature = "accuraterip")] Self::NoDriveOffsets => "No drive offsets were found.",
 
+			#[cfg(any(feature = "claxon", feature = "hound"))] Self::AudioFileFormat(n) => return write!(f, "Track {n}: audio must be 16-bit, 44.1 kHz stereo WAV or FLAC."),
+			#[cfg(any(feature = "claxon", feature = "hound"))] Self::AudioFileRead(n) => return write!(f, "Track {n}: unable to read audio file."),
+			#[cfg(any(feature = "claxon", feature = "hound"))] Self::AudioFileSamples(n, expected, found) => return write!(f, "Track {n}: expected {expected} samples, found {found}."),
+
 			#[cfg(feature = "cddb")] Self::CddbDecode => "Invalid CDDB ID string.",
+			#[cfg(feature = "cddb")] Self::CddbMismatch => "The CDDB ID does not match the table of contents.",
+			#[cfg(feature = "cddb")] Self::CddbResponse => "Invalid or unexpected CDDB server response.",
+			#[cfg(feature = "cddb")] Self::XmcdDecode => "Invalid xmcd record.",
+			#[cfg(feature = "ctdb")] Self::CtdbParity => "Invalid CTDB parity data.",
+			#[cfg(feature = "ctdb")] Self::CtdbTag(n) => return write!(f, "Malformed CTDB response on line {n}."),
+			#[cfg(feature = "musicbrainz")] Self::MusicBrainzResponse => "Invalid or unexpected MusicBrainz server response.",
+			#[cfg(feature = "serde")] Self::Unknown => "Unknown error.",
 			#[cfg(feature = "sha1")] Self::ShaB64Decode => "Invalid sha/base64 ID string.",
 		})
 	}
 }
 
 impl Error for TocError {}
+
+impl TocError {
+	#[must_use]
+	/// # Is Decode Error?
+	///
+	/// Returns `true` if the error came from decoding a short string or value
+	/// — an ID, a CDTOC, a duration, etc. — that was malformed.
+	///
+	/// Decode errors are a subset of [parse errors](TocError::is_parse).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let err = Toc::from_cdtoc("4+96+2D2B+hello").unwrap_err();
+	/// assert!(err.is_decode());
+	/// assert!(err.is_parse());
+	/// ```
+	pub const fn is_decode(&self) -> bool {
+		match self {
+			Self::CDTOCChars | Self::DurationParse | Self::Msf | Self::TrackPositionDecode => true,
+			#[cfg(feature = "accuraterip")] Self::AccurateRipDecode => true,
+			#[cfg(feature = "cddb")] Self::CddbDecode => true,
+			#[cfg(feature = "sha1")] Self::ShaB64Decode => true,
+			_ => false,
+		}
+	}
+
+	#[must_use]
+	/// # Is Parse Error?
+	///
+	/// Returns `true` if the error came from parsing malformed input of any
+	/// kind, be it a short string (see [`TocError::is_decode`]), a checksum
+	/// manifest or cache, a drive offset list, an xmcd record, or a server
+	/// response.
+	///
+	/// Errors arising from well-formed but logically invalid values — sectors
+	/// in the wrong order, etc. — are not included.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// // Out of order, but formatted okay.
+	/// let err = Toc::from_cdtoc("2+2D2B+96+6256").unwrap_err();
+	/// assert!(! err.is_parse());
+	/// ```
+	pub const fn is_parse(&self) -> bool {
+		if self.is_decode() { return true; }
+		match self {
+			Self::Checksums | Self::NoChecksums => true,
+			#[cfg(feature = "accuraterip")] Self::ChecksumCache | Self::DriveOffsetDecode => true,
+			#[cfg(feature = "cddb")] Self::CddbResponse | Self::XmcdDecode => true,
+			#[cfg(feature = "ctdb")] Self::CtdbParity | Self::CtdbTag(_) => true,
+			#[cfg(feature = "musicbrainz")] Self::MusicBrainzResponse => true,
+			_ => false,
+		}
+	}
+
+	#[must_use]
+	/// # Is Unsupported Format Error?
+	///
+	/// Returns `true` if the operation could not be carried out because the
+	/// disc or audio file is of an unsupported kind, rather than because
+	/// anything was actually wrong with it.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let mut toc = Toc::from_cdtoc("3+2D2B+6256+B327+D84A+X96").unwrap();
+	/// let err = toc.set_audio_leadin(200).unwrap_err();
+	/// assert!(err.is_unsupported_format());
+	/// ```
+	pub const fn is_unsupported_format(&self) -> bool {
+		match self {
+			Self::Format(_) => true,
+			#[cfg(any(feature = "claxon", feature = "hound"))] Self::AudioFileFormat(_) => true,
+			_ => false,
+		}
+	}
+}
+
+
+
+#[cfg(feature = "fetch")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fetch")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # Fetch Error Type.
+///
+/// This is returned by the AccurateRip download helpers, keeping "not in the
+/// database" separate from actual request failures.
+pub enum FetchError {
+	/// # HTTP Status.
+	///
+	/// The server responded with an unexpected (non-404) error status.
+	Http(u16),
+
+	/// # Network.
+	///
+	/// The request could not be completed (DNS, connection, timeout, etc.).
+	Network,
+
+	/// # Not Found.
+	///
+	/// The server responded with a 404; for checksums, this means the disc
+	/// is not in the AccurateRip database.
+	NotFound,
+
+	/// # Parse.
+	///
+	/// The response was received but could not be parsed.
+	Parse(TocError),
+
+	/// # Too Large.
+	///
+	/// The response body was unreasonably large.
+	TooLarge,
+}
+
+#[cfg(feature = "fetch")]
+impl fmt::Display for FetchError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Http(code) => return write!(f, "The server responded with HTTP {code}."),
+			Self::Network => "The request could not be completed.",
+			Self::NotFound => "The requested resource does not exist.",
+			Self::Parse(e) => return write!(f, "Unable to parse the response: {e}"),
+			Self::TooLarge => "The response was too large.",
+		})
+	}
+}
+
+#[cfg(feature = "fetch")]
+impl Error for FetchError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		if let Self::Parse(e) = self { Some(e) }
+		else { None }
+	}
+}
+
+#[cfg(feature = "fetch")]
+impl From<TocError> for FetchError {
+	#[inline]
+	fn from(err: TocError) -> Self { Self::Parse(err) }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_display() {
+		for (err, expected) in [
+			(TocError::LeadinSize(149), "Leadin must be at least 150, not 149."),
+			(TocError::SectorCount(4, 3), "Expected 4 audio sectors, found 3."),
+			(TocError::SectorOrder(11_563, 150), "Sectors are incorrectly ordered or overlap; 150 should come after 11563."),
+			(TocError::SectorSize(4_294_967_296), "Sector sizes may not exceed four bytes (u32), not 4294967296."),
+			(TocError::TrackNumber(100), "Track numbers must be between 1..=99, not 100."),
+			(TocError::Format(TocKind::DataFirst), "This operation can't be applied to data+audio discs."),
+		] {
+			assert_eq!(err.to_string(), expected);
+		}
+
+		#[cfg(feature = "ctdb")]
+		assert_eq!(
+			TocError::CtdbTag(3).to_string(),
+			"Malformed CTDB response on line 3.",
+		);
+	}
+
+	#[test]
+	fn t_predicates() {
+		for err in [TocError::CDTOCChars, TocError::DurationParse, TocError::Msf, TocError::TrackPositionDecode] {
+			assert!(err.is_decode(), "{err:?}");
+			assert!(err.is_parse(), "{err:?}");
+			assert!(! err.is_unsupported_format(), "{err:?}");
+		}
+
+		for err in [TocError::Checksums, TocError::NoChecksums] {
+			assert!(! err.is_decode(), "{err:?}");
+			assert!(err.is_parse(), "{err:?}");
+			assert!(! err.is_unsupported_format(), "{err:?}");
+		}
+
+		for kind in [TocKind::Audio, TocKind::CDExtra, TocKind::DataFirst] {
+			let err = TocError::Format(kind);
+			assert!(! err.is_decode(), "{err:?}");
+			assert!(! err.is_parse(), "{err:?}");
+			assert!(err.is_unsupported_format(), "{err:?}");
+		}
+
+		for err in [
+			TocError::CDDASampleCount,
+			TocError::DurationConversion,
+			TocError::LeadinSize(0),
+			TocError::NoAudio,
+			TocError::SectorCount(1, 2),
+			TocError::SectorOrder(2, 1),
+			TocError::SectorSize(u64::MAX),
+			TocError::TrackCount,
+			TocError::TrackNumber(0),
+		] {
+			assert!(! err.is_decode(), "{err:?}");
+			assert!(! err.is_parse(), "{err:?}");
+			assert!(! err.is_unsupported_format(), "{err:?}");
+		}
+
+		#[cfg(feature = "sha1")]
+		assert!(TocError::ShaB64Decode.is_decode());
+
+		#[cfg(feature = "cddb")]
+		assert!(TocError::CddbResponse.is_parse() && ! TocError::CddbResponse.is_decode());
+
+		#[cfg(feature = "ctdb")]
+		assert!(TocError::CtdbTag(1).is_parse() && ! TocError::CtdbTag(1).is_decode());
+
+		#[cfg(any(feature = "claxon", feature = "hound"))]
+		assert!(TocError::AudioFileFormat(1).is_unsupported_format());
+	}
+}
@@ -15,6 +15,14 @@ CDTOC is a simple Rust library for parsing and working with audio CD tables of c
 
 By default it can also generate disc IDs for services like [AccurateRip](http://accuraterip.com/), [CDDB](https://en.wikipedia.org/wiki/CDDB), [CUETools Database](http://cue.tools/wiki/CUETools_Database), and [MusicBrainz](https://musicbrainz.org/), but you can disable the corresponding crate feature(s) — `accuraterip`, `cddb`, `ctdb`, and `musicbrainz` respectively — to shrink the dependency tree if you don't need that functionality.
 
+The optional `offsets-embedded` crate feature bundles a snapshot of AccurateRip's drive offset table, accessible via [`AccurateRip::builtin_drive_offsets`]. The table is generated at build time from `skel/DriveOffsets.bin` — currently a four-drive placeholder — or the file named by the `CDTOC_DRIVE_OFFSETS` environment variable.
+
+The optional `hound` (WAV) and `claxon` (FLAC) crate features enable [`AccurateRip::checksum_files`], which computes AccurateRip checksums directly from per-track audio files.
+
+The optional `fetch` crate feature adds [`AccurateRip::fetch_checksums`] and [`AccurateRip::fetch_drive_offsets`], thin (blocking) HTTP helpers for downloading and parsing AccurateRip data.
+
+The optional `musicbrainz-json` crate feature adds [`MbDiscIdResponse::from_json`], for parsing MusicBrainz disc ID lookup responses in JSON; XML responses can be parsed with [`MbDiscIdResponse::from_xml`] without any extra dependencies.
+
 
 
 ## Examples
@@ -47,13 +55,16 @@ The optional `serde` crate feature can be enabled to expose de/serialization imp
 
 | Type | Format | Notes |
 | ---- | ------ | ----- |
-| [`AccurateRip`] | `String` | |
-| [`Cddb`] | `String` | |
-| [`Duration`] | `u64` | |
-| [`ShaB64`] | `String` | MusicBrainz and CTDB IDs. |
-| [`Toc`] | `String` | |
-| [`Track`] | `Map` | |
+| [`AccurateRip`] | `String` | Compact (non-human-readable) formats use the raw `[u8; 13]` bytes instead. |
+| [`Cddb`] | `String` | Compact (non-human-readable) formats use the raw `u32` instead. |
+| [`Duration`] | `u64` | Strings are accepted too; see [`duration_string`] for string output. |
+| [`DurationParts`] | `{ days, hours, minutes, seconds, frames }` | |
+| [`ShaB64`] | `String` | MusicBrainz and CTDB IDs. Compact (non-human-readable) formats use the raw `[u8; 20]` bytes instead. |
+| [`Toc`] | `String` | Maps are accepted too; see [`Toc::as_struct`] for map output. |
+| [`TocError`] | `Map` | Tagged by `type`, e.g. `{"type":"SectorOrder","previous":11563,"next":150}`. Compact (non-human-readable) formats use a `(type, u64, u64, u64)` tuple instead. Unrecognized types deserialize as [`TocError::Unknown`]. |
+| [`Track`] | `Map` | Fields are validated against one another; `pos` is optional. See also [`Track::serialize_detailed`]. |
 | [`TrackPosition`] | `String` | |
+| [`VerifyReport`] | `Map` | Serialize only. |
 */
 
 #![deny(
@@ -111,24 +122,92 @@ The optional `serde` crate feature can be enabled to expose de/serialization imp
 
 
 mod error;
+mod msf;
 mod time;
 mod track;
 #[cfg(feature = "accuraterip")] mod accuraterip;
 #[cfg(feature = "cddb")] mod cddb;
+#[cfg(any(feature = "accuraterip", feature = "ctdb"))] mod crc32;
 #[cfg(feature = "ctdb")] mod ctdb;
 #[cfg(feature = "musicbrainz")] mod musicbrainz;
 #[cfg(feature = "serde")] mod serde;
 #[cfg(feature = "sha1")] mod shab64;
+#[cfg(any(feature = "ctdb", feature = "musicbrainz"))] mod xml;
 
 pub use error::TocError;
-pub use time::Duration;
+#[cfg(feature = "fetch")] pub use error::FetchError;
+pub use msf::{
+	lba_to_msf,
+	msf_to_lba,
+	Msf,
+};
+pub use time::{
+	AlignedStyle,
+	Duration,
+	DurationParts,
+	Rounding,
+	SignedDuration,
+};
 pub use track::{
+	OffsetRange,
 	Track,
 	Tracks,
 	TrackPosition,
 };
-#[cfg(feature = "accuraterip")] pub use accuraterip::AccurateRip;
-#[cfg(feature = "cddb")] pub use cddb::Cddb;
+#[cfg(feature = "accuraterip")] pub use accuraterip::{
+	AccurateRip,
+	ArLeadout,
+	ArVersion,
+	ChecksumEntry,
+	ChunkRef,
+	DiscChecksums,
+	DriveOffset,
+	DriveOffsets,
+	OffsetMatrix,
+	TrackSummary,
+	TrackVerdict,
+	VerifyReport,
+};
+#[cfg(feature = "fetch")] pub use accuraterip::FetchOptions;
+#[cfg(feature = "ctdb")] pub use ctdb::{
+	CtdbChecksummer,
+	CtdbDiscChecksummer,
+	CtdbEntry,
+	CtdbEntryRef,
+	CtdbLookupOptions,
+	CtdbMetadata,
+	CtdbMetadataLevel,
+	CtdbParity,
+	CtdbRequest,
+	CtdbResponse,
+	CtdbSubmission,
+	CtdbTrackSummary,
+};
+#[cfg(feature = "cddb")] pub use cddb::{
+	Cddb,
+	CddbMatch,
+	CddbMode,
+	CddbReply,
+	CddbSession,
+	CddbStatus,
+	GnudbHello,
+	Xmcd,
+	XmcdMeta,
+	XmcdVerify,
+};
+#[cfg(feature = "musicbrainz")] pub use musicbrainz::{
+	MbDiscIdResponse,
+	MbFormat,
+	MbLeadout,
+	MbLookupOptions,
+	MbMedium,
+	MbRelease,
+};
+#[cfg(feature = "serde")] pub use self::serde::{
+	duration_string,
+	TocStruct,
+	TrackDetailed,
+};
 #[cfg(feature = "sha1")] pub use shab64::ShaB64;
 
 use dactyl::traits::HexToUnsigned;
@@ -136,16 +215,6 @@ use std::fmt;
 
 
 
-#[cfg(any(feature = "musicbrainz", feature = "ctdb"))]
-/// # Lotsa Zeroes.
-///
-/// MusicBrainz and CTDB take a sha1 hash of 100 hex-encoded tracks, most of
-/// which, most of the time, are just zero-padding. Slicing what we need out of
-/// a prebuilt static is much faster than pushing zeroes on-the-fly.
-static ZEROES: [u8; 792] = [b'0'; 792];
-
-
-
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 /// # CDTOC.
 ///
@@ -217,14 +286,14 @@ impl fmt::Display for Toc {
 
 		// Audio track count.
 		let audio_len = self.audio.len() as u8;
-		faster_hex::hex_encode_fallback(&[audio_len], &mut buf[..2]);
+		faster_hex::hex_encode_upper_fallback(&[audio_len], &mut buf[..2]);
 		if 16 <= audio_len { out.push(buf[0]); }
 		out.push(buf[1]);
 
 		/// # Helper: Add Track to Buffer.
 		macro_rules! push {
 			($v:expr) => (
-				faster_hex::hex_encode_fallback($v.to_be_bytes().as_slice(), &mut buf);
+				faster_hex::hex_encode_upper_fallback($v.to_be_bytes().as_slice(), &mut buf);
 				out.push(b'+');
 				out.extend_from_slice(buf.trim_start_matches(b'0'));
 			);
@@ -244,14 +313,13 @@ impl fmt::Display for Toc {
 				push!(self.leadout);
 
 				// Handle this manually since there's the weird X marker.
-				faster_hex::hex_encode_fallback(self.data.to_be_bytes().as_slice(), &mut buf);
+				faster_hex::hex_encode_upper_fallback(self.data.to_be_bytes().as_slice(), &mut buf);
 				out.push(b'+');
 				out.push(b'X');
 				out.extend_from_slice(buf.trim_start_matches(b'0'));
 			},
 		}
 
-		out.make_ascii_uppercase();
 		std::str::from_utf8(&out)
 			.map_err(|_| fmt::Error)
 			.and_then(|s| f.write_str(s))
@@ -329,10 +397,8 @@ impl Toc {
 		let mut last: u32 = leadin.unwrap_or(150);
 		let mut audio: Vec<u32> = vec![last];
 		for d in src {
-			let next = u32::try_from(d.sectors())
-				.ok()
-				.and_then(|n| last.checked_add(n))
-				.ok_or(TocError::SectorSize)?;
+			let next = u64::from(last).saturating_add(d.sectors());
+			let next = u32::try_from(next).map_err(|_| TocError::SectorSize(next))?;
 			audio.push(next);
 			last = next;
 		}
@@ -382,24 +448,22 @@ impl Toc {
 		if 99 < audio_len { return Err(TocError::TrackCount); }
 
 		// Audio leadin must be at least 150.
-		if audio[0] < 150 { return Err(TocError::LeadinSize); }
+		if audio[0] < 150 { return Err(TocError::LeadinSize(audio[0])); }
 
 		// Audio is out of order?
-		if
-			(1 < audio_len && audio.windows(2).any(|pair| pair[1] <= pair[0])) ||
-			leadout <= audio[audio_len - 1]
-		{
-			return Err(TocError::SectorOrder);
+		if let Some(pair) = audio.windows(2).find(|pair| pair[1] <= pair[0]) {
+			return Err(TocError::SectorOrder(pair[0], pair[1]));
 		}
+		let last = audio[audio_len - 1];
+		if leadout <= last { return Err(TocError::SectorOrder(last, leadout)); }
 
 		// Figure out the kind and validate the data sector.
 		let kind =
 			if let Some(d) = data {
 				if d < audio[0] { TocKind::DataFirst }
-				else if audio[audio_len - 1] < d && d < leadout {
-					TocKind::CDExtra
-				}
-				else { return Err(TocError::SectorOrder); }
+				else if last < d && d < leadout { TocKind::CDExtra }
+				else if leadout <= d { return Err(TocError::SectorOrder(d, leadout)); }
+				else { return Err(TocError::SectorOrder(last, d)); }
 			}
 			else { TocKind::Audio };
 
@@ -461,7 +525,7 @@ impl Toc {
 	pub fn set_audio_leadin(&mut self, leadin: u32) -> Result<(), TocError> {
 		use std::cmp::Ordering;
 
-		if leadin < 150 { Err(TocError::LeadinSize) }
+		if leadin < 150 { Err(TocError::LeadinSize(leadin)) }
 		else if matches!(self.kind, TocKind::DataFirst) {
 			Err(TocError::Format(TocKind::DataFirst))
 		}
@@ -478,15 +542,14 @@ impl Toc {
 				// Nudge upward.
 				Ordering::Greater => {
 					let diff = leadin - current;
-					for v in &mut self.audio {
-						*v = v.checked_add(diff).ok_or(TocError::SectorSize)?;
-					}
-					if self.has_data() {
-						self.data = self.data.checked_add(diff)
-							.ok_or(TocError::SectorSize)?;
-					}
-					self.leadout = self.leadout.checked_add(diff)
-						.ok_or(TocError::SectorSize)?;
+
+					// The leadout is the biggest sector, so if it fits, the
+					// rest will too.
+					self.leadout = self.leadout.checked_add(diff).ok_or_else(||
+						TocError::SectorSize(u64::from(self.leadout) + u64::from(diff))
+					)?;
+					for v in &mut self.audio { *v += diff; }
+					if self.has_data() { self.data += diff; }
 				},
 				// Noop.
 				Ordering::Equal => {},
@@ -820,6 +883,40 @@ impl Toc {
 		}
 	}
 
+	#[must_use]
+	/// # Pre-gap Before Track.
+	///
+	/// Return the implicit gap preceding the given audio track — the space
+	/// between the end of the previous track and the start of this one — if
+	/// it can be inferred from the table of contents.
+	///
+	/// CDTOCs only carry track _starts_, so today this can only ever return
+	/// the [HTOA](Toc::htoa), for track #1. All other tracks butt up against
+	/// their predecessors, so yield `None`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// // No HTOA, no pre-gap.
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert!(toc.pregap_before(1).is_none());
+	///
+	/// // This one has an HTOA.
+	/// let toc = Toc::from_cdtoc("15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46").unwrap();
+	/// let gap = toc.pregap_before(1).unwrap();
+	/// assert!(gap.is_htoa());
+	/// assert_eq!(Some(gap), toc.htoa());
+	///
+	/// // But only before the first track.
+	/// assert!(toc.pregap_before(2).is_none());
+	/// ```
+	pub fn pregap_before(&self, track: usize) -> Option<Track> {
+		if track == 1 { self.htoa() }
+		else { None }
+	}
+
 	#[must_use]
 	/// # CD Format.
 	///
@@ -927,12 +1024,114 @@ impl Toc {
 	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
 	/// assert_eq!(
 	///     toc.duration(),
-	///     toc.audio_tracks().map(|t| t.duration()).sum(),
+	///     toc.audio_tracks().map(|t| t.duration()).sum::<Duration>(),
 	/// );
 	/// ```
 	pub fn duration(&self) -> Duration {
 		Duration::from(self.audio_leadout() - self.audio_leadin())
 	}
+
+	/// # Short Tracks.
+	///
+	/// Return an iterator of the audio tracks whose durations are shorter
+	/// than `min`, useful for flagging index markers that were mistakenly
+	/// ripped as tracks.
+	///
+	/// Pass [`Track::MIN_DURATION`] to check against the Red Book minimum of
+	/// four seconds.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Toc, Track};
+	///
+	/// // Nothing short here.
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert_eq!(toc.short_tracks(Track::MIN_DURATION).count(), 0);
+	///
+	/// // But the second track of this one is only a second long.
+	/// let toc = Toc::from_parts(vec![150, 11_563, 11_638], None, 55_370).unwrap();
+	/// let short: Vec<u8> = toc.short_tracks(Track::MIN_DURATION)
+	///     .map(|t| t.number())
+	///     .collect();
+	/// assert_eq!(short, [2]);
+	/// ```
+	pub fn short_tracks(&self, min: Duration) -> impl Iterator<Item = Track> + '_ {
+		self.audio_tracks().filter(move |t| t.is_shorter_than(min))
+	}
+
+	#[must_use]
+	/// # Track Weights.
+	///
+	/// Return the relative size of each audio track — its sectors divided by
+	/// the total audio sectors — in order. The values will sum to (roughly)
+	/// `1.0`, making them handy for things like whole-disc progress bars.
+	///
+	/// The HTOA and data session, if any, are excluded. To include them, use
+	/// [`Toc::track_weights_with`] instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let weights = toc.track_weights();
+	/// assert_eq!(weights.len(), 4);
+	/// assert!((weights.iter().sum::<f64>() - 1.0).abs() < f64::EPSILON * 4.0);
+	/// ```
+	pub fn track_weights(&self) -> Vec<f64> { self.track_weights_with(false, false) }
+
+	#[must_use]
+	/// # Track Weights (Custom).
+	///
+	/// Same as [`Toc::track_weights`], but optionally including the HTOA
+	/// and/or data session in the mix.
+	///
+	/// The weights are returned in disc order, so when included, an HTOA will
+	/// come first, and the data session will come first or last depending on
+	/// the [`TocKind`]. (For CD-Extra discs, the 11,400-sector gap separating
+	/// the two sessions belongs to neither, so is not counted.)
+	///
+	/// Weights are calculated from whole sector counts, which — like
+	/// [`Duration::to_f64_lossy`] — convert to `f64` losslessly; only the
+	/// final division is subject to rounding.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46").unwrap();
+	/// assert!(toc.htoa().is_some());
+	///
+	/// // With the HTOA, there's an extra entry.
+	/// assert_eq!(toc.track_weights().len(), 21);
+	/// assert_eq!(toc.track_weights_with(true, false).len(), 22);
+	///
+	/// // This disc has no data, so that doesn't change anything.
+	/// assert_eq!(toc.track_weights_with(false, true), toc.track_weights());
+	/// ```
+	pub fn track_weights_with(&self, htoa: bool, data: bool) -> Vec<f64> {
+		let mut out: Vec<u32> = Vec::with_capacity(self.audio.len() + 2);
+
+		// Leading extras.
+		if let Some(t) = self.htoa().filter(|_| htoa) { out.push(t.sectors()); }
+		if data && matches!(self.kind, TocKind::DataFirst) {
+			out.push(self.audio_leadin() - self.data);
+		}
+
+		// The audio.
+		out.extend(self.audio_tracks().map(|t| t.sectors()));
+
+		// Trailing extras.
+		if data && matches!(self.kind, TocKind::CDExtra) {
+			out.push(self.leadout - self.data);
+		}
+
+		let total: f64 = out.iter().copied().map(f64::from).sum();
+		out.into_iter().map(|v| f64::from(v) / total).collect()
+	}
 }
 
 
@@ -1018,9 +1217,8 @@ fn parse_cdtoc_metadata(src: &[u8]) -> Result<(Vec<u32>, Option<u32>, u32), TocE
 	let sectors: Vec<u32> = split
 		.by_ref()
 		.take(usize::from(audio_len))
-		.map(u32::htou)
-		.collect::<Option<Vec<u32>>>()
-		.ok_or(TocError::SectorSize)?;
+		.map(parse_cdtoc_sector)
+		.collect::<Result<Vec<u32>, TocError>>()?;
 
 	// Make sure we actually do.
 	let sectors_len = sectors.len();
@@ -1032,18 +1230,16 @@ fn parse_cdtoc_metadata(src: &[u8]) -> Result<(Vec<u32>, Option<u32>, u32), TocE
 	// There should be at least one more entry to mark the audio leadout.
 	let last1 = split.next()
 		.ok_or(TocError::SectorCount(audio_len, sectors_len - 1))?;
-	let last1 = u32::htou(last1).ok_or(TocError::SectorSize)?;
+	let last1 = parse_cdtoc_sector(last1)?;
 
 	// If there is yet another entry, we've got a mixed-mode disc.
 	if let Some(last2) = split.next() {
 		// Unlike the other values, this entry might have an x-prefix to denote
 		// a non-standard data-first position.
-		let last2 = u32::htou(last2)
-			.or_else(||
-				last2.strip_prefix(b"X").or_else(|| last2.strip_prefix(b"x"))
-					.and_then(u32::htou)
-			)
-			.ok_or(TocError::SectorSize)?;
+		let last2 = parse_cdtoc_sector(
+			last2.strip_prefix(b"X").or_else(|| last2.strip_prefix(b"x"))
+				.unwrap_or(last2)
+		)?;
 
 		// That should be that!
 		let remaining = split.count();
@@ -1066,12 +1262,38 @@ fn parse_cdtoc_metadata(src: &[u8]) -> Result<(Vec<u32>, Option<u32>, u32), TocE
 	else { Ok((sectors, None, last1)) }
 }
 
+/// # Parse CDTOC Sector.
+///
+/// Decode a single hex-encoded sector value, distinguishing between values
+/// that are too big and those that aren't numbers at all.
+fn parse_cdtoc_sector(src: &[u8]) -> Result<u32, TocError> {
+	u32::htou(src).ok_or_else(||
+		if ! src.is_empty() && src.iter().all(u8::is_ascii_hexdigit) {
+			TocError::SectorSize(u64::htou(src).unwrap_or(u64::MAX))
+		}
+		else { TocError::CDTOCChars }
+	)
+}
+
 
 
+#[cfg(test)]
+/// # Xorshift.
+///
+/// Advance a simple xorshift64 generator, returning the new state. This is
+/// used to produce pseudo-random test data.
+fn xorshift(seed: &mut u64) -> u64 {
+	*seed ^= *seed << 13;
+	*seed ^= *seed >> 7;
+	*seed ^= *seed << 17;
+	*seed
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use brunch as _;
+	use postcard as _;
 	use serde_json as _;
 
 	const CDTOC_AUDIO: &str = "B+96+5DEF+A0F2+F809+1529F+1ACB3+20CBC+24E14+2AF17+2F4EA+35BDD+3B96D";
@@ -1211,6 +1433,44 @@ mod tests {
 		] {
 			assert!(Toc::from_cdtoc(i).is_err());
 		}
+
+		// Make sure the errors say what's wrong.
+		for (raw, err) in [
+			("A+96+3757+696D+C64F+10A13+14DA2+19E88+2784E+1DBAA+213A4+2D7AF+36F11", TocError::SectorOrder(161_870, 121_770)),
+			("4+96+2D2B+6256+B327+B327", TocError::SectorOrder(45_863, 45_863)),
+			("4+95+2D2B+6256+B327+D84A", TocError::LeadinSize(149)),
+			("4+96+2D2B+hello+B327+D84A", TocError::CDTOCChars),
+			("4+96+2D2B++B327+D84A", TocError::CDTOCChars),
+			("4+96+2D2B+100000000+B327+D84A", TocError::SectorSize(4_294_967_296)),
+			("4+96+2D2B+6256+B327+D84A+FFFFFFFFFFFFFFFFFF", TocError::SectorSize(u64::MAX)),
+		] {
+			assert_eq!(Toc::from_cdtoc(raw), Err(err), "{raw}");
+		}
+
+		// Misplaced data.
+		let audio = vec![150, 11_563, 25_174, 45_863];
+		assert_eq!(
+			Toc::from_parts(audio.clone(), Some(11_600), 55_370),
+			Err(TocError::SectorOrder(45_863, 11_600)),
+		);
+		assert_eq!(
+			Toc::from_parts(audio, Some(60_000), 55_370),
+			Err(TocError::SectorOrder(60_000, 55_370)),
+		);
+
+		// Overflow.
+		assert_eq!(
+			Toc::from_durations([Duration::from(u64::from(u32::MAX))], None),
+			Err(TocError::SectorSize(u64::from(u32::MAX) + 150)),
+		);
+		let mut toc = Toc::from_parts(vec![150], None, u32::MAX - 10)
+			.expect("Invalid TOC.");
+		let orig = toc.clone();
+		assert_eq!(
+			toc.set_audio_leadin(200),
+			Err(TocError::SectorSize(u64::from(u32::MAX) + 40)),
+		);
+		assert_eq!(toc, orig, "Failed leadin change should be a noop.");
 	}
 
 	#[test]
@@ -1296,4 +1556,52 @@ mod tests {
 		assert!(toc.set_kind(TocKind::CDExtra).is_ok());
 		assert_eq!(toc, extra);
 	}
+
+	#[test]
+	/// # Test Track Weights.
+	fn t_track_weights() {
+		/// # Assert Sum.
+		fn assert_sum(weights: &[f64], cdtoc: &str) {
+			assert!(weights.iter().all(|w| 0.0 < *w && *w < 1.0), "{cdtoc}");
+			let sum: f64 = weights.iter().sum();
+			assert!((sum - 1.0).abs() < f64::EPSILON * 8.0, "{cdtoc}: {sum}");
+		}
+
+		for cdtoc in [
+			CDTOC_AUDIO,
+			CDTOC_EXTRA,
+			CDTOC_DATA_AUDIO,
+			"15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46",
+		] {
+			let toc = Toc::from_cdtoc(cdtoc).expect("Invalid TOC.");
+			let extra = usize::from(toc.htoa().is_some()) + usize::from(toc.has_data());
+			assert_eq!(toc.track_weights().len(), toc.audio_len());
+			assert_eq!(toc.track_weights_with(true, true).len(), toc.audio_len() + extra);
+			for (htoa, data) in [(false, false), (true, false), (false, true), (true, true)] {
+				assert_sum(&toc.track_weights_with(htoa, data), cdtoc);
+			}
+		}
+
+		// The audio weights are proportional to the sectors.
+		let toc = Toc::from_cdtoc(CDTOC_EXTRA).expect("Invalid TOC.");
+		let weights = toc.track_weights();
+		let total = f64::from(toc.audio_leadout() - toc.audio_leadin());
+		for (w, t) in weights.iter().zip(toc.audio_tracks()) {
+			assert!((w - f64::from(t.sectors()) / total).abs() < f64::EPSILON);
+		}
+
+		// CD-Extra data comes last, and doesn't include the session gap.
+		let weights = toc.track_weights_with(false, true);
+		assert_eq!(weights.len(), 11);
+		let data = f64::from(225_041 - 186_287);
+		let expected = data / (f64::from(174_887 - 150) + data);
+		assert!((weights[10] - expected).abs() < f64::EPSILON, "{}", weights[10]);
+
+		// Data-first data comes first.
+		let toc = Toc::from_cdtoc(CDTOC_DATA_AUDIO).expect("Invalid TOC.");
+		let weights = toc.track_weights_with(false, true);
+		assert_eq!(weights.len(), 11);
+		let expected = f64::from(14_167 - 150) / f64::from(225_041 - 150);
+		assert!((weights[0] - expected).abs() < f64::EPSILON, "{}", weights[0]);
+	}
 }
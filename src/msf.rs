@@ -0,0 +1,445 @@
+/*!
+# CDTOC: MSF
+*/
+
+use crate::{
+	Duration,
+	TocError,
+};
+use dactyl::traits::BytesToUnsigned;
+use std::{
+	fmt,
+	ops::{
+		Add,
+		AddAssign,
+		Sub,
+		SubAssign,
+	},
+	str::FromStr,
+};
+
+
+
+/// # Sectors Per Second.
+const SECTORS_PER_SECOND: u32 = 75;
+
+/// # Sectors Per Minute.
+const SECTORS_PER_MINUTE: u32 = SECTORS_PER_SECOND * 60;
+
+/// # Maximum Minutes.
+///
+/// MSFs are interchangeable with `u32` sector positions, so the minutes are
+/// capped accordingly.
+const MAX_MINUTES: u32 = u32::MAX / SECTORS_PER_MINUTE;
+
+
+
+#[must_use]
+/// # LBA to MSF.
+///
+/// Convert a sector position — as used throughout this crate, i.e. including
+/// the 150-sector leadin — to minutes, seconds, and frames.
+///
+/// This is a shorthand for `Msf::from_lba(sectors).as_tuple()`.
+///
+/// ## Examples
+///
+/// ```
+/// assert_eq!(cdtoc::lba_to_msf(150), (0, 2, 0));
+/// assert_eq!(cdtoc::lba_to_msf(11_563), (2, 34, 13));
+/// ```
+pub const fn lba_to_msf(sectors: u32) -> (u32, u8, u8) {
+	Msf::from_lba(sectors).as_tuple()
+}
+
+#[must_use]
+/// # MSF to LBA.
+///
+/// Convert minutes, seconds, and frames back to a sector position, the
+/// inverse of [`lba_to_msf`].
+///
+/// Returns `None` if the seconds are `60` or more, the frames are `75` or
+/// more, or the result would overflow `u32`.
+///
+/// ## Examples
+///
+/// ```
+/// assert_eq!(cdtoc::msf_to_lba(0, 2, 0), Some(150));
+/// assert_eq!(cdtoc::msf_to_lba(2, 34, 13), Some(11_563));
+/// assert_eq!(cdtoc::msf_to_lba(2, 60, 13), None);
+/// ```
+pub const fn msf_to_lba(m: u32, s: u8, f: u8) -> Option<u32> {
+	match Msf::new(m, s, f) {
+		Ok(msf) => Some(msf.to_lba()),
+		Err(_) => None,
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # MSF.
+///
+/// This struct holds a disc position expressed as minutes, seconds, and
+/// frames (sectors), the notation used by cue sheets and many ripping tools.
+///
+/// Values are interchangeable with the sector positions used elsewhere in
+/// this crate via [`Msf::from_lba`] and [`Msf::to_lba`]. Note that those
+/// sectors _include_ the mandatory 150-sector CD leadin, so the first track
+/// of a typical disc begins at `00:02:00`.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{Msf, Toc};
+///
+/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+/// let track = toc.audio_track(2).unwrap();
+///
+/// let msf = track.msf_typed();
+/// assert_eq!(msf.to_string(), "02:34:13");
+/// assert_eq!(msf.to_lba(), track.sector_range().start);
+/// assert_eq!(msf, Msf::new(2, 34, 13).unwrap());
+/// assert_eq!(msf, "02:34:13".parse().unwrap());
+/// ```
+pub struct Msf {
+	/// # Minutes.
+	m: u32,
+
+	/// # Seconds.
+	s: u8,
+
+	/// # Frames.
+	f: u8,
+}
+
+impl<T> Add<T> for Msf
+where Duration: From<T> {
+	type Output = Self;
+	#[inline]
+	fn add(self, other: T) -> Self {
+		let other = u32::try_from(Duration::from(other).sectors()).unwrap_or(u32::MAX);
+		Self::from_lba(self.to_lba().saturating_add(other))
+	}
+}
+
+impl<T> AddAssign<T> for Msf
+where Duration: From<T> {
+	#[inline]
+	fn add_assign(&mut self, other: T) { *self = *self + other; }
+}
+
+impl fmt::Display for Msf {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:02}:{:02}:{:02}", self.m, self.s, self.f)
+	}
+}
+
+impl From<Msf> for (u32, u8, u8) {
+	#[inline]
+	fn from(src: Msf) -> Self { src.as_tuple() }
+}
+
+impl FromStr for Msf {
+	type Err = TocError;
+	#[inline]
+	fn from_str(src: &str) -> Result<Self, Self::Err> { Self::decode(src) }
+}
+
+impl<T> Sub<T> for Msf
+where Duration: From<T> {
+	type Output = Self;
+	#[inline]
+	fn sub(self, other: T) -> Self {
+		let other = u32::try_from(Duration::from(other).sectors()).unwrap_or(u32::MAX);
+		Self::from_lba(self.to_lba().saturating_sub(other))
+	}
+}
+
+impl Sub for Msf {
+	type Output = Duration;
+	#[inline]
+	fn sub(self, other: Self) -> Duration {
+		Duration::from(self.to_lba().saturating_sub(other.to_lba()))
+	}
+}
+
+impl<T> SubAssign<T> for Msf
+where Duration: From<T> {
+	#[inline]
+	fn sub_assign(&mut self, other: T) { *self = *self - other; }
+}
+
+impl TryFrom<&str> for Msf {
+	type Error = TocError;
+	#[inline]
+	fn try_from(src: &str) -> Result<Self, Self::Error> { Self::decode(src) }
+}
+
+impl TryFrom<(u32, u8, u8)> for Msf {
+	type Error = TocError;
+	#[inline]
+	fn try_from(src: (u32, u8, u8)) -> Result<Self, Self::Error> {
+		Self::new(src.0, src.1, src.2)
+	}
+}
+
+impl Msf {
+	/// # New.
+	///
+	/// Create a new MSF from its parts.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Msf;
+	///
+	/// let msf = Msf::new(2, 34, 13).unwrap();
+	/// assert_eq!(msf.minutes(), 2);
+	/// assert_eq!(msf.seconds(), 34);
+	/// assert_eq!(msf.frames(), 13);
+	///
+	/// // Seconds and frames have limits.
+	/// assert!(Msf::new(2, 60, 0).is_err());
+	/// assert!(Msf::new(2, 0, 75).is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the seconds are `60` or more, the frames
+	/// are `75` or more, or the total number of sectors would exceed
+	/// [`u32::MAX`].
+	pub const fn new(m: u32, s: u8, f: u8) -> Result<Self, TocError> {
+		if
+			(s as u32) < 60 &&
+			(f as u32) < SECTORS_PER_SECOND &&
+			(
+				m < MAX_MINUTES ||
+				(
+					m == MAX_MINUTES &&
+					(s as u32) * SECTORS_PER_SECOND + f as u32 <= u32::MAX % SECTORS_PER_MINUTE
+				)
+			)
+		{
+			Ok(Self { m, s, f })
+		}
+		else { Err(TocError::Msf) }
+	}
+
+	#[must_use]
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # From LBA.
+	///
+	/// Convert a sector position — as used throughout this crate, i.e.
+	/// including the 150-sector leadin — to an MSF.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Msf;
+	///
+	/// assert_eq!(Msf::from_lba(0).to_string(), "00:00:00");
+	/// assert_eq!(Msf::from_lba(150).to_string(), "00:02:00");
+	/// assert_eq!(Msf::from_lba(11_563).to_string(), "02:34:13");
+	/// ```
+	pub const fn from_lba(sectors: u32) -> Self {
+		// 75 sectors per second.
+		let mut s = sectors.wrapping_div(SECTORS_PER_SECOND);
+		let f = sectors - s * SECTORS_PER_SECOND;
+
+		// 60 seconds per minute.
+		let m = s.wrapping_div(60);
+		s -= m * 60;
+
+		Self { m, s: s as u8, f: f as u8 }
+	}
+
+	/// # Decode.
+	///
+	/// Parse an MSF string in `MM:SS:FF` format. The frames may alternatively
+	/// be separated by a period, i.e. `MM:SS.FF`.
+	///
+	/// The seconds and frames must be exactly two digits each. The minutes
+	/// must be at least one digit, but are otherwise unbounded.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Msf;
+	///
+	/// let msf = Msf::decode("02:34:13").unwrap();
+	/// assert_eq!(Msf::decode("02:34.13"), Ok(msf));
+	/// assert_eq!(Msf::decode("2:34:13"), Ok(msf));
+	///
+	/// // Minutes can exceed two digits.
+	/// assert_eq!(Msf::decode("100:00:00").unwrap().minutes(), 100);
+	/// ```
+	///
+	/// Alternatively, you can use its `FromStr` and `TryFrom<&str>` impls:
+	///
+	/// ```
+	/// use cdtoc::Msf;
+	///
+	/// let msf = Msf::new(2, 34, 13).unwrap();
+	/// assert_eq!(Msf::try_from("02:34:13"), Ok(msf));
+	/// assert_eq!("02:34:13".parse::<Msf>(), Ok(msf));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the string is not formatted correctly or
+	/// any of its parts are out of range.
+	pub fn decode<S>(src: S) -> Result<Self, TocError>
+	where S: AsRef<str> {
+		let src = src.as_ref().as_bytes();
+		let len = src.len();
+		if
+			len < 7 ||
+			src[len - 6] != b':' ||
+			! matches!(src[len - 3], b':' | b'.')
+		{
+			return Err(TocError::Msf);
+		}
+
+		let m = u32::btou(&src[..len - 6]).ok_or(TocError::Msf)?;
+		let s = u8::btou(&src[len - 5..len - 3]).ok_or(TocError::Msf)?;
+		let f = u8::btou(&src[len - 2..]).ok_or(TocError::Msf)?;
+		Self::new(m, s, f)
+	}
+}
+
+impl Msf {
+	#[must_use]
+	/// # As Tuple.
+	///
+	/// Return the minutes, seconds, and frames as a tuple, the same format
+	/// returned by [`Track::msf`](crate::Track::msf).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Msf;
+	///
+	/// let msf = Msf::new(2, 34, 13).unwrap();
+	/// assert_eq!(msf.as_tuple(), (2, 34, 13));
+	/// ```
+	pub const fn as_tuple(self) -> (u32, u8, u8) { (self.m, self.s, self.f) }
+
+	#[must_use]
+	/// # Frames.
+	pub const fn frames(self) -> u8 { self.f }
+
+	#[must_use]
+	/// # Minutes.
+	pub const fn minutes(self) -> u32 { self.m }
+
+	#[must_use]
+	/// # Seconds.
+	pub const fn seconds(self) -> u8 { self.s }
+
+	#[must_use]
+	/// # To LBA.
+	///
+	/// Convert the MSF back to a sector position, the inverse of
+	/// [`Msf::from_lba`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Msf;
+	///
+	/// let msf = Msf::new(2, 34, 13).unwrap();
+	/// assert_eq!(msf.to_lba(), 11_563);
+	/// assert_eq!(Msf::from_lba(11_563), msf);
+	/// ```
+	pub const fn to_lba(self) -> u32 {
+		self.m * SECTORS_PER_MINUTE + self.s as u32 * SECTORS_PER_SECOND + self.f as u32
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_msf_lba() {
+		for (lba, expected) in [
+			(0, "00:00:00"),
+			(74, "00:00:74"),
+			(75, "00:01:00"),
+			(150, "00:02:00"),
+			(4_499, "00:59:74"),
+			(4_500, "01:00:00"),
+			(449_999, "99:59:74"),
+			(450_000, "100:00:00"),
+			(u32::MAX, "954437:10:45"),
+		] {
+			let msf = Msf::from_lba(lba);
+			assert_eq!(msf.to_string(), expected);
+			assert_eq!(msf.to_lba(), lba);
+			assert_eq!(Msf::decode(expected), Ok(msf));
+			assert_eq!(Msf::try_from(msf.as_tuple()), Ok(msf));
+		}
+
+		// One past the end is too big.
+		assert_eq!(Msf::new(954_437, 10, 46), Err(TocError::Msf));
+		assert_eq!(Msf::new(954_438, 0, 0), Err(TocError::Msf));
+	}
+
+	#[test]
+	fn t_lba_to_msf() {
+		for lba in (0..10_000).chain([449_999, 450_000, u32::MAX - 1, u32::MAX]) {
+			let (m, s, f) = lba_to_msf(lba);
+			assert!(s < 60 && f < 75);
+			assert_eq!(msf_to_lba(m, s, f), Some(lba));
+		}
+
+		assert_eq!(msf_to_lba(0, 60, 0), None);
+		assert_eq!(msf_to_lba(0, 0, 75), None);
+		assert_eq!(msf_to_lba(954_437, 10, 46), None);
+		assert_eq!(msf_to_lba(u32::MAX, 0, 0), None);
+	}
+
+	#[test]
+	fn t_msf_decode() {
+		let msf = Msf::new(2, 34, 13).expect("MSF failed.");
+		for good in ["02:34:13", "02:34.13", "2:34:13", "002:34:13"] {
+			assert_eq!(Msf::decode(good), Ok(msf), "{good}");
+		}
+
+		for bad in [
+			"", "00:00", "00:00:0", "0:0:00", ":00:00", "00.00:00", "00:00,00",
+			"00:60:00", "00:00:75", "-1:00:00", "00:+1:00", "aa:00:00",
+			" 02:34:13", "02:34:13 ", "4294967296:00:00",
+		] {
+			assert_eq!(Msf::decode(bad), Err(TocError::Msf), "{bad}");
+		}
+	}
+
+	#[test]
+	fn t_msf_arithmetic() {
+		let msf = Msf::new(0, 2, 0).expect("MSF failed.");
+		assert_eq!((msf + 75_u32).to_string(), "00:03:00");
+		assert_eq!((msf - 75_u32).to_string(), "00:01:00");
+
+		// Subtraction saturates.
+		assert_eq!(msf - 1_000_u32, Msf::default());
+
+		// So does addition.
+		assert_eq!((msf + u64::MAX).to_lba(), u32::MAX);
+
+		// Two MSFs make a duration.
+		let other = Msf::new(2, 34, 13).expect("MSF failed.");
+		assert_eq!(other - msf, Duration::from(11_413_u32));
+		assert_eq!(msf - other, Duration::default());
+
+		let mut msf2 = msf;
+		msf2 += other - msf;
+		assert_eq!(msf2, other);
+		msf2 -= other - msf;
+		assert_eq!(msf2, msf);
+	}
+}
@@ -2,31 +2,86 @@
 # CDTOC: MusicBrainz
 */
 
+mod lookup;
+mod response;
+
+pub use lookup::{
+	MbFormat,
+	MbLookupOptions,
+};
+pub use response::{
+	MbDiscIdResponse,
+	MbMedium,
+	MbRelease,
+};
+
 use crate::{
 	ShaB64,
 	Toc,
+	TocError,
 };
+use std::fmt;
+
 
 
+/// # Disc ID Page Base URL.
+const URL_BASE: &str = "https://musicbrainz.org/cdtoc/";
 
-/// # Stereo Sample Chunk Size.
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # MusicBrainz Leadout Mode.
 ///
-/// Each CDDA sample has a 16-bit left and 16-bit right value; combined they're
-/// four bytes.
-const CHUNK_SIZE: usize = 4;
+/// This determines which leadout is hashed into the MusicBrainz ID by
+/// [`Toc::musicbrainz_id_with_leadout`]. The two only differ for CD-Extra
+/// discs.
+pub enum MbLeadout {
+	#[default]
+	/// # Audio Session.
+	///
+	/// Use the [audio leadout](Toc::audio_leadout), i.e. `11,400` sectors
+	/// before the start of the data session for CD-Extra discs. This is what
+	/// the MusicBrainz spec calls for, and what [`Toc::musicbrainz_id`] uses.
+	AudioSession,
+
+	/// # Disc Leadout.
+	///
+	/// Use the [disc leadout](Toc::leadout), after the data session. Some
+	/// older tools computed CD-Extra IDs this way, and their submissions are
+	/// still in the database.
+	DiscLeadout,
+}
+
+impl fmt::Display for MbLeadout {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl MbLeadout {
+	#[must_use]
+	/// # As Str.
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::AudioSession => "audio session",
+			Self::DiscLeadout => "disc leadout",
+		}
+	}
+}
 
 
 
 impl Toc {
 	#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
 	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
-	#[expect(clippy::missing_panics_doc, reason = "False positive.")]
 	#[must_use]
 	/// # MusicBrainz ID.
 	///
 	/// This returns the [MusicBrainz](https://musicbrainz.org/) ID
 	/// corresponding to the table of contents.
 	///
+	/// The first track is assumed to be number one, as it is for virtually
+	/// all pressed CDs. See [`Toc::musicbrainz_id_with`] for the exceptions.
+	///
 	/// ## Examples
 	///
 	/// ```
@@ -43,60 +98,214 @@ impl Toc {
 	/// );
 	/// ```
 	pub fn musicbrainz_id(&self) -> ShaB64 {
+		self.musicbrainz_hash(1, self.audio_len() as u8, self.audio_leadout())
+	}
+
+	#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # MusicBrainz ID (Custom Leadout).
+	///
+	/// Same as [`Toc::musicbrainz_id`], but with a choice of leadout.
+	///
+	/// Per the spec, the leadout of a CD-Extra disc is that of the audio
+	/// session, but a number of disc IDs were submitted by tools that used
+	/// the disc leadout instead. If a CD-Extra disc can't be found under its
+	/// regular ID, it may be worth trying [`MbLeadout::DiscLeadout`].
+	///
+	/// For all other discs, the two modes produce the same ID.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{MbLeadout, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11").unwrap();
+	/// assert_eq!(
+	///     toc.musicbrainz_id_with_leadout(MbLeadout::AudioSession),
+	///     toc.musicbrainz_id(),
+	/// );
+	/// assert_eq!(
+	///     toc.musicbrainz_id_with_leadout(MbLeadout::DiscLeadout).to_string(),
+	///     "MxtByNvIi4yxLWPrm3qf97JwVDw-",
+	/// );
+	/// ```
+	pub fn musicbrainz_id_with_leadout(&self, mode: MbLeadout) -> ShaB64 {
+		let leadout = match mode {
+			MbLeadout::AudioSession => self.audio_leadout(),
+			MbLeadout::DiscLeadout => self.leadout(),
+		};
+		self.musicbrainz_hash(1, self.audio_len() as u8, leadout)
+	}
+
+	#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[must_use]
+	/// # MusicBrainz Hash Input.
+	///
+	/// Return the exact string hashed to produce the
+	/// [MusicBrainz ID](Toc::musicbrainz_id), mostly for debugging
+	/// disagreements with other software.
+	///
+	/// This consists of the (uppercase hex) first and last track numbers, the
+	/// [audio leadout](Toc::audio_leadout), and the starting sectors of tracks
+	/// `1..=99`, zero for any that don't exist, for a total of 804
+	/// characters.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let input = toc.musicbrainz_hash_input();
+	/// assert_eq!(input.len(), 804);
+	/// assert!(input.starts_with("01040000D84A0000009600002D2B000062560000B327"));
+	/// assert!(input[44..].bytes().all(|b| b == b'0'));
+	/// ```
+	pub fn musicbrainz_hash_input(&self) -> String {
+		let out = self.musicbrainz_hash_buf(1, self.audio_len() as u8, self.audio_leadout()).to_vec();
+
+		// Safety: hex is ASCII.
+		unsafe { String::from_utf8_unchecked(out) }
+	}
+
+	#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+	/// # MusicBrainz ID (Custom First Track).
+	///
+	/// Same as [`Toc::musicbrainz_id`], but with an explicit first track
+	/// number.
+	///
+	/// Some drives report a first audio track other than one for enhanced
+	/// and multi-session discs, and because the number is part of the hash,
+	/// the disc IDs submitted from them differ accordingly.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert_eq!(toc.musicbrainz_id_with(1), Ok(toc.musicbrainz_id()));
+	/// assert_eq!(
+	///     toc.musicbrainz_id_with(2).unwrap().to_string(),
+	///     "gt_kNWpX5RZf7cO.7QvsYGqY8MI-",
+	/// );
+	///
+	/// // The last track can't go past 99.
+	/// assert!(toc.musicbrainz_id_with(96).is_ok());
+	/// assert!(toc.musicbrainz_id_with(97).is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the first track is zero, or if the last
+	/// audio track would be numbered higher than `99`.
+	pub fn musicbrainz_id_with(&self, first_track: u8) -> Result<ShaB64, TocError> {
+		let last_track = self.musicbrainz_last_track(first_track)?;
+		Ok(self.musicbrainz_hash(first_track, last_track, self.audio_leadout()))
+	}
+
+	/// # MusicBrainz Last Track.
+	///
+	/// Validate the first track number, returning the corresponding last
+	/// audio track number.
+	pub(super) fn musicbrainz_last_track(&self, first_track: u8) -> Result<u8, TocError> {
+		if first_track == 0 { return Err(TocError::TrackNumber(0)); }
+		let last_track = usize::from(first_track) + self.audio_len() - 1;
+		u8::try_from(last_track)
+			.ok()
+			.filter(|n| *n <= 99)
+			.ok_or(TocError::TrackNumber(last_track))
+	}
+
+	/// # MusicBrainz Hash.
+	///
+	/// Crunch the ID. The track numbers must already be validated.
+	fn musicbrainz_hash(&self, first_track: u8, last_track: u8, leadout: u32) -> ShaB64 {
 		use sha1::Digest;
 		let mut sha = sha1::Sha1::new();
-		let mut src = [b'0'; CHUNK_SIZE * 4]; // Four raw u32s.
-		let mut dst: [u8; CHUNK_SIZE * 8] = [
-			b'0', b'1', b'0', b'0', b'0', b'0', b'0', b'0',
-			b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0',
-			b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0',
-			b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0',
-		]; // Four hexed u32s.
-
-		// Start with "01", the audio track count, and leadout.
-		faster_hex::hex_encode_fallback(&[self.audio_len() as u8], &mut dst[2..4]);
-		faster_hex::hex_encode_fallback(self.audio_leadout().to_be_bytes().as_slice(), &mut dst[4..12]);
-		dst[2..12].make_ascii_uppercase();
-		sha.update(&dst[..12]);
-
-		// Process the sector positions in batches of four to leverage SSE hex
-		// optimizations.
+		sha.update(self.musicbrainz_hash_buf(first_track, last_track, leadout).as_slice());
+		ShaB64::from(sha)
+	}
+
+	/// # MusicBrainz Hash Buffer.
+	///
+	/// Return the uppercase hex string hashed by [`Toc::musicbrainz_hash`]:
+	/// the first and last track numbers, the leadout, and the positions of
+	/// tracks `1..=99`, zeroes for any that don't exist. The track numbers
+	/// must already be validated.
+	fn musicbrainz_hash_buf(&self, first_track: u8, last_track: u8, leadout: u32)
+	-> [u8; 804] {
+		let mut out = [b'0'; 804];
+
+		// Start with the first and last track numbers, and leadout.
+		faster_hex::hex_encode_upper_fallback(&[first_track, last_track], &mut out[..4]);
+		faster_hex::hex_encode_upper_fallback(leadout.to_be_bytes().as_slice(), &mut out[4..12]);
+
+		// Copy the sectors to a raw buffer.
 		let sectors = self.audio_sectors();
-		let len = sectors.len();
-		let rem = len % CHUNK_SIZE;
-		for v in sectors.chunks_exact(CHUNK_SIZE) {
-			// Copy the values to the source buffer.
-			for (s_chunk, v) in src.chunks_exact_mut(4).zip(v) {
-				s_chunk.copy_from_slice(v.to_be_bytes().as_slice());
-			}
-
-			// Encode and hash, en masse.
-			faster_hex::hex_encode(src.as_slice(), &mut dst).unwrap();
-			dst.make_ascii_uppercase();
-			sha.update(dst.as_slice());
+		let mut raw = [0_u8; 396];
+		let len = sectors.len() * 4;
+		for (dst, v) in raw.chunks_exact_mut(4).zip(sectors) {
+			dst.copy_from_slice(v.to_be_bytes().as_slice());
 		}
 
-		// Handle the remaining sectors, if any,
-		if rem != 0 {
-			// Copy the values to the source buffer.
-			for (s_chunk, v) in src.chunks_exact_mut(4).zip(sectors[len - rem..].iter()) {
-				s_chunk.copy_from_slice(v.to_be_bytes().as_slice());
-			}
-
-			// Encode and hash, en masse.
-			let src_to = rem * 4;
-			let dst2 = &mut dst[..src_to * 2];
-			faster_hex::hex_encode_fallback(&src[..src_to], dst2);
-			dst2.make_ascii_uppercase();
-			sha.update(dst2);
-		}
+		// And hex-encode them en masse, after any skipped tracks.
+		let start = 12 + (usize::from(first_track) - 1) * 8;
+		faster_hex::hex_encode_upper(&raw[..len], &mut out[start..start + len * 2]).unwrap();
+		out
+	}
 
-		// Pad with zeroes.
-		let padding = 99 - sectors.len();
-		if padding != 0 { sha.update(&crate::ZEROES[..padding * 8]); }
+	#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+	#[must_use]
+	/// # MusicBrainz URL.
+	///
+	/// This returns the URL of the disc ID's page on the MusicBrainz website,
+	/// listing the releases — if any — it is attached to.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert_eq!(
+	///     toc.musicbrainz_url(),
+	///     "https://musicbrainz.org/cdtoc/nljDXdC8B_pDwbdY1vZJvdrAZI4-",
+	/// );
+	/// ```
+	pub fn musicbrainz_url(&self) -> String { self.musicbrainz_id().musicbrainz_url() }
+}
 
-		// Run it through base64 and we're done!
-		ShaB64::from(sha)
+impl ShaB64 {
+	#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+	#[must_use]
+	/// # MusicBrainz URL.
+	///
+	/// This returns the URL of the disc ID's page on the MusicBrainz website.
+	///
+	/// Note: this only makes sense for [MusicBrainz IDs](Toc::musicbrainz_id);
+	/// the same type is used for CTDB IDs, which MusicBrainz knows nothing
+	/// about.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::ShaB64;
+	///
+	/// let mb_id = ShaB64::decode("nljDXdC8B_pDwbdY1vZJvdrAZI4-").unwrap();
+	/// assert_eq!(
+	///     mb_id.musicbrainz_url(),
+	///     "https://musicbrainz.org/cdtoc/nljDXdC8B_pDwbdY1vZJvdrAZI4-",
+	/// );
+	/// ```
+	pub fn musicbrainz_url(&self) -> String {
+		let mut out = String::with_capacity(URL_BASE.len() + 28);
+		out.push_str(URL_BASE);
+		out.extend(self.to_array().into_iter().map(char::from));
+		out
 	}
 }
 
@@ -138,6 +347,17 @@ mod tests {
 			let mb_id = toc.musicbrainz_id();
 			assert_eq!(mb_id.to_string(), id);
 			assert_eq!(mb_id.pretty_print(), id);
+			assert_eq!(mb_id.to_array(), mb_id.to_string().as_bytes());
+			assert_eq!(mb_id.as_display().to_string(), id);
+			assert_eq!(toc.musicbrainz_id_with(1), Ok(mb_id));
+
+			// The exposed hash input should hash to the same thing.
+			let input = toc.musicbrainz_hash_input();
+			assert_eq!(input.len(), 804);
+			assert!(input.bytes().all(|b| matches!(b, b'0'..=b'9' | b'A'..=b'F')));
+			assert_eq!(ShaB64::from(<sha1::Sha1 as sha1::Digest>::new_with_prefix(&input)), mb_id);
+			assert_eq!(toc.musicbrainz_url(), format!("https://musicbrainz.org/cdtoc/{id}"));
+			assert_eq!(mb_id.musicbrainz_url(), format!("https://musicbrainz.org/cdtoc/{id}"));
 
 			// Test decoding three ways.
 			assert_eq!(ShaB64::decode(id), Ok(mb_id));
@@ -145,4 +365,78 @@ mod tests {
 			assert_eq!(id.parse::<ShaB64>(), Ok(mb_id));
 		}
 	}
+
+	#[test]
+	fn t_musicbrainz_first_track() {
+		// Starting at one, the ID should match t_musicbrainz. The others were
+		// checked against an independent implementation of the libdiscid
+		// algorithm; they were not read from real discs.
+		let toc = Toc::from_cdtoc("D+96+3B5D+78E3+B441+EC83+134F4+17225+1A801+1EA5C+23B5B+27CEF+2B58B+2F974+35D56+514C8")
+			.expect("Invalid TOC");
+		for (first, id) in [
+			(1, "ucgpiD84p.2iBxO4j3hdjSjhtnw-"),
+			(5, "s665Q7yygWSey7GhK34KDVIRwUg-"),
+			(87, "5B8AwBgJip4Ykfu9OfBQ6AZWSZk-"),
+		] {
+			assert_eq!(
+				toc.musicbrainz_id_with(first).map(|v| v.to_string()).as_deref(),
+				Ok(id),
+			);
+		}
+
+		// CD-Extra.
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC");
+		assert_eq!(
+			toc.musicbrainz_id_with(2).map(|v| v.to_string()).as_deref(),
+			Ok("jMG6UfMZ7Rquy0d6jOjY9s5GXIY-"),
+		);
+
+		// Out of range.
+		assert_eq!(toc.musicbrainz_id_with(0), Err(TocError::TrackNumber(0)));
+		assert!(toc.musicbrainz_id_with(90).is_ok());
+		assert_eq!(toc.musicbrainz_id_with(91), Err(TocError::TrackNumber(100)));
+		assert_eq!(toc.musicbrainz_id_with(u8::MAX), Err(TocError::TrackNumber(264)));
+
+		// A full disc can only start at one.
+		let toc = Toc::from_cdtoc("63+96+12D9+5546+A8A2+CAAA+128BF+17194+171DF+1722A+17275+172C0+1730B+17356+173A1+173EC+17437+17482+174CD+17518+17563+175AE+175F9+17644+1768F+176DA+17725+17770+177BB+17806+17851+1789C+178E7+17932+1797D+179C8+17A13+17A5E+17AA9+17AF4+17B3F+17B8A+17BD5+17C20+17C6B+17CB6+17D01+17D4C+17D97+17DE2+17E2D+17E78+17EC3+17F0E+17F59+17FA4+17FEF+1803A+18085+180D0+1811B+18166+181B1+181FC+18247+18292+182DD+18328+18373+183BE+18409+18454+1849F+184EA+18535+18580+185CB+18616+18661+186AC+186F7+18742+1878D+187D8+18823+1886E+188B9+18904+1894F+1899A+189E5+18A30+18A7B+18AC6+18B11+18B5C+18BA7+18BF2+18C38+1ECDC+246E9")
+			.expect("Invalid TOC");
+		assert!(toc.musicbrainz_id_with(1).is_ok());
+		assert_eq!(toc.musicbrainz_id_with(2), Err(TocError::TrackNumber(100)));
+	}
+
+	#[test]
+	fn t_musicbrainz_leadout() {
+		// The reference IDs were generated with the libdiscid algorithm.
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC");
+		let audio = toc.musicbrainz_id_with_leadout(MbLeadout::AudioSession);
+		let disc = toc.musicbrainz_id_with_leadout(MbLeadout::DiscLeadout);
+		assert_eq!(audio, toc.musicbrainz_id());
+		assert_eq!(audio, toc.musicbrainz_id_with_leadout(MbLeadout::default()));
+		assert_eq!(audio.to_string(), "h_SoGYguj8wB.DWtLbdGNOkbqvQ-");
+		assert_eq!(disc.to_string(), "MxtByNvIi4yxLWPrm3qf97JwVDw-");
+
+		// The disc leadout variant should be the same as an audio-only disc
+		// ending where the data does.
+		let mut parts = toc.audio_sectors().to_vec();
+		parts.truncate(10);
+		let alt = Toc::from_parts(parts, None, toc.leadout()).expect("Invalid TOC");
+		assert_eq!(alt.musicbrainz_id(), disc);
+
+		// Everything else should be unaffected.
+		for t in [
+			"4+96+2D2B+6256+B327+D84A",
+			"10+B6+5352+62AC+99D6+E218+12AC0+135E7+142E9+178B0+19D22+1B0D0+1E7FA+22882+247DB+27074+2A1BD+2C0FB",
+			"15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46",
+		] {
+			let toc = Toc::from_cdtoc(t).expect("Invalid TOC");
+			assert_eq!(
+				toc.musicbrainz_id_with_leadout(MbLeadout::AudioSession),
+				toc.musicbrainz_id_with_leadout(MbLeadout::DiscLeadout),
+			);
+		}
+
+		assert_eq!(MbLeadout::DiscLeadout.to_string(), "disc leadout");
+	}
 }
@@ -0,0 +1,382 @@
+/*!
+# CDTOC: MusicBrainz Lookup
+*/
+
+use crate::{
+	Toc,
+	TocError,
+};
+use std::fmt;
+
+
+
+/// # Web Service Base URL.
+const WS_BASE: &str = "https://musicbrainz.org/ws/2/discid/";
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # MusicBrainz Response Format.
+///
+/// This sets the `fmt` parameter of a [lookup](Toc::musicbrainz_lookup_url).
+pub enum MbFormat {
+	#[default]
+	/// # JSON.
+	Json,
+
+	/// # XML.
+	Xml,
+}
+
+impl fmt::Display for MbFormat {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl MbFormat {
+	#[must_use]
+	/// # As Str.
+	///
+	/// Return the value as it appears in the lookup URL.
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Json => "json",
+			Self::Xml => "xml",
+		}
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # MusicBrainz Lookup Options.
+///
+/// This holds the query settings used by [`Toc::musicbrainz_lookup_url`].
+///
+/// The [`Default`] requests JSON with `recordings` and `artist-credits`,
+/// includes the `toc` parameter for fuzzy matching, leaves `cdstubs` and
+/// `media-format` at the server defaults, and numbers the first track `1`.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{MbFormat, MbLookupOptions};
+///
+/// let opts = MbLookupOptions::default()
+///     .with_format(MbFormat::Xml)
+///     .with_inc(["labels"]);
+/// assert_eq!(opts.format(), MbFormat::Xml);
+/// assert_eq!(opts.inc(), ["labels"]);
+/// assert!(opts.toc());
+/// ```
+pub struct MbLookupOptions {
+	/// # Includes.
+	inc: Vec<String>,
+
+	/// # Format.
+	format: MbFormat,
+
+	/// # Include TOC.
+	toc: bool,
+
+	/// # Include CD Stubs.
+	cdstubs: bool,
+
+	/// # All Media Formats.
+	all_media: bool,
+
+	/// # First Track Number.
+	first_track: u8,
+}
+
+impl Default for MbLookupOptions {
+	fn default() -> Self {
+		Self {
+			inc: vec!["recordings".to_owned(), "artist-credits".to_owned()],
+			format: MbFormat::Json,
+			toc: true,
+			cdstubs: true,
+			all_media: false,
+			first_track: 1,
+		}
+	}
+}
+
+impl MbLookupOptions {
+	#[must_use]
+	/// # With All Media Formats.
+	///
+	/// When `true`, fuzzy `toc` matches will consider all media formats rather
+	/// than just CDs (`media-format=all`).
+	pub const fn with_all_media(mut self, all_media: bool) -> Self {
+		self.all_media = all_media;
+		self
+	}
+
+	#[must_use]
+	/// # With CD Stubs.
+	///
+	/// Set to `false` to exclude CD stubs from the results (`cdstubs=no`).
+	pub const fn with_cdstubs(mut self, cdstubs: bool) -> Self {
+		self.cdstubs = cdstubs;
+		self
+	}
+
+	#[must_use]
+	/// # With First Track.
+	///
+	/// Set the first track number used for both the disc ID and the `toc`
+	/// parameter. See [`Toc::musicbrainz_id_with`] for more details.
+	pub const fn with_first_track(mut self, first_track: u8) -> Self {
+		self.first_track = first_track;
+		self
+	}
+
+	#[must_use]
+	/// # With Format.
+	///
+	/// Set the response format (`fmt`).
+	pub const fn with_format(mut self, format: MbFormat) -> Self {
+		self.format = format;
+		self
+	}
+
+	#[must_use]
+	/// # With Includes.
+	///
+	/// Replace the list of related entities to include in the response
+	/// (`inc`), e.g. `recordings`, `artist-credits`, `labels`. An empty list
+	/// omits the parameter.
+	///
+	/// The values are passed through as-is, joined with `+`.
+	pub fn with_inc<I, S>(mut self, inc: I) -> Self
+	where I: IntoIterator<Item=S>, S: Into<String> {
+		self.inc = inc.into_iter().map(Into::into).collect();
+		self
+	}
+
+	#[must_use]
+	/// # With TOC.
+	///
+	/// Enable or disable the `toc` parameter. With it, the server can fall
+	/// back to fuzzy matching when the disc ID itself is unknown.
+	pub const fn with_toc(mut self, toc: bool) -> Self {
+		self.toc = toc;
+		self
+	}
+
+	#[must_use]
+	/// # All Media Formats?
+	pub const fn all_media(&self) -> bool { self.all_media }
+
+	#[must_use]
+	/// # Include CD Stubs?
+	pub const fn cdstubs(&self) -> bool { self.cdstubs }
+
+	#[must_use]
+	/// # First Track.
+	pub const fn first_track(&self) -> u8 { self.first_track }
+
+	#[must_use]
+	/// # Format.
+	pub const fn format(&self) -> MbFormat { self.format }
+
+	#[must_use]
+	/// # Includes.
+	pub fn inc(&self) -> &[String] { &self.inc }
+
+	#[must_use]
+	/// # Include TOC?
+	pub const fn toc(&self) -> bool { self.toc }
+}
+
+
+
+impl Toc {
+	#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+	/// # MusicBrainz Lookup URL.
+	///
+	/// This returns the MusicBrainz web service (`ws/2`) URL for looking up
+	/// the disc's releases.
+	///
+	/// The `toc` parameter, if enabled, lists the first and last track
+	/// numbers, the [audio leadout](Toc::audio_leadout), and the starting
+	/// sector of each audio track, separated by `+`. As with
+	/// [`Toc::musicbrainz_id`], only the audio session is considered; for
+	/// CD-Extra discs the leadout is that of the audio session, not the disc.
+	///
+	/// The track numbering follows [`MbLookupOptions::first_track`], for both
+	/// the `toc` and the [disc ID](Toc::musicbrainz_id_with).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{MbLookupOptions, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert_eq!(
+	///     toc.musicbrainz_lookup_url(&MbLookupOptions::default()).unwrap(),
+	///     "https://musicbrainz.org/ws/2/discid/nljDXdC8B_pDwbdY1vZJvdrAZI4-?toc=1+4+55370+150+11563+25174+45863&inc=recordings+artist-credits&fmt=json",
+	/// );
+	///
+	/// // The same disc, numbered from two.
+	/// assert_eq!(
+	///     toc.musicbrainz_lookup_url(&MbLookupOptions::default().with_first_track(2)).unwrap(),
+	///     "https://musicbrainz.org/ws/2/discid/gt_kNWpX5RZf7cO.7QvsYGqY8MI-?toc=2+5+55370+150+11563+25174+45863&inc=recordings+artist-credits&fmt=json",
+	/// );
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the first track number is invalid for the
+	/// disc.
+	pub fn musicbrainz_lookup_url(&self, opts: &MbLookupOptions) -> Result<String, TocError> {
+		let id = self.musicbrainz_id_with(opts.first_track)?;
+		let mut out = String::with_capacity(128);
+		out.push_str(WS_BASE);
+		out.extend(id.to_array().into_iter().map(char::from));
+		out.push('?');
+
+		if opts.toc {
+			let mut buf = itoa::Buffer::new();
+			out.push_str("toc=");
+			out.push_str(buf.format(opts.first_track));
+			out.push('+');
+			out.push_str(buf.format(self.musicbrainz_last_track(opts.first_track)?));
+			out.push('+');
+			out.push_str(buf.format(self.audio_leadout()));
+			for v in self.audio_sectors() {
+				out.push('+');
+				out.push_str(buf.format(*v));
+			}
+			out.push('&');
+		}
+
+		if ! opts.inc.is_empty() {
+			out.push_str("inc=");
+			for (k, v) in opts.inc.iter().enumerate() {
+				if k != 0 { out.push('+'); }
+				out.push_str(v);
+			}
+			out.push('&');
+		}
+
+		if ! opts.cdstubs { out.push_str("cdstubs=no&"); }
+		if opts.all_media { out.push_str("media-format=all&"); }
+
+		out.push_str("fmt=");
+		out.push_str(opts.format.as_str());
+		Ok(out)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_lookup_url() {
+		for (t, expected) in [
+			(
+				"4+96+2D2B+6256+B327+D84A",
+				"https://musicbrainz.org/ws/2/discid/nljDXdC8B_pDwbdY1vZJvdrAZI4-?toc=1+4+55370+150+11563+25174+45863&inc=recordings+artist-credits&fmt=json",
+			),
+			(
+				"D+96+3B5D+78E3+B441+EC83+134F4+17225+1A801+1EA5C+23B5B+27CEF+2B58B+2F974+35D56+514C8",
+				"https://musicbrainz.org/ws/2/discid/ucgpiD84p.2iBxO4j3hdjSjhtnw-?toc=1+13+209102+150+15197+30947+46145+60547+79092+94757+108545+125532+146267+163055+177547+194932&inc=recordings+artist-credits&fmt=json",
+			),
+			(
+				"10+B6+5352+62AC+99D6+E218+12AC0+135E7+142E9+178B0+19D22+1B0D0+1E7FA+22882+247DB+27074+2A1BD+2C0FB",
+				"https://musicbrainz.org/ws/2/discid/PQ02DnwdDaxgWEFSpAzI_IVBL3o-?toc=1+16+180475+182+21330+25260+39382+57880+76480+79335+82665+96432+105762+110800+124922+141442+149467+159860+172477&inc=recordings+artist-credits&fmt=json",
+			),
+			(
+				"15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46",
+				"https://musicbrainz.org/ws/2/discid/JTsyXbyn9DUbppDWELj5o5CiFaI-?toc=1+21+207686+9342+11244+19188+29544+38660+46996+57969+69840+76666+83393+93359+103887+111631+127050+136064+144941+153757+162109+173920+186930+196833&inc=recordings+artist-credits&fmt=json",
+			),
+		] {
+			let toc = Toc::from_cdtoc(t).expect("Invalid TOC.");
+			assert_eq!(toc.musicbrainz_lookup_url(&MbLookupOptions::default()).as_deref(), Ok(expected), "{t}");
+		}
+	}
+
+	#[test]
+	fn t_lookup_url_cdextra() {
+		// The leadout should be that of the audio session, i.e. 11,400 sectors
+		// before the data.
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC.");
+		let mb_id = toc.musicbrainz_id();
+		assert_eq!(
+			toc.musicbrainz_lookup_url(&MbLookupOptions::default().with_inc::<_, String>([])),
+			Ok(format!("https://musicbrainz.org/ws/2/discid/{mb_id}?toc=1+10+174887+150+14167+26989+50767+68115+85410+106120+121770+136100+161870&fmt=json")),
+		);
+	}
+
+	#[test]
+	fn t_lookup_url_first_track() {
+		let toc = Toc::from_cdtoc("A+96+3757+696D+C64F+10A13+14DA2+19E88+1DBAA+213A4+2784E+2D7AF+36F11")
+			.expect("Invalid TOC.");
+		let opts = MbLookupOptions::default().with_inc::<_, String>([]).with_first_track(2);
+		assert_eq!(opts.first_track(), 2);
+		assert_eq!(
+			toc.musicbrainz_lookup_url(&opts).as_deref(),
+			Ok("https://musicbrainz.org/ws/2/discid/jMG6UfMZ7Rquy0d6jOjY9s5GXIY-?toc=2+11+174887+150+14167+26989+50767+68115+85410+106120+121770+136100+161870&fmt=json"),
+		);
+
+		// The ID and TOC should agree.
+		let id = toc.musicbrainz_id_with(2).expect("Invalid first track.");
+		assert!(toc.musicbrainz_lookup_url(&opts).is_ok_and(|v| v.contains(&id.to_string())));
+
+		// Bad numbers.
+		for (first, last) in [(0, 0), (91, 100), (99, 108)] {
+			assert_eq!(
+				toc.musicbrainz_lookup_url(&opts.clone().with_first_track(first)),
+				Err(TocError::TrackNumber(last)),
+			);
+		}
+	}
+
+	#[test]
+	fn t_lookup_opts() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let base = "https://musicbrainz.org/ws/2/discid/nljDXdC8B_pDwbdY1vZJvdrAZI4-?";
+		let toc_param = "toc=1+4+55370+150+11563+25174+45863&";
+
+		for (opts, query) in [
+			(
+				MbLookupOptions::default().with_toc(false),
+				"inc=recordings+artist-credits&fmt=json".to_owned(),
+			),
+			(
+				MbLookupOptions::default().with_format(MbFormat::Xml).with_inc(["labels"]),
+				format!("{toc_param}inc=labels&fmt=xml"),
+			),
+			(
+				MbLookupOptions::default().with_cdstubs(false).with_all_media(true),
+				format!("{toc_param}inc=recordings+artist-credits&cdstubs=no&media-format=all&fmt=json"),
+			),
+			(
+				MbLookupOptions::default()
+					.with_toc(false)
+					.with_inc::<_, &str>([])
+					.with_cdstubs(false),
+				"cdstubs=no&fmt=json".to_owned(),
+			),
+		] {
+			assert_eq!(toc.musicbrainz_lookup_url(&opts), Ok(format!("{base}{query}")));
+		}
+
+		// Getters.
+		let opts = MbLookupOptions::default();
+		assert_eq!(opts.inc(), ["recordings", "artist-credits"]);
+		assert_eq!(opts.format(), MbFormat::Json);
+		assert!(opts.toc());
+		assert!(opts.cdstubs());
+		assert!(! opts.all_media());
+		assert_eq!(opts.first_track(), 1);
+		assert_eq!(MbFormat::Xml.to_string(), "xml");
+	}
+}
@@ -0,0 +1,830 @@
+/*!
+# CDTOC: MusicBrainz Response
+*/
+
+use crate::{
+	Toc,
+	TocError,
+	xml::{
+		Tag,
+		Tags,
+	},
+};
+#[cfg(feature = "musicbrainz-json")] use serde_json::Value;
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # MusicBrainz Disc ID Response.
+///
+/// This holds the useful parts of a MusicBrainz `ws/2` disc ID
+/// [lookup](Toc::musicbrainz_lookup_url) response: the disc itself — if it
+/// was found — and the releases it belongs to.
+///
+/// When the disc ID is unknown but the lookup included a `toc`, the server
+/// may instead return a list of releases with similar tables of contents. In
+/// that case there is no disc information and [`MbDiscIdResponse::is_fuzzy`]
+/// returns `true`.
+pub struct MbDiscIdResponse {
+	/// # Disc ID.
+	id: Option<String>,
+
+	/// # Total Sectors.
+	sectors: u32,
+
+	/// # Track Offsets.
+	offsets: Vec<u32>,
+
+	/// # Releases.
+	releases: Vec<MbRelease>,
+}
+
+impl MbDiscIdResponse {
+	#[must_use]
+	/// # Disc ID.
+	///
+	/// Return the disc ID, unless the response is [fuzzy](MbDiscIdResponse::is_fuzzy).
+	pub fn id(&self) -> Option<&str> { self.id.as_deref() }
+
+	#[must_use]
+	/// # Is Fuzzy?
+	///
+	/// Returns `true` if the response is a list of releases with similar
+	/// tables of contents rather than an exact disc match.
+	pub const fn is_fuzzy(&self) -> bool { self.id.is_none() }
+
+	#[must_use]
+	/// # Matches?
+	///
+	/// Returns `true` if the disc's sector count and track offsets match the
+	/// table of contents, i.e. its [audio leadout](Toc::audio_leadout) and
+	/// [audio sectors](Toc::audio_sectors). Fuzzy responses never match.
+	pub fn matches(&self, toc: &Toc) -> bool {
+		! self.is_fuzzy() &&
+		self.sectors == toc.audio_leadout() &&
+		self.offsets == toc.audio_sectors()
+	}
+
+	#[must_use]
+	/// # Track Offsets.
+	///
+	/// Return the starting sector of each track, including the 150-sector
+	/// lead-in. This is empty for fuzzy responses.
+	pub fn offsets(&self) -> &[u32] { &self.offsets }
+
+	#[must_use]
+	/// # Releases.
+	pub fn releases(&self) -> &[MbRelease] { &self.releases }
+
+	#[must_use]
+	/// # Total Sectors.
+	///
+	/// Return the disc's length in sectors, i.e. the leadout, or zero for
+	/// fuzzy responses.
+	pub const fn sectors(&self) -> u32 { self.sectors }
+}
+
+impl MbDiscIdResponse {
+	/// # From XML.
+	///
+	/// Parse a `fmt=xml` disc ID lookup response, the server's default.
+	///
+	/// The result is the same as [`MbDiscIdResponse::from_json`] would
+	/// return for the equivalent JSON. Namespace prefixes are ignored.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{MbDiscIdResponse, Toc};
+	///
+	/// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+	/// <metadata xmlns="http://musicbrainz.org/ns/mmd-2.0#">
+	///   <disc id="nljDXdC8B_pDwbdY1vZJvdrAZI4-">
+	///     <sectors>55370</sectors>
+	///     <offset-list count="4">
+	///       <offset position="1">150</offset>
+	///       <offset position="2">11563</offset>
+	///       <offset position="3">25174</offset>
+	///       <offset position="4">45863</offset>
+	///     </offset-list>
+	///     <release-list count="1">
+	///       <release id="5f3e4c7a-2b1d-4e8f-9a6c-0d7b8e9f1a2b">
+	///         <title>Example</title>
+	///         <medium-list count="1">
+	///           <medium><position>1</position><format>CD</format><track-list count="4" /></medium>
+	///         </medium-list>
+	///       </release>
+	///     </release-list>
+	///   </disc>
+	/// </metadata>"#;
+	///
+	/// let res = MbDiscIdResponse::from_xml(xml).unwrap();
+	/// assert_eq!(res.releases()[0].title(), "Example");
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert!(res.matches(&toc));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the XML is malformed, or doesn't look like
+	/// a disc ID or release list response.
+	pub fn from_xml(src: &str) -> Result<Self, TocError> {
+		let mut state = XmlState::default();
+		for tag in Tags::new(src) {
+			let tag = tag.map_err(|_| TocError::MusicBrainzResponse)?;
+			if tag.is_closing() { state.close(&tag)?; }
+			else {
+				state.open(&tag)?;
+				if tag.is_self_closing() { state.close(&tag)?; }
+			}
+		}
+		state.finish()
+	}
+}
+
+#[cfg(feature = "musicbrainz-json")]
+impl MbDiscIdResponse {
+	#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz-json")))]
+	/// # From JSON.
+	///
+	/// Parse a `fmt=json` disc ID lookup response.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{MbDiscIdResponse, Toc};
+	///
+	/// let json = r#"{
+	///   "id": "nljDXdC8B_pDwbdY1vZJvdrAZI4-",
+	///   "sectors": 55370,
+	///   "offset-count": 4,
+	///   "offsets": [150, 11563, 25174, 45863],
+	///   "releases": [{
+	///     "id": "5f3e4c7a-2b1d-4e8f-9a6c-0d7b8e9f1a2b",
+	///     "title": "Example",
+	///     "date": "1999-06-12",
+	///     "country": "IS",
+	///     "media": [{ "position": 1, "format": "CD", "track-count": 4 }]
+	///   }]
+	/// }"#;
+	///
+	/// let res = MbDiscIdResponse::from_json(json).unwrap();
+	/// assert_eq!(res.releases()[0].title(), "Example");
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// assert!(res.matches(&toc));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the JSON is invalid, or doesn't look like
+	/// a disc ID or release list response.
+	pub fn from_json(src: &str) -> Result<Self, TocError> {
+		let Ok(Value::Object(map)) = serde_json::from_str::<Value>(src) else {
+			return Err(TocError::MusicBrainzResponse);
+		};
+
+		// Releases are common to both shapes.
+		let releases = match map.get("releases") {
+			Some(Value::Array(v)) => v.iter()
+				.map(MbRelease::from_json)
+				.collect::<Option<Vec<_>>>()
+				.ok_or(TocError::MusicBrainzResponse)?,
+			None if map.contains_key("id") => Vec::new(),
+			_ => return Err(TocError::MusicBrainzResponse),
+		};
+
+		// A disc?
+		if let Some(id) = map.get("id") {
+			let id = id.as_str()
+				.filter(|v| ! v.is_empty())
+				.ok_or(TocError::MusicBrainzResponse)?
+				.to_owned();
+			let sectors = map.get("sectors")
+				.and_then(json_u32)
+				.ok_or(TocError::MusicBrainzResponse)?;
+			let offsets = map.get("offsets")
+				.and_then(Value::as_array)
+				.and_then(|v| v.iter().map(json_u32).collect::<Option<Vec<_>>>())
+				.filter(|v| ! v.is_empty())
+				.ok_or(TocError::MusicBrainzResponse)?;
+
+			Ok(Self { id: Some(id), sectors, offsets, releases })
+		}
+		// A fuzzy release list.
+		else {
+			Ok(Self { id: None, sectors: 0, offsets: Vec::new(), releases })
+		}
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # MusicBrainz Release.
+///
+/// This holds the highlights of a release from a [`MbDiscIdResponse`].
+pub struct MbRelease {
+	/// # ID (MBID).
+	id: String,
+
+	/// # Title.
+	title: String,
+
+	/// # Date.
+	date: Option<String>,
+
+	/// # Country.
+	country: Option<String>,
+
+	/// # Media.
+	media: Vec<MbMedium>,
+}
+
+impl MbRelease {
+	#[must_use]
+	/// # Country.
+	///
+	/// Return the release country code, if known.
+	pub fn country(&self) -> Option<&str> { self.country.as_deref() }
+
+	#[must_use]
+	/// # Date.
+	///
+	/// Return the release date, if known. This may be a full `YYYY-MM-DD`
+	/// date, or just the year or year and month.
+	pub fn date(&self) -> Option<&str> { self.date.as_deref() }
+
+	#[must_use]
+	/// # ID.
+	///
+	/// Return the release's MusicBrainz ID (a UUID).
+	pub fn id(&self) -> &str { &self.id }
+
+	#[must_use]
+	/// # Media.
+	pub fn media(&self) -> &[MbMedium] { &self.media }
+
+	#[must_use]
+	/// # Title.
+	pub fn title(&self) -> &str { &self.title }
+}
+
+#[cfg(feature = "musicbrainz-json")]
+impl MbRelease {
+	/// # From JSON.
+	fn from_json(src: &Value) -> Option<Self> {
+		let id = src.get("id")?.as_str().filter(|v| ! v.is_empty())?.to_owned();
+		let title = src.get("title")?.as_str()?.to_owned();
+		let date = json_opt_str(src, "date");
+		let country = json_opt_str(src, "country");
+		let media = match src.get("media") {
+			Some(Value::Array(v)) => v.iter().map(MbMedium::from_json).collect::<Option<Vec<_>>>()?,
+			None | Some(Value::Null) => Vec::new(),
+			_ => return None,
+		};
+
+		Some(Self { id, title, date, country, media })
+	}
+}
+
+
+
+#[cfg_attr(docsrs, doc(cfg(feature = "musicbrainz")))]
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+/// # MusicBrainz Medium.
+///
+/// This holds the summary of a single medium from an [`MbRelease`].
+pub struct MbMedium {
+	/// # Position.
+	position: Option<u16>,
+
+	/// # Format.
+	format: Option<String>,
+
+	/// # Track Count.
+	track_count: u16,
+}
+
+impl MbMedium {
+	#[must_use]
+	/// # Format.
+	///
+	/// Return the format, e.g. `CD`, if known.
+	pub fn format(&self) -> Option<&str> { self.format.as_deref() }
+
+	#[must_use]
+	/// # Position.
+	///
+	/// Return the (one-based) position of the medium within the release, if
+	/// known.
+	pub const fn position(&self) -> Option<u16> { self.position }
+
+	#[must_use]
+	/// # Track Count.
+	pub const fn track_count(&self) -> u16 { self.track_count }
+}
+
+#[cfg(feature = "musicbrainz-json")]
+impl MbMedium {
+	/// # From JSON.
+	fn from_json(src: &Value) -> Option<Self> {
+		let track_count = src.get("track-count")
+			.and_then(Value::as_u64)
+			.and_then(|v| u16::try_from(v).ok())?;
+		let position = match src.get("position") {
+			Some(Value::Null) | None => None,
+			Some(v) => Some(v.as_u64().and_then(|v| u16::try_from(v).ok())?),
+		};
+		let format = json_opt_str(src, "format");
+		Some(Self { position, format, track_count })
+	}
+}
+
+
+
+#[derive(Default)]
+/// # XML Parser State.
+struct XmlState<'a> {
+	/// # Output.
+	out: MbDiscIdResponse,
+
+	/// # Open Elements (Local Names).
+	stack: Vec<&'a str>,
+
+	/// # Found a Release List?
+	list: bool,
+
+	/// # Current Release.
+	release: Option<MbRelease>,
+
+	/// # Current Release Has a Title?
+	title: bool,
+
+	/// # Current Medium.
+	medium: Option<MbMedium>,
+
+	/// # Current Medium Track Count.
+	tracks: Option<u16>,
+}
+
+impl<'a> XmlState<'a> {
+	/// # Open Tag.
+	fn open(&mut self, tag: &Tag<'a>) -> Result<(), TocError> {
+		let name = tag.local_name();
+		match (self.stack.as_slice(), name) {
+			([], "metadata") => {},
+			([], _) => return Err(TocError::MusicBrainzResponse),
+			(["metadata"], "disc") => {
+				let id = tag.attr("id")
+					.filter(|v| ! v.trim().is_empty())
+					.ok_or(TocError::MusicBrainzResponse)?;
+				self.out.id = Some(id.trim().to_owned());
+			},
+			(["metadata"] | ["metadata", "disc"], "release-list") => { self.list = true; },
+			([.., "release-list"], "release") if self.release.is_none() => {
+				let id = tag.attr("id")
+					.filter(|v| ! v.trim().is_empty())
+					.ok_or(TocError::MusicBrainzResponse)?;
+				self.release = Some(MbRelease { id: id.trim().to_owned(), ..MbRelease::default() });
+				self.title = false;
+			},
+			([.., "release", "medium-list"], "medium") if self.release.is_some() => {
+				self.medium = Some(MbMedium::default());
+				self.tracks = None;
+			},
+			([.., "medium"], "track-list") if self.medium.is_some() => {
+				self.tracks = Some(
+					tag.attr("count")
+						.and_then(|v| v.trim().parse::<u16>().ok())
+						.ok_or(TocError::MusicBrainzResponse)?
+				);
+			},
+			_ => {},
+		}
+
+		self.stack.push(name);
+		Ok(())
+	}
+
+	/// # Close Tag.
+	fn close(&mut self, tag: &Tag<'a>) -> Result<(), TocError> {
+		if self.stack.last() != Some(&tag.local_name()) {
+			return Err(TocError::MusicBrainzResponse);
+		}
+
+		let text = if tag.is_self_closing() { "".into() } else { tag.text() };
+		match self.stack.as_slice() {
+			["metadata", "disc", "sectors"] => {
+				self.out.sectors = text.parse().map_err(|_| TocError::MusicBrainzResponse)?;
+			},
+			["metadata", "disc", "offset-list", "offset"] => {
+				self.out.offsets.push(text.parse().map_err(|_| TocError::MusicBrainzResponse)?);
+			},
+			[.., "release", "title"] => if let Some(r) = self.release.as_mut() {
+				r.title = text.into_owned();
+				self.title = true;
+			},
+			[.., "release", "date"] => if let Some(r) = self.release.as_mut() {
+				r.date = Some(text.into_owned()).filter(|v| ! v.is_empty());
+			},
+			[.., "release", "country"] => if let Some(r) = self.release.as_mut() {
+				r.country = Some(text.into_owned()).filter(|v| ! v.is_empty());
+			},
+			[.., "medium", "position"] => if let Some(m) = self.medium.as_mut() {
+				m.position = Some(text.parse().map_err(|_| TocError::MusicBrainzResponse)?);
+			},
+			[.., "medium", "format"] => if let Some(m) = self.medium.as_mut() {
+				m.format = Some(text.into_owned()).filter(|v| ! v.is_empty());
+			},
+			[.., "medium-list", "medium"] => if let Some(mut m) = self.medium.take() {
+				m.track_count = self.tracks.take().ok_or(TocError::MusicBrainzResponse)?;
+				self.release.as_mut().ok_or(TocError::MusicBrainzResponse)?.media.push(m);
+			},
+			[.., "release-list", "release"] => if let Some(r) = self.release.take() {
+				if ! self.title { return Err(TocError::MusicBrainzResponse); }
+				self.out.releases.push(r);
+			},
+			_ => {},
+		}
+
+		self.stack.pop();
+		Ok(())
+	}
+
+	/// # Finish.
+	fn finish(self) -> Result<MbDiscIdResponse, TocError> {
+		if ! self.stack.is_empty() { return Err(TocError::MusicBrainzResponse); }
+
+		// A disc needs its sectors and offsets; otherwise we need a list.
+		let ok =
+			if self.out.id.is_some() {
+				self.out.sectors != 0 && ! self.out.offsets.is_empty()
+			}
+			else { self.list };
+
+		if ok { Ok(self.out) }
+		else { Err(TocError::MusicBrainzResponse) }
+	}
+}
+
+
+
+#[cfg(feature = "musicbrainz-json")]
+/// # JSON Optional String.
+///
+/// Return the value if it is a non-empty string.
+fn json_opt_str(src: &Value, key: &str) -> Option<String> {
+	src.get(key)
+		.and_then(Value::as_str)
+		.filter(|v| ! v.is_empty())
+		.map(str::to_owned)
+}
+
+#[cfg(feature = "musicbrainz-json")]
+/// # JSON u32.
+fn json_u32(src: &Value) -> Option<u32> {
+	src.as_u64().and_then(|v| u32::try_from(v).ok())
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[cfg(feature = "musicbrainz-json")]
+	/// # Exact Lookup (JSON).
+	///
+	/// This follows the layout of a `ws/2/discid/<id>?fmt=json` response,
+	/// trimmed down a little.
+	pub(super) const EXACT_JSON: &str = r#"{
+  "id": "nljDXdC8B_pDwbdY1vZJvdrAZI4-",
+  "sectors": 55370,
+  "offset-count": 4,
+  "offsets": [150, 11563, 25174, 45863],
+  "releases": [
+    {
+      "id": "5f3e4c7a-2b1d-4e8f-9a6c-0d7b8e9f1a2b",
+      "title": "Ágætis byrjun",
+      "status": "Official",
+      "quality": "normal",
+      "date": "1999-06-12",
+      "country": "IS",
+      "barcode": "5690351010880",
+      "text-representation": { "language": "isl", "script": "Latn" },
+      "media": [
+        {
+          "position": 1,
+          "format": "CD",
+          "format-id": "9712d52a-4509-3d4b-a1a2-67c88c643e31",
+          "title": "",
+          "track-count": 4,
+          "track-offset": 0,
+          "discs": [
+            {
+              "id": "nljDXdC8B_pDwbdY1vZJvdrAZI4-",
+              "sectors": 55370,
+              "offset-count": 4,
+              "offsets": [150, 11563, 25174, 45863]
+            }
+          ]
+        }
+      ]
+    },
+    {
+      "id": "0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d",
+      "title": "Ágætis byrjun",
+      "status": "Official",
+      "date": "2000",
+      "country": null,
+      "media": [
+        { "position": 1, "format": null, "track-count": 4 },
+        { "position": 2, "format": "DVD-Video", "track-count": 2 }
+      ]
+    }
+  ]
+}"#;
+
+	#[cfg(feature = "musicbrainz-json")]
+	/// # Fuzzy Lookup (JSON).
+	///
+	/// This follows the layout of a `ws/2/discid/-?toc=…&fmt=json` response,
+	/// where the server returns a release list instead of a disc.
+	pub(super) const FUZZY_JSON: &str = r#"{
+  "release-count": 2,
+  "release-offset": 0,
+  "releases": [
+    {
+      "id": "5f3e4c7a-2b1d-4e8f-9a6c-0d7b8e9f1a2b",
+      "title": "Ágætis byrjun",
+      "date": "1999-06-12",
+      "country": "IS",
+      "media": [{ "position": 1, "format": "CD", "track-count": 4 }]
+    },
+    {
+      "id": "7b8c9d0e-1f2a-4b3c-9d4e-5f6a7b8c9d0e",
+      "title": "Von & \"Vonbrigði\"",
+      "date": "",
+      "media": []
+    }
+  ]
+}"#;
+
+	/// # Exact Lookup (XML).
+	///
+	/// The same as [`EXACT_JSON`], but in the server's default format.
+	pub(super) const EXACT_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata xmlns="http://musicbrainz.org/ns/mmd-2.0#">
+  <disc id="nljDXdC8B_pDwbdY1vZJvdrAZI4-">
+    <sectors>55370</sectors>
+    <offset-list count="4">
+      <offset position="1">150</offset>
+      <offset position="2">11563</offset>
+      <offset position="3">25174</offset>
+      <offset position="4">45863</offset>
+    </offset-list>
+    <release-list count="2">
+      <release id="5f3e4c7a-2b1d-4e8f-9a6c-0d7b8e9f1a2b">
+        <title>Ágætis byrjun</title>
+        <status id="4e304316-386d-3409-af2e-78857eec5cfe">Official</status>
+        <quality>normal</quality>
+        <text-representation>
+          <language>isl</language>
+          <script>Latn</script>
+        </text-representation>
+        <date>1999-06-12</date>
+        <country>IS</country>
+        <barcode>5690351010880</barcode>
+        <medium-list count="1">
+          <medium>
+            <position>1</position>
+            <format id="9712d52a-4509-3d4b-a1a2-67c88c643e31">CD</format>
+            <disc-list count="1">
+              <disc id="nljDXdC8B_pDwbdY1vZJvdrAZI4-">
+                <sectors>55370</sectors>
+                <offset-list count="4">
+                  <offset position="1">150</offset>
+                  <offset position="2">11563</offset>
+                  <offset position="3">25174</offset>
+                  <offset position="4">45863</offset>
+                </offset-list>
+              </disc>
+            </disc-list>
+            <track-list count="4" offset="0" />
+          </medium>
+        </medium-list>
+      </release>
+      <release id="0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d">
+        <title>Ágætis byrjun</title>
+        <status>Official</status>
+        <date>2000</date>
+        <medium-list count="2">
+          <medium>
+            <position>1</position>
+            <track-list count="4" />
+          </medium>
+          <medium>
+            <position>2</position>
+            <format>DVD-Video</format>
+            <track-list count="2" />
+          </medium>
+        </medium-list>
+      </release>
+    </release-list>
+  </disc>
+</metadata>"#;
+
+	/// # Fuzzy Lookup (XML).
+	///
+	/// The same as [`FUZZY_JSON`], but in XML, with a namespace prefix for
+	/// good measure.
+	pub(super) const FUZZY_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<mmd:metadata xmlns:mmd="http://musicbrainz.org/ns/mmd-2.0#">
+  <!-- Fuzzy matches. -->
+  <mmd:release-list count="2" offset="0">
+    <mmd:release id="5f3e4c7a-2b1d-4e8f-9a6c-0d7b8e9f1a2b">
+      <mmd:title>Ágætis byrjun</mmd:title>
+      <mmd:date>1999-06-12</mmd:date>
+      <mmd:country>IS</mmd:country>
+      <mmd:medium-list count="1">
+        <mmd:medium>
+          <mmd:position>1</mmd:position>
+          <mmd:format>CD</mmd:format>
+          <mmd:track-list count="4" />
+        </mmd:medium>
+      </mmd:medium-list>
+    </mmd:release>
+    <mmd:release id="7b8c9d0e-1f2a-4b3c-9d4e-5f6a7b8c9d0e">
+      <mmd:title>Von &amp; &quot;Vonbrigði&quot;</mmd:title>
+      <mmd:date />
+      <mmd:medium-list count="0" />
+    </mmd:release>
+  </mmd:release-list>
+</mmd:metadata>"#;
+
+	/// # Expected Exact.
+	pub(super) fn exact() -> MbDiscIdResponse {
+		MbDiscIdResponse {
+			id: Some("nljDXdC8B_pDwbdY1vZJvdrAZI4-".to_owned()),
+			sectors: 55370,
+			offsets: vec![150, 11563, 25174, 45863],
+			releases: vec![
+				MbRelease {
+					id: "5f3e4c7a-2b1d-4e8f-9a6c-0d7b8e9f1a2b".to_owned(),
+					title: "Ágætis byrjun".to_owned(),
+					date: Some("1999-06-12".to_owned()),
+					country: Some("IS".to_owned()),
+					media: vec![
+						MbMedium { position: Some(1), format: Some("CD".to_owned()), track_count: 4 },
+					],
+				},
+				MbRelease {
+					id: "0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d".to_owned(),
+					title: "Ágætis byrjun".to_owned(),
+					date: Some("2000".to_owned()),
+					country: None,
+					media: vec![
+						MbMedium { position: Some(1), format: None, track_count: 4 },
+						MbMedium { position: Some(2), format: Some("DVD-Video".to_owned()), track_count: 2 },
+					],
+				},
+			],
+		}
+	}
+
+	/// # Expected Fuzzy.
+	pub(super) fn fuzzy() -> MbDiscIdResponse {
+		MbDiscIdResponse {
+			id: None,
+			sectors: 0,
+			offsets: Vec::new(),
+			releases: vec![
+				MbRelease {
+					id: "5f3e4c7a-2b1d-4e8f-9a6c-0d7b8e9f1a2b".to_owned(),
+					title: "Ágætis byrjun".to_owned(),
+					date: Some("1999-06-12".to_owned()),
+					country: Some("IS".to_owned()),
+					media: vec![
+						MbMedium { position: Some(1), format: Some("CD".to_owned()), track_count: 4 },
+					],
+				},
+				MbRelease {
+					id: "7b8c9d0e-1f2a-4b3c-9d4e-5f6a7b8c9d0e".to_owned(),
+					title: "Von & \"Vonbrigði\"".to_owned(),
+					date: None,
+					country: None,
+					media: Vec::new(),
+				},
+			],
+		}
+	}
+
+	#[cfg(feature = "musicbrainz-json")]
+	#[test]
+	fn t_from_json() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+
+		let res = MbDiscIdResponse::from_json(EXACT_JSON).expect("Parse failed.");
+		assert_eq!(res, exact());
+		assert!(! res.is_fuzzy());
+		assert_eq!(res.id(), Some(toc.musicbrainz_id().to_string().as_str()));
+		assert_eq!(res.sectors(), 55370);
+		assert!(res.matches(&toc));
+		assert_eq!(res.releases()[1].media()[1].format(), Some("DVD-Video"));
+
+		// A different TOC shouldn't match.
+		let other = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84B").expect("Invalid TOC.");
+		assert!(! res.matches(&other));
+
+		let res = MbDiscIdResponse::from_json(FUZZY_JSON).expect("Parse failed.");
+		assert_eq!(res, fuzzy());
+		assert!(res.is_fuzzy());
+		assert_eq!(res.id(), None);
+		assert!(res.offsets().is_empty());
+		assert!(! res.matches(&toc));
+	}
+
+	#[cfg(feature = "musicbrainz-json")]
+	#[test]
+	fn t_from_json_bad() {
+		for bad in [
+			"",
+			"[]",
+			"{}",
+			r#"{"error": "Not Found", "help": "For usage, please see: https://musicbrainz.org/development/mmd"}"#,
+			r#"{"id": "nljDXdC8B_pDwbdY1vZJvdrAZI4-", "offsets": [150]}"#,
+			r#"{"id": "nljDXdC8B_pDwbdY1vZJvdrAZI4-", "sectors": 55370, "offsets": []}"#,
+			r#"{"id": "nljDXdC8B_pDwbdY1vZJvdrAZI4-", "sectors": -1, "offsets": [150]}"#,
+			r#"{"releases": [{"title": "No ID"}]}"#,
+			r#"{"releases": [{"id": "x", "title": "Bad Media", "media": [{"position": 1}]}]}"#,
+			r#"{"releases": {}}"#,
+		] {
+			assert_eq!(MbDiscIdResponse::from_json(bad), Err(TocError::MusicBrainzResponse), "{bad}");
+		}
+
+		// A disc with no releases is fine.
+		let res = MbDiscIdResponse::from_json(
+			r#"{"id": "nljDXdC8B_pDwbdY1vZJvdrAZI4-", "sectors": 55370, "offsets": [150, 11563, 25174, 45863]}"#
+		).expect("Parse failed.");
+		assert!(res.releases().is_empty());
+	}
+
+	#[test]
+	fn t_from_xml() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+
+		let res = MbDiscIdResponse::from_xml(EXACT_XML).expect("Parse failed.");
+		assert_eq!(res, exact());
+		assert!(res.matches(&toc));
+
+		let res = MbDiscIdResponse::from_xml(FUZZY_XML).expect("Parse failed.");
+		assert_eq!(res, fuzzy());
+		assert!(res.is_fuzzy());
+
+		// Both formats should agree.
+		#[cfg(feature = "musicbrainz-json")]
+		{
+			assert_eq!(
+				MbDiscIdResponse::from_xml(EXACT_XML),
+				MbDiscIdResponse::from_json(EXACT_JSON),
+			);
+			assert_eq!(
+				MbDiscIdResponse::from_xml(FUZZY_XML),
+				MbDiscIdResponse::from_json(FUZZY_JSON),
+			);
+		}
+
+		// A disc with no releases is fine.
+		let res = MbDiscIdResponse::from_xml(
+			r#"<metadata><disc id="x"><sectors>300</sectors><offset-list><offset>150</offset></offset-list></disc></metadata>"#
+		).expect("Parse failed.");
+		assert_eq!(res.id(), Some("x"));
+		assert_eq!(res.offsets(), [150]);
+		assert!(res.releases().is_empty());
+	}
+
+	#[test]
+	fn t_from_xml_bad() {
+		for bad in [
+			"",
+			"Not Found",
+			"<metadata />",
+			r#"<?xml version="1.0" encoding="UTF-8"?><error><text>Not Found</text></error>"#,
+			r#"<metadata><disc id="x"><sectors>300</sectors></disc></metadata>"#,
+			r#"<metadata><disc id="x"><offset-list><offset>150</offset></offset-list></disc></metadata>"#,
+			r#"<metadata><disc id="x"><sectors>-1</sectors><offset-list><offset>150</offset></offset-list></disc></metadata>"#,
+			r#"<metadata><disc><sectors>300</sectors><offset-list><offset>150</offset></offset-list></disc></metadata>"#,
+			r#"<metadata><disc id="x"><sectors>300</sectors><offset-list><offset>150</offset></offset-list></metadata>"#,
+			r#"<metadata><release-list><release><title>No ID</title></release></release-list></metadata>"#,
+			r#"<metadata><release-list><release id="x" /></release-list></metadata>"#,
+			r#"<metadata><release-list><release id="x"><title>Bad Media</title><medium-list><medium><position>1</position></medium></medium-list></release></release-list></metadata>"#,
+			r#"<metadata><release-list><release id="x"><title>Bad Count</title><medium-list><medium><track-list count="many" /></medium></medium-list></release></release-list></metadata>"#,
+		] {
+			assert_eq!(MbDiscIdResponse::from_xml(bad), Err(TocError::MusicBrainzResponse), "{bad}");
+		}
+	}
+}
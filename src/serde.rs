@@ -4,11 +4,19 @@
 
 use crate::{
 	Duration,
+	DurationParts,
 	Toc,
+	TocError,
+	TocKind,
 	Track,
 	TrackPosition,
 };
-#[cfg(feature = "accuraterip")] use crate::AccurateRip;
+#[cfg(feature = "accuraterip")] use crate::{
+	AccurateRip,
+	ArVersion,
+	TrackVerdict,
+	VerifyReport,
+};
 #[cfg(feature = "cddb")] use crate::Cddb;
 #[cfg(feature = "sha1")] use crate::ShaB64;
 use serde::{
@@ -16,6 +24,7 @@ use serde::{
 	Deserialize,
 	ser::{
 		self,
+		SerializeMap,
 		SerializeStruct,
 	},
 	Serialize,
@@ -24,73 +33,707 @@ use std::fmt;
 
 
 
-/// # Helper: Deserialize as String.
-macro_rules! deserialize_str_with {
+/// # Helper: Serialize as String.
+macro_rules! serialize_with {
 	($ty:ty, $fn:ident) => (
 		#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-		impl<'de> Deserialize<'de> for $ty {
-			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-			where D: de::Deserializer<'de> {
-				struct Visitor;
+		impl Serialize for $ty {
+			#[inline]
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where S: ser::Serializer { self.$fn().serialize(serializer) }
+		}
+	);
+}
+
+
+
+/// # TOC Kind Names.
+const TOC_KINDS: [&str; 3] = [
+	TocKind::Audio.as_str(),
+	TocKind::CDExtra.as_str(),
+	TocKind::DataFirst.as_str(),
+];
+
+/// # Helper: TOC Kind From String.
+///
+/// Match a [`TocKind`] to its [string representation](TocKind::as_str).
+fn toc_kind<E: de::Error>(src: &str) -> Result<TocKind, E> {
+	match src {
+		"audio-only" => Ok(TocKind::Audio),
+		"CD-Extra" => Ok(TocKind::CDExtra),
+		"data+audio" => Ok(TocKind::DataFirst),
+		_ => Err(de::Error::unknown_variant(src, &TOC_KINDS)),
+	}
+}
+
+
+
+#[cfg(feature = "accuraterip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for AccurateRip {
+	/// # Deserialize.
+	///
+	/// Human-readable formats use the string form; compact formats use the
+	/// raw 13-byte form, though the string is accepted there too.
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: de::Deserializer<'de> {
+		/// # Visitor Instance.
+		struct Visitor;
+
+		impl de::Visitor<'_> for Visitor {
+			type Value = AccurateRip;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("string or 13 bytes")
+			}
+
+			fn visit_str<S>(self, src: &str) -> Result<AccurateRip, S>
+			where S: de::Error {
+				AccurateRip::decode(src).map_err(de::Error::custom)
+			}
+
+			fn visit_bytes<S>(self, src: &[u8]) -> Result<AccurateRip, S>
+			where S: de::Error {
+				if src.len() == 13 {
+					AccurateRip::try_from(src).map_err(de::Error::custom)
+				}
+				else {
+					std::str::from_utf8(src)
+						.map_err(de::Error::custom)
+						.and_then(|s| self.visit_str(s))
+				}
+			}
+		}
+
+		if deserializer.is_human_readable() { deserializer.deserialize_str(Visitor) }
+		else { deserializer.deserialize_bytes(Visitor) }
+	}
+}
+
+#[cfg(feature = "accuraterip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for AccurateRip {
+	/// # Serialize.
+	///
+	/// Human-readable formats get the string form; compact formats get the
+	/// raw 13-byte form.
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer {
+		if serializer.is_human_readable() {
+			self.pretty_print().serialize(serializer)
+		}
+		else { serializer.serialize_bytes(self.as_ref()) }
+	}
+}
+
+#[cfg(feature = "accuraterip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for ArVersion {
+	#[inline]
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer {
+		match self {
+			Self::V1 => serializer.serialize_u8(1),
+			Self::V2 => serializer.serialize_u8(2),
+		}
+	}
+}
+
+#[cfg(feature = "accuraterip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for TrackVerdict {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer {
+		match self {
+			Self::AccuratelyRipped { version, confidence } => {
+				let mut state = serializer.serialize_struct("TrackVerdict", 3)?;
+				state.serialize_field("status", "accurate")?;
+				state.serialize_field("version", version)?;
+				state.serialize_field("confidence", confidence)?;
+				state.end()
+			},
+			Self::NotInDatabase => {
+				let mut state = serializer.serialize_struct("TrackVerdict", 1)?;
+				state.serialize_field("status", "not_in_database")?;
+				state.end()
+			},
+			Self::Mismatch { best_confidence_seen } => {
+				let mut state = serializer.serialize_struct("TrackVerdict", 2)?;
+				state.serialize_field("status", "mismatch")?;
+				state.serialize_field("best_confidence_seen", best_confidence_seen)?;
+				state.end()
+			},
+		}
+	}
+}
+
+#[cfg(feature = "accuraterip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for VerifyReport {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer {
+		let mut state = serializer.serialize_struct("VerifyReport", 2)?;
+
+		state.serialize_field("accurate", &self.accurate())?;
+		state.serialize_field("tracks", self.tracks())?;
+
+		state.end()
+	}
+}
 
-				impl<'de> de::Visitor<'de> for Visitor {
-					type Value = $ty;
+#[cfg(feature = "cddb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Cddb {
+	/// # Deserialize.
+	///
+	/// Human-readable formats use the string form; compact formats use the
+	/// raw `u32`. Either is accepted for human-readable formats.
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: de::Deserializer<'de> {
+		/// # Visitor Instance.
+		struct Visitor;
+
+		impl de::Visitor<'_> for Visitor {
+			type Value = Cddb;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("string or u32")
+			}
+
+			fn visit_u32<S>(self, src: u32) -> Result<Cddb, S>
+			where S: de::Error { Ok(Cddb::new(src)) }
+
+			fn visit_u64<S>(self, src: u64) -> Result<Cddb, S>
+			where S: de::Error {
+				u32::try_from(src)
+					.map(Cddb::new)
+					.map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(src), &self))
+			}
 
-					fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-						f.write_str("string")
+			fn visit_str<S>(self, src: &str) -> Result<Cddb, S>
+			where S: de::Error {
+				Cddb::decode(src).map_err(de::Error::custom)
+			}
+
+			fn visit_bytes<S>(self, src: &[u8]) -> Result<Cddb, S>
+			where S: de::Error {
+				std::str::from_utf8(src)
+					.map_err(de::Error::custom)
+					.and_then(|s| self.visit_str(s))
+			}
+		}
+
+		if deserializer.is_human_readable() { deserializer.deserialize_any(Visitor) }
+		else { deserializer.deserialize_u32(Visitor) }
+	}
+}
+
+#[cfg(feature = "cddb")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Cddb {
+	/// # Serialize.
+	///
+	/// Human-readable formats get the string form; compact formats get the
+	/// raw `u32`.
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer {
+		if serializer.is_human_readable() { self.to_string().serialize(serializer) }
+		else { serializer.serialize_u32(self.as_u32()) }
+	}
+}
+
+#[cfg(feature = "sha1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ShaB64 {
+	/// # Deserialize.
+	///
+	/// Human-readable formats use the string form; compact formats use the
+	/// raw 20-byte form, though the string is accepted there too.
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: de::Deserializer<'de> {
+		/// # Visitor Instance.
+		struct Visitor;
+
+		impl de::Visitor<'_> for Visitor {
+			type Value = ShaB64;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("string or 20 bytes")
+			}
+
+			fn visit_str<S>(self, src: &str) -> Result<ShaB64, S>
+			where S: de::Error {
+				ShaB64::decode(src).map_err(de::Error::custom)
+			}
+
+			fn visit_bytes<S>(self, src: &[u8]) -> Result<ShaB64, S>
+			where S: de::Error {
+				if src.len() == 20 {
+					ShaB64::try_from(src).map_err(de::Error::custom)
+				}
+				else {
+					std::str::from_utf8(src)
+						.map_err(de::Error::custom)
+						.and_then(|s| self.visit_str(s))
+				}
+			}
+		}
+
+		if deserializer.is_human_readable() { deserializer.deserialize_str(Visitor) }
+		else { deserializer.deserialize_bytes(Visitor) }
+	}
+}
+
+#[cfg(feature = "sha1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for ShaB64 {
+	/// # Serialize.
+	///
+	/// Human-readable formats get the string form; compact formats get the
+	/// raw 20-byte form.
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer {
+		if serializer.is_human_readable() { serializer.collect_str(&self.as_display()) }
+		else { serializer.serialize_bytes(self.as_bytes()) }
+	}
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Toc {
+	/// # Deserialize.
+	///
+	/// Tables of contents are normally serialized as CDTOC strings, but for
+	/// self-describing formats like JSON, the map form produced by
+	/// [`Toc::as_struct`] is accepted too.
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: de::Deserializer<'de> {
+		/// # Fields of Interest.
+		const FIELDS: &[&str] = &["kind", "audio", "data", "leadout"];
+
+		/// # Visitor Instance.
+		struct TocVisitor;
+
+		impl<'de> de::Visitor<'de> for TocVisitor {
+			type Value = Toc;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("CDTOC string or struct Toc")
+			}
+
+			fn visit_str<S>(self, src: &str) -> Result<Toc, S>
+			where S: de::Error {
+				Toc::from_cdtoc(src).map_err(de::Error::custom)
+			}
+
+			fn visit_bytes<S>(self, src: &[u8]) -> Result<Toc, S>
+			where S: de::Error {
+				std::str::from_utf8(src)
+					.map_err(de::Error::custom)
+					.and_then(|s| self.visit_str(s))
+			}
+
+			fn visit_map<V>(self, mut map: V) -> Result<Toc, V::Error>
+			where V: de::MapAccess<'de> {
+				let mut kind: Option<String> = None;
+				let mut audio: Option<Vec<u32>> = None;
+				let mut data: Option<Option<u32>> = None;
+				let mut leadout = None;
+
+				/// # Helper: Accept or Reject Value.
+				macro_rules! set {
+					($var:ident, $name:literal) => (
+						if $var.is_none() { $var.replace(map.next_value()?); }
+						else { return Err(de::Error::duplicate_field($name)); }
+					);
+				}
+
+				while let Some(key) = map.next_key()? {
+					match key {
+						"kind" => set!(kind, "kind"),
+						"audio" => set!(audio, "audio"),
+						"data" => set!(data, "data"),
+						"leadout" => set!(leadout, "leadout"),
+						_ => return Err(de::Error::unknown_field(key, FIELDS)),
 					}
+				}
+
+				let audio = audio.ok_or_else(|| de::Error::missing_field("audio"))?;
+				let leadout = leadout.ok_or_else(|| de::Error::missing_field("leadout"))?;
+				let toc = Toc::from_parts(audio, data.flatten(), leadout)
+					.map_err(de::Error::custom)?;
 
-					fn visit_str<S>(self, src: &str) -> Result<$ty, S>
-					where S: de::Error {
-						<$ty>::$fn(src).map_err(de::Error::custom)
+				// The kind is implied by the sectors, but if present, it had
+				// better agree!
+				if let Some(kind) = kind {
+					if toc_kind::<V::Error>(&kind)? != toc.kind() {
+						return Err(de::Error::custom(format_args!(
+							"kind is {kind}, but the sectors describe a {} disc",
+							toc.kind(),
+						)));
 					}
+				}
 
-					fn visit_bytes<S>(self, src: &[u8]) -> Result<$ty, S>
-					where S: de::Error {
-						std::str::from_utf8(src)
-							.map_err(de::Error::custom)
-							.and_then(|s| <$ty>::$fn(s).map_err(de::Error::custom))
+				Ok(toc)
+			}
+		}
+
+		if deserializer.is_human_readable() { deserializer.deserialize_any(TocVisitor) }
+		else { deserializer.deserialize_str(TocVisitor) }
+	}
+}
+
+serialize_with!(Toc, to_string);
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Structured TOC Serializer.
+///
+/// This is a serialization-only wrapper for [`Toc`] that writes a map of its
+/// parts rather than the usual CDTOC string:
+///
+/// | Field | Type | Description |
+/// | ----- | ---- | ----------- |
+/// | `kind` | `String` | The [`TocKind`], e.g. `CD-Extra`. |
+/// | `audio` | `[u32]` | The starting sector of each audio track. |
+/// | `data` | `Option<u32>` | The starting sector of the data session, if any. |
+/// | `leadout` | `u32` | The disc leadout. |
+///
+/// [`Toc`]'s own deserializer accepts this form too — validating it as per
+/// [`Toc::from_parts`] — at least for self-describing formats like JSON.
+///
+/// It is the return value of [`Toc::as_struct`].
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::Toc;
+///
+/// let toc = Toc::from_cdtoc("3+96+2D2B+6256+B327+D84A").unwrap();
+///
+/// let json = serde_json::to_string(&toc.as_struct()).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"kind":"CD-Extra","audio":[150,11563,25174],"data":45863,"leadout":55370}"#,
+/// );
+///
+/// // And back again.
+/// assert_eq!(serde_json::from_str::<Toc>(&json).unwrap(), toc);
+///
+/// // The default is unchanged.
+/// assert_eq!(serde_json::to_string(&toc).unwrap(), r#""3+96+2D2B+6256+B327+D84A""#);
+/// ```
+pub struct TocStruct<'a>(&'a Toc);
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for TocStruct<'_> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer {
+		let mut state = serializer.serialize_struct("Toc", 4)?;
+
+		state.serialize_field("kind", self.0.kind.as_str())?;
+		state.serialize_field("audio", &self.0.audio)?;
+		state.serialize_field("data", &self.0.data_sector())?;
+		state.serialize_field("leadout", &self.0.leadout)?;
+
+		state.end()
+	}
+}
+
+impl Toc {
+	#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+	#[must_use]
+	/// # Structured Serialization.
+	///
+	/// Return a wrapper that serializes the table of contents as a map of
+	/// its parts — `kind`, `audio`, `data`, and `leadout` — rather than a
+	/// CDTOC string.
+	///
+	/// The default [`Toc`] serialization is unaffected.
+	///
+	/// See [`TocStruct`] for more information.
+	pub const fn as_struct(&self) -> TocStruct<'_> { TocStruct(self) }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TocError {
+	/// # Deserialize.
+	///
+	/// This accepts the output of the corresponding [`Serialize`]
+	/// implementation.
+	///
+	/// Errors of an unrecognized `type` — including those belonging to crate
+	/// features that aren't enabled — are returned as [`TocError::Unknown`].
+	/// Unrecognized fields are ignored.
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: de::Deserializer<'de> {
+		/// # Payload Fields.
+		///
+		/// Every numeric field name used by any variant.
+		const FIELDS: [&str; 8] = ["expected", "found", "leadin", "line", "next", "previous", "track", "value"];
+
+		/// # Visitor Instance.
+		struct TocErrorVisitor;
+
+		impl<'de> de::Visitor<'de> for TocErrorVisitor {
+			type Value = TocError;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				formatter.write_str("struct TocError")
+			}
+
+			fn visit_map<V>(self, mut map: V) -> Result<TocError, V::Error>
+			where V: de::MapAccess<'de> {
+				let mut kind: Option<String> = None;
+				let mut ty: Option<String> = None;
+				let mut nums: [Option<u64>; 8] = [None; 8];
+
+				/// # Helper: Accept or Reject Value.
+				macro_rules! set {
+					($var:expr, $name:expr) => (
+						if $var.is_none() { $var.replace(map.next_value()?); }
+						else { return Err(de::Error::duplicate_field($name)); }
+					);
+				}
+
+				while let Some(key) = map.next_key()? {
+					match key {
+						"kind" => set!(kind, "kind"),
+						"type" => set!(ty, "type"),
+						_ => match FIELDS.iter().position(|f| *f == key) {
+							Some(idx) => set!(nums[idx], FIELDS[idx]),
+							None => { map.next_value::<de::IgnoredAny>()?; },
+						},
 					}
 				}
 
-				deserializer.deserialize_str(Visitor)
+				let ty = ty.ok_or_else(|| de::Error::missing_field("type"))?;
+
+				// The format's payload is a kind rather than a number.
+				if ty == "Format" {
+					let kind = kind.ok_or_else(|| de::Error::missing_field("kind"))?;
+					return toc_kind(&kind).map(TocError::Format);
+				}
+
+				let mut values = [0; 3];
+				for (v, field) in values.iter_mut().zip(TocError::serde_fields(&ty)) {
+					*v = FIELDS.iter()
+						.position(|f| f == field)
+						.and_then(|idx| nums[idx])
+						.ok_or_else(|| de::Error::missing_field(field))?;
+				}
+
+				TocError::from_serde_parts(&ty, values)
 			}
 		}
-	);
+
+		if deserializer.is_human_readable() { deserializer.deserialize_map(TocErrorVisitor) }
+		else {
+			let (ty, a, b, c) = <(String, u64, u64, u64)>::deserialize(deserializer)?;
+			Self::from_serde_parts(&ty, [a, b, c])
+		}
+	}
 }
 
-/// # Helper: Serialize as String.
-macro_rules! serialize_with {
-	($ty:ty, $fn:ident) => (
-		#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-		impl Serialize for $ty {
-			#[inline]
-			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-			where S: ser::Serializer { self.$fn().serialize(serializer) }
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for TocError {
+	/// # Serialize.
+	///
+	/// Errors are serialized as a map with the variant name under `type`,
+	/// followed by any payload fields, e.g.
+	/// `{"type":"SectorOrder","previous":11563,"next":150}`.
+	///
+	/// Compact (non-human-readable) formats use a `(type, u64, u64, u64)`
+	/// tuple instead, with unused payload slots set to zero.
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer {
+		let (ty, values) = self.serde_parts();
+		if serializer.is_human_readable() {
+			let fields = Self::serde_fields(ty);
+			let mut state = serializer.serialize_map(Some(fields.len() + 1))?;
+			state.serialize_entry("type", ty)?;
+			if let Self::Format(kind) = self { state.serialize_entry("kind", kind.as_str())?; }
+			else {
+				for (k, v) in fields.iter().zip(values) { state.serialize_entry(k, &v)?; }
+			}
+			state.end()
 		}
-	);
+		else { (ty, values[0], values[1], values[2]).serialize(serializer) }
+	}
 }
 
+impl TocError {
+	/// # Serde Fields.
+	///
+	/// Return the payload field names for the variant of the given name, if
+	/// any.
+	fn serde_fields(ty: &str) -> &'static [&'static str] {
+		match ty {
+			"AudioFileFormat" | "AudioFileRead" | "TrackNumber" => &["track"],
+			"AudioFileSamples" => &["track", "expected", "found"],
+			"ChecksumCount" | "SectorCount" => &["expected", "found"],
+			"CtdbTag" => &["line"],
+			"Format" => &["kind"],
+			"LeadinSize" => &["leadin"],
+			"SectorOrder" => &["previous", "next"],
+			"SectorSize" => &["value"],
+			_ => &[],
+		}
+	}
 
+	/// # Serde Parts.
+	///
+	/// Return the variant name and (numeric) payload.
+	const fn serde_parts(self) -> (&'static str, [u64; 3]) {
+		match self {
+			Self::CDDASampleCount => ("CDDASampleCount", [0; 3]),
+			Self::CDTOCChars => ("CDTOCChars", [0; 3]),
+			Self::Checksums => ("Checksums", [0; 3]),
+			Self::DurationConversion => ("DurationConversion", [0; 3]),
+			Self::DurationParse => ("DurationParse", [0; 3]),
+			Self::Format(kind) => ("Format", [kind as u64, 0, 0]),
+			Self::LeadinSize(n) => ("LeadinSize", [n as u64, 0, 0]),
+			Self::Msf => ("Msf", [0; 3]),
+			Self::NoAudio => ("NoAudio", [0; 3]),
+			Self::NoChecksums => ("NoChecksums", [0; 3]),
+			Self::SectorCount(a, b) => ("SectorCount", [a as u64, b as u64, 0]),
+			Self::SectorOrder(a, b) => ("SectorOrder", [a as u64, b as u64, 0]),
+			Self::SectorSize(n) => ("SectorSize", [n, 0, 0]),
+			Self::TrackCount => ("TrackCount", [0; 3]),
+			Self::TrackNumber(n) => ("TrackNumber", [n as u64, 0, 0]),
+			Self::TrackPositionDecode => ("TrackPositionDecode", [0; 3]),
 
-#[cfg(feature = "accuraterip")] deserialize_str_with!(AccurateRip, decode);
-#[cfg(feature = "accuraterip")] serialize_with!(AccurateRip, pretty_print);
+			#[cfg(feature = "accuraterip")] Self::AccurateRipDecode => ("AccurateRipDecode", [0; 3]),
+			#[cfg(feature = "accuraterip")] Self::ChecksumCache => ("ChecksumCache", [0; 3]),
+			#[cfg(any(feature = "accuraterip", feature = "ctdb"))] Self::ChecksumCount(a, b) => ("ChecksumCount", [a as u64, b as u64, 0]),
+			#[cfg(feature = "accuraterip")] Self::DriveOffsetDecode => ("DriveOffsetDecode", [0; 3]),
+			#[cfg(feature = "accuraterip")] Self::NoDriveOffsets => ("NoDriveOffsets", [0; 3]),
 
-#[cfg(feature = "cddb")] deserialize_str_with!(Cddb, decode);
-#[cfg(feature = "cddb")] serialize_with!(Cddb, to_string);
+			#[cfg(any(feature = "claxon", feature = "hound"))] Self::AudioFileFormat(n) => ("AudioFileFormat", [n as u64, 0, 0]),
+			#[cfg(any(feature = "claxon", feature = "hound"))] Self::AudioFileRead(n) => ("AudioFileRead", [n as u64, 0, 0]),
+			#[cfg(any(feature = "claxon", feature = "hound"))] Self::AudioFileSamples(n, a, b) => ("AudioFileSamples", [n as u64, a, b]),
 
-#[cfg(feature = "sha1")] deserialize_str_with!(ShaB64, decode);
-#[cfg(feature = "sha1")] serialize_with!(ShaB64, pretty_print);
+			#[cfg(feature = "cddb")] Self::CddbDecode => ("CddbDecode", [0; 3]),
+			#[cfg(feature = "cddb")] Self::CddbMismatch => ("CddbMismatch", [0; 3]),
+			#[cfg(feature = "cddb")] Self::CddbResponse => ("CddbResponse", [0; 3]),
+			#[cfg(feature = "cddb")] Self::XmcdDecode => ("XmcdDecode", [0; 3]),
+			#[cfg(feature = "ctdb")] Self::CtdbParity => ("CtdbParity", [0; 3]),
+			#[cfg(feature = "ctdb")] Self::CtdbTag(n) => ("CtdbTag", [n as u64, 0, 0]),
+			#[cfg(feature = "musicbrainz")] Self::MusicBrainzResponse => ("MusicBrainzResponse", [0; 3]),
+			Self::Unknown => ("Unknown", [0; 3]),
+			#[cfg(feature = "sha1")] Self::ShaB64Decode => ("ShaB64Decode", [0; 3]),
+		}
+	}
 
-deserialize_str_with!(Toc, from_cdtoc);
-serialize_with!(Toc, to_string);
+	/// # From Serde Parts.
+	///
+	/// Rebuild an error from its variant name and (numeric) payload.
+	///
+	/// ## Errors
+	///
+	/// Unrecognized names map to [`TocError::Unknown`], but payloads that
+	/// don't fit the variant are rejected.
+	fn from_serde_parts<E: de::Error>(ty: &str, values: [u64; 3]) -> Result<Self, E> {
+		/// # Helper: Payload Value.
+		macro_rules! val {
+			($idx:literal) => (
+				values[$idx].try_into().map_err(|_| de::Error::invalid_value(
+					de::Unexpected::Unsigned(values[$idx]),
+					&Self::serde_fields(ty)[$idx],
+				))?
+			);
+		}
+
+		Ok(match ty {
+			"CDDASampleCount" => Self::CDDASampleCount,
+			"CDTOCChars" => Self::CDTOCChars,
+			"Checksums" => Self::Checksums,
+			"DurationConversion" => Self::DurationConversion,
+			"DurationParse" => Self::DurationParse,
+			"Format" => Self::Format(match values[0] {
+				0 => TocKind::Audio,
+				1 => TocKind::CDExtra,
+				2 => TocKind::DataFirst,
+				n => return Err(de::Error::invalid_value(de::Unexpected::Unsigned(n), &"kind")),
+			}),
+			"LeadinSize" => Self::LeadinSize(val!(0)),
+			"Msf" => Self::Msf,
+			"NoAudio" => Self::NoAudio,
+			"NoChecksums" => Self::NoChecksums,
+			"SectorCount" => Self::SectorCount(val!(0), val!(1)),
+			"SectorOrder" => Self::SectorOrder(val!(0), val!(1)),
+			"SectorSize" => Self::SectorSize(values[0]),
+			"TrackCount" => Self::TrackCount,
+			"TrackNumber" => Self::TrackNumber(val!(0)),
+			"TrackPositionDecode" => Self::TrackPositionDecode,
+
+			#[cfg(feature = "accuraterip")] "AccurateRipDecode" => Self::AccurateRipDecode,
+			#[cfg(feature = "accuraterip")] "ChecksumCache" => Self::ChecksumCache,
+			#[cfg(any(feature = "accuraterip", feature = "ctdb"))] "ChecksumCount" => Self::ChecksumCount(val!(0), val!(1)),
+			#[cfg(feature = "accuraterip")] "DriveOffsetDecode" => Self::DriveOffsetDecode,
+			#[cfg(feature = "accuraterip")] "NoDriveOffsets" => Self::NoDriveOffsets,
+
+			#[cfg(any(feature = "claxon", feature = "hound"))] "AudioFileFormat" => Self::AudioFileFormat(val!(0)),
+			#[cfg(any(feature = "claxon", feature = "hound"))] "AudioFileRead" => Self::AudioFileRead(val!(0)),
+			#[cfg(any(feature = "claxon", feature = "hound"))] "AudioFileSamples" => Self::AudioFileSamples(val!(0), values[1], values[2]),
+
+			#[cfg(feature = "cddb")] "CddbDecode" => Self::CddbDecode,
+			#[cfg(feature = "cddb")] "CddbMismatch" => Self::CddbMismatch,
+			#[cfg(feature = "cddb")] "CddbResponse" => Self::CddbResponse,
+			#[cfg(feature = "cddb")] "XmcdDecode" => Self::XmcdDecode,
+			#[cfg(feature = "ctdb")] "CtdbParity" => Self::CtdbParity,
+			#[cfg(feature = "ctdb")] "CtdbTag" => Self::CtdbTag(val!(0)),
+			#[cfg(feature = "musicbrainz")] "MusicBrainzResponse" => Self::MusicBrainzResponse,
+			#[cfg(feature = "sha1")] "ShaB64Decode" => Self::ShaB64Decode,
+
+			_ => Self::Unknown,
+		})
+	}
+}
 
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 impl<'de> Deserialize<'de> for Duration {
-	#[inline]
+	/// # Deserialize.
+	///
+	/// Durations are normally serialized as sectors (`u64`), but for
+	/// human-readable formats like JSON, the string form produced by
+	/// [`duration_string`] is accepted too.
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: de::Deserializer<'de> {
-		u64::deserialize(deserializer).map(Self::from)
+		/// # Visitor Instance.
+		struct Visitor;
+
+		impl de::Visitor<'_> for Visitor {
+			type Value = Duration;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("sectors (u64) or duration string")
+			}
+
+			fn visit_u64<S>(self, src: u64) -> Result<Duration, S>
+			where S: de::Error { Ok(Duration::from(src)) }
+
+			fn visit_i64<S>(self, src: i64) -> Result<Duration, S>
+			where S: de::Error {
+				u64::try_from(src)
+					.map(Duration::from)
+					.map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(src), &self))
+			}
+
+			fn visit_str<S>(self, src: &str) -> Result<Duration, S>
+			where S: de::Error {
+				Duration::decode(src).map_err(de::Error::custom)
+			}
+
+			fn visit_bytes<S>(self, src: &[u8]) -> Result<Duration, S>
+			where S: de::Error {
+				std::str::from_utf8(src)
+					.map_err(de::Error::custom)
+					.and_then(|s| self.visit_str(s))
+			}
+		}
+
+		if deserializer.is_human_readable() { deserializer.deserialize_any(Visitor) }
+		else { deserializer.deserialize_u64(Visitor) }
 	}
 }
 
@@ -101,8 +744,175 @@ impl Serialize for Duration {
 	where S: ser::Serializer { self.0.serialize(serializer) }
 }
 
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+/// # Duration as String.
+///
+/// [`Duration`] serializes as a plain `u64` sector count by default. This
+/// module can be used with serde's `with` field attribute —
+/// `#[serde(with = "cdtoc::duration_string")]` — to serialize it as the
+/// canonical `Dd HH:MM:SS+FF` string instead.
+///
+/// Deserialization accepts either form, so switching one way or the other
+/// will not break existing data.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{Duration, duration_string};
+///
+/// let duration = Duration::from(8629_u32);
+///
+/// let mut out = Vec::new();
+/// duration_string::serialize(&duration, &mut serde_json::Serializer::new(&mut out)).unwrap();
+/// assert_eq!(out, br#""00:01:55+04""#);
+///
+/// // Both forms work on the way back in.
+/// for raw in [r#""00:01:55+04""#, "8629"] {
+///     let mut de = serde_json::Deserializer::from_str(raw);
+///     assert_eq!(duration_string::deserialize(&mut de).unwrap(), duration);
+/// }
+/// ```
+pub mod duration_string {
+	use super::{
+		de,
+		Deserialize,
+		Duration,
+		ser,
+	};
+
+	/// # Serialize.
+	///
+	/// Serialize a [`Duration`] as a string.
+	///
+	/// ## Errors
+	///
+	/// This will bubble up any errors from the serializer.
+	pub fn serialize<S>(src: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer { serializer.collect_str(src) }
+
+	/// # Deserialize.
+	///
+	/// Deserialize a [`Duration`] from either a string or a `u64` sector
+	/// count.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the value is neither a valid duration
+	/// string nor an unsigned integer.
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+	where D: de::Deserializer<'de> { Duration::deserialize(deserializer) }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for DurationParts {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: de::Deserializer<'de> {
+		/// # Fields of Interest.
+		const FIELDS: &[&str] = &["days", "hours", "minutes", "seconds", "frames"];
+
+		/// # Visitor Instance.
+		struct DurationPartsVisitor;
+
+		impl<'de> de::Visitor<'de> for DurationPartsVisitor {
+			type Value = DurationParts;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				formatter.write_str("struct DurationParts")
+			}
+
+			fn visit_seq<V>(self, mut seq: V) -> Result<DurationParts, V::Error>
+			where V: de::SeqAccess<'de> {
+				let days = seq.next_element()?
+					.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+				let hours = seq.next_element()?
+					.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+				let minutes = seq.next_element()?
+					.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+				let seconds = seq.next_element()?
+					.ok_or_else(|| de::Error::invalid_length(3, &self))?;
+				let frames = seq.next_element()?
+					.ok_or_else(|| de::Error::invalid_length(4, &self))?;
+				validate(DurationParts { days, hours, minutes, seconds, frames })
+			}
+
+			fn visit_map<V>(self, mut map: V) -> Result<DurationParts, V::Error>
+			where V: de::MapAccess<'de> {
+				let mut days = None;
+				let mut hours = None;
+				let mut minutes = None;
+				let mut seconds = None;
+				let mut frames = None;
+
+				/// # Helper: Accept or Reject Value.
+				macro_rules! set {
+					($var:ident, $name:literal) => (
+						if $var.is_none() { $var.replace(map.next_value()?); }
+						else { return Err(de::Error::duplicate_field($name)); }
+					);
+				}
+
+				while let Some(key) = map.next_key()? {
+					match key {
+						"days" => set!(days, "days"),
+						"hours" => set!(hours, "hours"),
+						"minutes" => set!(minutes, "minutes"),
+						"seconds" => set!(seconds, "seconds"),
+						"frames" => set!(frames, "frames"),
+						_ => return Err(de::Error::unknown_field(key, FIELDS)),
+					}
+				}
+
+				let days = days.ok_or_else(|| de::Error::missing_field("days"))?;
+				let hours = hours.ok_or_else(|| de::Error::missing_field("hours"))?;
+				let minutes = minutes.ok_or_else(|| de::Error::missing_field("minutes"))?;
+				let seconds = seconds.ok_or_else(|| de::Error::missing_field("seconds"))?;
+				let frames = frames.ok_or_else(|| de::Error::missing_field("frames"))?;
+
+				validate(DurationParts { days, hours, minutes, seconds, frames })
+			}
+		}
+
+		/// # Validate Parts.
+		///
+		/// Parts are only accepted if they represent a valid [`Duration`].
+		fn validate<E: de::Error>(parts: DurationParts) -> Result<DurationParts, E> {
+			Duration::try_from(parts)
+				.map(|_| parts)
+				.map_err(de::Error::custom)
+		}
+
+		deserializer.deserialize_struct("DurationParts", FIELDS, DurationPartsVisitor)
+	}
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for DurationParts {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer {
+		let mut state = serializer.serialize_struct("DurationParts", 5)?;
+
+		state.serialize_field("days", &self.days)?;
+		state.serialize_field("hours", &self.hours)?;
+		state.serialize_field("minutes", &self.minutes)?;
+		state.serialize_field("seconds", &self.seconds)?;
+		state.serialize_field("frames", &self.frames)?;
+
+		state.end()
+	}
+}
+
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 impl<'de> Deserialize<'de> for Track {
+	/// # Deserialize.
+	///
+	/// The fields are checked against one another before a track is
+	/// returned: the sector range must be non-empty, the number must be
+	/// between `0..=99`, and the position — if not [`TrackPosition::Invalid`]
+	/// — must make sense for the number. Number zero is reserved for
+	/// [HTOA](Track::is_htoa) tracks, which additionally must have an invalid
+	/// position and start after the mandatory `150`-sector leadin.
+	///
+	/// For maps, a missing `pos` defaults to [`TrackPosition::Invalid`].
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: de::Deserializer<'de> {
 		/// # Fields of Interest.
@@ -128,7 +938,7 @@ impl<'de> Deserialize<'de> for Track {
 					.ok_or_else(|| de::Error::invalid_length(2, &self))?;
 				let to = seq.next_element()?
 					.ok_or_else(|| de::Error::invalid_length(3, &self))?;
-				Ok(Track { num, pos, from, to })
+				validate(Track { num, pos, from, to })
             }
 
 			fn visit_map<V>(self, mut map: V) -> Result<Track, V::Error>
@@ -152,16 +962,48 @@ impl<'de> Deserialize<'de> for Track {
 						"pos" => set!(pos, "pos"),
 						"from" => set!(from, "from"),
 						"to" => set!(to, "to"),
+						// Derived fields from TrackDetailed are accepted but
+						// ignored.
+						"sectors" | "duration" | "msf" => {
+							map.next_value::<de::IgnoredAny>()?;
+						},
 						_ => return Err(de::Error::unknown_field(key, FIELDS)),
 					}
 				}
 
 				let num = num.ok_or_else(|| de::Error::missing_field("num"))?;
-				let pos = pos.ok_or_else(|| de::Error::missing_field("pos"))?;
+				let pos = pos.unwrap_or(TrackPosition::Invalid);
 				let from = from.ok_or_else(|| de::Error::missing_field("from"))?;
 				let to = to.ok_or_else(|| de::Error::missing_field("to"))?;
 
-				Ok(Track { num, pos, from, to })
+				validate(Track { num, pos, from, to })
+			}
+		}
+
+		/// # Validate Track.
+		///
+		/// Tracks are only accepted if their fields are mutually consistent.
+		fn validate<E: de::Error>(track: Track) -> Result<Track, E> {
+			if 99 < track.num {
+				return Err(de::Error::custom(TocError::TrackNumber(usize::from(track.num))));
+			}
+			if track.to <= track.from {
+				return Err(de::Error::custom(TocError::SectorOrder(track.from, track.to)));
+			}
+
+			match (track.num, track.pos) {
+				(0, TrackPosition::Invalid) =>
+					if track.from < 150 { Err(de::Error::custom(TocError::LeadinSize(track.from))) }
+					else { Ok(track) },
+				(0, pos) => Err(de::Error::custom(format_args!(
+					"position {pos} is not valid for HTOA track #0",
+				))),
+				(_, TrackPosition::Invalid) |
+				(1, TrackPosition::First | TrackPosition::Only) |
+				(2..=99, TrackPosition::Middle | TrackPosition::Last) => Ok(track),
+				(num, pos) => Err(de::Error::custom(format_args!(
+					"position {pos} is not valid for track #{num}",
+				))),
 			}
 		}
 
@@ -184,10 +1026,85 @@ impl Serialize for Track {
 	}
 }
 
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+/// # Detailed Track Serializer.
+///
+/// This is a serialization-only wrapper for [`Track`] that includes a few
+/// additional read-only fields alongside the usual ones:
+///
+/// | Field | Type | Description |
+/// | ----- | ---- | ----------- |
+/// | `sectors` | `u32` | The number of sectors. |
+/// | `duration` | `u64` | The [`Duration`], in sectors. |
+/// | `msf` | `String` | The normalized [MSF](Track::msf_normalized) as `MM:SS:FF`. |
+///
+/// [`Track`]'s own deserializer accepts (and ignores) these fields, so the
+/// output can be read back in just like the compact form, at least for
+/// self-describing formats like JSON.
+///
+/// It is the return value of [`Track::serialize_detailed`].
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{Toc, Track};
+///
+/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+/// let track = toc.audio_track(2).unwrap();
+///
+/// let json = serde_json::to_string(&track.serialize_detailed()).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"num":2,"pos":"Middle","from":11563,"to":25174,"sectors":13611,"duration":13611,"msf":"02:32:13"}"#,
+/// );
+///
+/// // And back again.
+/// assert_eq!(serde_json::from_str::<Track>(&json).unwrap(), track);
+/// ```
+pub struct TrackDetailed(Track);
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for TrackDetailed {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: ser::Serializer {
+		let mut state = serializer.serialize_struct("Track", 7)?;
+
+		state.serialize_field("num", &self.0.num)?;
+		state.serialize_field("pos", &self.0.pos)?;
+		state.serialize_field("from", &self.0.from)?;
+		state.serialize_field("to", &self.0.to)?;
+
+		state.serialize_field("sectors", &self.0.sectors())?;
+		state.serialize_field("duration", &self.0.duration())?;
+		state.serialize_field("msf", &format_args!("{}", self.0.msf_normalized_display()))?;
+
+		state.end()
+	}
+}
+
+impl Track {
+	#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+	#[must_use]
+	/// # Detailed Serialization.
+	///
+	/// Return a wrapper that serializes the track with a few extra derived
+	/// fields — `sectors`, `duration`, and `msf` — for the benefit of human
+	/// readers.
+	///
+	/// The default [`Track`] serialization is unaffected.
+	///
+	/// See [`TrackDetailed`] for more information.
+	pub const fn serialize_detailed(&self) -> TrackDetailed { TrackDetailed(*self) }
+}
+
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 impl<'de> Deserialize<'de> for TrackPosition {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: de::Deserializer<'de> {
+		/// # Variant Names.
+		const VARIANTS: &[&str] = &["Invalid", "First", "Middle", "Last", "Only"];
+
 		/// # Visitor Instance.
 		struct Visitor;
 
@@ -200,24 +1117,15 @@ impl<'de> Deserialize<'de> for TrackPosition {
 
 			fn visit_str<S>(self, src: &str) -> Result<TrackPosition, S>
 			where S: de::Error {
-				Ok(match src {
-					"First" => TrackPosition::First,
-					"Middle" => TrackPosition::Middle,
-					"Last" => TrackPosition::Last,
-					"Only" => TrackPosition::Only,
-					_ => TrackPosition::Invalid,
-				})
+				TrackPosition::decode(src)
+					.map_err(|_| de::Error::unknown_variant(src, VARIANTS))
 			}
 
 			fn visit_bytes<S>(self, src: &[u8]) -> Result<TrackPosition, S>
 			where S: de::Error {
-				Ok(match src {
-					b"First" => TrackPosition::First,
-					b"Middle" => TrackPosition::Middle,
-					b"Last" => TrackPosition::Last,
-					b"Only" => TrackPosition::Only,
-					_ => TrackPosition::Invalid,
-				})
+				std::str::from_utf8(src)
+					.map_err(de::Error::custom)
+					.and_then(|s| self.visit_str(s))
 			}
 		}
 
@@ -251,6 +1159,84 @@ mod tests {
 		inout!(accuraterip, AccurateRip, "AccurateRip");
 	}
 
+	#[cfg(feature = "accuraterip")]
+	#[test]
+	fn serde_accuraterip_bin() {
+		let accuraterip = Toc::from_cdtoc(TOC).expect("Invalid TOC.").accuraterip_id();
+
+		// Compact formats should get the raw bytes (plus a length prefix).
+		let s = postcard::to_stdvec(&accuraterip).expect("AccurateRip serialize failed.");
+		assert_eq!(s.len(), 14);
+		assert_eq!(&s[1..], <[u8; 13]>::from(accuraterip));
+		let d = postcard::from_bytes::<AccurateRip>(&s).expect("AccurateRip deserialize failed.");
+		assert_eq!(accuraterip, d);
+
+		// The string form should still work.
+		let s = postcard::to_stdvec(&accuraterip.to_string()).expect("String serialize failed.");
+		let d = postcard::from_bytes::<AccurateRip>(&s).expect("AccurateRip deserialize failed.");
+		assert_eq!(accuraterip, d);
+
+		// Wrong lengths should not.
+		let raw = <[u8; 13]>::from(accuraterip);
+		for len in [0, 1, 12] {
+			let s = postcard::to_stdvec(&serde_bytes_ref(&raw[..len])).expect("Bytes serialize failed.");
+			assert!(postcard::from_bytes::<AccurateRip>(&s).is_err());
+		}
+		let mut long = raw.to_vec();
+		long.push(0);
+		let s = postcard::to_stdvec(&serde_bytes_ref(&long)).expect("Bytes serialize failed.");
+		assert!(postcard::from_bytes::<AccurateRip>(&s).is_err());
+
+		// As should bad track counts.
+		let mut bad = raw;
+		bad[0] = 0;
+		let s = postcard::to_stdvec(&serde_bytes_ref(&bad)).expect("Bytes serialize failed.");
+		assert!(postcard::from_bytes::<AccurateRip>(&s).is_err());
+	}
+
+	#[cfg(any(feature = "accuraterip", feature = "sha1"))]
+	/// # Serialize Bytes.
+	///
+	/// Slices serialize as sequences by default; this forces bytes.
+	fn serde_bytes_ref(src: &[u8]) -> impl Serialize + '_ {
+		/// # Wrapper.
+		struct Bytes<'a>(&'a [u8]);
+		impl Serialize for Bytes<'_> {
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where S: ser::Serializer { serializer.serialize_bytes(self.0) }
+		}
+		Bytes(src)
+	}
+
+	#[cfg(feature = "accuraterip")]
+	#[test]
+	fn serde_verify_report() {
+		use std::collections::BTreeMap;
+
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		let parsed: Vec<BTreeMap<u32, u8>> = vec![
+			[(1, 10), (2, 20)].into_iter().collect(),
+			[(3, 30)].into_iter().collect(),
+			BTreeMap::new(),
+			[(5, 50)].into_iter().collect(),
+		];
+		let report = toc.accuraterip_id()
+			.verify(&[(1, 2), (3, 4), (5, 6), (7, 8)], &parsed)
+			.expect("Verify failed.");
+
+		assert_eq!(
+			serde_json::to_string(&report).expect("Serialize failed."),
+			concat!(
+				r#"{"accurate":2,"tracks":["#,
+				r#"{"status":"accurate","version":2,"confidence":20},"#,
+				r#"{"status":"accurate","version":1,"confidence":30},"#,
+				r#"{"status":"not_in_database"},"#,
+				r#"{"status":"mismatch","best_confidence_seen":50}"#,
+				"]}",
+			),
+		);
+	}
+
 	#[cfg(feature = "cddb")]
 	#[test]
 	fn serde_cddb() {
@@ -258,6 +1244,34 @@ mod tests {
 		inout!(cddb, Cddb, "CDDB");
 	}
 
+	#[cfg(feature = "cddb")]
+	#[test]
+	fn serde_cddb_u32() {
+		let cddb = Toc::from_cdtoc(TOC).expect("Invalid TOC.").cddb_id();
+
+		// JSON gets a string, but accepts numbers too.
+		let s = serde_json::to_string(&cddb).expect("CDDB serialize failed.");
+		assert_eq!(s, format!("\"{cddb}\""));
+		let d = serde_json::from_str::<Cddb>(&cddb.as_u32().to_string())
+			.expect("CDDB deserialize failed.");
+		assert_eq!(cddb, d);
+
+		// But not out-of-range ones.
+		for bad in ["4294967296", "-1", "1.5"] {
+			assert!(serde_json::from_str::<Cddb>(bad).is_err(), "{bad}");
+		}
+
+		// Compact formats should get the u32.
+		let s = postcard::to_stdvec(&cddb).expect("CDDB serialize failed.");
+		assert_eq!(s, postcard::to_stdvec(&cddb.as_u32()).expect("u32 serialize failed."));
+		let d = postcard::from_bytes::<Cddb>(&s).expect("CDDB deserialize failed.");
+		assert_eq!(cddb, d);
+
+		// Too big.
+		let s = postcard::to_stdvec(&(u64::from(u32::MAX) + 1)).expect("u64 serialize failed.");
+		assert!(postcard::from_bytes::<Cddb>(&s).is_err());
+	}
+
 	#[cfg(feature = "ctdb")]
 	#[test]
 	fn serde_ctdb() {
@@ -272,10 +1286,111 @@ mod tests {
 		inout!(mb, ShaB64, "ShaB64");
 	}
 
+	#[cfg(feature = "sha1")]
+	#[test]
+	fn serde_shab64_bin() {
+		let id = ShaB64::decode("nljDXdC8B_pDwbdY1vZJvdrAZI4-").expect("Invalid ID.");
+
+		// JSON gets the string.
+		let s = serde_json::to_string(&id).expect("ShaB64 serialize failed.");
+		assert_eq!(s, "\"nljDXdC8B_pDwbdY1vZJvdrAZI4-\"");
+		let d = serde_json::from_str::<ShaB64>(&s).expect("ShaB64 deserialize failed.");
+		assert_eq!(id, d);
+
+		// Compact formats should get the raw bytes (plus a length prefix).
+		let s = postcard::to_stdvec(&id).expect("ShaB64 serialize failed.");
+		assert_eq!(s.len(), 21);
+		assert_eq!(&s[1..], id.as_bytes());
+		let d = postcard::from_bytes::<ShaB64>(&s).expect("ShaB64 deserialize failed.");
+		assert_eq!(id, d);
+
+		// The string form should still work.
+		let s = postcard::to_stdvec(&id.to_string()).expect("String serialize failed.");
+		let d = postcard::from_bytes::<ShaB64>(&s).expect("ShaB64 deserialize failed.");
+		assert_eq!(id, d);
+
+		// Wrong lengths should not.
+		let raw = id.as_bytes();
+		for len in [0, 1, 19] {
+			let s = postcard::to_stdvec(&serde_bytes_ref(&raw[..len])).expect("Bytes serialize failed.");
+			assert!(postcard::from_bytes::<ShaB64>(&s).is_err());
+		}
+		let mut long = raw.to_vec();
+		long.push(0);
+		let s = postcard::to_stdvec(&serde_bytes_ref(&long)).expect("Bytes serialize failed.");
+		assert!(postcard::from_bytes::<ShaB64>(&s).is_err());
+	}
+
 	#[test]
 	fn serde_duration() {
 		let duration = Duration::from(123_u32);
 		inout!(duration, Duration, "Duration");
+
+		// The default output is still a number.
+		let s = serde_json::to_string(&duration).expect("Duration serialize failed.");
+		assert_eq!(s, "123");
+
+		// But strings are accepted too.
+		let d = serde_json::from_str::<Duration>(r#""00:00:01+48""#)
+			.expect("Duration deserialize failed.");
+		assert_eq!(d, duration);
+
+		// Negatives and nonsense are not.
+		for bad in ["-1", "1.5", r#""00:00:01""#, "null"] {
+			assert!(
+				serde_json::from_str::<Duration>(bad).is_err(),
+				"Duration deserialization should have failed for {bad}.",
+			);
+		}
+	}
+
+	#[test]
+	fn serde_duration_string() {
+		for duration in [Duration::ZERO, Duration::from(123_u32), Duration::MAX] {
+			// String out.
+			let mut s = Vec::new();
+			duration_string::serialize(&duration, &mut serde_json::Serializer::new(&mut s))
+				.expect("Duration string serialize failed.");
+			assert_eq!(s, format!("\"{duration}\"").into_bytes());
+
+			// Either in.
+			let mut de = serde_json::Deserializer::from_slice(&s);
+			let d = duration_string::deserialize(&mut de)
+				.expect("Duration string deserialize failed.");
+			assert_eq!(d, duration);
+
+			let raw = duration.sectors().to_string();
+			let mut de = serde_json::Deserializer::from_str(&raw);
+			let d = duration_string::deserialize(&mut de)
+				.expect("Duration string deserialize failed.");
+			assert_eq!(d, duration);
+		}
+	}
+
+	#[test]
+	fn serde_duration_parts() {
+		for d in [
+			Duration::ZERO,
+			Duration::from(12_345_u64),
+			Duration::MAX,
+		] {
+			let parts = d.parts();
+			inout!(parts, DurationParts, "DurationParts");
+		}
+
+		let parts: DurationParts = serde_json::from_str(
+			r#"{"days":1,"hours":2,"minutes":3,"seconds":4,"frames":5}"#
+		).expect("DurationParts deserialize failed.");
+		assert_eq!(parts.to_string(), "1d 02:03:04+05");
+
+		// Invalid parts should be rejected.
+		for bad in [
+			r#"{"days":0,"hours":24,"minutes":0,"seconds":0,"frames":0}"#,
+			r#"{"days":0,"hours":0,"minutes":0,"seconds":0,"frames":75}"#,
+			r#"{"days":0,"hours":0,"minutes":0,"seconds":0}"#,
+		] {
+			assert!(serde_json::from_str::<DurationParts>(bad).is_err());
+		}
 	}
 
 	#[test]
@@ -284,6 +1399,191 @@ mod tests {
 		inout!(toc, Toc, "TOC");
 	}
 
+	#[test]
+	fn serde_toc_struct() {
+		let toc = Toc::from_cdtoc(TOC).expect("Invalid TOC.");
+		let s = serde_json::to_string(&toc.as_struct()).expect("TOC serialize failed.");
+		assert_eq!(
+			s,
+			r#"{"kind":"audio-only","audio":[150,24047,41202,63497,86687,109747,134332,151060,175895,193770,220125],"data":null,"leadout":244077}"#,
+		);
+		let d = serde_json::from_str::<Toc>(&s).expect("TOC deserialize failed.");
+		assert_eq!(toc, d);
+
+		// Field order shouldn't matter, nor should kind or (empty) data.
+		for raw in [
+			r#"{"leadout":55370,"audio":[150,11563,25174,45863]}"#,
+			r#"{"data":null,"leadout":55370,"kind":"audio-only","audio":[150,11563,25174,45863]}"#,
+			r#"{"audio":[150,11563,25174,45863],"kind":"audio-only","leadout":55370,"data":null}"#,
+		] {
+			assert_eq!(
+				serde_json::from_str::<Toc>(raw).map_err(|e| e.to_string()),
+				Ok(Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.")),
+				"{raw}",
+			);
+		}
+		assert_eq!(
+			serde_json::from_str::<Toc>(r#"{"leadout":55370,"data":45863,"audio":[150,11563,25174]}"#)
+				.map(|t| t.kind())
+				.map_err(|e| e.to_string()),
+			Ok(TocKind::CDExtra),
+		);
+		assert_eq!(
+			serde_json::from_str::<Toc>(r#"{"leadout":55370,"data":150,"audio":[11563,25174,45863],"kind":"data+audio"}"#)
+				.map(|t| t.kind())
+				.map_err(|e| e.to_string()),
+			Ok(TocKind::DataFirst),
+		);
+
+		// Missing fields.
+		for (raw, field) in [
+			(r#"{"leadout":55370}"#, "audio"),
+			(r#"{"kind":"audio-only","audio":[150,11563,25174,45863]}"#, "leadout"),
+			("{}", "audio"),
+		] {
+			let err = serde_json::from_str::<Toc>(raw).expect_err("Missing field accepted.");
+			assert!(err.to_string().contains(&format!("missing field `{field}`")), "{raw}: {err}");
+		}
+
+		// And other problems.
+		for raw in [
+			// Bad sectors.
+			r#"{"audio":[],"leadout":55370}"#,
+			r#"{"audio":[150,11563,11563],"leadout":55370}"#,
+			r#"{"audio":[150,11563],"leadout":100}"#,
+			// Wrong kind.
+			r#"{"kind":"CD-Extra","audio":[150,11563,25174,45863],"leadout":55370}"#,
+			r#"{"kind":"cd-extra","audio":[150,11563,25174],"data":45863,"leadout":55370}"#,
+			// Duplicates and strangers.
+			r#"{"audio":[150],"audio":[150],"leadout":55370}"#,
+			r#"{"audio":[150],"leadout":55370,"tracks":4}"#,
+		] {
+			assert!(serde_json::from_str::<Toc>(raw).is_err(), "{raw}");
+		}
+
+		// Compact formats stick with strings.
+		let s = postcard::to_stdvec(&toc).expect("TOC serialize failed.");
+		assert_eq!(s, postcard::to_stdvec(TOC).expect("String serialize failed."));
+		let d = postcard::from_bytes::<Toc>(&s).expect("TOC deserialize failed.");
+		assert_eq!(toc, d);
+	}
+
+	#[test]
+	fn serde_toc_error() {
+		let all = [
+			TocError::CDDASampleCount,
+			TocError::CDTOCChars,
+			TocError::Checksums,
+			TocError::DurationConversion,
+			TocError::DurationParse,
+			TocError::Format(TocKind::Audio),
+			TocError::Format(TocKind::CDExtra),
+			TocError::Format(TocKind::DataFirst),
+			TocError::LeadinSize(149),
+			TocError::Msf,
+			TocError::NoAudio,
+			TocError::NoChecksums,
+			TocError::SectorCount(u8::MAX, usize::MAX),
+			TocError::SectorOrder(11_563, 150),
+			TocError::SectorSize(u64::MAX),
+			TocError::TrackCount,
+			TocError::TrackNumber(100),
+			TocError::TrackPositionDecode,
+			TocError::Unknown,
+			#[cfg(feature = "accuraterip")] TocError::AccurateRipDecode,
+			#[cfg(feature = "accuraterip")] TocError::ChecksumCache,
+			#[cfg(any(feature = "accuraterip", feature = "ctdb"))] TocError::ChecksumCount(10, 9),
+			#[cfg(feature = "accuraterip")] TocError::DriveOffsetDecode,
+			#[cfg(feature = "accuraterip")] TocError::NoDriveOffsets,
+			#[cfg(any(feature = "claxon", feature = "hound"))] TocError::AudioFileFormat(1),
+			#[cfg(any(feature = "claxon", feature = "hound"))] TocError::AudioFileRead(2),
+			#[cfg(any(feature = "claxon", feature = "hound"))] TocError::AudioFileSamples(3, 588, 587),
+			#[cfg(feature = "cddb")] TocError::CddbDecode,
+			#[cfg(feature = "cddb")] TocError::CddbMismatch,
+			#[cfg(feature = "cddb")] TocError::CddbResponse,
+			#[cfg(feature = "cddb")] TocError::XmcdDecode,
+			#[cfg(feature = "ctdb")] TocError::CtdbParity,
+			#[cfg(feature = "ctdb")] TocError::CtdbTag(12),
+			#[cfg(feature = "musicbrainz")] TocError::MusicBrainzResponse,
+			#[cfg(feature = "sha1")] TocError::ShaB64Decode,
+		];
+
+		for err in all {
+			inout!(err, TocError, "TocError");
+
+			let s = postcard::to_stdvec(&err).expect("TocError serialize failed.");
+			let d = postcard::from_bytes::<TocError>(&s).expect("TocError deserialize failed.");
+			assert_eq!(err, d, "TocError postcard serialize/deserialize does not match the original.");
+		}
+
+		// Pin down a few representations.
+		for (err, expected) in [
+			(TocError::NoAudio, r#"{"type":"NoAudio"}"#),
+			(TocError::Format(TocKind::CDExtra), r#"{"type":"Format","kind":"CD-Extra"}"#),
+			(TocError::SectorCount(4, 3), r#"{"type":"SectorCount","expected":4,"found":3}"#),
+			(TocError::SectorOrder(11_563, 150), r#"{"type":"SectorOrder","previous":11563,"next":150}"#),
+		] {
+			assert_eq!(serde_json::to_string(&err).expect("TocError serialize failed."), expected);
+		}
+		assert_eq!(
+			postcard::to_stdvec(&TocError::SectorOrder(1, 2)).expect("TocError serialize failed."),
+			postcard::to_stdvec(&("SectorOrder", 1_u64, 2_u64, 0_u64)).expect("Tuple serialize failed."),
+		);
+
+		// Field order shouldn't matter, and strangers are ignored.
+		assert_eq!(
+			serde_json::from_str::<TocError>(r#"{"next":150,"extra":[1,2,3],"previous":11563,"type":"SectorOrder"}"#)
+				.map_err(|e| e.to_string()),
+			Ok(TocError::SectorOrder(11_563, 150)),
+		);
+
+		// Missing, duplicate, or invalid values are not okay.
+		for raw in [
+			r#"{"kind":"CD-Extra"}"#,
+			r#"{"type":"Format"}"#,
+			r#"{"type":"Format","kind":"cd-extra"}"#,
+			r#"{"type":"SectorOrder","previous":11563}"#,
+			r#"{"type":"SectorOrder","previous":11563,"next":150,"next":150}"#,
+			r#"{"type":"SectorCount","expected":256,"found":3}"#,
+			r#"{"type":"LeadinSize","leadin":-1}"#,
+		] {
+			assert!(serde_json::from_str::<TocError>(raw).is_err(), "{raw}");
+		}
+		let raw = postcard::to_stdvec(&("Format", 3_u64, 0_u64, 0_u64)).expect("Tuple serialize failed.");
+		assert!(postcard::from_bytes::<TocError>(&raw).is_err());
+	}
+
+	#[test]
+	fn serde_toc_error_unknown() {
+		// Unknown types deserialize to Unknown, payload and all.
+		for raw in [
+			r#"{"type":"Bogus"}"#,
+			r#"{"type":"Bogus","track":3,"reason":"Who knows?"}"#,
+			r#"{"type":"Unknown"}"#,
+		] {
+			assert_eq!(
+				serde_json::from_str::<TocError>(raw).map_err(|e| e.to_string()),
+				Ok(TocError::Unknown),
+				"{raw}",
+			);
+		}
+		let raw = postcard::to_stdvec(&("Bogus", 1_u64, 2_u64, 3_u64)).expect("Tuple serialize failed.");
+		assert_eq!(postcard::from_bytes::<TocError>(&raw), Ok(TocError::Unknown));
+
+		// As do those belonging to disabled features.
+		#[cfg(not(feature = "cddb"))]
+		assert_eq!(
+			serde_json::from_str::<TocError>(r#"{"type":"CddbDecode"}"#).map_err(|e| e.to_string()),
+			Ok(TocError::Unknown),
+		);
+		#[cfg(not(any(feature = "claxon", feature = "hound")))]
+		assert_eq!(
+			serde_json::from_str::<TocError>(r#"{"type":"AudioFileSamples","track":3,"expected":588,"found":587}"#)
+				.map_err(|e| e.to_string()),
+			Ok(TocError::Unknown),
+		);
+	}
+
 	#[test]
 	fn serde_tracks() {
 		let toc = Toc::from_cdtoc(TOC).expect("Invalid TOC.");
@@ -296,4 +1596,106 @@ mod tests {
 		let htoa = toc.htoa().expect("Mummies HTOA failed.");
 		inout!(htoa, Track, "HTOA");
 	}
+
+	#[test]
+	fn serde_tracks_invalid() {
+		// A missing position is fine for maps.
+		let track = serde_json::from_str::<Track>(r#"{"num":2,"from":11563,"to":25174}"#)
+			.expect("Track deserialize failed.");
+		assert_eq!(track.number(), 2);
+		assert_eq!(track.position(), TrackPosition::Invalid);
+		assert_eq!(track.sector_range(), 11563..25174);
+		let track = serde_json::from_str::<Track>(r#"{"num":0,"from":150,"to":11563}"#)
+			.expect("HTOA deserialize failed.");
+		assert!(track.is_htoa());
+
+		// An explicitly invalid position is fine too, as are any of the valid
+		// ones so long as they fit the number.
+		for raw in [
+			r#"{"num":5,"pos":"Invalid","from":150,"to":11563}"#,
+			r#"{"num":1,"pos":"First","from":150,"to":11563}"#,
+			r#"{"num":1,"pos":"Only","from":150,"to":11563}"#,
+			r#"{"num":2,"pos":"Middle","from":150,"to":11563}"#,
+			r#"{"num":99,"pos":"Last","from":150,"to":11563}"#,
+			r#"[3,"Last",150,11563]"#,
+		] {
+			assert!(serde_json::from_str::<Track>(raw).is_ok(), "{raw}");
+		}
+
+		for (raw, err) in [
+			// Bad sector ranges.
+			(r#"{"num":2,"pos":"Middle","from":25174,"to":11563}"#, TocError::SectorOrder(25174, 11563).to_string()),
+			(r#"{"num":2,"pos":"Middle","from":11563,"to":11563}"#, TocError::SectorOrder(11563, 11563).to_string()),
+			(r#"[2,"Middle",25174,11563]"#, TocError::SectorOrder(25174, 11563).to_string()),
+
+			// Bad numbers.
+			(r#"{"num":100,"pos":"Invalid","from":150,"to":11563}"#, TocError::TrackNumber(100).to_string()),
+			(r#"[200,"Last",150,11563]"#, TocError::TrackNumber(200).to_string()),
+
+			// Bad HTOA.
+			(r#"{"num":0,"pos":"Invalid","from":0,"to":150}"#, TocError::LeadinSize(0).to_string()),
+			(r#"{"num":0,"pos":"First","from":150,"to":11563}"#, "position First is not valid for HTOA track #0".to_owned()),
+
+			// Bad positions.
+			(r#"{"num":1,"pos":"Middle","from":150,"to":11563}"#, "position Middle is not valid for track #1".to_owned()),
+			(r#"{"num":1,"pos":"Last","from":150,"to":11563}"#, "position Last is not valid for track #1".to_owned()),
+			(r#"{"num":2,"pos":"First","from":150,"to":11563}"#, "position First is not valid for track #2".to_owned()),
+			(r#"[3,"Only",150,11563]"#, "position Only is not valid for track #3".to_owned()),
+		] {
+			let e = serde_json::from_str::<Track>(raw).expect_err("Invalid track accepted.");
+			assert!(e.to_string().starts_with(&err), "{raw}: {e}");
+		}
+
+		// Compact formats get the same treatment.
+		let raw = postcard::to_stdvec(&(1_u8, TrackPosition::Middle, 150_u32, 11563_u32))
+			.expect("Tuple serialize failed.");
+		assert!(postcard::from_bytes::<Track>(&raw).is_err());
+	}
+
+	#[test]
+	fn serde_track_position() {
+		for pos in [
+			TrackPosition::Invalid,
+			TrackPosition::First,
+			TrackPosition::Middle,
+			TrackPosition::Last,
+			TrackPosition::Only,
+		] {
+			inout!(pos, TrackPosition, "TrackPosition");
+
+			// The string form should match Display/FromStr too.
+			let s = serde_json::to_string(&pos).expect("TrackPosition serialize failed.");
+			assert_eq!(s, format!("\"{pos}\""));
+			assert_eq!(pos.to_string().parse::<TrackPosition>(), Ok(pos));
+		}
+
+		// Unknown values should be rejected rather than mapped to Invalid.
+		for bad in [r#""Frist""#, r#""first""#, "\"\""] {
+			assert!(
+				serde_json::from_str::<TrackPosition>(bad).is_err(),
+				"TrackPosition deserialization should have failed for {bad}.",
+			);
+		}
+	}
+
+	#[test]
+	fn serde_tracks_detailed() {
+		let toc = Toc::from_cdtoc(TOC).expect("Invalid TOC.");
+		for track in toc.audio_tracks() {
+			let s = serde_json::to_string(&track.serialize_detailed())
+				.expect("Detailed track serialize failed.");
+			assert!(s.contains(r#""sectors":"#), "Detailed track is missing sectors.");
+			assert!(s.contains(r#""duration":"#), "Detailed track is missing duration.");
+			assert!(s.contains(r#""msf":"#), "Detailed track is missing MSF.");
+
+			// The extra fields should be ignored on the way back in.
+			let d = serde_json::from_str::<Track>(&s)
+				.expect("Detailed track deserialize failed.");
+			assert_eq!(track, d, "Detailed track serialize/deserialize does not match the original.");
+
+			// The compact version should be unchanged.
+			let s = serde_json::to_string(&track).expect("Track serialize failed.");
+			assert!(! s.contains(r#""msf":"#), "Compact track has detailed fields.");
+		}
+	}
 }
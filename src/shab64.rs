@@ -8,6 +8,7 @@ use sha1::{
 	Sha1,
 };
 use std::{
+	borrow::Borrow,
 	fmt,
 	str::FromStr,
 };
@@ -23,16 +24,45 @@ use std::{
 ///
 /// String formatting is deferred until `ShaB64::to_string` or
 /// [`ShaB64::pretty_print`] are called, allowing for a slightly smaller and
-/// `copy`-friendly footprint.
+/// `copy`-friendly footprint. For allocation-free rendering, see
+/// [`ShaB64::to_array`] and [`ShaB64::as_display`].
 ///
 /// If you already have a stringified copy and want to get back to a `ShaB64`,
 /// you can use [`ShaB64::decode`] or its `FromStr` or `TryFrom<&str>` impls.
+/// If the copy came from somewhere less careful, [`ShaB64::decode_lenient`]
+/// might help.
+///
+/// The raw sha1 bytes are available via [`ShaB64::as_bytes`], and can be
+/// converted back with `From<[u8; 20]>` or `TryFrom<&[u8]>`.
+///
+/// With the `serde` crate feature enabled, human-readable formats like JSON
+/// (de)serialize the string form, while compact binary formats use the raw
+/// bytes.
+///
+/// Note: MusicBrainz and CTDB IDs share this type, and there is nothing in
+/// either form to tell them apart. If you store both, store which is which
+/// alongside them.
 pub struct ShaB64([u8; 20]);
 
+impl AsRef<[u8]> for ShaB64 {
+	#[inline]
+	fn as_ref(&self) -> &[u8] { &self.0 }
+}
+
+impl AsRef<[u8; 20]> for ShaB64 {
+	#[inline]
+	fn as_ref(&self) -> &[u8; 20] { &self.0 }
+}
+
+impl Borrow<[u8]> for ShaB64 {
+	#[inline]
+	fn borrow(&self) -> &[u8] { &self.0 }
+}
+
 impl fmt::Display for ShaB64 {
 	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		f.pad(&self.pretty_print())
+		fmt::Display::fmt(&self.as_display(), f)
 	}
 }
 
@@ -41,6 +71,31 @@ impl From<Sha1> for ShaB64 {
 	fn from(src: Sha1) -> Self { Self(<[u8; 20]>::from(src.finalize())) }
 }
 
+impl From<ShaB64> for [u8; 20] {
+	#[inline]
+	fn from(src: ShaB64) -> Self { src.0 }
+}
+
+impl From<[u8; 20]> for ShaB64 {
+	#[inline]
+	/// # From Raw.
+	///
+	/// Any twenty bytes make for a valid sha1 hash, so this is infallible.
+	fn from(src: [u8; 20]) -> Self { Self::from_bytes(src) }
+}
+
+impl TryFrom<&[u8]> for ShaB64 {
+	type Error = TocError;
+
+	#[inline]
+	/// # From Raw Slice.
+	///
+	/// This will return an error unless the slice is exactly twenty bytes.
+	fn try_from(src: &[u8]) -> Result<Self, Self::Error> {
+		<[u8; 20]>::try_from(src).map(Self).map_err(|_| TocError::ShaB64Decode)
+	}
+}
+
 impl FromStr for ShaB64 {
 	type Err = TocError;
 	#[inline]
@@ -54,6 +109,55 @@ impl TryFrom<&str> for ShaB64 {
 }
 
 impl ShaB64 {
+	#[must_use]
+	/// # As Bytes.
+	///
+	/// Return the raw (binary) sha1 hash.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::ShaB64;
+	///
+	/// let id = ShaB64::decode("nljDXdC8B_pDwbdY1vZJvdrAZI4-").unwrap();
+	/// assert_eq!(
+	///     id.as_bytes(),
+	///     &[158, 88, 195, 93, 208, 188, 7, 250, 67, 193, 183, 88, 214, 246, 73, 189, 218, 192, 100, 142],
+	/// );
+	///
+	/// // And back again.
+	/// assert_eq!(ShaB64::from(*id.as_bytes()), id);
+	/// assert_eq!(ShaB64::try_from(id.as_bytes().as_slice()), Ok(id));
+	/// ```
+	pub const fn as_bytes(&self) -> &[u8; 20] { &self.0 }
+
+	/// # From Hex.
+	///
+	/// Decode a 40-character hex string — the usual `sha1sum`-style
+	/// representation of the underlying hash — into a [`ShaB64`]. Upper- and
+	/// lowercase are both fine.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::ShaB64;
+	///
+	/// let id = ShaB64::from_hex("9e58c35dd0bc07fa43c1b758d6f649bddac0648e").unwrap();
+	/// assert_eq!(id.to_string(), "nljDXdC8B_pDwbdY1vZJvdrAZI4-");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the string is not exactly 40 hex digits.
+	pub fn from_hex(src: &str) -> Result<Self, TocError> {
+		let src = src.as_bytes();
+		let mut out = [0_u8; 20];
+		if src.len() == 40 && faster_hex::hex_decode(src, &mut out).is_ok() {
+			Ok(Self(out))
+		}
+		else { Err(TocError::ShaB64Decode) }
+	}
+
 	/// # Decode.
 	///
 	/// Convert a string ID back into a [`ShaB64`] instance.
@@ -62,37 +166,181 @@ impl ShaB64 {
 	///
 	/// This will return an error if decoding fails.
 	pub fn decode<S>(src: S) -> Result<Self, TocError>
+	where S: AsRef<str> { Self::decode_bytes(src.as_ref().as_bytes()) }
+
+	/// # Decode (Lenient).
+	///
+	/// Same as [`ShaB64::decode`], but more forgiving of how the ID was
+	/// mangled on its way to you. In addition to the canonical form, this
+	/// accepts:
+	///
+	/// * The standard base64 alphabet, i.e. `+` and `/` in place of `.` and `_`;
+	/// * `=` padding in place of the trailing `-`;
+	/// * Percent-encoded characters, e.g. `%2D` for `-`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::ShaB64;
+	///
+	/// let id = ShaB64::decode("ucgpiD84p.2iBxO4j3hdjSjhtnw-").unwrap();
+	/// for v in [
+	///     "ucgpiD84p.2iBxO4j3hdjSjhtnw-",
+	///     "ucgpiD84p+2iBxO4j3hdjSjhtnw=",
+	///     "ucgpiD84p.2iBxO4j3hdjSjhtnw%2D",
+	///     "ucgpiD84p%2B2iBxO4j3hdjSjhtnw%3D",
+	/// ] {
+	///     assert_eq!(ShaB64::decode_lenient(v), Ok(id));
+	/// }
+	///
+	/// // Garbage is still garbage.
+	/// assert!(ShaB64::decode_lenient("ucgpiD84p!2iBxO4j3hdjSjhtnw-").is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the (normalized) length is wrong, the
+	/// percent-encoding is malformed, or any characters are invalid.
+	pub fn decode_lenient<S>(src: S) -> Result<Self, TocError>
 	where S: AsRef<str> {
-		let src = src.as_ref().as_bytes();
-		if src.len() == 28 && src[27] == b'-' {
-			let mut out = [0_u8; 20];
-
-			// Handle all the nice four-byte chunks en masse.
-			for (i, chunk) in out.chunks_exact_mut(3).zip(src.chunks_exact(4)) {
-				let a = base64_decode(chunk[0])?;
-				let b = base64_decode(chunk[1])?;
-				let c = base64_decode(chunk[2])?;
-				let d = base64_decode(chunk[3])?;
-				i.copy_from_slice(&[
-					(a & 0b0011_1111) << 2 | b >> 4,
-					(b & 0b0000_1111) << 4 | c >> 2,
-					(c & 0b0000_0011) << 6 | d & 0b0011_1111,
-				]);
+		let mut buf = [0_u8; 28];
+		let mut len = 0;
+		let mut iter = src.as_ref().bytes();
+		while let Some(mut b) = iter.next() {
+			// Unescape percent-encoded bytes.
+			if b == b'%' {
+				let hi = iter.next().and_then(hex_decode).ok_or(TocError::ShaB64Decode)?;
+				let lo = iter.next().and_then(hex_decode).ok_or(TocError::ShaB64Decode)?;
+				b = hi << 4 | lo;
 			}
 
-			// Handle the remainder manually.
-			let a = base64_decode(src[24])?;
-			let b = base64_decode(src[25])?;
-			let c = base64_decode(src[26])?;
-			out[18] = (a & 0b0011_1111) << 2 | b >> 4;
-			out[19] = (b & 0b0000_1111) << 4 | c >> 2;
+			// Normalize the alphabet.
+			*buf.get_mut(len).ok_or(TocError::ShaB64Decode)? = match b {
+				b'+' => b'.',
+				b'/' => b'_',
+				b'=' => b'-',
+				_ => b,
+			};
+			len += 1;
+		}
 
-			// Done!
-			Ok(Self(out))
+		Self::decode_bytes(&buf[..len])
+	}
+
+	/// # Decode Bytes.
+	///
+	/// This is the actual decoding logic for the canonical form.
+	fn decode_bytes(src: &[u8]) -> Result<Self, TocError> {
+		<&[u8; 28]>::try_from(src).map_or(Err(TocError::ShaB64Decode), Self::from_ascii)
+	}
+
+	/// # From ASCII.
+	///
+	/// Same as [`ShaB64::decode`], but for a fixed-length byte string, and
+	/// usable in `const` contexts. This makes it possible to embed known IDs
+	/// as constants, with typos failing the build rather than a test.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::ShaB64;
+	///
+	/// const MUMMIES_ID: ShaB64 = match ShaB64::from_ascii(b"nljDXdC8B_pDwbdY1vZJvdrAZI4-") {
+	///     Ok(id) => id,
+	///     Err(_) => panic!("Invalid ID."),
+	/// };
+	///
+	/// assert_eq!(ShaB64::decode("nljDXdC8B_pDwbdY1vZJvdrAZI4-"), Ok(MUMMIES_ID));
+	/// ```
+	///
+	/// A typo, on the other hand, won't compile:
+	///
+	/// ```compile_fail
+	/// use cdtoc::ShaB64;
+	///
+	/// const MUMMIES_ID: ShaB64 = match ShaB64::from_ascii(b"nljDXdC8B_pDwbdY1vZJvdrAZI4!") {
+	///     Ok(id) => id,
+	///     Err(_) => panic!("Invalid ID."),
+	/// };
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if decoding fails.
+	pub const fn from_ascii(src: &[u8; 28]) -> Result<Self, TocError> {
+		/// # Helper: Decode or Bail.
+		macro_rules! dec {
+			($idx:expr) => (
+				match base64_decode(src[$idx]) {
+					Ok(v) => v,
+					Err(e) => return Err(e),
+				}
+			);
 		}
-		else { Err(TocError::ShaB64Decode) }
+
+		if src[27] != b'-' { return Err(TocError::ShaB64Decode); }
+		let mut out = [0_u8; 20];
+
+		// Handle all the nice four-byte chunks.
+		let mut from = 0;
+		let mut to = 0;
+		while to < 18 {
+			let a = dec!(from);
+			let b = dec!(from + 1);
+			let c = dec!(from + 2);
+			let d = dec!(from + 3);
+			out[to] = (a & 0b0011_1111) << 2 | b >> 4;
+			out[to + 1] = (b & 0b0000_1111) << 4 | c >> 2;
+			out[to + 2] = (c & 0b0000_0011) << 6 | d & 0b0011_1111;
+			from += 4;
+			to += 3;
+		}
+
+		// Handle the remainder manually.
+		let a = dec!(24);
+		let b = dec!(25);
+		let c = dec!(26);
+		out[18] = (a & 0b0011_1111) << 2 | b >> 4;
+		out[19] = (b & 0b0000_1111) << 4 | c >> 2;
+
+		// Done!
+		Ok(Self(out))
 	}
 
+	#[must_use]
+	/// # From Bytes.
+	///
+	/// Same as `From<[u8; 20]>`, but usable in `const` contexts.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::ShaB64;
+	///
+	/// const EMPTY: ShaB64 = ShaB64::from_bytes([0; 20]);
+	/// assert_eq!(EMPTY.to_string(), "AAAAAAAAAAAAAAAAAAAAAAAAAAA-");
+	/// ```
+	pub const fn from_bytes(raw: [u8; 20]) -> Self { Self(raw) }
+
+	#[must_use]
+	/// # As Display.
+	///
+	/// Return a wrapper holding the pre-rendered ID, implementing
+	/// [`Display`](fmt::Display) without any allocation. This is handy when
+	/// the same ID needs to be written out more than once.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::ShaB64;
+	///
+	/// let id = ShaB64::decode("nljDXdC8B_pDwbdY1vZJvdrAZI4-").unwrap();
+	/// let disp = id.as_display();
+	/// assert_eq!(format!("<{disp}>"), "<nljDXdC8B_pDwbdY1vZJvdrAZI4->");
+	/// assert_eq!(format!("{disp:>30}"), "  nljDXdC8B_pDwbdY1vZJvdrAZI4-");
+	/// ```
+	pub fn as_display(&self) -> impl fmt::Display { ShaB64Display(self.to_array()) }
+
 	#[expect(unsafe_code, reason = "For performance.")]
 	#[must_use]
 	/// # Pretty Print.
@@ -100,31 +348,81 @@ impl ShaB64 {
 	/// Return the value has a human-readable string, exactly like `ShaB64::to_string`,
 	/// but slightly faster. The result will always be 28-characters in length.
 	pub fn pretty_print(&self) -> String {
-		let mut out = Vec::with_capacity(28);
+		let out = self.to_array().to_vec();
+
+		// Safety: our alphabet is ASCII.
+		unsafe { String::from_utf8_unchecked(out) }
+	}
+
+	#[must_use]
+	/// # To Hex.
+	///
+	/// Return the underlying sha1 hash as a 40-character lowercase hex
+	/// string, the way `sha1sum` and most other tools would print it.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::ShaB64;
+	///
+	/// let id = ShaB64::decode("nljDXdC8B_pDwbdY1vZJvdrAZI4-").unwrap();
+	/// assert_eq!(
+	///     id.to_hex(),
+	///     "9e58c35dd0bc07fa43c1b758d6f649bddac0648e",
+	/// );
+	/// ```
+	pub fn to_hex(&self) -> String { faster_hex::hex_string(self.0.as_slice()) }
+
+	#[must_use]
+	/// # To Array.
+	///
+	/// Return the human-readable value as an array of ASCII bytes, exactly
+	/// as they'd appear in `ShaB64::to_string`, but without the allocation.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::ShaB64;
+	///
+	/// let id = ShaB64::decode("nljDXdC8B_pDwbdY1vZJvdrAZI4-").unwrap();
+	/// assert_eq!(&id.to_array(), b"nljDXdC8B_pDwbdY1vZJvdrAZI4-");
+	/// ```
+	pub fn to_array(&self) -> [u8; 28] {
+		let mut out = [b'-'; 28];
 
 		// Handle all the nice 3-byte chunks en masse.
-		for chunk in self.0.chunks_exact(3) {
-			out.push(base64_encode(chunk[0] >> 2));
-			out.push(base64_encode((chunk[0] & 0b0000_0011) << 4 | chunk[1] >> 4));
-			out.push(base64_encode((chunk[1] & 0b0000_1111) << 2 | chunk[2] >> 6));
-			out.push(base64_encode(chunk[2] & 0b0011_1111));
+		for (chunk, dst) in self.0.chunks_exact(3).zip(out.chunks_exact_mut(4)) {
+			dst[0] = base64_encode(chunk[0] >> 2);
+			dst[1] = base64_encode((chunk[0] & 0b0000_0011) << 4 | chunk[1] >> 4);
+			dst[2] = base64_encode((chunk[1] & 0b0000_1111) << 2 | chunk[2] >> 6);
+			dst[3] = base64_encode(chunk[2] & 0b0011_1111);
 		}
 
-		// Handle the remainder manually.
-		out.push(base64_encode(self.0[18] >> 2));
-		out.push(base64_encode((self.0[18] & 0b0000_0011) << 4 | self.0[19] >> 4));
-		out.push(base64_encode((self.0[19] & 0b0000_1111) << 2));
+		// Handle the remainder manually. The last byte is padding, which we
+		// already have.
+		out[24] = base64_encode(self.0[18] >> 2);
+		out[25] = base64_encode((self.0[18] & 0b0000_0011) << 4 | self.0[19] >> 4);
+		out[26] = base64_encode((self.0[19] & 0b0000_1111) << 2);
+
+		debug_assert!(out.is_ascii(), "Bug: Sha/base64 ID is malformed.");
+		out
+	}
+}
+
 
-		// And add one byte for padding.
-		out.push(b'-');
 
-		debug_assert!(
-			out.len() == 28 && out.is_ascii(),
-			"Bug: Sha/base64 ID is malformed."
-		);
+/// # Display Wrapper.
+///
+/// This holds a pre-rendered ID for [`ShaB64::as_display`].
+struct ShaB64Display([u8; 28]);
 
+impl fmt::Display for ShaB64Display {
+	#[expect(unsafe_code, reason = "For performance.")]
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		debug_assert!(self.0.is_ascii(), "Bug: Sha/base64 ID is malformed.");
 		// Safety: our alphabet is ASCII.
-		unsafe { String::from_utf8_unchecked(out) }
+		f.pad(unsafe { std::str::from_utf8_unchecked(self.0.as_slice()) })
 	}
 }
 
@@ -146,6 +444,18 @@ const fn base64_encode(byte: u8) -> u8 {
 	}
 }
 
+/// # Hex Decode.
+///
+/// Decode a single hex digit, e.g. for percent-encoding.
+const fn hex_decode(byte: u8) -> Option<u8> {
+	match byte {
+		b'0'..=b'9' => Some(byte - b'0'),
+		b'A'..=b'F' => Some(byte - 55),
+		b'a'..=b'f' => Some(byte - 87),
+		_ => None,
+	}
+}
+
 /// # Base64 Decode.
 const fn base64_decode(byte: u8) -> Result<u8, TocError> {
 	match byte {
@@ -157,3 +467,146 @@ const fn base64_decode(byte: u8) -> Result<u8, TocError> {
 		_ => Err(TocError::ShaB64Decode),
 	}
 }
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_bytes() {
+		for id in [
+			"nljDXdC8B_pDwbdY1vZJvdrAZI4-",
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw-",
+			"efFU9TD0IyDF3iME6KlK.rZJEaw-",
+			"AAAAAAAAAAAAAAAAAAAAAAAAAAA-",
+			"__________________________8-",
+		] {
+			let sha = ShaB64::decode(id).expect("Decode failed.");
+			let raw: [u8; 20] = sha.into();
+			assert_eq!(sha.as_bytes(), &raw);
+			assert_eq!(AsRef::<[u8]>::as_ref(&sha), raw.as_slice());
+
+			// Back again, both ways.
+			assert_eq!(ShaB64::from(raw), sha);
+			assert_eq!(ShaB64::try_from(raw.as_slice()), Ok(sha));
+			assert_eq!(ShaB64::from(raw).to_string(), id);
+		}
+
+		// Byte order.
+		let mut raw = [0_u8; 20];
+		raw[0] = 0b1111_1100;
+		assert_eq!(ShaB64::from(raw).to_string(), "_AAAAAAAAAAAAAAAAAAAAAAAAAA-");
+		raw[0] = 0;
+		raw[19] = 0b0000_0100;
+		assert_eq!(ShaB64::from(raw).to_string(), "AAAAAAAAAAAAAAAAAAAAAAAAAAQ-");
+
+		// Wrong sizes.
+		for len in [0, 19, 21] {
+			assert_eq!(ShaB64::try_from(vec![0_u8; len].as_slice()), Err(TocError::ShaB64Decode));
+		}
+	}
+
+	#[test]
+	fn t_decode_lenient() {
+		let id = ShaB64::decode("ucgpiD84p.2iBxO4j3hdjSjhtnw-").expect("Decode failed.");
+		for v in [
+			// Canonical.
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw-",
+			// Standard alphabet.
+			"ucgpiD84p+2iBxO4j3hdjSjhtnw-",
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw=",
+			"ucgpiD84p+2iBxO4j3hdjSjhtnw=",
+			// Percent-encoded.
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw%2D",
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw%2d",
+			"ucgpiD84p%2E2iBxO4j3hdjSjhtnw-",
+			"ucgpiD84p%2B2iBxO4j3hdjSjhtnw%3D",
+			"%75%63gpiD84p.2iBxO4j3hdjSjhtnw-",
+		] {
+			assert_eq!(ShaB64::decode_lenient(v), Ok(id), "{v}");
+		}
+
+		// Slashes too.
+		let id = ShaB64::decode("__________________________8-").expect("Decode failed.");
+		for v in [
+			"//////////////////////////8=",
+			"%2F_________________________8-",
+			"__________________________8%3d",
+		] {
+			assert_eq!(ShaB64::decode_lenient(v), Ok(id), "{v}");
+		}
+
+		// The strict decoder should still be strict.
+		assert!(ShaB64::decode("ucgpiD84p+2iBxO4j3hdjSjhtnw=").is_err());
+		assert!(ShaB64::decode("ucgpiD84p.2iBxO4j3hdjSjhtnw%2D").is_err());
+
+		for v in [
+			"",
+			"%",
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw",
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw--",
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw-=",
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw%2",
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw%ZZ",
+			"ucgpiD84p!2iBxO4j3hdjSjhtnw-",
+			"ucgpiD84p%212iBxO4j3hdjSjhtnw-",
+			"ucgpiD84p-2iBxO4j3hdjSjhtnw-",
+			"ucgpiD84p.2iBxO4j3hdjSjhtnw.",
+			"=cgpiD84p.2iBxO4j3hdjSjhtnw-",
+		] {
+			assert_eq!(ShaB64::decode_lenient(v), Err(TocError::ShaB64Decode), "{v}");
+		}
+	}
+
+	#[test]
+	fn t_hex() {
+		for (id, hex) in [
+			// Computed independently from the MusicBrainz hash input for
+			// "4+96+2D2B+6256+B327+D84A".
+			("nljDXdC8B_pDwbdY1vZJvdrAZI4-", "9e58c35dd0bc07fa43c1b758d6f649bddac0648e"),
+			("AAAAAAAAAAAAAAAAAAAAAAAAAAA-", "0000000000000000000000000000000000000000"),
+			("__________________________8-", "ffffffffffffffffffffffffffffffffffffffff"),
+		] {
+			let sha = ShaB64::decode(id).expect("Decode failed.");
+			assert_eq!(sha.to_hex(), hex);
+			assert_eq!(ShaB64::from_hex(hex), Ok(sha));
+			assert_eq!(ShaB64::from_hex(&hex.to_ascii_uppercase()), Ok(sha));
+			assert_eq!(ShaB64::from_hex(&sha.to_hex()).map(|v| v.to_string()).as_deref(), Ok(id));
+		}
+
+		for bad in [
+			"",
+			"9e58c35dd0bc07fa43c1b758d6f649bddac0648",
+			"9e58c35dd0bc07fa43c1b758d6f649bddac0648e0",
+			"9e58c35dd0bc07fa43c1b758d6f649bddac0648e00",
+			"9e58c35dd0bc07fa43c1b758d6f649bddac0648g",
+			" 9e58c35dd0bc07fa43c1b758d6f649bddac0648",
+		] {
+			assert_eq!(ShaB64::from_hex(bad), Err(TocError::ShaB64Decode), "{bad}");
+		}
+	}
+
+	#[test]
+	fn t_const() {
+		/// # Known ID.
+		const ID: ShaB64 = match ShaB64::from_ascii(b"ucgpiD84p.2iBxO4j3hdjSjhtnw-") {
+			Ok(id) => id,
+			Err(_) => panic!("Invalid ID."),
+		};
+		assert_eq!(ShaB64::decode("ucgpiD84p.2iBxO4j3hdjSjhtnw-"), Ok(ID));
+		assert_eq!(ShaB64::from_bytes(*ID.as_bytes()), ID);
+
+		// Const and non-const decoding should agree on failure too.
+		for bad in [
+			b"ucgpiD84p.2iBxO4j3hdjSjhtnw=",
+			b"ucgpiD84p+2iBxO4j3hdjSjhtnw-",
+			b"!cgpiD84p.2iBxO4j3hdjSjhtnw-",
+			b"ucgpiD84p.2iBxO4j3hdjSjhtn!-",
+		] {
+			assert_eq!(ShaB64::from_ascii(bad), Err(TocError::ShaB64Decode));
+			assert_eq!(ShaB64::from_ascii(bad), ShaB64::decode(std::str::from_utf8(bad).expect("Invalid UTF-8.")));
+		}
+	}
+}
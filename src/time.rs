@@ -6,11 +6,12 @@ use crate::TocError;
 use dactyl::{
 	NiceElapsed,
 	traits::{
-		IntDivFloat,
+		BytesToUnsigned,
 		NiceInflection,
 	},
 };
 use std::{
+	cmp::Ordering,
 	fmt,
 	hash,
 	iter::Sum,
@@ -23,7 +24,9 @@ use std::{
 		DivAssign,
 		Mul,
 		MulAssign,
+		Neg,
 	},
+	str::FromStr,
 	time,
 };
 
@@ -32,9 +35,21 @@ use std::{
 /// # Samples Per Sector.
 const SAMPLES_PER_SECTOR: u64 = 588;
 
+/// # Samples Per Sector (Signed).
+const SAMPLES_PER_SECTOR_SIGNED: i64 = 588;
+
 /// # Sectors Per Second.
 const SECTORS_PER_SECOND: u64 = 75;
 
+/// # Nanoseconds Per Sector (Numerator).
+///
+/// There are `1_000_000_000 / 75` nanoseconds per sector, which reduces to
+/// `40_000_000 / 3`.
+const NANOS_PER_SECTOR_N: u128 = 40_000_000;
+
+/// # Nanoseconds Per Sector (Denominator).
+const NANOS_PER_SECTOR_D: u128 = 3;
+
 
 
 #[derive(Debug, Clone, Copy, Default, Ord, PartialOrd)]
@@ -76,7 +91,55 @@ const SECTORS_PER_SECOND: u64 = 75;
 ///     .map(|t| t.duration())
 ///     .sum();
 /// assert_eq!(duration.to_string(), "00:34:41+63");
+///
+/// // Slices of durations can be summed directly too.
+/// let durations: Vec<Duration> = toc.audio_tracks()
+///     .map(|t| t.duration())
+///     .collect();
+/// assert_eq!(durations.iter().sum::<Duration>(), duration);
+/// assert_eq!(durations[0] + &durations[1], durations[..2].iter().sum::<Duration>());
+/// ```
+///
+/// Anything convertible to a `u64` sector count works as the right-hand
+/// side of an operator, including `Duration` and `&Duration`.
+///
+/// ## Comparisons
+///
+/// Durations can be compared directly against `u64` sector counts, as well
+/// as [`std::time::Duration`] values.
+///
+/// Because the two time scales rarely line up exactly, the latter are
+/// considered equal if they are less than one frame apart. Be careful: this
+/// means equality is not transitive across the two types. Use
+/// [`Duration::try_from`] or [`Duration::from_std_lossy`] if you need
+/// something stricter.
+///
 /// ```
+/// use cdtoc::Duration;
+///
+/// let duration = Duration::from(75_u32);
+/// assert_eq!(duration, 75_u64);
+/// assert!(duration < 76_u64);
+///
+/// // Within a frame is close enough.
+/// let one_second = std::time::Duration::from_secs(1);
+/// assert_eq!(duration, one_second);
+/// assert_eq!(duration, one_second + std::time::Duration::from_millis(13));
+/// assert_ne!(duration, one_second + std::time::Duration::from_millis(14));
+/// assert!(duration < one_second + std::time::Duration::from_millis(14));
+/// ```
+///
+/// ## Overflow
+///
+/// The arithmetic operators behave like those of the inner `u64`, with a
+/// couple exceptions:
+///
+/// * `+` and `*` panic on overflow in debug builds and wrap in release builds;
+/// * `-` saturates at zero;
+/// * `/` returns zero when dividing by zero.
+///
+/// If the values might be untrusted, use the explicit [`Duration::checked_add`]
+/// and friends instead.
 pub struct Duration(pub(crate) u64);
 
 impl<T> Add<T> for Duration
@@ -98,7 +161,7 @@ where u64: From<T> {
 	#[inline]
 	fn div(self, other: T) -> Self {
 		let other = u64::from(other);
-		if other == 0 { Self(0) }
+		if other == 0 { Self::ZERO }
 		else { Self(self.0.wrapping_div(other)) }
 	}
 }
@@ -116,16 +179,9 @@ where u64: From<T> {
 impl Eq for Duration {}
 
 impl fmt::Display for Duration {
-	#[expect(clippy::many_single_char_names, reason = "Consistency is preferred.")]
 	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		let (d, h, m, s, frames) = self.dhmsf();
-		if d == 0 {
-			write!(f, "{h:02}:{m:02}:{s:02}+{frames:02}")
-		}
-		else {
-			write!(f, "{d}d {h:02}:{m:02}:{s:02}+{frames:02}")
-		}
+		<DurationParts as fmt::Display>::fmt(&self.parts(), f)
 	}
 }
 
@@ -149,6 +205,31 @@ impl From<Duration> for u64 {
 	fn from(src: Duration) -> Self { src.0 }
 }
 
+impl From<&Duration> for u64 {
+	#[inline]
+	fn from(src: &Duration) -> Self { src.0 }
+}
+
+impl TryFrom<time::Duration> for Duration {
+	type Error = TocError;
+	fn try_from(src: time::Duration) -> Result<Self, Self::Error> {
+		let secs = src.as_secs();
+		let nanos = u64::from(src.subsec_nanos());
+		let floor = Self::from_seconds_parts(secs, nanos, 1_000_000_000, Rounding::Floor);
+		let ceil = Self::from_seconds_parts(secs, nanos, 1_000_000_000, Rounding::Ceil);
+		match (floor, ceil) {
+			(Some(a), Some(b)) if a.0 == b.0 => Ok(a),
+			_ => Err(TocError::DurationConversion),
+		}
+	}
+}
+
+impl FromStr for Duration {
+	type Err = TocError;
+	#[inline]
+	fn from_str(src: &str) -> Result<Self, Self::Err> { Self::decode(src) }
+}
+
 impl hash::Hash for Duration {
 	#[inline]
 	fn hash<H: hash::Hasher>(&self, state: &mut H) { state.write_u64(self.0); }
@@ -159,6 +240,52 @@ impl PartialEq for Duration {
 	fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
 }
 
+impl PartialEq<u64> for Duration {
+	#[inline]
+	fn eq(&self, other: &u64) -> bool { self.0 == *other }
+}
+
+impl PartialEq<Duration> for u64 {
+	#[inline]
+	fn eq(&self, other: &Duration) -> bool { *self == other.0 }
+}
+
+impl PartialEq<time::Duration> for Duration {
+	#[inline]
+	fn eq(&self, other: &time::Duration) -> bool {
+		matches!(self.cmp_std(*other), Ordering::Equal)
+	}
+}
+
+impl PartialEq<Duration> for time::Duration {
+	#[inline]
+	fn eq(&self, other: &Duration) -> bool { other == self }
+}
+
+impl PartialOrd<u64> for Duration {
+	#[inline]
+	fn partial_cmp(&self, other: &u64) -> Option<Ordering> { Some(self.0.cmp(other)) }
+}
+
+impl PartialOrd<Duration> for u64 {
+	#[inline]
+	fn partial_cmp(&self, other: &Duration) -> Option<Ordering> { Some(self.cmp(&other.0)) }
+}
+
+impl PartialOrd<time::Duration> for Duration {
+	#[inline]
+	fn partial_cmp(&self, other: &time::Duration) -> Option<Ordering> {
+		Some(self.cmp_std(*other))
+	}
+}
+
+impl PartialOrd<Duration> for time::Duration {
+	#[inline]
+	fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
+		Some(other.cmp_std(*self).reverse())
+	}
+}
+
 impl<T> Mul<T> for Duration
 where u64: From<T> {
 	type Output = Self;
@@ -185,13 +312,296 @@ where u64: From<T> {
 	fn sub_assign(&mut self, other: T) { self.0 = self.0.saturating_sub(u64::from(other)); }
 }
 
+impl TryFrom<&str> for Duration {
+	type Error = TocError;
+	#[inline]
+	fn try_from(src: &str) -> Result<Self, Self::Error> { Self::decode(src) }
+}
+
 impl Sum for Duration {
 	#[inline]
 	fn sum<I>(iter: I) -> Self
-	where I: Iterator<Item = Self> { iter.fold(Self::default(), |a, b| a + b) }
+	where I: Iterator<Item = Self> { iter.fold(Self::ZERO, |a, b| a + b) }
+}
+
+impl<'a> Sum<&'a Self> for Duration {
+	#[inline]
+	fn sum<I>(iter: I) -> Self
+	where I: Iterator<Item = &'a Self> { iter.fold(Self::ZERO, |a, b| a + b) }
+}
+
+impl Duration {
+	/// # Zero.
+	pub const ZERO: Self = Self(0);
+
+	/// # One Frame (Sector).
+	pub const FRAME: Self = Self(1);
+
+	/// # One Second.
+	pub const SECOND: Self = Self(SECTORS_PER_SECOND);
+
+	/// # One Minute.
+	pub const MINUTE: Self = Self(SECTORS_PER_SECOND * 60);
+
+	/// # Maximum.
+	///
+	/// This is `u64::MAX` frames, or a little over 7.8 billion years.
+	pub const MAX: Self = Self(u64::MAX);
 }
 
 impl Duration {
+	#[expect(clippy::many_single_char_names, reason = "Consistency is preferred.")]
+	/// # Decode.
+	///
+	/// Parse a duration string in the same `Dd HH:MM:SS+FF` format used by
+	/// its `Display` implementation, i.e. the inverse of `to_string`.
+	///
+	/// The day prefix is optional. The hours, minutes, seconds, and frames
+	/// must each be exactly two digits, and cannot exceed `23`, `59`, `59`,
+	/// and `74` respectively.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::decode("00:01:55+04").unwrap();
+	/// assert_eq!(duration.sectors(), 8629);
+	///
+	/// // Days are supported too.
+	/// let duration = Duration::decode("2d 03:01:55+04").unwrap();
+	/// assert_eq!(duration.dhmsf(), (2, 3, 1, 55, 4));
+	///
+	/// // Either way, it should match the original.
+	/// assert_eq!(duration.to_string(), "2d 03:01:55+04");
+	/// ```
+	///
+	/// Alternatively, you can use its `FromStr` and `TryFrom<&str>` impls:
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::from(8629_u32);
+	/// assert_eq!(Duration::try_from("00:01:55+04"), Ok(duration));
+	/// assert_eq!("00:01:55+04".parse::<Duration>(), Ok(duration));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the string is not formatted correctly,
+	/// any of its parts are out of range, or the total overflows.
+	pub fn decode<S>(src: S) -> Result<Self, TocError>
+	where S: AsRef<str> {
+		/// # Two-Digit Part.
+		fn part(src: &[u8], max: u8) -> Result<u64, TocError> {
+			if src.len() == 2 {
+				if let Some(v) = u8::btou(src) {
+					if v <= max { return Ok(u64::from(v)); }
+				}
+			}
+			Err(TocError::DurationParse)
+		}
+
+		let src = src.as_ref().as_bytes();
+
+		// Split off the days, if any.
+		let (d, src) = match src.iter().position(|b| b'd'.eq(b)) {
+			Some(idx) =>
+				if src.get(idx + 1) == Some(&b' ') {
+					let d = u64::btou(&src[..idx]).ok_or(TocError::DurationParse)?;
+					(d, &src[idx + 2..])
+				}
+				else { return Err(TocError::DurationParse); },
+			None => (0, src),
+		};
+
+		// The rest should be HH:MM:SS+FF.
+		if src.len() != 11 || src[2] != b':' || src[5] != b':' || src[8] != b'+' {
+			return Err(TocError::DurationParse);
+		}
+
+		let h = part(&src[..2], 23)?;
+		let m = part(&src[3..5], 59)?;
+		let s = part(&src[6..8], 59)?;
+		let f = part(&src[9..], 74)?;
+
+		d.checked_mul(86_400)
+			.and_then(|d| d.checked_add(h * 3600 + m * 60 + s))
+			.and_then(|s| s.checked_mul(SECTORS_PER_SECOND))
+			.and_then(|s| s.checked_add(f))
+			.map(Self)
+			.ok_or(TocError::DurationParse)
+	}
+
+	/// # From Seconds (`f64`, Lossy).
+	///
+	/// Convert floating-point seconds into a CD sector duration, snapping it
+	/// to the 1/75th-of-a-second grid using the given [`Rounding`] policy.
+	///
+	/// This is the inverse of [`Duration::to_f64_lossy`], give or take half
+	/// a frame. Note that `f64` can only represent integers exactly up to
+	/// `2^53`, so durations much beyond about 3,800 years will start to lose
+	/// whole frames of precision.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Duration, Rounding};
+	///
+	/// let duration = Duration::from_secs_f64_lossy(115.05333333333333, Rounding::Nearest).unwrap();
+	/// assert_eq!(duration.to_string(), "00:01:55+04");
+	///
+	/// // Half a frame is a tie.
+	/// let half = 1.0 / 150.0;
+	/// assert_eq!(Duration::from_secs_f64_lossy(half, Rounding::Floor).unwrap().sectors(), 0);
+	/// assert_eq!(Duration::from_secs_f64_lossy(half, Rounding::Ceil).unwrap().sectors(), 1);
+	/// assert_eq!(Duration::from_secs_f64_lossy(half, Rounding::Nearest).unwrap().sectors(), 1);
+	///
+	/// // Nonsense is an error.
+	/// assert!(Duration::from_secs_f64_lossy(-1.0, Rounding::Floor).is_err());
+	/// assert!(Duration::from_secs_f64_lossy(f64::NAN, Rounding::Floor).is_err());
+	/// assert!(Duration::from_secs_f64_lossy(f64::INFINITY, Rounding::Floor).is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the value is negative, NaN, infinite, or
+	/// too large to fit in `u64` sectors.
+	pub fn from_secs_f64_lossy(secs: f64, rounding: Rounding) -> Result<Self, TocError> {
+		Self::from_frames_f64(secs * 75.0, rounding)
+	}
+
+	#[must_use]
+	/// # From [`std::time::Duration`] (Lossy).
+	///
+	/// Convert a "normal" [`std::time::Duration`] into a CD sector duration,
+	/// snapping it to the 1/75th-of-a-second grid using the given
+	/// [`Rounding`] policy. Values too large to represent are capped at
+	/// `u64::MAX` sectors.
+	///
+	/// If the value needs to be exact, use the `TryFrom` implementation
+	/// instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Duration, Rounding};
+	///
+	/// // One and a half frames.
+	/// let std = std::time::Duration::from_millis(20);
+	/// assert_eq!(Duration::from_std_lossy(std, Rounding::Floor).sectors(), 1);
+	/// assert_eq!(Duration::from_std_lossy(std, Rounding::Ceil).sectors(), 2);
+	/// assert_eq!(Duration::from_std_lossy(std, Rounding::Nearest).sectors(), 2);
+	///
+	/// // Exact values are exact.
+	/// let std = std::time::Duration::from_secs(2);
+	/// assert_eq!(Duration::from_std_lossy(std, Rounding::Floor).sectors(), 150);
+	/// assert_eq!(Duration::try_from(std).unwrap().sectors(), 150);
+	/// ```
+	pub const fn from_std_lossy(src: time::Duration, rounding: Rounding) -> Self {
+		match Self::from_seconds_parts(
+			src.as_secs(),
+			src.subsec_nanos() as u64,
+			1_000_000_000,
+			rounding,
+		) {
+			Some(d) => d,
+			None => Self::MAX,
+		}
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # From Seconds (Rational).
+	///
+	/// Convert `secs + subsec_num / subsec_den` seconds into a CD sector
+	/// duration, snapping the fractional part to the 1/75th-of-a-second grid
+	/// using the given [`Rounding`] policy.
+	///
+	/// The math is exact, making this the foundation for the crate's other
+	/// lossy rational conversions, such as [`Duration::from_std_lossy`] and
+	/// [`Duration::from_samples`].
+	///
+	/// The numerator may be larger than the denominator.
+	///
+	/// Returns `None` if the denominator is zero or the result would
+	/// overflow `u64` sectors.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Duration, Rounding};
+	///
+	/// // Two and a half frames.
+	/// let d = Duration::from_seconds_parts(0, 5, 150, Rounding::Floor).unwrap();
+	/// assert_eq!(d.sectors(), 2);
+	///
+	/// let d = Duration::from_seconds_parts(0, 5, 150, Rounding::Ceil).unwrap();
+	/// assert_eq!(d.sectors(), 3);
+	///
+	/// let d = Duration::from_seconds_parts(0, 5, 150, Rounding::Nearest).unwrap();
+	/// assert_eq!(d.sectors(), 3);
+	///
+	/// let d = Duration::from_seconds_parts(0, 5, 150, Rounding::NearestTiesEven).unwrap();
+	/// assert_eq!(d.sectors(), 2);
+	///
+	/// // Zero denominators are no good.
+	/// assert!(Duration::from_seconds_parts(1, 5, 0, Rounding::Floor).is_none());
+	/// ```
+	pub const fn from_seconds_parts(
+		secs: u64,
+		subsec_num: u64,
+		subsec_den: u64,
+		rounding: Rounding,
+	) -> Option<Self> {
+		if subsec_den == 0 { return None; }
+
+		let n = subsec_num as u128 * SECTORS_PER_SECOND as u128;
+		let d = subsec_den as u128;
+		let total = rounding.apply(
+			secs as u128 * SECTORS_PER_SECOND as u128 + n / d,
+			n % d,
+			d,
+		);
+		if total <= u64::MAX as u128 { Some(Self(total as u64)) }
+		else { None }
+	}
+
+	/// # From MSF.
+	///
+	/// Create a duration from minutes, seconds, and frames.
+	///
+	/// Unlike [`Msf`](crate::Msf), the minutes here are not constrained by
+	/// sector addressing limits.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::from_msf(1, 55, 4).unwrap();
+	/// assert_eq!(duration.to_string(), "00:01:55+04");
+	///
+	/// // Minutes absorb hours.
+	/// let duration = Duration::from_msf(61, 0, 0).unwrap();
+	/// assert_eq!(duration.to_string(), "01:01:00+00");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the seconds are `60` or more, or the
+	/// frames are `75` or more.
+	pub const fn from_msf(m: u32, s: u8, f: u8) -> Result<Self, TocError> {
+		if 60 <= s || 75 <= f { Err(TocError::Msf) }
+		else {
+			Ok(Self(
+				m as u64 * 60 * SECTORS_PER_SECOND +
+				s as u64 * SECTORS_PER_SECOND +
+				f as u64
+			))
+		}
+	}
+
 	/// # From CDDA Samples.
 	///
 	/// Derive the duration from the total number of a track's _CDDA-quality_
@@ -226,19 +636,15 @@ impl Duration {
 		else { Err(TocError::CDDASampleCount) }
 	}
 
-	#[expect(
-		clippy::cast_possible_truncation,
-		clippy::cast_sign_loss,
-		reason = "False positive.",
-	)]
 	#[must_use]
 	/// # From Samples (Rescaled).
 	///
 	/// Derive the equivalent CDDA duration for a track with an arbitrary
 	/// sample rate (i.e. not 44.1 kHz) or sample count.
 	///
-	/// This operation is potentially lossy and may result in a duration that
-	/// is off by ±1 frame.
+	/// Partial frames are truncated (i.e. [`Rounding::Floor`]); use
+	/// [`Duration::from_seconds_parts`] directly if a different policy is
+	/// needed.
 	///
 	/// For standard CDDA tracks, use [`Duration::from_cdda_samples`] instead.
 	///
@@ -254,156 +660,281 @@ impl Duration {
 	/// );
 	/// ```
 	pub fn from_samples(sample_rate: u32,  total_samples: u64) -> Self {
-		if sample_rate == 0 || total_samples == 0 { Self::default() }
+		if sample_rate == 0 || total_samples == 0 { Self::ZERO }
 		else {
 			let sample_rate = u64::from(sample_rate);
-			let (s, rem) = (total_samples.wrapping_div(sample_rate), total_samples % sample_rate);
-			if rem == 0 { Self(s * SECTORS_PER_SECOND) }
-			else {
-				let f = (rem * 75).div_float(sample_rate)
-					.map_or(0, |f| f.trunc() as u64);
-				Self(s * SECTORS_PER_SECOND + f)
-			}
+			Self::from_seconds_parts(
+				total_samples.wrapping_div(sample_rate),
+				total_samples % sample_rate,
+				sample_rate,
+				Rounding::Floor,
+			)
+				.unwrap_or(Self::MAX)
 		}
 	}
 }
 
 impl Duration {
-	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
-	#[expect(clippy::many_single_char_names, reason = "Consistency is preferred.")]
 	#[must_use]
-	/// # Days, Hours, Minutes, Seconds, Frames.
+	/// # Checked Add.
 	///
-	/// Carve up the duration into a quintuple of days, hours, minutes,
-	/// seconds, and frames.
+	/// Add two durations (or a duration and a sector count), returning `None`
+	/// on overflow.
 	///
 	/// ## Examples
 	///
 	/// ```
-	/// use cdtoc::Toc;
+	/// use cdtoc::Duration;
 	///
-	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
-	/// let track = toc.audio_track(9).unwrap();
-	/// assert_eq!(
-	///     track.duration().dhmsf(),
-	///     (0, 0, 1, 55, 4),
-	/// );
+	/// let duration = Duration::from(75_u32);
+	/// assert_eq!(duration.checked_add(duration), Some(Duration::from(150_u32)));
+	/// assert_eq!(duration.checked_add(u64::MAX), None);
 	/// ```
-	pub const fn dhmsf(self) -> (u64, u8, u8, u8, u8) {
-		let (s, f) = self.seconds_frames();
-		if s <= 4_294_967_295 {
-			let (d, h, m, s) = NiceElapsed::dhms(s as u32);
-			(d as u64, h, m, s, f)
-		}
-		else {
-			let d = s.wrapping_div(86_400);
-			let [h, m, s] = NiceElapsed::hms((s - d * 86_400) as u32);
-			(d, h, m, s, f)
-		}
+	pub fn checked_add<T>(self, other: T) -> Option<Self>
+	where u64: From<T> {
+		self.0.checked_add(u64::from(other)).map(Self)
 	}
 
 	#[must_use]
-	/// # Total Samples.
+	/// # Checked Div.
 	///
-	/// Return the total number of samples.
+	/// Divide the duration by `other`, returning `None` if `other` is zero.
 	///
 	/// ## Examples
 	///
 	/// ```
-	/// use cdtoc::Toc;
+	/// use cdtoc::Duration;
 	///
-	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
-	/// let track = toc.audio_track(9).unwrap();
-	/// assert_eq!(
-	///     track.duration().samples(),
-	///     5_073_852,
-	/// );
+	/// let duration = Duration::from(150_u32);
+	/// assert_eq!(duration.checked_div(2_u32), Some(Duration::from(75_u32)));
+	/// assert_eq!(duration.checked_div(0_u32), None);
 	/// ```
-	pub const fn samples(self) -> u64 { self.0 * SAMPLES_PER_SECTOR }
+	pub fn checked_div<T>(self, other: T) -> Option<Self>
+	where u64: From<T> {
+		self.0.checked_div(u64::from(other)).map(Self)
+	}
 
 	#[must_use]
-	/// # Seconds + Frames.
-	///
-	/// Return the duration as a tuple containing the total number of seconds
-	/// and remaining frames (some fraction of a second).
+	/// # Checked Mul.
 	///
-	/// Audio CDs have 75 frames per second, so the frame portion will always
-	/// be in the range of `0..75`.
+	/// Multiply the duration by `other`, returning `None` on overflow.
 	///
 	/// ## Examples
 	///
 	/// ```
-	/// use cdtoc::Toc;
+	/// use cdtoc::Duration;
 	///
-	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
-	/// let track = toc.audio_track(9).unwrap();
-	/// assert_eq!(
-	///     track.duration().seconds_frames(),
-	///     (115, 4),
-	/// );
+	/// let duration = Duration::from(75_u32);
+	/// assert_eq!(duration.checked_mul(2_u32), Some(Duration::from(150_u32)));
+	/// assert_eq!(duration.checked_mul(u64::MAX), None);
 	/// ```
-	pub const fn seconds_frames(self) -> (u64, u8) {
-		(self.0.wrapping_div(SECTORS_PER_SECOND), (self.0 % SECTORS_PER_SECOND) as u8)
+	pub fn checked_mul<T>(self, other: T) -> Option<Self>
+	where u64: From<T> {
+		self.0.checked_mul(u64::from(other)).map(Self)
 	}
 
 	#[must_use]
-	/// # Number of Sectors.
+	/// # Checked Sub.
 	///
-	/// Return the total number of sectors.
+	/// Subtract `other` from the duration, returning `None` if the result
+	/// would be negative.
 	///
 	/// ## Examples
 	///
 	/// ```
-	/// use cdtoc::Toc;
+	/// use cdtoc::Duration;
 	///
-	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
-	/// let track = toc.audio_track(9).unwrap();
-	/// assert_eq!(
-	///     track.duration().sectors(),
-	///     8629,
-	/// );
+	/// let duration = Duration::from(75_u32);
+	/// assert_eq!(duration.checked_sub(25_u32), Some(Duration::from(50_u32)));
+	/// assert_eq!(duration.checked_sub(76_u32), None);
+	///
+	/// // The operator saturates instead.
+	/// assert_eq!(duration - 76_u32, Duration::ZERO);
 	/// ```
-	pub const fn sectors(self) -> u64 { self.0 }
+	pub fn checked_sub<T>(self, other: T) -> Option<Self>
+	where u64: From<T> {
+		self.0.checked_sub(u64::from(other)).map(Self)
+	}
 
-	#[expect(clippy::cast_precision_loss, reason = "False positive.")]
 	#[must_use]
-	/// # To `f64` (Lossy).
-	///
-	/// Return the duration as a float (seconds.subseconds).
+	/// # Div/Rem.
 	///
-	/// Given that 75ths don't always make the cleanest of fractions, there
-	/// will likely be some loss in precision.
+	/// Divide the duration into `n` whole-frame parts, returning the size of
+	/// each part along with the frames left over, or `None` if `n` is zero.
 	///
 	/// ## Examples
 	///
 	/// ```
-	/// use cdtoc::Toc;
+	/// use cdtoc::Duration;
 	///
-	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
-	/// let track = toc.audio_track(9).unwrap();
+	/// let duration = Duration::from(151_u32);
 	/// assert_eq!(
-	///     track.duration().to_f64_lossy(),
-	///     115.05333333333333,
+	///     duration.div_rem(2),
+	///     Some((Duration::from(75_u32), Duration::from(1_u32))),
 	/// );
+	/// assert_eq!(duration.div_rem(0), None);
 	/// ```
-	pub fn to_f64_lossy(self) -> f64 {
-		// Most durations will probably fit within `u32`, which converts
-		// cleanly.
-		if self.0 <= 4_294_967_295 { self.0 as f64 / 75.0 }
-		// Otherwise let's try to do it in parts and hope for the best.
-		else {
-			let (s, f) = self.seconds_frames();
-			s as f64 + f64::from(f) / 75.0
+	pub const fn div_rem(self, n: u64) -> Option<(Self, Self)> {
+		match (self.0.checked_div(n), self.0.checked_rem(n)) {
+			(Some(q), Some(r)) => Some((Self(q), Self(r))),
+			_ => None,
 		}
 	}
 
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 	#[must_use]
-	/// # To [`std::time::Duration`] (Lossy).
+	/// # Split Evenly.
 	///
-	/// Return the value as a "normal" [`std::time::Duration`].
+	/// Split the duration into `n` frame-aligned pieces that sum exactly to
+	/// the original. If it doesn't divide evenly, the leftover frames are
+	/// distributed one apiece to the leading pieces, so no two pieces differ
+	/// by more than a single frame.
 	///
-	/// Note that the `std` struct only counts time down to the nanosecond, so
-	/// this value might be off by a few frames.
+	/// Returns `None` if `n` is zero.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::from(11_u32);
+	/// let parts = duration.split_evenly(4).unwrap();
+	/// assert_eq!(
+	///     parts,
+	///     [3_u32, 3, 3, 2].map(Duration::from),
+	/// );
+	/// assert_eq!(parts.iter().sum::<Duration>(), duration);
+	///
+	/// assert!(duration.split_evenly(0).is_none());
+	/// ```
+	pub fn split_evenly(self, n: usize) -> Option<Vec<Self>> {
+		let (q, r) = self.div_rem(n as u64)?;
+		let mut out = vec![q; n];
+		for v in out.iter_mut().take(r.0 as usize) { v.0 += 1; }
+		Some(out)
+	}
+
+	/// # Div by Float (Lossy).
+	///
+	/// Divide the duration by a floating-point ratio, snapping the result to
+	/// the frame grid using the given [`Rounding`] policy.
+	///
+	/// See [`Duration::mul_f64_lossy`] for precision notes.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Duration, Rounding};
+	///
+	/// let duration = Duration::from(8629_u32);
+	/// assert_eq!(
+	///     duration.div_f64_lossy(2.0, Rounding::Floor).unwrap().sectors(),
+	///     4314,
+	/// );
+	/// assert_eq!(
+	///     duration.div_f64_lossy(2.0, Rounding::Ceil).unwrap().sectors(),
+	///     4315,
+	/// );
+	///
+	/// // Zero is an error.
+	/// assert!(duration.div_f64_lossy(0.0, Rounding::Floor).is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the ratio is zero, negative, NaN, or
+	/// infinite, or the result is too large to fit in `u64` sectors.
+	pub fn div_f64_lossy(self, ratio: f64, rounding: Rounding) -> Result<Self, TocError> {
+		if ratio.is_finite() && 0.0 < ratio {
+			Self::from_frames_f64(self.to_frames_f64() / ratio, rounding)
+		}
+		else { Err(TocError::DurationConversion) }
+	}
+
+	/// # Mul by Float (Lossy).
+	///
+	/// Multiply the duration by a floating-point ratio — for example, to
+	/// figure out how long a track takes when played at `1.05×` — snapping
+	/// the result to the frame grid using the given [`Rounding`] policy.
+	///
+	/// Note that `f64` can only represent integers exactly up to `2^53`, so
+	/// durations beyond about 3,800 years will lose some precision in the
+	/// process.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Duration, Rounding};
+	///
+	/// let duration = Duration::from(8629_u32);
+	/// assert_eq!(
+	///     duration.mul_f64_lossy(1.05, Rounding::Nearest).unwrap().sectors(),
+	///     9060,
+	/// );
+	///
+	/// // Negative ratios are an error.
+	/// assert!(duration.mul_f64_lossy(-1.0, Rounding::Floor).is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the ratio is negative, NaN, or infinite,
+	/// or the result is too large to fit in `u64` sectors.
+	pub fn mul_f64_lossy(self, ratio: f64, rounding: Rounding) -> Result<Self, TocError> {
+		if ratio.is_finite() { Self::from_frames_f64(self.to_frames_f64() * ratio, rounding) }
+		else { Err(TocError::DurationConversion) }
+	}
+
+	#[must_use]
+	/// # Saturating Add.
+	///
+	/// Add two durations (or a duration and a sector count), capping the
+	/// result at `u64::MAX` sectors.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::from(75_u32);
+	/// assert_eq!(duration.saturating_add(75_u32), Duration::from(150_u32));
+	/// assert_eq!(duration.saturating_add(u64::MAX).sectors(), u64::MAX);
+	/// ```
+	pub fn saturating_add<T>(self, other: T) -> Self
+	where u64: From<T> {
+		Self(self.0.saturating_add(u64::from(other)))
+	}
+
+	#[must_use]
+	/// # Saturating Mul.
+	///
+	/// Multiply the duration by `other`, capping the result at `u64::MAX`
+	/// sectors.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::from(75_u32);
+	/// assert_eq!(duration.saturating_mul(2_u32), Duration::from(150_u32));
+	/// assert_eq!(duration.saturating_mul(u64::MAX).sectors(), u64::MAX);
+	/// ```
+	pub fn saturating_mul<T>(self, other: T) -> Self
+	where u64: From<T> {
+		Self(self.0.saturating_mul(u64::from(other)))
+	}
+}
+
+impl Duration {
+	#[must_use]
+	/// # Days, Hours, Minutes, Seconds, Frames.
+	///
+	/// Carve up the duration into a quintuple of days, hours, minutes,
+	/// seconds, and frames.
+	///
+	/// See also [`Duration::parts`], which returns the same values with
+	/// names attached.
 	///
 	/// ## Examples
 	///
@@ -413,72 +944,672 @@ impl Duration {
 	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
 	/// let track = toc.audio_track(9).unwrap();
 	/// assert_eq!(
-	///     track.duration().to_std_duration_lossy().as_nanos(),
-	///     115_053_333_333,
+	///     track.duration().dhmsf(),
+	///     (0, 0, 1, 55, 4),
 	/// );
 	/// ```
-	pub fn to_std_duration_lossy(self) -> time::Duration {
-		// There are 1_000_000_000 nanoseconds per 75 sectors. Reducing this to
-		// 40_000_000:3 leaves less chance of temporary overflow.
-		self.0.checked_mul(40_000_000)
-			.map_or_else(
-				|| {
-					let (s, f) = self.seconds_frames();
-					time::Duration::from_secs(s) +
-					time::Duration::from_nanos((u64::from(f) * 40_000_000).wrapping_div(3))
-				},
-				|n| time::Duration::from_nanos(n.wrapping_div(3)),
-			)
+	pub const fn dhmsf(self) -> (u64, u8, u8, u8, u8) {
+		let DurationParts { days, hours, minutes, seconds, frames } = self.parts();
+		(days, hours, minutes, seconds, frames)
 	}
 
-	#[expect(clippy::many_single_char_names, reason = "Consistency is preferred.")]
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 	#[must_use]
-	/// # To String Pretty.
+	/// # Parts.
 	///
-	/// Return a string reprsentation of the non-zero parts with English
-	/// labels, separated Oxford-comma-style.
+	/// Carve up the duration into named days, hours, minutes, seconds, and
+	/// frames.
 	///
 	/// ## Examples
 	///
 	/// ```
-	/// use cdtoc::{Toc, Duration};
+	/// use cdtoc::{DurationParts, Toc};
 	///
 	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
 	/// let track = toc.audio_track(9).unwrap();
+	/// let parts = track.duration().parts();
 	/// assert_eq!(
-	///     track.duration().to_string_pretty(),
-	///     "1 minute, 55 seconds, and 4 frames",
+	///     parts,
+	///     DurationParts {
+	///         days: 0,
+	///         hours: 0,
+	///         minutes: 1,
+	///         seconds: 55,
+	///         frames: 4,
+	///     },
 	/// );
 	///
-	/// // Empty durations look like this:
+	/// // They print the same way too.
+	/// assert_eq!(parts.to_string(), track.duration().to_string());
+	/// ```
+	pub const fn parts(self) -> DurationParts {
+		let (s, frames) = self.seconds_frames();
+		if s <= 4_294_967_295 {
+			let (days, hours, minutes, seconds) = NiceElapsed::dhms(s as u32);
+			DurationParts { days: days as u64, hours, minutes, seconds, frames }
+		}
+		else {
+			let days = s.wrapping_div(86_400);
+			let [hours, minutes, seconds] = NiceElapsed::hms((s - days * 86_400) as u32);
+			DurationParts { days, hours, minutes, seconds, frames }
+		}
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # To MSF.
+	///
+	/// Carve up the duration into a triple of minutes, seconds, and frames,
+	/// with the minutes absorbing any hours and days.
+	///
+	/// For sector counts that fit within a `u32`, this uses the same math as
+	/// [`Track::msf`](crate::Track::msf). Anything larger than that saturates
+	/// to `(u32::MAX, 59, 74)`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::from(8629_u32);
+	/// assert_eq!(duration.to_msf(), (1, 55, 4));
+	///
+	/// // Minutes absorb hours.
+	/// let duration = Duration::from(274_500_u32);
+	/// assert_eq!(duration.to_string(), "01:01:00+00");
+	/// assert_eq!(duration.to_msf(), (61, 0, 0));
+	/// ```
+	pub const fn to_msf(self) -> (u32, u8, u8) {
+		let (m, s, f) = self.minutes_seconds_frames();
+		if m <= u32::MAX as u64 { (m as u32, s, f) }
+		else { (u32::MAX, 59, 74) }
+	}
+
+	#[must_use]
+	/// # Aligned Display.
+	///
+	/// Return a formatting wrapper that prints the same whole-second output
+	/// as [`Duration::display_short`], but right-aligned to the width
+	/// specified by the [`AlignedStyle`], making it suitable for columns of
+	/// track listings.
+	///
+	/// Values wider than the style are printed in full.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{AlignedStyle, Duration};
+	///
+	/// let durations = [
+	///     Duration::from_msf(4, 13, 0).unwrap(),
+	///     Duration::from_msf(12, 7, 0).unwrap(),
+	///     Duration::from_msf(62, 45, 0).unwrap(),
+	/// ];
+	/// let style = AlignedStyle::for_durations(durations);
+	/// let column: Vec<String> = durations.iter()
+	///     .map(|d| d.display_aligned(style).to_string())
+	///     .collect();
+	/// assert_eq!(column, ["   4:13", "  12:07", "1:02:45"]);
+	/// ```
+	pub const fn display_aligned(self, style: AlignedStyle) -> impl fmt::Display {
+		DurationAligned(self, style)
+	}
+
+	#[must_use]
+	/// # Short Display.
+	///
+	/// Return a formatting wrapper suitable for user-facing track listings,
+	/// like `4:13`.
+	///
+	/// The hours are only included if non-zero, and absorb any days; the
+	/// minutes are only zero-padded when hours are present.
+	///
+	/// By default the frames are rounded to the nearest whole second — 38 or
+	/// more rounds up — but the formatter's precision can be used to show
+	/// (rounded) decimal seconds instead, up to nine places.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::from_msf(4, 13, 52).unwrap();
+	/// assert_eq!(duration.to_string(), "00:04:13+52");
+	/// assert_eq!(duration.display_short().to_string(), "4:14");
+	/// assert_eq!(format!("{:.1}", duration.display_short()), "4:13.7");
+	/// assert_eq!(format!("{:.2}", duration.display_short()), "4:13.69");
+	///
+	/// // Hours show up when needed.
+	/// let duration = Duration::from_msf(61, 2, 0).unwrap();
+	/// assert_eq!(duration.display_short().to_string(), "1:01:02");
+	/// ```
+	pub const fn display_short(self) -> impl fmt::Display { DurationShort(self) }
+
+	#[must_use]
+	/// # To Cue Timestamp.
+	///
+	/// Return the duration formatted for use in cue sheets, i.e. `MM:SS:FF`.
+	///
+	/// Each part is zero-padded to two digits, but unlike the regular
+	/// `Display` output, the minutes are not wrapped into hours or days. Past
+	/// the 99-minute mark, the minutes will simply grow to three or more
+	/// digits.
+	///
+	/// See [`Duration::write_cue_timestamp`] for an allocation-free version.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::from_msf(4, 13, 52).unwrap();
+	/// assert_eq!(duration.to_cue_timestamp(), "04:13:52");
+	///
+	/// // Minutes don't wrap.
+	/// let duration = Duration::from_msf(100, 0, 0).unwrap();
+	/// assert_eq!(duration.to_string(), "01:40:00+00");
+	/// assert_eq!(duration.to_cue_timestamp(), "100:00:00");
+	/// ```
+	pub fn to_cue_timestamp(self) -> String {
+		let mut out = String::with_capacity(8);
+		let _res = self.write_cue_timestamp(&mut out);
+		out
+	}
+
+	/// # Write Cue Timestamp.
+	///
+	/// Same as [`Duration::to_cue_timestamp`], but written to an arbitrary
+	/// [`fmt::Write`] implementor.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	/// use std::fmt::Write;
+	///
+	/// let mut out = String::from("INDEX 01 ");
+	/// Duration::from_msf(4, 13, 52).unwrap()
+	///     .write_cue_timestamp(&mut out)
+	///     .unwrap();
+	/// assert_eq!(out, "INDEX 01 04:13:52");
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will bubble up any errors from the writer.
+	pub fn write_cue_timestamp<W: fmt::Write>(self, out: &mut W) -> fmt::Result {
+		let (s, f) = self.seconds_frames();
+		let m = s.wrapping_div(60);
+		write!(out, "{m:02}:{:02}:{f:02}", s - m * 60)
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # Hours, Minutes, Seconds, Frames.
+	///
+	/// Same as [`Duration::dhmsf`], except days are folded into the hours.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::decode("2d 03:01:55+04").unwrap();
+	/// assert_eq!(duration.dhmsf(), (2, 3, 1, 55, 4));
+	/// assert_eq!(duration.hmsf(), (51, 1, 55, 4));
+	/// ```
+	pub const fn hmsf(self) -> (u64, u8, u8, u8) {
+		let (m, s, f) = self.minutes_seconds_frames();
+		let h = m.wrapping_div(60);
+		(h, (m - h * 60) as u8, s, f)
+	}
+
+	#[must_use]
+	/// # Is Zero?
+	///
+	/// Return `true` if the duration is empty.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// assert!(Duration::ZERO.is_zero());
+	/// assert!(! Duration::FRAME.is_zero());
+	/// ```
+	pub const fn is_zero(self) -> bool { self.0 == 0 }
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[must_use]
+	/// # Minutes, Seconds, Frames.
+	///
+	/// Carve up the duration into a triple of minutes, seconds, and frames,
+	/// with the minutes absorbing any hours and days, as in the classic
+	/// `74:59+00` disc-length rendering.
+	///
+	/// Unlike [`Duration::to_msf`], the minutes are returned as a `u64` so
+	/// never saturate.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::decode("01:14:59+00").unwrap();
+	/// assert_eq!(duration.minutes_seconds_frames(), (74, 59, 0));
+	/// ```
+	pub const fn minutes_seconds_frames(self) -> (u64, u8, u8) {
+		let (s, f) = self.seconds_frames();
+		let m = s.wrapping_div(60);
+		(m, (s - m * 60) as u8, f)
+	}
+
+	#[must_use]
+	/// # Total Samples.
+	///
+	/// Return the total number of samples.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
+	/// let track = toc.audio_track(9).unwrap();
 	/// assert_eq!(
-	///     Duration::default().to_string_pretty(),
-	///     "0 seconds",
+	///     track.duration().samples(),
+	///     5_073_852,
 	/// );
 	/// ```
-	pub fn to_string_pretty(self) -> String {
-		let (d, h, m, s, f) = self.dhmsf();
-		let mut parts: Vec<String> = Vec::new();
-
-		// Days work the same way as the other parts, but have a different
-		// integer type.
-		if d != 0 { parts.push(d.nice_inflect("day", "days").to_string()); }
+	pub const fn samples(self) -> u64 { self.0 * SAMPLES_PER_SECTOR }
 
-		for (num, single, plural) in [
-			(h, "hour", "hours"),
-			(m, "minute", "minutes"),
-			(s, "second", "seconds"),
-			(f, "frame", "frames"),
-		] {
-			if num != 0 { parts.push(num.nice_inflect(single, plural).to_string()); }
-		}
+	#[must_use]
+	/// # Total Samples (Rescaled).
+	///
+	/// Return the number of samples the duration occupies at an arbitrary
+	/// sample rate, the inverse of [`Duration::from_samples`].
+	///
+	/// If the result is fractional, it is truncated, i.e. only whole samples
+	/// are counted. Use [`Duration::samples_at_rate_exact`] if that would be
+	/// a problem. Values too large for `u64` are capped at `u64::MAX`.
+	///
+	/// For standard CDDA rates, [`Duration::samples`] is equivalent.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::from(8629_u32);
+	/// assert_eq!(duration.samples_at_rate(44_100), duration.samples());
+	///
+	/// // 8629 frames at 32 kHz works out to 3,681,706⅔ samples.
+	/// assert_eq!(duration.samples_at_rate(32_000), 3_681_706);
+	/// ```
+	pub fn samples_at_rate(self, sample_rate: u32) -> u64 {
+		let total = u128::from(self.0) * u128::from(sample_rate);
+		u64::try_from(total / u128::from(SECTORS_PER_SECOND)).unwrap_or(u64::MAX)
+	}
 
-		match parts.len() {
-			0 => "0 seconds".to_owned(),
-			1 => parts.remove(0),
-			2 => parts.join(" and "),
-			n => {
-				let last = parts.remove(n - 1);
+	#[must_use]
+	/// # Total Samples (Rescaled, Exact).
+	///
+	/// Same as [`Duration::samples_at_rate`], except `None` is returned if
+	/// the result would be fractional or overflow.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// // 44.1 kHz always works.
+	/// let duration = Duration::from(8629_u32);
+	/// assert_eq!(duration.samples_at_rate_exact(44_100), Some(5_073_852));
+	///
+	/// // 32 kHz has 426⅔ samples per frame, so only works for multiples of
+	/// // three frames.
+	/// assert_eq!(duration.samples_at_rate_exact(32_000), None);
+	/// let duration = Duration::from(8631_u32);
+	/// assert_eq!(duration.samples_at_rate_exact(32_000), Some(3_682_560));
+	/// ```
+	pub fn samples_at_rate_exact(self, sample_rate: u32) -> Option<u64> {
+		let total = u128::from(self.0) * u128::from(sample_rate);
+		if total % u128::from(SECTORS_PER_SECOND) == 0 {
+			u64::try_from(total / u128::from(SECTORS_PER_SECOND)).ok()
+		}
+		else { None }
+	}
+
+	#[must_use]
+	/// # Seconds + Frames.
+	///
+	/// Return the duration as a tuple containing the total number of seconds
+	/// and remaining frames (some fraction of a second).
+	///
+	/// Audio CDs have 75 frames per second, so the frame portion will always
+	/// be in the range of `0..75`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
+	/// let track = toc.audio_track(9).unwrap();
+	/// assert_eq!(
+	///     track.duration().seconds_frames(),
+	///     (115, 4),
+	/// );
+	/// ```
+	pub const fn seconds_frames(self) -> (u64, u8) {
+		(self.0.wrapping_div(SECTORS_PER_SECOND), (self.0 % SECTORS_PER_SECOND) as u8)
+	}
+
+	#[must_use]
+	/// # Number of Sectors.
+	///
+	/// Return the total number of sectors.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
+	/// let track = toc.audio_track(9).unwrap();
+	/// assert_eq!(
+	///     track.duration().sectors(),
+	///     8629,
+	/// );
+	/// ```
+	pub const fn sectors(self) -> u64 { self.0 }
+
+	#[expect(clippy::cast_precision_loss, reason = "False positive.")]
+	#[must_use]
+	/// # To `f64` (Lossy).
+	///
+	/// Return the duration as a float (seconds.subseconds).
+	///
+	/// Given that 75ths don't always make the cleanest of fractions, there
+	/// will likely be some loss in precision.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
+	/// let track = toc.audio_track(9).unwrap();
+	/// assert_eq!(
+	///     track.duration().to_f64_lossy(),
+	///     115.05333333333333,
+	/// );
+	/// ```
+	pub fn to_f64_lossy(self) -> f64 {
+		// Most durations will probably fit within `u32`, which converts
+		// cleanly.
+		if self.0 <= 4_294_967_295 { self.0 as f64 / 75.0 }
+		// Otherwise let's try to do it in parts and hope for the best.
+		else {
+			let (s, f) = self.seconds_frames();
+			s as f64 + f64::from(f) / 75.0
+		}
+	}
+
+	#[must_use]
+	/// # As Ratio.
+	///
+	/// Return the duration in seconds as an exact fraction — `(numerator,
+	/// denominator)` — reduced to lowest terms.
+	///
+	/// Unlike [`Duration::to_f64_lossy`], this is lossless; the denominator
+	/// will always be one of `1`, `3`, `5`, `15`, `25`, or `75`.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// assert_eq!(Duration::from(8629_u64).as_ratio(), (8629, 75));
+	/// assert_eq!(Duration::from(150_u64).as_ratio(), (2, 1));
+	/// assert_eq!(Duration::from(30_u64).as_ratio(), (2, 5));
+	/// assert_eq!(Duration::ZERO.as_ratio(), (0, 1));
+	///
+	/// // And back again.
+	/// let d = Duration::from(8629_u64);
+	/// let (num, den) = d.as_ratio();
+	/// assert_eq!(Duration::try_from_ratio(num, den.into()), Ok(d));
+	/// ```
+	pub const fn as_ratio(self) -> (u64, u32) {
+		let mut num = self.0;
+		let mut den: u32 = 75;
+
+		// 75 is 3 * 5 * 5, so there isn't much to check.
+		if num % 25 == 0 {
+			num /= 25;
+			den /= 25;
+		}
+		else if num % 5 == 0 {
+			num /= 5;
+			den /= 5;
+		}
+
+		if num % 3 == 0 {
+			num /= 3;
+			den /= 3;
+		}
+
+		(num, den)
+	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # Try From Ratio.
+	///
+	/// Convert an exact number of seconds, expressed as the fraction
+	/// `num / den`, into a duration.
+	///
+	/// The fraction need not be reduced, but it must land exactly on the
+	/// 1/75th-of-a-second sector grid. For a lossy conversion, use
+	/// [`Duration::from_seconds_parts`] instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// assert_eq!(
+	///     Duration::try_from_ratio(2, 5).unwrap().sectors(),
+	///     30,
+	/// );
+	/// assert_eq!(
+	///     Duration::try_from_ratio(88_200, 44_100).unwrap().sectors(),
+	///     150,
+	/// );
+	///
+	/// // A CDDA sector is 588 samples.
+	/// assert_eq!(
+	///     Duration::try_from_ratio(588, 44_100).unwrap().sectors(),
+	///     1,
+	/// );
+	///
+	/// // But 587 samples doesn't fit the grid.
+	/// assert!(Duration::try_from_ratio(587, 44_100).is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the denominator is zero, the value does
+	/// not fall exactly on a sector boundary, or the result is too large to
+	/// fit in `u64` sectors.
+	pub const fn try_from_ratio(num: u64, den: u64) -> Result<Self, TocError> {
+		if den != 0 {
+			let n = num as u128 * SECTORS_PER_SECOND as u128;
+			let d = den as u128;
+			if n % d == 0 {
+				let q = n / d;
+				if q <= u64::MAX as u128 { return Ok(Self(q as u64)); }
+			}
+		}
+
+		Err(TocError::DurationConversion)
+	}
+
+	#[must_use]
+	/// # To [`std::time::Duration`] (Lossy).
+	///
+	/// Return the value as a "normal" [`std::time::Duration`].
+	///
+	/// Note that the `std` struct only counts time down to the nanosecond, so
+	/// this value might be off by a few frames.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
+	/// let track = toc.audio_track(9).unwrap();
+	/// assert_eq!(
+	///     track.duration().to_std_duration_lossy().as_nanos(),
+	///     115_053_333_333,
+	/// );
+	/// ```
+	pub fn to_std_duration_lossy(self) -> time::Duration {
+		// There are 1_000_000_000 nanoseconds per 75 sectors. Reducing this to
+		// 40_000_000:3 leaves less chance of temporary overflow.
+		self.0.checked_mul(40_000_000)
+			.map_or_else(
+				|| {
+					let (s, f) = self.seconds_frames();
+					time::Duration::from_secs(s) +
+					time::Duration::from_nanos((u64::from(f) * 40_000_000).wrapping_div(3))
+				},
+				|n| time::Duration::from_nanos(n.wrapping_div(3)),
+			)
+	}
+
+	#[must_use]
+	/// # To String Compact.
+	///
+	/// Return a short string representation suitable for table cells, like
+	/// `1m 55s 4f` or `2h 03m`.
+	///
+	/// The parts and their (stable) suffixes are:
+	///
+	/// | Part | Suffix |
+	/// | ---- | ------ |
+	/// | Days | `d` |
+	/// | Hours | `h` |
+	/// | Minutes | `m` |
+	/// | Seconds | `s` |
+	/// | Frames | `f` |
+	///
+	/// Output begins with the largest non-zero part and includes at most
+	/// `max_units` (minimum one) consecutive parts from there, separated by
+	/// spaces. Any trailing zero parts are then dropped. Parts beyond the
+	/// cutoff are truncated, not rounded. Hours, minutes, and seconds are
+	/// zero-padded to two digits when preceded by a larger part; days and
+	/// frames never are.
+	///
+	/// Frames are only included if `frames` is `true`.
+	///
+	/// A zero (or zero-after-truncation) duration is written as `0s`, or `0f`
+	/// if frames are enabled and the duration is less than a second.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Duration;
+	///
+	/// let duration = Duration::from_msf(1, 55, 4).unwrap();
+	/// assert_eq!(duration.to_string_compact(4, true), "1m 55s 4f");
+	/// assert_eq!(duration.to_string_compact(4, false), "1m 55s");
+	/// assert_eq!(duration.to_string_compact(1, true), "1m");
+	///
+	/// let duration = Duration::from_msf(123, 0, 2).unwrap();
+	/// assert_eq!(duration.to_string_compact(2, true), "2h 03m");
+	/// assert_eq!(duration.to_string_compact(3, true), "2h 03m");
+	/// assert_eq!(duration.to_string_compact(4, true), "2h 03m 00s 2f");
+	/// ```
+	pub fn to_string_compact(self, max_units: usize, frames: bool) -> String {
+		use std::fmt::Write;
+
+		let DurationParts { days, hours, minutes, seconds, frames: f } = self.parts();
+		let parts = [
+			(days, "d"),
+			(u64::from(hours), "h"),
+			(u64::from(minutes), "m"),
+			(u64::from(seconds), "s"),
+			(u64::from(f), "f"),
+		];
+		let parts = if frames { &parts[..] } else { &parts[..4] };
+
+		// Find the range of parts to print.
+		let Some(start) = parts.iter().position(|(v, _)| *v != 0) else {
+			return if frames { "0f" } else { "0s" }.to_owned();
+		};
+		let end = parts.len().min(start.saturating_add(max_units.max(1)));
+		let parts = &parts[start..end];
+		let end = parts.iter().rposition(|(v, _)| *v != 0).map_or(0, |e| e + 1);
+
+		let mut out = String::new();
+		for (idx, (v, unit)) in parts[..end].iter().enumerate() {
+			if idx == 0 { let _res = write!(out, "{v}{unit}"); }
+			else if *unit == "f" { let _res = write!(out, " {v}{unit}"); }
+			else { let _res = write!(out, " {v:02}{unit}"); }
+		}
+
+		out
+	}
+
+	#[must_use]
+	/// # To String Pretty.
+	///
+	/// Return a string reprsentation of the non-zero parts with English
+	/// labels, separated Oxford-comma-style.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Toc, Duration};
+	///
+	/// let toc = Toc::from_cdtoc("9+96+5766+A284+E600+11FE5+15913+19A98+1E905+240CB+26280").unwrap();
+	/// let track = toc.audio_track(9).unwrap();
+	/// assert_eq!(
+	///     track.duration().to_string_pretty(),
+	///     "1 minute, 55 seconds, and 4 frames",
+	/// );
+	///
+	/// // Empty durations look like this:
+	/// assert_eq!(
+	///     Duration::ZERO.to_string_pretty(),
+	///     "0 seconds",
+	/// );
+	/// ```
+	pub fn to_string_pretty(self) -> String {
+		if self.is_zero() { return "0 seconds".to_owned(); }
+
+		let DurationParts { days, hours, minutes, seconds, frames } = self.parts();
+		let mut parts: Vec<String> = Vec::new();
+
+		// Days work the same way as the other parts, but have a different
+		// integer type.
+		if days != 0 { parts.push(days.nice_inflect("day", "days").to_string()); }
+
+		for (num, single, plural) in [
+			(hours, "hour", "hours"),
+			(minutes, "minute", "minutes"),
+			(seconds, "second", "seconds"),
+			(frames, "frame", "frames"),
+		] {
+			if num != 0 { parts.push(num.nice_inflect(single, plural).to_string()); }
+		}
+
+		match parts.len() {
+			0 | 1 => parts.pop().unwrap_or_default(),
+			2 => parts.join(" and "),
+			n => {
+				let last = parts.remove(n - 1);
 				let mut out = parts.join(", ");
 				out.push_str(", and ");
 				out.push_str(&last);
@@ -487,3 +1618,1403 @@ impl Duration {
 		}
 	}
 }
+
+
+
+impl Duration {
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # From Frames (`f64`).
+	///
+	/// Round a fractional frame count to the frame grid.
+	///
+	/// Every finite `f64` is a mantissa times a power of two, so rather than
+	/// rounding in floating point, the value is broken into those parts and
+	/// run through the same exact integer rounding used by
+	/// [`Duration::from_seconds_parts`].
+	fn from_frames_f64(frames: f64, rounding: Rounding) -> Result<Self, TocError> {
+		let bits = frames.to_bits();
+		let exp = ((bits >> 52) & 0x7FF) as i32;
+		let frac = bits & ((1 << 52) - 1);
+
+		// NaN, infinity, and negative (non-zero) values are no good.
+		if exp == 0x7FF || (frames.is_sign_negative() && frames != 0.0) {
+			return Err(TocError::DurationConversion);
+		}
+
+		// frames = m * 2^e, exactly.
+		let (m, e) =
+			if exp == 0 { (frac, -1074) }
+			else { (frac | (1 << 52), exp - 1075) };
+		if m == 0 { return Ok(Self(0)); }
+		let m = u128::from(m);
+
+		let total =
+			// Whole numbers need only fit.
+			if 0 <= e {
+				if 64 <= e { return Err(TocError::DurationConversion); }
+				m << e
+			}
+			else {
+				// The mantissa is under 2^53, so anything shifted further than
+				// 64 is just some (non-zero) fraction well under one half; its
+				// exact value no longer matters.
+				let (m, shift) = match e.unsigned_abs() {
+					shift @ 0..=64 => (m, shift),
+					_ => (1, 64),
+				};
+				let d = 1_u128 << shift;
+				rounding.apply(m >> shift, m & (d - 1), d)
+			};
+
+		if total <= u128::from(u64::MAX) { Ok(Self(total as u64)) }
+		else { Err(TocError::DurationConversion) }
+	}
+
+	/// # Compare to Standard Duration.
+	///
+	/// Compare the duration to a [`std::time::Duration`], treating anything
+	/// less than one frame apart as equal.
+	const fn cmp_std(self, other: time::Duration) -> Ordering {
+		// Scale both sides to thirds of a nanosecond to keep it exact.
+		let a = self.0 as u128 * NANOS_PER_SECTOR_N;
+		let b = other.as_nanos() * NANOS_PER_SECTOR_D;
+		if a.abs_diff(b) < NANOS_PER_SECTOR_N { Ordering::Equal }
+		else if a < b { Ordering::Less }
+		else { Ordering::Greater }
+	}
+
+	/// # Short Parts.
+	///
+	/// Return the hours, minutes, and seconds — rounded to the nearest
+	/// whole second — used by the short and aligned displays.
+	const fn short_parts(self) -> (u64, u64, u64) {
+		let mut secs = self.0 / SECTORS_PER_SECOND;
+		if SECTORS_PER_SECOND <= (self.0 % SECTORS_PER_SECOND) * 2 { secs += 1; }
+		(secs / 3600, (secs % 3600) / 60, secs % 60)
+	}
+
+	#[expect(clippy::cast_precision_loss, reason = "False positive.")]
+	/// # To Frames (`f64`).
+	const fn to_frames_f64(self) -> f64 { self.0 as f64 }
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # Duration Parts.
+///
+/// This is a named breakdown of a [`Duration`] into days, hours, minutes,
+/// seconds, and frames, as returned by [`Duration::parts`].
+///
+/// Parts can be converted back into a [`Duration`] using [`TryFrom`], so
+/// long as each field is within its natural range — hours less than `24`,
+/// minutes and seconds less than `60`, frames less than `75` — and the
+/// total fits.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{Duration, DurationParts};
+///
+/// let parts = DurationParts {
+///     days: 1,
+///     hours: 2,
+///     minutes: 3,
+///     seconds: 4,
+///     frames: 5,
+/// };
+/// assert_eq!(parts.to_string(), "1d 02:03:04+05");
+///
+/// let duration = Duration::try_from(parts).unwrap();
+/// assert_eq!(duration.parts(), parts);
+///
+/// // Out-of-range fields are rejected.
+/// let parts = DurationParts { frames: 75, ..DurationParts::default() };
+/// assert!(Duration::try_from(parts).is_err());
+/// ```
+pub struct DurationParts {
+	/// # Days.
+	pub days: u64,
+
+	/// # Hours.
+	pub hours: u8,
+
+	/// # Minutes.
+	pub minutes: u8,
+
+	/// # Seconds.
+	pub seconds: u8,
+
+	/// # Frames.
+	pub frames: u8,
+}
+
+impl fmt::Display for DurationParts {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let Self { days, hours, minutes, seconds, frames } = *self;
+		if days == 0 {
+			write!(f, "{hours:02}:{minutes:02}:{seconds:02}+{frames:02}")
+		}
+		else {
+			write!(f, "{days}d {hours:02}:{minutes:02}:{seconds:02}+{frames:02}")
+		}
+	}
+}
+
+impl From<Duration> for DurationParts {
+	#[inline]
+	fn from(src: Duration) -> Self { src.parts() }
+}
+
+impl TryFrom<DurationParts> for Duration {
+	type Error = TocError;
+
+	fn try_from(src: DurationParts) -> Result<Self, Self::Error> {
+		let DurationParts { days, hours, minutes, seconds, frames } = src;
+		if hours < 24 && minutes < 60 && seconds < 60 && frames < 75 {
+			let rest =
+				u64::from(hours) * 3600 +
+				u64::from(minutes) * 60 +
+				u64::from(seconds);
+			if let Some(secs) = days.checked_mul(86_400).and_then(|d| d.checked_add(rest)) {
+				if let Some(out) = secs.checked_mul(SECTORS_PER_SECOND).and_then(|s| s.checked_add(u64::from(frames))) {
+					return Ok(Self(out));
+				}
+			}
+		}
+
+		Err(TocError::DurationConversion)
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # Signed Duration.
+///
+/// This struct holds a signed CDDA sample count — `588` per sector, `44_100`
+/// per second — for things like drive read offsets that can move in either
+/// direction.
+///
+/// Samples are used rather than sectors because offsets are rarely
+/// sector-aligned; a `+667` read offset is one sector and `79` samples.
+///
+/// Display output always includes a leading sign.
+///
+/// ## Examples
+///
+/// ```
+/// use cdtoc::{Duration, SignedDuration};
+///
+/// let offset = SignedDuration::from_samples(667);
+/// assert_eq!(offset.to_string(), "+667");
+/// assert_eq!((-offset).to_string(), "-667");
+///
+/// // Whole-sector values can be converted to and from unsigned durations.
+/// let offset = SignedDuration::from(Duration::FRAME);
+/// assert_eq!(offset.samples(), 588);
+/// assert_eq!(Duration::try_from(offset), Ok(Duration::FRAME));
+/// assert!(Duration::try_from(-offset).is_err());
+/// ```
+///
+/// ## Overflow
+///
+/// The arithmetic operators behave like those of the inner `i64`, panicking
+/// on overflow in debug builds and wrapping in release builds. If the values
+/// might be untrusted, use [`SignedDuration::checked_add`] and friends
+/// instead.
+pub struct SignedDuration(i64);
+
+impl Add for SignedDuration {
+	type Output = Self;
+	#[inline]
+	fn add(self, other: Self) -> Self { Self(self.0 + other.0) }
+}
+
+impl AddAssign for SignedDuration {
+	#[inline]
+	fn add_assign(&mut self, other: Self) { self.0 += other.0; }
+}
+
+impl fmt::Display for SignedDuration {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:+}", self.0)
+	}
+}
+
+impl From<Duration> for SignedDuration {
+	#[inline]
+	/// # From Duration.
+	///
+	/// Durations too large to be represented (about 1.6 billion years) are
+	/// saturated to [`SignedDuration::MAX`].
+	fn from(src: Duration) -> Self {
+		i64::try_from(src.0).map_or(Self::MAX, Self::from_sectors)
+	}
+}
+
+impl Neg for SignedDuration {
+	type Output = Self;
+	#[inline]
+	fn neg(self) -> Self { Self(-self.0) }
+}
+
+impl Sub for SignedDuration {
+	type Output = Self;
+	#[inline]
+	fn sub(self, other: Self) -> Self { Self(self.0 - other.0) }
+}
+
+impl SubAssign for SignedDuration {
+	#[inline]
+	fn sub_assign(&mut self, other: Self) { self.0 -= other.0; }
+}
+
+impl TryFrom<SignedDuration> for Duration {
+	type Error = TocError;
+
+	#[inline]
+	/// # From Signed Duration.
+	///
+	/// This will return an error if the value is negative or not evenly
+	/// divisible by `588`, the number of samples-per-sector.
+	fn try_from(src: SignedDuration) -> Result<Self, Self::Error> {
+		if src.0 % SAMPLES_PER_SECTOR_SIGNED == 0 {
+			if let Ok(n) = u64::try_from(src.0) {
+				return Ok(Self(n / SAMPLES_PER_SECTOR));
+			}
+		}
+
+		Err(TocError::DurationConversion)
+	}
+}
+
+impl SignedDuration {
+	/// # Zero.
+	pub const ZERO: Self = Self(0);
+
+	/// # Minimum.
+	pub const MIN: Self = Self(i64::MIN);
+
+	/// # Maximum.
+	pub const MAX: Self = Self(i64::MAX);
+
+	#[must_use]
+	/// # From Samples.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::SignedDuration;
+	///
+	/// assert_eq!(SignedDuration::from_samples(-30).samples(), -30);
+	/// ```
+	pub const fn from_samples(samples: i64) -> Self { Self(samples) }
+
+	#[must_use]
+	/// # From Sectors.
+	///
+	/// Sector counts too large (or small) to be represented as samples are
+	/// saturated to [`SignedDuration::MAX`] or [`SignedDuration::MIN`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::SignedDuration;
+	///
+	/// assert_eq!(SignedDuration::from_sectors(-2).samples(), -1176);
+	/// assert_eq!(SignedDuration::from_sectors(i64::MAX), SignedDuration::MAX);
+	/// ```
+	pub const fn from_sectors(sectors: i64) -> Self {
+		Self(sectors.saturating_mul(SAMPLES_PER_SECTOR_SIGNED))
+	}
+
+	#[must_use]
+	/// # Samples.
+	///
+	/// Return the signed sample count.
+	pub const fn samples(self) -> i64 { self.0 }
+
+	#[must_use]
+	/// # Sectors and Samples.
+	///
+	/// Split the value into whole sectors and leftover samples. Like the
+	/// integer division it is based on, both halves carry the sign.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::SignedDuration;
+	///
+	/// assert_eq!(SignedDuration::from_samples(667).sectors_samples(), (1, 79));
+	/// assert_eq!(SignedDuration::from_samples(-667).sectors_samples(), (-1, -79));
+	/// ```
+	pub const fn sectors_samples(self) -> (i64, i64) {
+		(
+			self.0 / SAMPLES_PER_SECTOR_SIGNED,
+			self.0 % SAMPLES_PER_SECTOR_SIGNED,
+		)
+	}
+
+	#[must_use]
+	/// # Is Negative?
+	pub const fn is_negative(self) -> bool { self.0 < 0 }
+
+	#[must_use]
+	/// # Is Positive?
+	pub const fn is_positive(self) -> bool { 0 < self.0 }
+
+	#[must_use]
+	/// # Is Zero?
+	pub const fn is_zero(self) -> bool { self.0 == 0 }
+
+	#[must_use]
+	/// # Unsigned Absolute Value.
+	///
+	/// Return the magnitude as an unsigned sample count.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::SignedDuration;
+	///
+	/// assert_eq!(SignedDuration::from_samples(-30).unsigned_abs(), 30);
+	/// assert_eq!(SignedDuration::MIN.unsigned_abs(), 9_223_372_036_854_775_808);
+	/// ```
+	pub const fn unsigned_abs(self) -> u64 { self.0.unsigned_abs() }
+}
+
+impl SignedDuration {
+	#[must_use]
+	/// # Checked Add.
+	///
+	/// Add two signed durations, returning `None` on overflow.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::SignedDuration;
+	///
+	/// let a = SignedDuration::from_samples(667);
+	/// let b = SignedDuration::from_samples(-30);
+	/// assert_eq!(a.checked_add(b), Some(SignedDuration::from_samples(637)));
+	/// assert_eq!(SignedDuration::MAX.checked_add(a), None);
+	/// ```
+	pub const fn checked_add(self, other: Self) -> Option<Self> {
+		if let Some(n) = self.0.checked_add(other.0) { Some(Self(n)) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # Checked Negation.
+	///
+	/// Flip the sign, returning `None` for [`SignedDuration::MIN`], which has
+	/// no positive counterpart.
+	pub const fn checked_neg(self) -> Option<Self> {
+		if let Some(n) = self.0.checked_neg() { Some(Self(n)) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # Checked Sub.
+	///
+	/// Subtract one signed duration from another, returning `None` on
+	/// overflow.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::SignedDuration;
+	///
+	/// let a = SignedDuration::from_samples(-30);
+	/// let b = SignedDuration::from_samples(667);
+	/// assert_eq!(a.checked_sub(b), Some(SignedDuration::from_samples(-697)));
+	/// assert_eq!(SignedDuration::MIN.checked_sub(b), None);
+	/// ```
+	pub const fn checked_sub(self, other: Self) -> Option<Self> {
+		if let Some(n) = self.0.checked_sub(other.0) { Some(Self(n)) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # Saturating Add.
+	///
+	/// Add two signed durations, clamping to [`SignedDuration::MIN`] or
+	/// [`SignedDuration::MAX`] on overflow.
+	pub const fn saturating_add(self, other: Self) -> Self {
+		Self(self.0.saturating_add(other.0))
+	}
+
+	#[must_use]
+	/// # Saturating Negation.
+	///
+	/// Flip the sign, clamping [`SignedDuration::MIN`] to
+	/// [`SignedDuration::MAX`].
+	pub const fn saturating_neg(self) -> Self { Self(self.0.saturating_neg()) }
+
+	#[must_use]
+	/// # Saturating Sub.
+	///
+	/// Subtract one signed duration from another, clamping to
+	/// [`SignedDuration::MIN`] or [`SignedDuration::MAX`] on overflow.
+	pub const fn saturating_sub(self, other: Self) -> Self {
+		Self(self.0.saturating_sub(other.0))
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Aligned Duration Style.
+///
+/// This holds the column width used by [`Duration::display_aligned`]. It is
+/// typically derived from the longest duration in the column using
+/// [`AlignedStyle::for_max`] or [`AlignedStyle::for_durations`].
+pub struct AlignedStyle {
+	/// # Width.
+	width: usize,
+}
+
+impl AlignedStyle {
+	#[must_use]
+	/// # For Max.
+	///
+	/// Return a style wide enough to fit `max` (and anything shorter).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{AlignedStyle, Duration};
+	///
+	/// let style = AlignedStyle::for_max(Duration::from_msf(12, 7, 0).unwrap());
+	/// assert_eq!(style.width(), 5);
+	/// ```
+	pub const fn for_max(max: Duration) -> Self {
+		let (h, m, _) = max.short_parts();
+		Self { width: short_width(h, m) }
+	}
+
+	#[must_use]
+	/// # For Durations.
+	///
+	/// Return a style wide enough to fit every duration in the set.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{AlignedStyle, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let style = AlignedStyle::for_durations(toc.audio_tracks().map(|t| t.duration()));
+	/// assert_eq!(style.width(), 4);
+	/// ```
+	pub fn for_durations<I>(src: I) -> Self
+	where I: IntoIterator<Item = Duration> {
+		src.into_iter().max().map_or_else(Self::default, Self::for_max)
+	}
+
+	#[must_use]
+	/// # Width.
+	///
+	/// Return the column width, in characters.
+	pub const fn width(self) -> usize { self.width }
+}
+
+
+
+/// # Aligned Duration Display.
+///
+/// This is the wrapper returned by [`Duration::display_aligned`].
+struct DurationAligned(Duration, AlignedStyle);
+
+impl fmt::Display for DurationAligned {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (h, m, s) = self.0.short_parts();
+		let pad = self.1.width.saturating_sub(short_width(h, m));
+		for _ in 0..pad { f.write_str(" ")?; }
+
+		if h == 0 { write!(f, "{m}:{s:02}") }
+		else { write!(f, "{h}:{m:02}:{s:02}") }
+	}
+}
+
+
+
+/// # Short Duration Display.
+///
+/// This is the wrapper returned by [`Duration::display_short`].
+struct DurationShort(Duration);
+
+impl fmt::Display for DurationShort {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		/// # Powers of Ten.
+		const SCALES: [u128; 10] = [
+			1, 10, 100, 1_000, 10_000, 100_000, 1_000_000, 10_000_000,
+			100_000_000, 1_000_000_000,
+		];
+
+		let precision = f.precision().map_or(0, |p| p.min(9));
+		let scale = SCALES[precision];
+
+		// Rescale the sectors, rounding ties up.
+		let units = (u128::from(self.0.0) * scale * 2 + u128::from(SECTORS_PER_SECOND)) /
+			u128::from(SECTORS_PER_SECOND * 2);
+		let frac = units % scale;
+		let secs = units / scale;
+
+		let h = secs / 3600;
+		let m = (secs % 3600) / 60;
+		let s = secs % 60;
+
+		if h == 0 { write!(f, "{m}:{s:02}")?; }
+		else { write!(f, "{h}:{m:02}:{s:02}")?; }
+
+		if precision == 0 { Ok(()) }
+		else { write!(f, ".{frac:0precision$}") }
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+/// # Rounding Policy.
+///
+/// This is used by lossy [`Duration`] conversions to decide what to do with
+/// values falling somewhere between two frames (sectors).
+pub enum Rounding {
+	#[default]
+	/// # Round Down.
+	Floor,
+
+	/// # Round Up.
+	Ceil,
+
+	/// # Round to Nearest.
+	///
+	/// Ties are rounded up (away from zero).
+	Nearest,
+
+	/// # Round to Nearest (Ties Even).
+	///
+	/// Ties are rounded to whichever neighbor is even, i.e. banker's
+	/// rounding.
+	NearestTiesEven,
+}
+
+impl Rounding {
+	/// # Apply.
+	///
+	/// Round the quotient `q` according to its remainder `r` and divisor `d`.
+	const fn apply(self, q: u128, r: u128, d: u128) -> u128 {
+		match self {
+			Self::Floor => q,
+			Self::Ceil => if r == 0 { q } else { q + 1 },
+			Self::Nearest => if r < d - r { q } else { q + 1 },
+			Self::NearestTiesEven =>
+				if r < d - r || (r == d - r && q % 2 == 0) { q }
+				else { q + 1 },
+		}
+	}
+}
+
+
+
+/// # Short Width.
+///
+/// Return the printed width of a short (whole-second) display with the given
+/// hours and minutes.
+const fn short_width(h: u64, m: u64) -> usize {
+	if h == 0 { digits(m) + 3 }
+	else { digits(h) + 6 }
+}
+
+/// # Digit Count.
+const fn digits(mut n: u64) -> usize {
+	let mut len = 1;
+	while 10 <= n {
+		n /= 10;
+		len += 1;
+	}
+	len
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		Msf,
+		Toc,
+	};
+
+	#[test]
+	fn t_msf() {
+		// The longest disc in the crate's test fixtures.
+		let toc = Toc::from_cdtoc("15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46")
+			.expect("TOC failed.");
+		for track in toc.audio_tracks().chain(toc.htoa()) {
+			let from = track.sector_range().start;
+			let d = Duration::from(from);
+			let msf = d.to_msf();
+
+			// Should match the Track and Msf math.
+			assert_eq!(msf, track.msf());
+			assert_eq!(msf, Msf::from_lba(from).as_tuple());
+
+			// And go back the way it came.
+			assert_eq!(Duration::from_msf(msf.0, msf.1, msf.2), Ok(d));
+
+			// Same for the normalized value.
+			let d = track.starts_at();
+			let msf = d.to_msf();
+			assert_eq!(msf, track.msf_normalized());
+			assert_eq!(Duration::from_msf(msf.0, msf.1, msf.2), Ok(d));
+		}
+
+		// The leadout too.
+		let d = Duration::from(toc.leadout());
+		assert_eq!(d.to_msf(), Msf::from_lba(toc.leadout()).as_tuple());
+		assert_eq!(d.to_msf(), (46, 9, 11));
+
+		// Check the edges.
+		assert_eq!(Duration(0).to_msf(), (0, 0, 0));
+		assert_eq!(Duration::from_msf(0, 0, 0), Ok(Duration(0)));
+		assert_eq!(Duration::from(u32::MAX).to_msf(), Msf::from_lba(u32::MAX).as_tuple());
+		assert_eq!(Duration(u64::MAX).to_msf(), (u32::MAX, 59, 74));
+		assert_eq!(
+			Duration::from_msf(u32::MAX, 59, 74),
+			Ok(Duration(u64::from(u32::MAX) * 4500 + 4499)),
+		);
+
+		// And the failures.
+		assert_eq!(Duration::from_msf(0, 60, 0), Err(TocError::Msf));
+		assert_eq!(Duration::from_msf(0, 0, 75), Err(TocError::Msf));
+	}
+
+	#[test]
+	fn t_consts() {
+		// These should all be usable in const contexts.
+		const ZERO: Duration = Duration::ZERO;
+		const IS_ZERO: bool = ZERO.is_zero();
+		const FRAMES: u64 = Duration::MINUTE.sectors() + Duration::SECOND.sectors();
+		const MSF: (u32, u8, u8) = Duration::MAX.to_msf();
+
+		assert!(IS_ZERO);
+		assert_eq!(ZERO, Duration::default());
+		assert_eq!(Duration::FRAME, Duration::from(1_u32));
+		assert_eq!(Duration::SECOND, Duration::FRAME * 75_u32);
+		assert_eq!(Duration::MINUTE, Duration::SECOND * 60_u32);
+		assert_eq!(FRAMES, 4575);
+		assert_eq!(Duration::MINUTE.to_string(), "00:01:00+00");
+		assert_eq!(Duration::MAX.sectors(), u64::MAX);
+		assert_eq!(MSF, (u32::MAX, 59, 74));
+		assert!(! Duration::MAX.is_zero());
+	}
+
+	#[test]
+	fn t_refs() {
+		let all = [Duration(1), Duration(2), Duration(3)];
+		assert_eq!(all.iter().sum::<Duration>(), Duration(6));
+		assert_eq!(all.into_iter().sum::<Duration>(), Duration(6));
+
+		let mut d = all[2] + &all[1];
+		assert_eq!(d, Duration(5));
+		d -= &all[0];
+		assert_eq!(d, Duration(4));
+		d += &all[0];
+		assert_eq!(d, Duration(5));
+		assert_eq!(d - &all[2], Duration(2));
+		assert_eq!(d.checked_add(&all[0]), Some(Duration(6)));
+	}
+
+	#[test]
+	fn t_split_evenly() {
+		let mut seed: u64 = 0xD1B5_4A32_D192_ED03;
+		for _ in 0..1_000 {
+			crate::xorshift(&mut seed);
+
+			let d = Duration(seed % (75 * 86_400));
+			let n = (seed >> 48) as usize % 100 + 1;
+
+			let (q, r) = d.div_rem(n as u64).expect("Div/rem failed.");
+			assert_eq!(q * n as u64 + r, d);
+			assert!(r.0 < n as u64);
+
+			let parts = d.split_evenly(n).expect("Split failed.");
+			assert_eq!(parts.len(), n);
+			assert_eq!(parts.iter().sum::<Duration>(), d);
+
+			let min = parts.iter().min().expect("Missing min.");
+			let max = parts.iter().max().expect("Missing max.");
+			assert!(max.0 - min.0 <= 1);
+		}
+
+		// The extremes.
+		assert_eq!(Duration::MAX.div_rem(1), Some((Duration::MAX, Duration::ZERO)));
+		assert_eq!(Duration::MAX.div_rem(u64::MAX), Some((Duration::FRAME, Duration::ZERO)));
+		assert_eq!(Duration::ZERO.split_evenly(3), Some(vec![Duration::ZERO; 3]));
+		assert_eq!(Duration::MAX.div_rem(0), None);
+		assert_eq!(Duration::MAX.split_evenly(0), None);
+	}
+
+	#[test]
+	fn t_compact() {
+		for (d, max, frames, expected) in [
+			(Duration::ZERO, 4, true, "0f"),
+			(Duration::ZERO, 4, false, "0s"),
+			(Duration::FRAME, 4, true, "1f"),
+			(Duration::FRAME, 4, false, "0s"),
+			(Duration::SECOND, 4, true, "1s"),
+			(Duration::MINUTE, 4, true, "1m"),
+			(Duration::MINUTE + 1_u64, 4, true, "1m 00s 1f"),
+			(Duration::MINUTE + 1_u64, 2, true, "1m"),
+			(Duration::MINUTE + 1_u64, 0, true, "1m"),
+			(Duration::MINUTE * 60_u64 * 24_u64 * 3_u64 + 76_u64, 5, true, "3d 00h 00m 01s 1f"),
+			(Duration::MINUTE * 60_u64 * 24_u64 * 3_u64 + 76_u64, 5, false, "3d 00h 00m 01s"),
+			(Duration::MINUTE * 60_u64 * 24_u64 * 3_u64 + 76_u64, 3, true, "3d"),
+			(Duration::MAX, 2, true, "2846719764461d 08h"),
+		] {
+			assert_eq!(d.to_string_compact(max, frames), expected);
+		}
+
+		// The numbers should always agree with the pretty version.
+		let d = Duration::from_msf(61, 1, 1).expect("Duration failed.");
+		assert_eq!(d.to_string_compact(5, true), "1h 01m 01s 1f");
+		assert_eq!(d.to_string_pretty(), "1 hour, 1 minute, 1 second, and 1 frame");
+	}
+
+	#[test]
+	fn t_mul_f64() {
+		/// # A Nudge (2^-20).
+		const E: f64 = 9.536_743_164_062_5e-7;
+
+		// Binary fractions keep the tie points exact.
+		let d = Duration(128);
+		for (ratio, floor, ceil, nearest) in [
+			(0.0, 0, 0, 0),
+			(1.0, 128, 128, 128),
+			(1.003_906_25 - E, 128, 129, 128),
+			(1.003_906_25, 128, 129, 129),
+			(1.003_906_25 + E, 128, 129, 129),
+			(1.007_812_5 - E, 128, 129, 129),
+			(1.007_812_5, 129, 129, 129),
+			(0.996_093_75 - E, 127, 128, 127),
+			(0.996_093_75, 127, 128, 128),
+			(0.996_093_75 + E, 127, 128, 128),
+		] {
+			assert_eq!(d.mul_f64_lossy(ratio, Rounding::Floor), Ok(Duration(floor)), "{ratio}");
+			assert_eq!(d.mul_f64_lossy(ratio, Rounding::Ceil), Ok(Duration(ceil)), "{ratio}");
+			assert_eq!(d.mul_f64_lossy(ratio, Rounding::Nearest), Ok(Duration(nearest)), "{ratio}");
+		}
+
+		// Division works the same way.
+		for (ratio, floor, ceil, nearest) in [
+			(1.0, 128, 128, 128),
+			(0.5, 256, 256, 256),
+			(2.0, 64, 64, 64),
+			(3.0, 42, 43, 43),
+			(256.0, 0, 1, 1),
+			(256.0 + E, 0, 1, 0),
+		] {
+			assert_eq!(d.div_f64_lossy(ratio, Rounding::Floor), Ok(Duration(floor)), "{ratio}");
+			assert_eq!(d.div_f64_lossy(ratio, Rounding::Ceil), Ok(Duration(ceil)), "{ratio}");
+			assert_eq!(d.div_f64_lossy(ratio, Rounding::Nearest), Ok(Duration(nearest)), "{ratio}");
+		}
+
+		// Errors.
+		for bad in [-1.0, -0.0001, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+			assert_eq!(d.mul_f64_lossy(bad, Rounding::Floor), Err(TocError::DurationConversion));
+			assert_eq!(d.div_f64_lossy(bad, Rounding::Floor), Err(TocError::DurationConversion));
+		}
+		assert_eq!(d.div_f64_lossy(0.0, Rounding::Floor), Err(TocError::DurationConversion));
+		assert_eq!(Duration::MAX.mul_f64_lossy(2.0, Rounding::Floor), Err(TocError::DurationConversion));
+		assert_eq!(d.mul_f64_lossy(f64::MAX, Rounding::Floor), Err(TocError::DurationConversion));
+	}
+
+	#[test]
+	fn t_parts() {
+		let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+		for _ in 0..2000 {
+			crate::xorshift(&mut seed);
+
+			// Mix small and large values.
+			let d = Duration(if seed & 1 == 0 { seed >> 24 } else { seed });
+			let parts = d.parts();
+			assert_eq!(Duration::try_from(parts), Ok(d));
+			assert_eq!(DurationParts::from(d), parts);
+			assert_eq!(parts.to_string(), d.to_string());
+
+			let (dd, h, m, s, f) = d.dhmsf();
+			assert_eq!(
+				parts,
+				DurationParts { days: dd, hours: h, minutes: m, seconds: s, frames: f },
+			);
+		}
+
+		// The edges.
+		assert_eq!(Duration::try_from(Duration::MAX.parts()), Ok(Duration::MAX));
+		assert_eq!(Duration::try_from(DurationParts::default()), Ok(Duration::ZERO));
+
+		// Out of range.
+		for parts in [
+			DurationParts { hours: 24, ..DurationParts::default() },
+			DurationParts { minutes: 60, ..DurationParts::default() },
+			DurationParts { seconds: 60, ..DurationParts::default() },
+			DurationParts { frames: 75, ..DurationParts::default() },
+			DurationParts { days: u64::MAX, ..DurationParts::default() },
+			DurationParts { frames: Duration::MAX.parts().frames + 1, ..Duration::MAX.parts() },
+		] {
+			assert_eq!(Duration::try_from(parts), Err(TocError::DurationConversion));
+		}
+	}
+
+	#[test]
+	fn t_signed() {
+		// Display.
+		for (n, expected) in [
+			(0, "+0"),
+			(1, "+1"),
+			(-1, "-1"),
+			(667, "+667"),
+			(-30, "-30"),
+			(i64::MIN, "-9223372036854775808"),
+		] {
+			assert_eq!(SignedDuration::from_samples(n).to_string(), expected);
+		}
+
+		// Duration round trips.
+		for d in [Duration::ZERO, Duration::FRAME, Duration::SECOND, Duration(12_345)] {
+			let signed = SignedDuration::from(d);
+			assert_eq!(u64::try_from(signed.samples()), Ok(d.samples()));
+			assert_eq!(u64::try_from(signed.sectors_samples().0), Ok(d.0));
+			assert_eq!(signed.sectors_samples().1, 0);
+			assert_eq!(Duration::try_from(signed), Ok(d));
+			if ! d.is_zero() {
+				assert_eq!(Duration::try_from(-signed), Err(TocError::DurationConversion));
+			}
+		}
+		assert_eq!(SignedDuration::from(Duration::MAX), SignedDuration::MAX);
+		assert_eq!(
+			Duration::try_from(SignedDuration::from_samples(589)),
+			Err(TocError::DurationConversion),
+		);
+
+		// Arithmetic.
+		let a = SignedDuration::from_samples(667);
+		let b = SignedDuration::from_samples(-30);
+		assert_eq!(a + b, SignedDuration::from_samples(637));
+		assert_eq!(b - a, SignedDuration::from_samples(-697));
+		assert_eq!(-b, SignedDuration::from_samples(30));
+		let mut c = a;
+		c += b;
+		c -= a;
+		assert_eq!(c, b);
+
+		assert!(b.is_negative());
+		assert!(a.is_positive());
+		assert!(SignedDuration::ZERO.is_zero());
+		assert!(b < SignedDuration::ZERO && SignedDuration::ZERO < a);
+
+		assert_eq!(SignedDuration::MIN.checked_neg(), None);
+		assert_eq!(SignedDuration::MIN.saturating_neg(), SignedDuration::MAX);
+		assert_eq!(SignedDuration::MAX.checked_add(a), None);
+		assert_eq!(SignedDuration::MAX.saturating_add(a), SignedDuration::MAX);
+		assert_eq!(SignedDuration::MIN.checked_sub(a), None);
+		assert_eq!(SignedDuration::MIN.saturating_sub(a), SignedDuration::MIN);
+		assert_eq!(SignedDuration::from_sectors(i64::MIN), SignedDuration::MIN);
+	}
+
+	#[test]
+	fn t_ratio() {
+		let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+		for _ in 0..2000 {
+			crate::xorshift(&mut seed);
+
+			let d = Duration(seed);
+			let (num, den) = d.as_ratio();
+			assert_eq!(75 % den, 0);
+			assert_eq!(Duration::try_from_ratio(num, u64::from(den)), Ok(d));
+
+			// Unreduced fractions work too.
+			assert_eq!(Duration::try_from_ratio(d.0, 75), Ok(d));
+		}
+
+		// Big values survive the ratio trip, but not the float one.
+		let d = Duration::MAX - 1_u64;
+		let (num, den) = d.as_ratio();
+		assert_eq!(Duration::try_from_ratio(num, u64::from(den)), Ok(d));
+		assert_ne!(
+			Duration::from_secs_f64_lossy(d.to_f64_lossy(), Rounding::Nearest),
+			Ok(d),
+		);
+
+		// Errors.
+		assert!(Duration::try_from_ratio(1, 0).is_err());
+		assert!(Duration::try_from_ratio(1, 76).is_err());
+		assert!(Duration::try_from_ratio(u64::MAX, 1).is_err());
+		assert_eq!(Duration::try_from_ratio(0, 1), Ok(Duration::ZERO));
+	}
+
+	#[test]
+	fn t_hmsf() {
+		for d in [
+			Duration::ZERO,
+			Duration::FRAME,
+			Duration::decode("23:59:59+74").expect("Decode failed."),
+			Duration::decode("1d 00:00:00+00").expect("Decode failed."),
+			Duration::decode("3d 07:08:09+10").expect("Decode failed."),
+			Duration::decode("1000d 23:01:02+03").expect("Decode failed."),
+			Duration::MAX,
+		] {
+			let (dd, h, m, s, f) = d.dhmsf();
+			let (h2, m2, s2, f2) = d.hmsf();
+			assert_eq!(h2, dd * 24 + u64::from(h));
+			assert_eq!((m2, s2, f2), (m, s, f));
+
+			let (m3, s3, f3) = d.minutes_seconds_frames();
+			assert_eq!(m3, h2 * 60 + u64::from(m));
+			assert_eq!((s3, f3), (s, f));
+
+			// And back again.
+			assert_eq!(
+				Duration::from_msf(0, s3, f3).expect("MSF failed.") +
+				Duration::MINUTE.checked_mul(m3).expect("Overflow."),
+				d,
+			);
+		}
+
+		assert_eq!(
+			Duration::decode("3d 07:08:09+10").expect("Decode failed.").hmsf(),
+			(79, 8, 9, 10),
+		);
+	}
+
+	#[test]
+	fn t_cmp() {
+		// Sectors.
+		let d = Duration(75);
+		assert_eq!(d, 75_u64);
+		assert_eq!(75_u64, d);
+		assert!(d < 76_u64 && 74_u64 < d);
+		assert!(76_u64 > d && d > 74_u64);
+
+		// Standard durations. One frame is 13,333,333⅓ nanoseconds.
+		let one = time::Duration::from_secs(1);
+		for (nanos, expected) in [
+			(0, Ordering::Equal),
+			(13_333_333, Ordering::Equal),
+			(13_333_334, Ordering::Less),
+			(u64::MAX, Ordering::Less),
+		] {
+			let other = one + time::Duration::from_nanos(nanos);
+			assert_eq!(d.partial_cmp(&other), Some(expected), "+{nanos}");
+			assert_eq!(other.partial_cmp(&d), Some(expected.reverse()), "+{nanos}");
+			assert_eq!(d == other, expected == Ordering::Equal);
+			assert_eq!(other == d, expected == Ordering::Equal);
+
+			let other = one - time::Duration::from_nanos(nanos.min(1_000_000_000));
+			let expected = expected.reverse();
+			assert_eq!(d.partial_cmp(&other), Some(expected), "-{nanos}");
+			assert_eq!(other.partial_cmp(&d), Some(expected.reverse()), "-{nanos}");
+		}
+
+		// The extremes.
+		assert!(Duration::MAX < time::Duration::MAX);
+		assert!(Duration::ZERO == time::Duration::ZERO);
+		assert!(Duration::FRAME > time::Duration::ZERO);
+	}
+
+	#[test]
+	fn t_display_aligned() {
+		// Snapshot of a mixed-length listing.
+		let durations = [
+			Duration::from_msf(0, 7, 0),
+			Duration::from_msf(4, 13, 37),
+			Duration::from_msf(4, 13, 38),
+			Duration::from_msf(12, 7, 0),
+			Duration::from_msf(59, 59, 40),
+			Duration::from_msf(62, 45, 0),
+			Duration::from_msf(600, 0, 0),
+		].map(|d| d.expect("MSF failed."));
+
+		let style = AlignedStyle::for_durations(durations);
+		assert_eq!(style.width(), 8);
+
+		let mut listing = String::new();
+		for d in durations {
+			listing.push_str(&format!("|{}|\n", d.display_aligned(style)));
+		}
+		assert_eq!(
+			listing,
+			"|    0:07|
+|    4:13|
+|    4:14|
+|   12:07|
+| 1:00:00|
+| 1:02:45|
+|10:00:00|
+",
+		);
+
+		// The aligned and short versions should always agree, give or take
+		// the leading spaces.
+		for d in durations {
+			assert_eq!(
+				d.display_aligned(style).to_string().trim_start(),
+				d.display_short().to_string(),
+			);
+		}
+
+		// Narrow styles don't truncate.
+		let style = AlignedStyle::default();
+		assert_eq!(durations[6].display_aligned(style).to_string(), "10:00:00");
+
+		// Check some widths directly.
+		for (d, width) in [
+			(Duration::ZERO, 4),
+			(Duration::from_msf(9, 59, 37).expect("MSF failed."), 4),
+			(Duration::from_msf(9, 59, 38).expect("MSF failed."), 5),
+			(Duration::from_msf(99, 59, 38).expect("MSF failed."), 7),
+			(Duration::MAX, 20),
+		] {
+			assert_eq!(AlignedStyle::for_max(d).width(), width);
+			assert_eq!(d.display_aligned(AlignedStyle::default()).to_string().len(), width);
+		}
+	}
+
+	#[test]
+	fn t_rounding() {
+		// Ties at exactly half a frame, both odd and even.
+		for (secs, num, den, floor, ceil, nearest, even) in [
+			(0, 1, 150, 0, 1, 1, 0),
+			(0, 3, 150, 1, 2, 2, 2),
+			(1, 5, 150, 77, 78, 78, 78),
+			(1, 7, 150, 78, 79, 79, 78),
+			(0, 1_000_000_000 / 150, 1_000_000_000, 0, 1, 0, 0),
+			(0, 1, 3, 25, 25, 25, 25),
+			(0, 1, 7, 10, 11, 11, 11),
+			(2, 3, 2, 262, 263, 263, 262),
+		] {
+			for (rounding, expected) in [
+				(Rounding::Floor, floor),
+				(Rounding::Ceil, ceil),
+				(Rounding::Nearest, nearest),
+				(Rounding::NearestTiesEven, even),
+			] {
+				assert_eq!(
+					Duration::from_seconds_parts(secs, num, den, rounding),
+					Some(Duration(expected)),
+					"{secs} + {num}/{den} {rounding:?}",
+				);
+			}
+		}
+
+		// The float versions should agree at the ties.
+		for (frames, floor, ceil, nearest, even) in [
+			(0.5, 0, 1, 1, 0),
+			(1.5, 1, 2, 2, 2),
+			(2.5, 2, 3, 3, 2),
+		] {
+			for (rounding, expected) in [
+				(Rounding::Floor, floor),
+				(Rounding::Ceil, ceil),
+				(Rounding::Nearest, nearest),
+				(Rounding::NearestTiesEven, even),
+			] {
+				assert_eq!(Duration::from_frames_f64(frames, rounding), Ok(Duration(expected)));
+				assert_eq!(
+					Duration::from_seconds_parts(0, (frames * 2.0) as u64, 150, rounding),
+					Some(Duration(expected)),
+				);
+			}
+		}
+
+		// The float extremes.
+		let tiny = f64::from_bits(1);
+		assert_eq!(Duration::from_frames_f64(tiny, Rounding::Floor), Ok(Duration(0)));
+		assert_eq!(Duration::from_frames_f64(tiny, Rounding::Ceil), Ok(Duration(1)));
+		assert_eq!(Duration::from_frames_f64(tiny, Rounding::Nearest), Ok(Duration(0)));
+		assert_eq!(Duration::from_frames_f64(-0.0, Rounding::Ceil), Ok(Duration(0)));
+		assert_eq!(
+			Duration::from_frames_f64(f64::from_bits(0x43EF_FFFF_FFFF_FFFF), Rounding::Ceil),
+			Ok(Duration(u64::MAX - 2047)),
+		);
+		assert!(Duration::from_frames_f64(f64::from_bits(0x43F0_0000_0000_0000), Rounding::Floor).is_err());
+		assert!(Duration::from_frames_f64(f64::MAX, Rounding::Floor).is_err());
+		assert!(Duration::from_frames_f64(f64::INFINITY, Rounding::Floor).is_err());
+		assert!(Duration::from_frames_f64(f64::NAN, Rounding::Floor).is_err());
+		assert!(Duration::from_frames_f64(-tiny, Rounding::Floor).is_err());
+
+		// Overflow and bad denominators.
+		assert_eq!(Duration::from_seconds_parts(u64::MAX, 0, 1, Rounding::Floor), None);
+		assert_eq!(
+			Duration::from_seconds_parts(u64::MAX / 75, 14, 75, Rounding::Floor),
+			Some(Duration(u64::MAX - 1)),
+		);
+		assert_eq!(
+			Duration::from_seconds_parts(u64::MAX / 75, 15, 75, Rounding::Floor),
+			Some(Duration::MAX),
+		);
+		assert_eq!(Duration::from_seconds_parts(u64::MAX / 75, 16, 75, Rounding::Floor), None);
+		assert_eq!(Duration::from_seconds_parts(0, u64::MAX, u64::MAX, Rounding::Floor), Some(Duration::SECOND));
+		assert_eq!(Duration::from_seconds_parts(0, 1, 0, Rounding::Floor), None);
+	}
+
+	#[test]
+	fn t_checked() {
+		let max = Duration(u64::MAX);
+		let one = Duration(1);
+
+		// Add.
+		assert_eq!(max.checked_add(0_u64), Some(max));
+		assert_eq!(max.checked_add(one), None);
+		assert_eq!(Duration(u64::MAX - 1).checked_add(one), Some(max));
+		assert_eq!(max.saturating_add(one), max);
+		assert_eq!(Duration(u64::MAX - 1).saturating_add(one), max);
+
+		// Sub.
+		assert_eq!(max.checked_sub(max), Some(Duration(0)));
+		assert_eq!(one.checked_sub(max), None);
+		assert_eq!(one - max, Duration(0));
+
+		// Mul.
+		assert_eq!(max.checked_mul(1_u64), Some(max));
+		assert_eq!(max.checked_mul(2_u64), None);
+		assert_eq!(Duration(u64::MAX / 2).checked_mul(2_u64), Some(Duration(u64::MAX - 1)));
+		assert_eq!(max.saturating_mul(2_u64), max);
+		assert_eq!(Duration(u64::MAX / 2).saturating_mul(2_u64), Duration(u64::MAX - 1));
+
+		// Div.
+		assert_eq!(max.checked_div(1_u64), Some(max));
+		assert_eq!(max.checked_div(0_u64), None);
+		assert_eq!(max / 0_u64, Duration(0));
+	}
+
+	#[test]
+	fn t_from_std() {
+		// Round-trip a range of durations through std and back.
+		for sectors in (0..10_000_u64).chain([u64::from(u32::MAX), u64::MAX / 2]) {
+			let d = Duration(sectors);
+			let std = d.to_std_duration_lossy();
+
+			// std truncates to the nanosecond, so flooring might lose a
+			// frame, but never more than that.
+			let floor = Duration::from_std_lossy(std, Rounding::Floor);
+			assert!(floor == d || floor.0 + 1 == d.0, "{sectors}");
+
+			// The other modes should find their way home.
+			assert_eq!(Duration::from_std_lossy(std, Rounding::Ceil), d);
+			assert_eq!(Duration::from_std_lossy(std, Rounding::Nearest), d);
+
+			// Only durations landing on a whole nanosecond are exact.
+			match Duration::try_from(std) {
+				Ok(d2) => {
+					assert_eq!(d2, d);
+					assert_eq!(sectors % 3, 0);
+				},
+				Err(e) => {
+					assert_eq!(e, TocError::DurationConversion);
+					assert_ne!(sectors % 3, 0);
+				},
+			}
+		}
+
+		// Nearest ties should round up.
+		let half = time::Duration::from_nanos(20_000_000 / 3 + 1);
+		assert_eq!(Duration::from_std_lossy(half, Rounding::Floor), Duration(0));
+		assert_eq!(Duration::from_std_lossy(half, Rounding::Nearest), Duration(1));
+		let half = time::Duration::from_millis(20);
+		assert_eq!(Duration::from_std_lossy(half, Rounding::Nearest), Duration(2));
+
+		// Too big.
+		assert_eq!(Duration::from_std_lossy(time::Duration::MAX, Rounding::Floor), Duration(u64::MAX));
+		assert_eq!(Duration::try_from(time::Duration::MAX), Err(TocError::DurationConversion));
+		assert_eq!(
+			Duration::try_from(time::Duration::from_secs(u64::MAX / 75 + 1)),
+			Err(TocError::DurationConversion),
+		);
+	}
+
+	#[test]
+	fn t_from_secs_f64() {
+		// Round-trip a bunch of pseudo-random durations through f64.
+		let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+		for i in 0..5_000_u32 {
+			crate::xorshift(&mut seed);
+
+			let d = Duration(match i % 3 {
+				0 => seed % 75,
+				1 => seed % (86_400 * SECTORS_PER_SECOND),
+				_ => seed % (1 << 40),
+			});
+			let f = d.to_f64_lossy();
+			assert_eq!(Duration::from_secs_f64_lossy(f, Rounding::Nearest), Ok(d), "{f}");
+
+			// The other modes should be within a frame.
+			let floor = Duration::from_secs_f64_lossy(f, Rounding::Floor).expect("Floor failed.");
+			let ceil = Duration::from_secs_f64_lossy(f, Rounding::Ceil).expect("Ceil failed.");
+			assert!(floor.0 <= d.0 && d.0 - floor.0 <= 1, "{f}");
+			assert!(d.0 <= ceil.0 && ceil.0 - d.0 <= 1, "{f}");
+		}
+
+		// Zero, including the negative kind.
+		assert_eq!(Duration::from_secs_f64_lossy(0.0, Rounding::Ceil), Ok(Duration(0)));
+		assert_eq!(Duration::from_secs_f64_lossy(-0.0, Rounding::Ceil), Ok(Duration(0)));
+
+		// Bad values.
+		for bad in [-0.01, f64::NEG_INFINITY, f64::INFINITY, f64::NAN, f64::MAX, 1e18] {
+			assert_eq!(
+				Duration::from_secs_f64_lossy(bad, Rounding::Floor),
+				Err(TocError::DurationConversion),
+				"{bad}",
+			);
+		}
+	}
+
+	#[test]
+	fn t_samples_at_rate() {
+		for sectors in 0..1_000_u64 {
+			let d = Duration(sectors);
+
+			// CDDA is always exact.
+			assert_eq!(d.samples_at_rate(44_100), d.samples());
+			assert_eq!(d.samples_at_rate_exact(44_100), Some(d.samples()));
+
+			for rate in [8_000, 22_050, 32_000, 48_000, 88_200, 96_000, 192_000] {
+				let samples = d.samples_at_rate(rate);
+
+				// Truncation, not rounding.
+				let total = sectors * u64::from(rate);
+				assert_eq!(samples, total / 75);
+				if total % 75 == 0 {
+					assert_eq!(d.samples_at_rate_exact(rate), Some(samples));
+				}
+				else {
+					assert_eq!(d.samples_at_rate_exact(rate), None);
+				}
+
+				// Whole sample counts convert back cleanly.
+				if d.samples_at_rate_exact(rate).is_some() {
+					assert_eq!(Duration::from_samples(rate, samples), d);
+				}
+			}
+		}
+
+		// Overflow.
+		assert_eq!(Duration(u64::MAX).samples_at_rate(192_000), u64::MAX);
+		assert_eq!(Duration(u64::MAX).samples_at_rate_exact(192_000), None);
+		assert_eq!(Duration(u64::MAX).samples_at_rate_exact(75), Some(u64::MAX));
+
+		// Zero is zero.
+		assert_eq!(Duration(1000).samples_at_rate(0), 0);
+		assert_eq!(Duration(1000).samples_at_rate_exact(0), Some(0));
+	}
+
+	#[test]
+	fn t_display_short() {
+		for (sectors, expected, expected1) in [
+			(0, "0:00", "0:00.0"),
+			(37, "0:00", "0:00.5"),
+			(38, "0:01", "0:00.5"),
+			(74, "0:01", "0:01.0"),
+			(75, "0:01", "0:01.0"),
+			(75 * 59 + 37, "0:59", "0:59.5"),
+			(75 * 59 + 38, "1:00", "0:59.5"),
+			(75 * 3599 + 38, "1:00:00", "59:59.5"),
+			(75 * 3600, "1:00:00", "1:00:00.0"),
+			(75 * 86_400 * 2, "48:00:00", "48:00:00.0"),
+		] {
+			let d = Duration(sectors);
+			assert_eq!(d.display_short().to_string(), expected, "{sectors}");
+			assert_eq!(format!("{:.0}", d.display_short()), expected, "{sectors}");
+			assert_eq!(format!("{:.1}", d.display_short()), expected1, "{sectors}");
+		}
+
+		// Precision caps at nine places.
+		let d = Duration(1);
+		assert_eq!(format!("{:.9}", d.display_short()), "0:00.013333333");
+		assert_eq!(format!("{:.12}", d.display_short()), "0:00.013333333");
+
+		// Make sure nothing overflows at the top end.
+		assert_eq!(
+			format!("{:.9}", Duration(u64::MAX).display_short()),
+			"68321274347072:24:48.200000000",
+		);
+	}
+
+	#[test]
+	fn t_cue_timestamp() {
+		for (sectors, expected) in [
+			(0, "00:00:00"),
+			(74, "00:00:74"),
+			(4_499, "00:59:74"),
+			(75 * 3600 - 1, "59:59:74"),
+			(75 * 3600, "60:00:00"),
+			(449_999, "99:59:74"),
+			(450_000, "100:00:00"),
+			(u64::MAX, "4099276460824344:48:15"),
+		] {
+			let d = Duration(sectors);
+			assert_eq!(d.to_cue_timestamp(), expected);
+
+			// Should match the Msf formatting when in range.
+			if let Ok(lba) = u32::try_from(sectors) {
+				assert_eq!(Msf::from_lba(lba).to_string(), expected);
+			}
+		}
+	}
+
+	#[test]
+	fn t_decode() {
+		// Round-trip a bunch of pseudo-random durations, big and small.
+		let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+		for i in 0..5_000_u32 {
+			crate::xorshift(&mut seed);
+
+			// Mix up the magnitudes a bit so we cover sub-second, sub-day,
+			// multi-day, and enormous values.
+			let sectors = match i % 4 {
+				0 => seed % 75,
+				1 => seed % (86_400 * SECTORS_PER_SECOND),
+				2 => seed % (1000 * 86_400 * SECTORS_PER_SECOND),
+				_ => seed,
+			};
+			let d = Duration(sectors);
+			let s = d.to_string();
+			assert_eq!(Duration::decode(&s), Ok(d), "{s}");
+		}
+
+		// And the edges.
+		for d in [Duration(0), Duration(u64::MAX)] {
+			let s = d.to_string();
+			assert_eq!(Duration::decode(&s), Ok(d), "{s}");
+		}
+
+		// Now some bad ones.
+		for bad in [
+			"",
+			"00:00:00",
+			"0:00:00+00",
+			"00:00:00+0",
+			"00:00:00:00",
+			"00-00:00+00",
+			"24:00:00+00",
+			"00:60:00+00",
+			"00:00:60+00",
+			"00:00:00+75",
+			"d 00:00:00+00",
+			"1d00:00:00+00",
+			"1d  00:00:00+00",
+			"-1d 00:00:00+00",
+			"1D 00:00:00+00",
+			" 00:00:00+00",
+			"00:00:00+00 ",
+			"2846719764461d 23:59:59+74",
+			"99999999999999999999d 00:00:00+00",
+		] {
+			assert_eq!(Duration::decode(bad), Err(TocError::DurationParse), "{bad}");
+		}
+	}
+}
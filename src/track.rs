@@ -2,8 +2,22 @@
 # CDTOC: Track
 */
 
-use crate::Duration;
-use std::ops::Range;
+use crate::{
+	Duration,
+	Msf,
+	SignedDuration,
+	TocError,
+};
+use std::{
+	fmt,
+	ops::Range,
+	str::FromStr,
+};
+
+
+
+/// # Samples Per Sector.
+const SAMPLES_PER_SECTOR: i64 = 588;
 
 
 
@@ -29,6 +43,101 @@ pub struct Track {
 }
 
 impl Track {
+	/// # Red Book Minimum Duration.
+	///
+	/// The Red Book standard requires every audio track be at least four
+	/// seconds long. Anything shorter is usually an index marker that was
+	/// mistakenly ripped as its own track.
+	///
+	/// This is a sensible default threshold for [`Track::is_shorter_than`]
+	/// and [`Toc::short_tracks`](crate::Toc::short_tracks).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Track;
+	///
+	/// assert_eq!(Track::MIN_DURATION.sectors(), 300);
+	/// ```
+	pub const MIN_DURATION: Duration = Duration(Duration::SECOND.sectors() * 4);
+
+	/// # New HTOA.
+	///
+	/// Manually construct a hidden pre-gap [`Track`] spanning the sectors
+	/// `from..to`, for example when synthesizing one from a cue sheet's
+	/// `INDEX 00`.
+	///
+	/// Like the ones returned by [`Toc::htoa`](crate::Toc::htoa), the result
+	/// will have a track number of zero and an [`Invalid`](TrackPosition::Invalid)
+	/// position.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Toc, Track};
+	///
+	/// let htoa = Track::new_htoa(150, 9342).unwrap();
+	/// assert!(htoa.is_htoa());
+	/// assert_eq!(htoa.number(), 0);
+	/// assert_eq!(htoa.sector_range(), 150..9342);
+	///
+	/// // Same as the real thing!
+	/// let toc = Toc::from_cdtoc("15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46").unwrap();
+	/// assert_eq!(toc.htoa(), Some(htoa));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if `from` is less than the mandatory `150`
+	/// leadin, or `to` does not come after `from`.
+	pub const fn new_htoa(from: u32, to: u32) -> Result<Self, TocError> {
+		if from < 150 { Err(TocError::LeadinSize(from)) }
+		else if to <= from { Err(TocError::SectorOrder(from, to)) }
+		else {
+			Ok(Self {
+				num: 0,
+				pos: TrackPosition::Invalid,
+				from,
+				to,
+			})
+		}
+	}
+
+	#[must_use]
+	/// # Approximately Equal?
+	///
+	/// Return `true` if `other` has the same track number as `self`, and
+	/// both its start and end sectors are within `tolerance_sectors` of
+	/// this one's.
+	///
+	/// This is useful for comparing tracks from two slightly different rips
+	/// of the same disc. If the discs might have different leadins, use
+	/// [`Track::same_length_within`] instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc1 = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let toc2 = Toc::from_cdtoc("4+96+2D2D+6255+B327+D84A").unwrap();
+	/// let t1 = toc1.audio_track(2).unwrap();
+	/// let t2 = toc2.audio_track(2).unwrap();
+	///
+	/// assert_ne!(t1, t2);
+	/// assert!(! t1.approx_eq(&t2, 1));
+	/// assert!(t1.approx_eq(&t2, 2));
+	///
+	/// // Different track numbers never match.
+	/// let t3 = toc2.audio_track(3).unwrap();
+	/// assert!(! t1.approx_eq(&t3, u32::MAX));
+	/// ```
+	pub const fn approx_eq(&self, other: &Self, tolerance_sectors: u32) -> bool {
+		self.num == other.num &&
+		self.from.abs_diff(other.from) <= tolerance_sectors &&
+		self.to.abs_diff(other.to) <= tolerance_sectors
+	}
+
 	#[must_use]
 	/// # Byte Size.
 	///
@@ -84,9 +193,32 @@ impl Track {
 	/// assert!(toc.audio_tracks().all(|v| ! v.is_htoa()));
 	/// ```
 	pub const fn is_htoa(&self) -> bool {
-		self.num == 0 &&
-		self.from == 150 &&
-		matches!(self.pos, TrackPosition::Invalid)
+		self.num == 0 && matches!(self.pos, TrackPosition::Invalid)
+	}
+
+	#[must_use]
+	/// # Is Shorter Than?
+	///
+	/// Return `true` if the track's [duration](Track::duration) is strictly
+	/// less than `d`.
+	///
+	/// See [`Track::MIN_DURATION`] for the Red Book minimum.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Duration, Toc, Track};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(1).unwrap();
+	/// assert_eq!(track.sectors(), 11_413);
+	///
+	/// assert!(! track.is_shorter_than(Track::MIN_DURATION));
+	/// assert!(! track.is_shorter_than(Duration::from(11_413_u32)));
+	/// assert!(track.is_shorter_than(Duration::from(11_414_u32)));
+	/// ```
+	pub const fn is_shorter_than(&self, d: Duration) -> bool {
+		(self.sectors() as u64) < d.0
 	}
 
 	#[must_use]
@@ -104,7 +236,7 @@ impl Track {
 	/// let track = toc.audio_track(2).unwrap();
 	/// assert_eq!(track.msf(), (2, 34, 13));
 	/// ```
-	pub const fn msf(&self) -> (u32, u8, u8) { lba_to_msf(self.from) }
+	pub const fn msf(&self) -> (u32, u8, u8) { crate::lba_to_msf(self.from) }
 
 	#[must_use]
 	/// # MSF (Normalized).
@@ -128,7 +260,132 @@ impl Track {
 	/// assert_eq!(track.msf(), (2, 34, 13));
 	/// assert_eq!(track.msf_normalized(), (2, 32, 13));
 	/// ```
-	pub const fn msf_normalized(&self) -> (u32, u8, u8) { lba_to_msf(self.from - 150) }
+	pub const fn msf_normalized(&self) -> (u32, u8, u8) {
+		crate::lba_to_msf(self.from - 150)
+	}
+
+	#[must_use]
+	/// # MSF Display.
+	///
+	/// Return a formatting wrapper for the (beginning) [MSF](Track::msf) of
+	/// the track, useful for allocation-free printing in `write!`-type
+	/// chains.
+	///
+	/// The format is `MM:SS:FF`, with colons separating all three parts as in
+	/// cue sheets. Each part is zero-padded to two digits, but the minutes
+	/// are not capped, so can grow to three or more digits for unusually
+	/// long programs.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(2).unwrap();
+	/// assert_eq!(
+	///     format!("Track #2 begins at {}.", track.msf_display()),
+	///     "Track #2 begins at 02:34:13.",
+	/// );
+	/// ```
+	pub const fn msf_display(&self) -> impl fmt::Display { self.msf_typed() }
+
+	#[must_use]
+	/// # MSF Display (Normalized).
+	///
+	/// Same as [`Track::msf_display`], but for the [normalized](Track::msf_normalized)
+	/// MSF.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(2).unwrap();
+	/// assert_eq!(
+	///     format!("Track #2 begins at {}.", track.msf_normalized_display()),
+	///     "Track #2 begins at 02:32:13.",
+	/// );
+	/// ```
+	pub const fn msf_normalized_display(&self) -> impl fmt::Display {
+		self.msf_normalized_typed()
+	}
+
+	#[must_use]
+	/// # MSF String.
+	///
+	/// Return the (beginning) [MSF](Track::msf) of the track as a string.
+	///
+	/// See [`Track::msf_display`] for formatting details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(2).unwrap();
+	/// assert_eq!(track.msf(), (2, 34, 13));
+	/// assert_eq!(track.msf_string(), "02:34:13");
+	/// ```
+	pub fn msf_string(&self) -> String { self.msf_display().to_string() }
+
+	#[must_use]
+	/// # MSF String (Normalized).
+	///
+	/// Return the (beginning) [normalized MSF](Track::msf_normalized) of the
+	/// track as a string.
+	///
+	/// See [`Track::msf_display`] for formatting details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(2).unwrap();
+	/// assert_eq!(track.msf_normalized(), (2, 32, 13));
+	/// assert_eq!(track.msf_normalized_string(), "02:32:13");
+	/// ```
+	pub fn msf_normalized_string(&self) -> String {
+		self.msf_normalized_display().to_string()
+	}
+
+	#[must_use]
+	/// # MSF (Typed).
+	///
+	/// Same as [`Track::msf`], but returned as an [`Msf`] rather than a
+	/// tuple.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(2).unwrap();
+	/// assert_eq!(track.msf_typed().as_tuple(), track.msf());
+	/// ```
+	pub const fn msf_typed(&self) -> Msf { Msf::from_lba(self.from) }
+
+	#[must_use]
+	/// # Normalized MSF (Typed).
+	///
+	/// Same as [`Track::msf_normalized`], but returned as an [`Msf`] rather
+	/// than a tuple.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(2).unwrap();
+	/// assert_eq!(track.msf_normalized_typed().as_tuple(), track.msf_normalized());
+	/// ```
+	pub const fn msf_normalized_typed(&self) -> Msf { Msf::from_lba(self.from - 150) }
 
 	#[must_use]
 	/// # Number.
@@ -183,6 +440,33 @@ impl Track {
 	/// ```
 	pub const fn samples(self) -> u64 { self.duration().samples() }
 
+	#[must_use]
+	/// # Same Length Within?
+	///
+	/// Return `true` if the [sector counts](Track::sectors) of `self` and
+	/// `other` differ by no more than `tolerance`, regardless of where
+	/// either track actually sits on its disc.
+	///
+	/// Unlike [`Track::approx_eq`], the track numbers are not compared.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// // The same album with different leadins.
+	/// let toc1 = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let toc2 = Toc::from_cdtoc("4+2000+4C95+81C0+D291+F7B4").unwrap();
+	/// let t1 = toc1.audio_track(2).unwrap();
+	/// let t2 = toc2.audio_track(2).unwrap();
+	///
+	/// assert!(! t1.approx_eq(&t2, 10));
+	/// assert!(t1.same_length_within(&t2, 0));
+	/// ```
+	pub const fn same_length_within(&self, other: &Self, tolerance: u32) -> bool {
+		self.sectors().abs_diff(other.sectors()) <= tolerance
+	}
+
 	#[must_use]
 	/// # Sector Size.
 	///
@@ -241,11 +525,297 @@ impl Track {
 	pub const fn sector_range_normalized(&self) -> Range<u32> {
 		self.from - 150..self.to - 150
 	}
+
+	#[must_use]
+	/// # Sector Range (Offset-Corrected).
+	///
+	/// Return the range of sectors a drive with the given read offset would
+	/// need to read to capture exactly this track's audio,
+	/// along with the number of partial-sector samples to discard from either
+	/// end.
+	///
+	/// Positive offsets shift the range toward the end of the disc; negative
+	/// offsets shift it toward the beginning.
+	///
+	/// The first addressable audio sector (`150`) and — for the last track —
+	/// the audio leadout cannot be overread, so shifts beyond those points
+	/// are clamped, with the missing samples reported as padding. (Rippers
+	/// usually fill such gaps with silence.)
+	///
+	/// See [`OffsetRange`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{SignedDuration, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(2).unwrap();
+	/// assert_eq!(track.sector_range(), 11_563..25_174);
+	///
+	/// // A drive with a +667 read offset lags a sector and change behind.
+	/// let offset = SignedDuration::from_samples(667);
+	/// let range = track.sector_range_with_offset(offset);
+	/// assert_eq!(range.sectors(), 11_564..25_176);
+	/// assert_eq!(range.leading_samples(), 79);
+	/// assert_eq!(range.trailing_samples(), 509);
+	///
+	/// // Either way, the total works out the same.
+	/// assert_eq!(range.samples(), track.samples());
+	/// ```
+	pub const fn sector_range_with_offset(&self, offset: SignedDuration) -> OffsetRange {
+		OffsetRange::new(*self, offset.samples())
+	}
+
+	#[must_use]
+	/// # Start Time.
+	///
+	/// Return the time at which this track begins, relative to the start of
+	/// the audio program (i.e. _without_ the mandatory 150-sector CD lead-in).
+	///
+	/// For most discs, this means track #1 starts at zero.
+	///
+	/// Discs with an [HTOA](crate::Toc::htoa) are the exception; the HTOA
+	/// itself starts at zero, pushing track #1 back by however long it runs.
+	/// If you need the absolute picture, use [`Track::sector_range`] instead.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Duration, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(1).unwrap();
+	/// assert_eq!(track.starts_at(), Duration::from(0_u32));
+	///
+	/// let track = toc.audio_track(2).unwrap();
+	/// assert_eq!(track.starts_at().to_string(), "00:02:32+13");
+	/// ```
+	pub const fn starts_at(&self) -> Duration { Duration((self.from - 150) as u64) }
+
+	#[must_use]
+	/// # End Time.
+	///
+	/// Return the (exclusive) time at which this track ends, relative to the
+	/// start of the audio program.
+	///
+	/// See [`Track::starts_at`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(1).unwrap();
+	/// assert_eq!(track.ends_at().to_string(), "00:02:32+13");
+	///
+	/// // One track's end is the next one's start.
+	/// assert_eq!(track.ends_at(), toc.audio_track(2).unwrap().starts_at());
+	/// ```
+	pub const fn ends_at(&self) -> Duration { Duration((self.to - 150) as u64) }
+
+	#[must_use]
+	/// # Time Range.
+	///
+	/// Return the range of time — `start..end` — occupied by this track,
+	/// relative to the start of the audio program.
+	///
+	/// This is the time-based equivalent of [`Track::sector_range_normalized`].
+	/// See [`Track::starts_at`] for more details.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::{Duration, Toc};
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let track = toc.audio_track(1).unwrap();
+	/// assert_eq!(
+	///     track.time_range(),
+	///     Duration::from(0_u32)..Duration::from(11_413_u32),
+	/// );
+	///
+	/// // HTOAs start at zero, pushing the first real track back a bit.
+	/// let toc = Toc::from_cdtoc("15+247E+2BEC+4AF4+7368+9704+B794+E271+110D0+12B7A+145C1+16CAF+195CF+1B40F+1F04A+21380+2362D+2589D+2793D+2A760+2DA32+300E1+32B46").unwrap();
+	/// let htoa = toc.htoa().unwrap();
+	/// assert_eq!(
+	///     htoa.time_range(),
+	///     Duration::from(0_u32)..Duration::from(9192_u32),
+	/// );
+	/// assert_eq!(
+	///     toc.audio_track(1).unwrap().starts_at(),
+	///     htoa.ends_at(),
+	/// );
+	/// ```
+	pub const fn time_range(&self) -> Range<Duration> {
+		self.starts_at()..self.ends_at()
+	}
+}
+
+
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+/// # Offset-Corrected Sector Range.
+///
+/// This struct describes the sectors that need to be read to extract a
+/// track's audio from a drive with a given read offset, and how many samples
+/// to trim from (or pad onto) either end to get it exactly right.
+///
+/// Put together, the track's audio is:
+///
+/// 1. [`OffsetRange::padding_start`] samples of silence;
+/// 2. The samples within [`OffsetRange::sectors`], minus the first [`OffsetRange::leading_samples`] and last [`OffsetRange::trailing_samples`];
+/// 3. [`OffsetRange::padding_end`] samples of silence;
+///
+/// The total always equals [`Track::samples`].
+///
+/// It is the return value of [`Track::sector_range_with_offset`].
+///
+/// ## Clamping
+///
+/// Nothing before sector `150` — the first addressable audio sector — can
+/// be read, so a range shifted past it is clamped there, with the shortfall
+/// reported as [`OffsetRange::padding_start`].
+///
+/// The same goes for the audio leadout, but only for the last track; every
+/// other track is free to read into the one that follows it. Any overshoot
+/// is reported as [`OffsetRange::padding_end`].
+///
+/// If the shift is so large that nothing readable remains, the sector range
+/// will be empty, sitting at the boundary that was crossed, and the entire
+/// track will be padding on that side: [`OffsetRange::padding_end`] for
+/// positive offsets, [`OffsetRange::padding_start`] for negative ones.
+pub struct OffsetRange {
+	/// # Sectors to Read.
+	sectors: Range<u32>,
+
+	/// # Samples to Discard From the First Sector.
+	lead: u16,
+
+	/// # Samples to Discard From the Last Sector.
+	trail: u16,
+
+	/// # Unreadable Samples (Start).
+	pad_start: u64,
+
+	/// # Unreadable Samples (End).
+	pad_end: u64,
+}
+
+impl OffsetRange {
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	#[expect(clippy::cast_sign_loss, reason = "False positive.")]
+	/// # New.
+	///
+	/// Shift the track's sample range by `offset`, clamping it to the
+	/// readable area of the disc.
+	const fn new(track: Track, offset: i64) -> Self {
+		// The readable boundaries, in samples.
+		let min = 150 * SAMPLES_PER_SECTOR;
+		let max =
+			if track.pos.is_last() { track.to as i64 * SAMPLES_PER_SECTOR }
+			else { u32::MAX as i64 * SAMPLES_PER_SECTOR };
+
+		// The ideal boundaries, in samples.
+		let mut start = (track.from as i64 * SAMPLES_PER_SECTOR).saturating_add(offset);
+		let mut end = (track.to as i64 * SAMPLES_PER_SECTOR).saturating_add(offset);
+
+		// Clamp the start.
+		let mut pad_start = 0;
+		if start < min {
+			pad_start = min.saturating_sub(start);
+			start = min;
+		}
+
+		// Clamp the end.
+		let mut pad_end = 0;
+		if max < end {
+			pad_end = end.saturating_sub(max);
+			end = max;
+		}
+
+		// If the shift is so large nothing is left, it's all padding, at the
+		// start or end depending on the direction of the shift.
+		if end <= start {
+			let (from, pad_start, pad_end) =
+				if 0 < offset { (max / SAMPLES_PER_SECTOR, 0, track.samples()) }
+				else { (min / SAMPLES_PER_SECTOR, track.samples(), 0) };
+			let from = from as u32;
+			return Self {
+				sectors: from..from,
+				lead: 0,
+				trail: 0,
+				pad_start,
+				pad_end,
+			};
+		}
+
+		// Convert to whole sectors, noting the partial remainders.
+		let from = start / SAMPLES_PER_SECTOR;
+		let lead = start - from * SAMPLES_PER_SECTOR;
+		let to = (end + SAMPLES_PER_SECTOR - 1) / SAMPLES_PER_SECTOR;
+		let trail = to * SAMPLES_PER_SECTOR - end;
+
+		Self {
+			sectors: from as u32..to as u32,
+			lead: lead as u16,
+			trail: trail as u16,
+			pad_start: pad_start as u64,
+			pad_end: pad_end as u64,
+		}
+	}
+
+	#[must_use]
+	/// # Sector Range.
+	///
+	/// Return the range of (whole) sectors that need to be read.
+	pub const fn sectors(&self) -> Range<u32> { self.sectors.start..self.sectors.end }
+
+	#[must_use]
+	/// # Leading Samples.
+	///
+	/// Return the number of samples to discard from the beginning of the
+	/// first sector read.
+	pub const fn leading_samples(&self) -> u16 { self.lead }
+
+	#[must_use]
+	/// # Trailing Samples.
+	///
+	/// Return the number of samples to discard from the end of the last
+	/// sector read.
+	pub const fn trailing_samples(&self) -> u16 { self.trail }
+
+	#[must_use]
+	/// # Padding (Start).
+	///
+	/// Return the number of samples that would have come before the first
+	/// readable sector of the disc, and so must be filled in some other way
+	/// (usually with silence).
+	pub const fn padding_start(&self) -> u64 { self.pad_start }
+
+	#[must_use]
+	/// # Padding (End).
+	///
+	/// Return the number of samples that would have come after the audio
+	/// leadout, and so must be filled in some other way (usually with
+	/// silence).
+	pub const fn padding_end(&self) -> u64 { self.pad_end }
+
+	#[must_use]
+	/// # Total Samples.
+	///
+	/// Return the total number of samples represented, including padding.
+	/// This will always match the track's [sample count](Track::samples).
+	pub const fn samples(&self) -> u64 {
+		let read = (self.sectors.end - self.sectors.start) as u64 * SAMPLES_PER_SECTOR as u64;
+		read - self.lead as u64 - self.trail as u64 + self.pad_start + self.pad_end
+	}
 }
 
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// # Audio Tracks Iterator.
 ///
 /// This is an iterator of [`Track`] details for a given [`Toc`](crate::Toc).
@@ -268,20 +838,10 @@ pub struct Tracks<'a> {
 impl Iterator for Tracks<'_> {
 	type Item = Track;
 
-	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
 	fn next(&mut self) -> Option<Self::Item> {
-		let len = self.tracks.len();
-		if len <= self.pos { return None; }
-
-		let num = (self.pos + 1) as u8;
-		let pos = TrackPosition::from((self.pos + 1, len));
-		let from = self.tracks[self.pos];
-		let to =
-			if self.pos + 1 < len { self.tracks[self.pos + 1] }
-			else { self.leadout };
-
+		let next = self.track_at(self.pos)?;
 		self.pos += 1;
-		Some(Track { num, pos, from, to })
+		Some(next)
 	}
 
 	#[inline]
@@ -301,6 +861,106 @@ impl<'a> Tracks<'a> {
 	pub(super) const fn new(tracks: &'a [u32], leadout: u32) -> Self {
 		Self { tracks, leadout, pos: 0 }
 	}
+
+	#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
+	/// # Track At Index.
+	///
+	/// Build the [`Track`] for `tracks[idx]`, if any.
+	fn track_at(&self, idx: usize) -> Option<Track> {
+		let len = self.tracks.len();
+		if len <= idx { return None; }
+
+		let num = (idx + 1) as u8;
+		let pos = TrackPosition::from((idx + 1, len));
+		let from = self.tracks[idx];
+		let to =
+			if idx + 1 < len { self.tracks[idx + 1] }
+			else { self.leadout };
+
+		Some(Track { num, pos, from, to })
+	}
+}
+
+impl<'a> Tracks<'a> {
+	#[must_use]
+	/// # All Audio Sectors.
+	///
+	/// Return the starting sectors of _all_ audio tracks being iterated,
+	/// regardless of how many have already been consumed.
+	///
+	/// This is equivalent to [`Toc::audio_sectors`](crate::Toc::audio_sectors).
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let mut tracks = toc.audio_tracks();
+	/// let _ = tracks.next();
+	/// assert_eq!(tracks.as_toc_slice(), toc.audio_sectors());
+	/// ```
+	pub const fn as_toc_slice(&self) -> &'a [u32] { self.tracks }
+
+	#[must_use]
+	/// # Leadout.
+	///
+	/// Return the (audio) leadout, i.e. the exclusive end of the last track.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let tracks = toc.audio_tracks();
+	/// assert_eq!(tracks.leadout(), toc.audio_leadout());
+	/// ```
+	pub const fn leadout(&self) -> u32 { self.leadout }
+
+	#[must_use]
+	/// # Peek.
+	///
+	/// Return the [`Track`] the next call to `next` will yield, without
+	/// consuming it.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let mut tracks = toc.audio_tracks();
+	/// assert_eq!(tracks.peek(), toc.audio_track(1));
+	/// assert_eq!(tracks.peek(), tracks.next());
+	/// assert_eq!(tracks.peek(), toc.audio_track(2));
+	/// ```
+	pub fn peek(&self) -> Option<Track> { self.track_at(self.pos) }
+
+	#[must_use]
+	/// # Remaining Sectors.
+	///
+	/// Return the starting sectors of the tracks that have not yet been
+	/// yielded.
+	///
+	/// Note that the leadout is not part of the slice; the end of the last
+	/// remaining track is available separately via [`Tracks::leadout`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::Toc;
+	///
+	/// let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").unwrap();
+	/// let mut tracks = toc.audio_tracks();
+	/// assert_eq!(tracks.remaining_sectors(), &[150, 11563, 25174, 45863]);
+	///
+	/// let _ = tracks.next();
+	/// assert_eq!(tracks.remaining_sectors(), &[11563, 25174, 45863]);
+	/// ```
+	pub fn remaining_sectors(&self) -> &'a [u32] {
+		self.tracks.get(self.pos..).unwrap_or_default()
+	}
 }
 
 
@@ -352,6 +1012,60 @@ macro_rules! pos_tuple {
 
 pos_tuple!(u8, u16, u32, u64, usize);
 
+impl fmt::Display for TrackPosition {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.pad(self.as_str()) }
+}
+
+impl FromStr for TrackPosition {
+	type Err = TocError;
+	#[inline]
+	fn from_str(src: &str) -> Result<Self, Self::Err> { Self::decode(src) }
+}
+
+impl TryFrom<&str> for TrackPosition {
+	type Error = TocError;
+	#[inline]
+	fn try_from(src: &str) -> Result<Self, Self::Error> { Self::decode(src) }
+}
+
+impl TrackPosition {
+	/// # Decode.
+	///
+	/// Convert a string — as returned by [`TrackPosition::as_str`] — back
+	/// into a [`TrackPosition`].
+	///
+	/// Matching is case-sensitive.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::TrackPosition;
+	///
+	/// assert_eq!(TrackPosition::decode("First"), Ok(TrackPosition::First));
+	/// assert_eq!("Last".parse::<TrackPosition>(), Ok(TrackPosition::Last));
+	///
+	/// // Typos are errors, not invalid positions.
+	/// assert!(TrackPosition::decode("Frist").is_err());
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the string does not match any of the
+	/// variants.
+	pub fn decode<S>(src: S) -> Result<Self, TocError>
+	where S: AsRef<str> {
+		match src.as_ref() {
+			"Invalid" => Ok(Self::Invalid),
+			"First" => Ok(Self::First),
+			"Middle" => Ok(Self::Middle),
+			"Last" => Ok(Self::Last),
+			"Only" => Ok(Self::Only),
+			_ => Err(TocError::TrackPositionDecode),
+		}
+	}
+}
+
 impl TrackPosition {
 	#[must_use]
 	/// # Is Valid?
@@ -403,6 +1117,15 @@ impl TrackPosition {
 	/// # As Str.
 	///
 	/// Return the equivalent string slice for the variant.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use cdtoc::TrackPosition;
+	///
+	/// assert_eq!(TrackPosition::Middle.as_str(), "Middle");
+	/// assert_eq!(TrackPosition::Middle.to_string(), "Middle");
+	/// ```
 	pub const fn as_str(self) -> &'static str {
 		match self {
 			Self::Invalid => "Invalid",
@@ -416,18 +1139,189 @@ impl TrackPosition {
 
 
 
-#[expect(clippy::cast_possible_truncation, reason = "False positive.")]
-/// # LBA to MSF.
-///
-/// Convert a logical block address (sectors) to minutes, seconds, and frames.
-const fn lba_to_msf(sectors: u32) -> (u32, u8, u8) {
-	// 75 sectors per second.
-	let mut s = sectors.wrapping_div(75);
-	let f = sectors - s * 75;
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Toc;
+
+	#[test]
+	fn t_new_htoa() {
+		// Bad ranges.
+		assert_eq!(Track::new_htoa(0, 9342), Err(TocError::LeadinSize(0)));
+		assert_eq!(Track::new_htoa(149, 9342), Err(TocError::LeadinSize(149)));
+		assert_eq!(Track::new_htoa(150, 150), Err(TocError::SectorOrder(150, 150)));
+		assert_eq!(Track::new_htoa(9342, 150), Err(TocError::SectorOrder(9342, 150)));
+
+		// Good ranges should be HTOA all the way down.
+		let htoa = Track::new_htoa(150, 9342).expect("HTOA failed.");
+		assert!(htoa.is_htoa());
+		assert_eq!(htoa.number(), 0);
+		assert!(! htoa.position().is_valid());
+		assert_eq!(htoa.sectors(), 9192);
+
+		// Regular tracks never are.
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("TOC failed.");
+		for t in toc.audio_tracks() {
+			assert!(! t.is_htoa());
+			assert_ne!(t.number(), 0);
+		}
+	}
+
+	#[test]
+	fn t_tracks_peek() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("TOC failed.");
+		let all = toc.audio_sectors();
+		let mut tracks = toc.audio_tracks();
+		assert_eq!(tracks.as_toc_slice(), all);
+		assert_eq!(tracks.leadout(), 55_370);
+
+		for i in 0..all.len() {
+			assert_eq!(tracks.remaining_sectors(), &all[i..]);
+			assert_eq!(tracks.len(), all.len() - i);
+
+			// Peeks are non-consuming and match what next will give.
+			let peek = tracks.peek();
+			assert_eq!(peek, tracks.peek());
+			assert_eq!(peek, toc.audio_track(i + 1));
 
-	// 60 seconds per minute.
-	let m = s.wrapping_div(60);
-	s -= m * 60;
+			// Forks pick up where the parent left off, without affecting it.
+			let mut fork = tracks.clone();
+			assert_eq!(fork.next(), peek);
+			assert_eq!(tracks.len(), all.len() - i);
 
-	(m, s as u8, f as u8)
+			assert_eq!(tracks.next(), peek);
+			assert_eq!(tracks.as_toc_slice(), all);
+		}
+
+		// The last track should end at the leadout.
+		let last = toc.audio_track(all.len()).expect("Missing last track.");
+		assert_eq!(last.sector_range().end, tracks.leadout());
+
+		// Everything should be empty now.
+		assert!(tracks.remaining_sectors().is_empty());
+		assert!(tracks.peek().is_none());
+		assert!(tracks.next().is_none());
+		assert!(tracks.clone().next().is_none());
+	}
+
+	#[test]
+	fn t_approx_eq() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("TOC failed.");
+		for t in toc.audio_tracks() {
+			// Everything should match itself at zero tolerance.
+			assert!(t.approx_eq(&t, 0));
+			assert!(t.same_length_within(&t, 0));
+
+			// Shift the end by a sector.
+			let mut t2 = t;
+			t2.to += 1;
+			assert!(! t.approx_eq(&t2, 0));
+			assert!(t.approx_eq(&t2, 1));
+			assert!(! t.same_length_within(&t2, 0));
+			assert!(t.same_length_within(&t2, 1));
+
+			// Shift the whole thing; the length is unchanged.
+			let mut t2 = t;
+			t2.from += 100;
+			t2.to += 100;
+			assert!(! t.approx_eq(&t2, 99));
+			assert!(t.approx_eq(&t2, 100));
+			assert!(t.same_length_within(&t2, 0));
+
+			// Tolerance is symmetrical.
+			assert_eq!(t.approx_eq(&t2, 99), t2.approx_eq(&t, 99));
+		}
+	}
+
+	#[test]
+	fn t_sector_range_with_offset() {
+		let toc = Toc::from_cdtoc("4+96+2D2B+6256+B327+D84A").expect("Invalid TOC.");
+		for track in toc.audio_tracks() {
+			// No offset, no change.
+			let range = track.sector_range_with_offset(SignedDuration::from_samples(0));
+			assert_eq!(range.sectors(), track.sector_range());
+			assert_eq!(range.leading_samples(), 0);
+			assert_eq!(range.trailing_samples(), 0);
+			assert_eq!(range.padding_start(), 0);
+			assert_eq!(range.padding_end(), 0);
+
+			// Whole-sector offsets shouldn't leave partials either.
+			let range = track.sector_range_with_offset(SignedDuration::from_samples(588));
+			assert_eq!(range.leading_samples(), 0);
+
+			// The total should always add up.
+			for offset in [-2940, -667, -30, -1, 1, 30, 667, 2940] {
+				assert_eq!(
+					track.sector_range_with_offset(SignedDuration::from_samples(offset)).samples(),
+					track.samples(),
+					"Track #{} sample mismatch with offset {offset}.",
+					track.number(),
+				);
+			}
+		}
+
+		// Negative offsets in the middle of the disc shift backward.
+		let track = toc.audio_track(2).expect("Missing track #2.");
+		let range = track.sector_range_with_offset(SignedDuration::from_samples(-30));
+		assert_eq!(range.sectors(), 11_562..25_174);
+		assert_eq!(range.leading_samples(), 558);
+		assert_eq!(range.trailing_samples(), 30);
+		assert_eq!(range.padding_start(), 0);
+		assert_eq!(range.padding_end(), 0);
+
+		// Track one can't go back past the first sector.
+		let track = toc.audio_track(1).expect("Missing track #1.");
+		let range = track.sector_range_with_offset(SignedDuration::from_samples(-30));
+		assert_eq!(range.sectors(), 150..11_563);
+		assert_eq!(range.leading_samples(), 0);
+		assert_eq!(range.trailing_samples(), 30);
+		assert_eq!(range.padding_start(), 30);
+		assert_eq!(range.padding_end(), 0);
+
+		// But it can move forward just fine.
+		let range = track.sector_range_with_offset(SignedDuration::from_samples(667));
+		assert_eq!(range.sectors(), 151..11_565);
+		assert_eq!(range.padding_start(), 0);
+
+		// The last track can't go past the leadout.
+		let track = toc.audio_track(4).expect("Missing track #4.");
+		let range = track.sector_range_with_offset(SignedDuration::from_samples(667));
+		assert_eq!(range.sectors(), 45_864..55_370);
+		assert_eq!(range.leading_samples(), 79);
+		assert_eq!(range.trailing_samples(), 0);
+		assert_eq!(range.padding_start(), 0);
+		assert_eq!(range.padding_end(), 667);
+
+		// But backward is fine.
+		let range = track.sector_range_with_offset(SignedDuration::from_samples(-667));
+		assert_eq!(range.sectors(), 45_861..55_369);
+		assert_eq!(range.padding_end(), 0);
+
+		// Shifting the last track past the leadout leaves nothing but
+		// padding at the end.
+		let range = track.sector_range_with_offset(SignedDuration::from_samples(10_000 * 588));
+		assert_eq!(range.sectors(), 55_370..55_370);
+		assert_eq!(range.leading_samples(), 0);
+		assert_eq!(range.trailing_samples(), 0);
+		assert_eq!(range.padding_start(), 0);
+		assert_eq!(range.padding_end(), track.samples());
+		assert_eq!(range.samples(), track.samples());
+
+		// Absurd offsets should be all padding rather than a panic, on the
+		// side the shift pushes toward.
+		for track in toc.audio_tracks() {
+			let range = track.sector_range_with_offset(SignedDuration::MIN);
+			assert_eq!(range.sectors(), 150..150);
+			assert_eq!(range.padding_start(), track.samples());
+			assert_eq!(range.padding_end(), 0);
+			assert_eq!(range.samples(), track.samples());
+
+			let range = track.sector_range_with_offset(SignedDuration::MAX);
+			let end = if track.position().is_last() { 55_370 } else { u32::MAX };
+			assert_eq!(range.sectors(), end..end);
+			assert_eq!(range.padding_start(), 0);
+			assert_eq!(range.padding_end(), track.samples());
+			assert_eq!(range.samples(), track.samples());
+		}
+	}
 }
@@ -0,0 +1,351 @@
+/*!
+# CDTOC: XML
+
+This is shared by the CTDB and MusicBrainz modules.
+*/
+
+#![expect(clippy::redundant_pub_crate, reason = "False positive.")]
+
+use std::borrow::Cow;
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # XML Tag.
+///
+/// This is a single (opening, closing, or self-closing) element tag, with
+/// its attributes — and the text preceding it — left raw until requested.
+///
+/// This is only meant for the simple, predictable responses served by CTDB
+/// and MusicBrainz; it is not a general-purpose XML parser.
+pub(super) struct Tag<'a> {
+	/// # Element Name.
+	name: &'a str,
+
+	/// # Raw Attributes.
+	attrs: &'a str,
+
+	#[cfg(feature = "musicbrainz")]
+	/// # Raw Preceding Text.
+	text: &'a str,
+
+	#[cfg(feature = "ctdb")]
+	/// # Position.
+	///
+	/// The byte offset of the opening `<` within the document.
+	pos: usize,
+
+	/// # Closing Tag?
+	closing: bool,
+
+	/// # Self-Closing Tag?
+	self_closing: bool,
+}
+
+impl<'a> Tag<'a> {
+	/// # Local Name.
+	///
+	/// Return the name without its namespace prefix, if any.
+	pub(super) fn local_name(&self) -> &'a str {
+		self.name.rsplit(':').next().unwrap_or(self.name)
+	}
+
+	/// # Is Closing?
+	pub(super) const fn is_closing(&self) -> bool { self.closing }
+
+	/// # Is Self-Closing?
+	pub(super) const fn is_self_closing(&self) -> bool { self.self_closing }
+
+	/// # Attribute.
+	///
+	/// Return the unescaped value of the named attribute, if present.
+	pub(super) fn attr(&self, name: &str) -> Option<Cow<'a, str>> {
+		self.attrs().find_map(|(k, v)| if k == name { Some(unescape(v)) } else { None })
+	}
+
+	/// # Attributes.
+	///
+	/// Return an iterator over the raw `(name, value)` attribute pairs.
+	/// Anything that doesn't look like an attribute is skipped.
+	pub(super) const fn attrs(&self) -> Attrs<'a> { Attrs(self.attrs) }
+
+	#[cfg(feature = "ctdb")]
+	/// # Line Number.
+	///
+	/// Return the (one-based) line number the tag starts on within `src`,
+	/// the document it was parsed from.
+	pub(super) fn line(&self, src: &str) -> usize { line_number(src, self.pos) }
+
+	#[cfg(feature = "musicbrainz")]
+	/// # Text.
+	///
+	/// Return the trimmed, unescaped text between the previous tag (or
+	/// comment, etc.) and this one. For the closing tag of a simple element,
+	/// this is its content.
+	pub(super) fn text(&self) -> Cow<'a, str> { unescape(self.text.trim()) }
+}
+
+
+
+/// # Attribute Iterator.
+pub(super) struct Attrs<'a>(&'a str);
+
+impl<'a> Iterator for Attrs<'a> {
+	type Item = (&'a str, &'a str);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let src = self.0.trim_start();
+			if src.is_empty() { return None; }
+
+			// Find the name and equal sign.
+			let Some(eq) = src.find('=') else {
+				self.0 = "";
+				return None;
+			};
+			let name = src[..eq].trim();
+			let rest = src[eq + 1..].trim_start();
+
+			// The value should be quoted.
+			let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+				// Skip ahead to the next whitespace and try again.
+				let next = rest.find(char::is_whitespace).unwrap_or(rest.len());
+				self.0 = &rest[next..];
+				continue;
+			};
+			let rest = &rest[1..];
+			let Some(end) = rest.find(quote) else {
+				self.0 = "";
+				return None;
+			};
+			self.0 = &rest[end + 1..];
+
+			// Names can't be empty or contain whitespace; a bare word before the
+			// name gets dropped.
+			let name = name.rsplit(char::is_whitespace).next().unwrap_or(name);
+			if ! name.is_empty() { return Some((name, &rest[..end])); }
+		}
+	}
+}
+
+
+
+/// # Tag Iterator.
+///
+/// Iterate over the element tags in a document, skipping text, comments,
+/// declarations, and processing instructions.
+///
+/// If a tag is left unterminated, the iterator will yield an `Err` with the
+/// (one-based) line number it started on, then stop.
+pub(super) struct Tags<'a> {
+	/// # Full Source.
+	src: &'a str,
+
+	/// # Position.
+	pos: usize,
+}
+
+impl<'a> Tags<'a> {
+	/// # New.
+	pub(super) const fn new(src: &'a str) -> Self { Self { src, pos: 0 } }
+
+	/// # Fail.
+	///
+	/// Stop iteration, returning the line number of the given position.
+	fn fail(&mut self, pos: usize) -> Result<Tag<'a>, usize> {
+		self.pos = self.src.len();
+		Err(line_number(self.src, pos))
+	}
+}
+
+impl<'a> Iterator for Tags<'a> {
+	type Item = Result<Tag<'a>, usize>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let rest = self.src.get(self.pos..)?;
+			#[cfg(feature = "musicbrainz")] let text_start = self.pos;
+			let start = self.pos + rest.find('<')?;
+			let rest = &self.src[start + 1..];
+
+			// Comments.
+			if let Some(rest) = rest.strip_prefix("!--") {
+				let Some(end) = rest.find("-->") else { return Some(self.fail(start)); };
+				self.pos = start + 4 + end + 3;
+				continue;
+			}
+
+			// Attribute values may contain a literal `>`, so we need to keep
+			// track of quotes while looking for the end.
+			let mut quote = None;
+			let Some(end) = rest.find(|c: char|
+				if let Some(q) = quote {
+					if c == q { quote = None; }
+					false
+				}
+				else if matches!(c, '"' | '\'') {
+					quote = Some(c);
+					false
+				}
+				else { c == '>' }
+			) else { return Some(self.fail(start)); };
+			self.pos = start + 1 + end + 1;
+
+			// Skip declarations and processing instructions.
+			let inner = &rest[..end];
+			if inner.starts_with(['?', '!']) { continue; }
+
+			let (closing, inner) = inner.strip_prefix('/').map_or((false, inner), |v| (true, v));
+			let (self_closing, inner) = inner.strip_suffix('/').map_or((false, inner), |v| (true, v));
+
+			let inner = inner.trim_start();
+			let split = inner.find(char::is_whitespace).unwrap_or(inner.len());
+			let (name, attrs) = inner.split_at(split);
+			if name.is_empty() { return Some(self.fail(start)); }
+
+			return Some(Ok(Tag {
+				name,
+				attrs,
+				#[cfg(feature = "musicbrainz")] text: &self.src[text_start..start],
+				#[cfg(feature = "ctdb")] pos: start,
+				closing,
+				self_closing,
+			}));
+		}
+	}
+}
+
+
+
+/// # Line Number.
+///
+/// Return the (one-based) line number corresponding to the byte position.
+fn line_number(src: &str, pos: usize) -> usize {
+	src.as_bytes().iter().take(pos).filter(|b| **b == b'\n').count() + 1
+}
+
+/// # Unescape.
+///
+/// Decode the five standard XML entities along with numeric character
+/// references. Anything unrecognized is left as-is.
+pub(super) fn unescape(src: &str) -> Cow<'_, str> {
+	if ! src.contains('&') { return Cow::Borrowed(src); }
+
+	let mut out = String::with_capacity(src.len());
+	let mut rest = src;
+	while let Some(pos) = rest.find('&') {
+		out.push_str(&rest[..pos]);
+		rest = &rest[pos..];
+
+		let decoded = rest.find(';').and_then(|end| {
+			let c = match &rest[1..end] {
+				"amp" => '&',
+				"apos" => '\'',
+				"gt" => '>',
+				"lt" => '<',
+				"quot" => '"',
+				v => {
+					let v = v.strip_prefix('#')?;
+					let n = match v.strip_prefix(['x', 'X']) {
+						Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+						None => v.parse::<u32>().ok()?,
+					};
+					char::from_u32(n)?
+				},
+			};
+			Some((c, end + 1))
+		});
+
+		if let Some((c, len)) = decoded {
+			out.push(c);
+			rest = &rest[len..];
+		}
+		else {
+			out.push('&');
+			rest = &rest[1..];
+		}
+	}
+	out.push_str(rest);
+
+	Cow::Owned(out)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_unescape() {
+		for (raw, expected) in [
+			("Plain", "Plain"),
+			("Simon &amp; Garfunkel", "Simon & Garfunkel"),
+			("&quot;Quoted&quot; &apos;too&apos;", "\"Quoted\" 'too'"),
+			("&lt;b&gt;", "<b>"),
+			("Bj&#246;rk &#x2014; Hom&#xE9;genic", "Björk — Homégenic"),
+			("AT&T; & &bogus; &#xZZ; &", "AT&T; & &bogus; &#xZZ; &"),
+		] {
+			assert_eq!(unescape(raw), expected, "{raw}");
+		}
+	}
+
+	#[test]
+	fn t_tags() {
+		let raw = r#"<?xml version="1.0"?>
+<!-- A <comment>. -->
+<ctdb xmlns="http://db.cuetools.net/ns/mmd-1.0#">
+	<entry confidence="3" note='a > b'
+		trackcrcs="01 02" />
+	<metadata artist="A &amp; B" bogus title="T">
+		<track name="One"/>
+	</metadata>
+</ctdb>"#;
+
+		let tags = Tags::new(raw).collect::<Result<Vec<_>, _>>().expect("Tags failed.");
+		assert_eq!(
+			tags.iter().map(|t| (t.name, t.is_closing(), t.is_self_closing())).collect::<Vec<_>>(),
+			[
+				("ctdb", false, false),
+				("entry", false, true),
+				("metadata", false, false),
+				("track", false, true),
+				("metadata", true, false),
+				("ctdb", true, false),
+			],
+		);
+
+		assert_eq!(tags[1].attr("confidence").as_deref(), Some("3"));
+		assert_eq!(tags[1].attr("note").as_deref(), Some("a > b"));
+		assert_eq!(tags[1].attr("trackcrcs").as_deref(), Some("01 02"));
+		assert_eq!(tags[1].attr("missing"), None);
+		assert_eq!(tags[2].attr("artist").as_deref(), Some("A & B"));
+		assert_eq!(tags[2].attr("title").as_deref(), Some("T"));
+
+		// Unterminated tags report their starting line.
+		let mut tags = Tags::new("<ctdb>\n<entry\nconfidence=\"1\"");
+		assert!(matches!(tags.next(), Some(Ok(_))));
+		assert_eq!(tags.next().map(|t| t.map(|t| t.name)), Some(Err(2)));
+		assert!(tags.next().is_none());
+	}
+
+	#[cfg(feature = "musicbrainz")]
+	#[test]
+	fn t_text() {
+		let tags = Tags::new("<a><b>  Simon &amp; Garfunkel </b><!-- x -->\n<mb:c>Hi</mb:c></a>")
+			.collect::<Result<Vec<_>, _>>()
+			.expect("Tags failed.");
+		assert_eq!(
+			tags.iter().map(|t| (t.local_name(), t.text())).collect::<Vec<_>>(),
+			[
+				("a", "".into()),
+				("b", "".into()),
+				("b", "Simon & Garfunkel".into()),
+				("c", "".into()),
+				("c", "Hi".into()),
+				("a", "".into()),
+			],
+		);
+		assert_eq!(tags[3].name, "mb:c");
+	}
+}